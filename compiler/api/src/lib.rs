@@ -0,0 +1,345 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+#![forbid(unsafe_code)]
+#![doc = include_str!("../README.md")]
+
+use leo_compiler::{Sandbox, SandboxOutput};
+use leo_errors::emitter::DiagnosticRecord;
+
+use indexmap::IndexMap;
+use snarkvm::{
+    circuit::AleoV0,
+    prelude::{Identifier, PrivateKey, Process, Program, Testnet3, Value},
+};
+use std::{fmt, str::FromStr, time::Duration};
+
+type CurrentNetwork = Testnet3;
+
+/// The result of a call to this crate.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// The options of a call, which are built from [`Options::default`] with the `with_*` methods.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Options {
+    imports: IndexMap<String, String>,
+    dce_enabled: bool,
+    max_source_size: usize,
+    time_limit: Duration,
+    max_nodes: usize,
+    max_constraints: u64,
+    private_key: Option<String>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        let sandbox = Sandbox::default();
+        Self {
+            imports: sandbox.imports,
+            dce_enabled: sandbox.build.dce_enabled,
+            max_source_size: sandbox.max_source_size,
+            time_limit: sandbox.time_limit,
+            max_nodes: sandbox.max_nodes,
+            max_constraints: sandbox.max_constraints,
+            private_key: None,
+        }
+    }
+}
+
+impl Options {
+    /// Makes the program `name` importable as `import {name}.leo;`, from its `source`.
+    /// A program imported again under the same name replaces the previous one.
+    pub fn with_import(mut self, name: impl Into<String>, source: impl Into<String>) -> Self {
+        self.imports.insert(name.into(), source.into());
+        self
+    }
+
+    /// Sets whether dead code elimination is enabled, which it is by default.
+    pub fn with_dce(mut self, enabled: bool) -> Self {
+        self.dce_enabled = enabled;
+        self
+    }
+
+    /// Sets the size each source may have, in bytes.
+    pub fn with_max_source_size(mut self, bytes: usize) -> Self {
+        self.max_source_size = bytes;
+        self
+    }
+
+    /// Sets the time a call may take to compile a program. It is checked between the stages of the compiler.
+    pub fn with_time_limit(mut self, time_limit: Duration) -> Self {
+        self.time_limit = time_limit;
+        self
+    }
+
+    /// Sets the number of AST nodes a call may create.
+    pub fn with_max_nodes(mut self, nodes: usize) -> Self {
+        self.max_nodes = nodes;
+        self
+    }
+
+    /// Sets the number of constraints each transition is estimated to have, beyond which it does not compile.
+    pub fn with_max_constraints(mut self, constraints: u64) -> Self {
+        self.max_constraints = constraints;
+        self
+    }
+
+    /// Sets the private key executions are signed with, e.g. `APrivateKey1...`.
+    /// By default, each execution is signed with a new key.
+    pub fn with_private_key(mut self, private_key: impl Into<String>) -> Self {
+        self.private_key = Some(private_key.into());
+        self
+    }
+
+    /// Returns the sandbox that programs are compiled in.
+    fn sandbox(&self) -> Sandbox {
+        let mut sandbox = Sandbox { imports: self.imports.clone(), ..Default::default() };
+        sandbox.build.dce_enabled = self.dce_enabled;
+        sandbox.max_source_size = self.max_source_size;
+        sandbox.time_limit = self.time_limit;
+        sandbox.max_nodes = self.max_nodes;
+        sandbox.max_constraints = self.max_constraints;
+        sandbox
+    }
+}
+
+/// Whether a diagnostic is an error or a warning.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Severity {
+    /// The program does not compile.
+    Error,
+    /// The program compiles, but likely has a bug.
+    Warning,
+}
+
+/// An error or a warning reported by the compiler.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Diagnostic {
+    /// Whether the diagnostic is an error or a warning.
+    pub severity: Severity,
+    /// The code of the diagnostic, e.g. `ETYC0372003`, if it has one.
+    pub code: Option<String>,
+    /// The text of the diagnostic.
+    pub message: String,
+    /// Where the diagnostic points, as `file:line:column`, if it points anywhere.
+    pub location: Option<String>,
+}
+
+impl From<DiagnosticRecord> for Diagnostic {
+    fn from(record: DiagnosticRecord) -> Self {
+        let severity = match record.severity {
+            leo_errors::emitter::Severity::Error => Severity::Error,
+            leo_errors::emitter::Severity::Warning => Severity::Warning,
+        };
+        Self { severity, code: record.code, message: record.message, location: record.location }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.severity {
+            Severity::Error => write!(f, "error")?,
+            Severity::Warning => write!(f, "warning")?,
+        }
+        if let Some(code) = &self.code {
+            write!(f, " [{code}]")?;
+        }
+        write!(f, ": {}", self.message)?;
+        if let Some(location) = &self.location {
+            write!(f, " at {location}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Why a call failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The program does not compile. The diagnostics hold its errors, along with its warnings.
+    Compilation(Vec<Diagnostic>),
+    /// The program could not be executed, e.g. because an input is malformed or an assertion failed.
+    Execution(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Compilation(diagnostics) => {
+                let errors = diagnostics.iter().filter(|diagnostic| diagnostic.severity == Severity::Error);
+                write!(f, "the program does not compile, with {} error(s)", errors.count())
+            }
+            Self::Execution(message) => write!(f, "the program could not be executed: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The outcome of a successful call.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Output<T> {
+    /// What the call produced, e.g. the bytecode of the program.
+    pub value: T,
+    /// The warnings reported by the compiler.
+    pub warnings: Vec<Diagnostic>,
+}
+
+/// An output of the compiler, selected in [`emit`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Emit {
+    /// The AST of the program as parsed, as JSON. Its layout may change with any release of the compiler.
+    Ast,
+    /// The program as formatted Leo source. Programs with comments cannot be formatted, since the AST does not keep
+    /// them.
+    Formatted,
+    /// The program as Leo source after constant propagation, loop unrolling, and inlining.
+    Expanded,
+    /// The Aleo bytecode of the program.
+    Bytecode,
+}
+
+/// Returns the value of a sandboxed call, or its diagnostics if the program has errors.
+fn finish<T>(diagnostics: Vec<DiagnosticRecord>, value: Option<T>) -> Result<Output<T>> {
+    let diagnostics = diagnostics.into_iter().map(Diagnostic::from).collect::<Vec<_>>();
+    match value {
+        Some(value) if diagnostics.iter().all(|diagnostic| diagnostic.severity != Severity::Error) => {
+            Ok(Output { value, warnings: diagnostics })
+        }
+        _ => Err(Error::Compilation(diagnostics)),
+    }
+}
+
+/// Returns an error raised while executing a program.
+fn execution_error(error: impl fmt::Display) -> Error {
+    Error::Execution(error.to_string())
+}
+
+/// Compiles the program `program_name` from its `source`, and returns its Aleo bytecode.
+pub fn compile(program_name: &str, source: &str, options: &Options) -> Result<Output<String>> {
+    emit(program_name, source, Emit::Bytecode, options)
+}
+
+/// Checks the program `program_name` from its `source`, like `leo check`, without generating its bytecode.
+pub fn check(program_name: &str, source: &str, options: &Options) -> Result<Output<()>> {
+    let output = options.sandbox().check(program_name, source);
+    finish(output.diagnostics, Some(()))
+}
+
+/// Compiles the program `program_name` from its `source` as far as needed, and returns the output selected by `emit`.
+pub fn emit(program_name: &str, source: &str, emit: Emit, options: &Options) -> Result<Output<String>> {
+    let sandbox = options.sandbox();
+    let (diagnostics, value) = match emit {
+        Emit::Ast => {
+            let SandboxOutput { ast, diagnostics, .. } = sandbox.parse(program_name, source);
+            (diagnostics, ast)
+        }
+        Emit::Formatted => {
+            let SandboxOutput { source, diagnostics, .. } = sandbox.format(program_name, source);
+            (diagnostics, source)
+        }
+        Emit::Expanded => {
+            let SandboxOutput { source, diagnostics, .. } = sandbox.expand(program_name, source);
+            (diagnostics, source)
+        }
+        Emit::Bytecode => {
+            let SandboxOutput { bytecode, diagnostics, .. } = sandbox.compile(program_name, source);
+            (diagnostics, bytecode)
+        }
+    };
+    finish(diagnostics, value)
+}
+
+/// Executes `function` of the compiled program `bytecode` on `inputs`, and returns its outputs.
+/// Inputs and outputs are written in the syntax of Aleo values, e.g. `1u32`. The compiled programs that `bytecode`
+/// imports are given in `imports`, each after the programs it imports in turn. The function is evaluated with snarkVM,
+/// but no proof is produced.
+pub fn execute(
+    bytecode: &str,
+    function: &str,
+    inputs: &[&str],
+    imports: &[&str],
+    options: &Options,
+) -> Result<Vec<String>> {
+    let rng = &mut rand::thread_rng();
+
+    // Load the imported programs, then the program itself.
+    let mut process = Process::<CurrentNetwork>::load().map_err(execution_error)?;
+    let mut program_id = None;
+    for bytecode in imports.iter().chain([&bytecode]) {
+        let program = Program::<CurrentNetwork>::from_str(bytecode).map_err(execution_error)?;
+        if !process.contains_program(program.id()) {
+            process.add_program(&program).map_err(execution_error)?;
+        }
+        program_id = Some(*program.id());
+    }
+
+    let private_key = match &options.private_key {
+        Some(private_key) => PrivateKey::<CurrentNetwork>::from_str(private_key),
+        None => PrivateKey::<CurrentNetwork>::new(rng),
+    }
+    .map_err(execution_error)?;
+    let function_name = Identifier::<CurrentNetwork>::from_str(function).map_err(execution_error)?;
+    let inputs = inputs
+        .iter()
+        .map(|input| Value::<CurrentNetwork>::from_str(input))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(execution_error)?;
+
+    // Evaluate the function, and each function that it calls.
+    let authorization = process
+        .authorize::<AleoV0, _>(&private_key, program_id.unwrap(), function_name, inputs.iter(), rng)
+        .map_err(execution_error)?;
+    let (response, _) = process.execute::<AleoV0, _>(authorization, rng).map_err(execution_error)?;
+    Ok(response.outputs().iter().map(|output| output.to_string()).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TOKEN: &str = "program token.aleo {\n    transition mint(a: u32) -> u32 {\n        return a;\n    }\n}\n";
+    const MAIN: &str = "import token.leo;\n\nprogram main.aleo {\n    transition main(a: u32) -> u32 {\n        return token.leo/mint(a);\n    }\n}\n";
+
+    #[test]
+    fn compiles_and_emits() {
+        let options = Options::default().with_import("token", TOKEN);
+        let output = compile("main", MAIN, &options).unwrap();
+        assert!(output.value.contains("call token.aleo/mint"));
+        assert_eq!(output.warnings, vec![]);
+
+        assert!(check("main", MAIN, &options).is_ok());
+        assert!(emit("main", MAIN, Emit::Ast, &options).unwrap().value.contains("\"program_scopes\""));
+        assert_eq!(emit("main", MAIN, Emit::Formatted, &options).unwrap().value, MAIN);
+        assert!(emit("main", MAIN, Emit::Expanded, &options).unwrap().value.contains("token.leo/mint(a)"));
+    }
+
+    #[test]
+    fn reports_errors() {
+        let Err(Error::Compilation(diagnostics)) = compile("main", MAIN, &Options::default()) else {
+            panic!("the import is missing");
+        };
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert!(diagnostics[0].to_string().starts_with("error [ECMP"));
+
+        let options = Options::default().with_import("token", TOKEN).with_max_source_size(8);
+        assert!(matches!(check("main", MAIN, &options), Err(Error::Compilation(_))));
+    }
+}
@@ -26,12 +26,12 @@ pub fn serialize<S: Serializer>(
     imported_modules: &IndexMap<Vec<Symbol>, Program>,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
-    let joined: IndexMap<String, Program> = with_session_globals(|s| {
+    let joined: IndexMap<String, &Program> = with_session_globals(|s| {
         imported_modules
             .into_iter()
             .map(|(package, program)| {
                 let package = package.iter().map(|x| x.as_str(s, |s| s.to_owned())).collect::<Vec<_>>();
-                (package.join("."), program.clone())
+                (package.join("."), program)
             })
             .collect()
     });
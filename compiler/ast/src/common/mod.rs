@@ -28,6 +28,9 @@ pub mod node;
 pub mod node_builder;
 pub use node_builder::*;
 
+pub mod node_index;
+pub use node_index::*;
+
 pub mod static_string;
 
 pub use static_string::*;
@@ -36,6 +36,12 @@ impl NodeBuilder {
     pub fn next_id(&self) -> NodeID {
         self.inner.borrow_mut().next_id()
     }
+
+    /// Returns the next `NodeID` without incrementing the internal state, which is the number of IDs produced so far
+    /// when counting from 0.
+    pub fn peek(&self) -> NodeID {
+        self.inner.borrow().next
+    }
 }
 
 impl Default for NodeBuilder {
@@ -0,0 +1,342 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+
+use indexmap::IndexMap;
+use leo_span::Span;
+
+/// The kind of a node in a [`NodeIndex`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NodeKind {
+    Annotation,
+    Struct,
+    Member,
+    Mapping,
+    Function,
+    Finalize,
+    Input,
+    Output,
+    Block,
+    Statement,
+    Expression,
+    StructInitializer,
+    Identifier,
+}
+
+/// A node of the AST, as recorded by a [`NodeIndex`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexedNode {
+    /// The kind of the node.
+    pub kind: NodeKind,
+    /// The span of the node.
+    pub span: Span,
+    /// The node containing this node, or `None` for the declarations of a program scope.
+    pub parent: Option<NodeID>,
+    /// The nodes contained in this node, in the order in which they appear in the source.
+    pub children: Vec<NodeID>,
+    /// The span covering the node and the nodes it contains.
+    /// It differs from `span` for a function with a finalize block, which follows the span of the function.
+    extent: Span,
+}
+
+/// An index of the nodes of a program and its imports by ID, with the parent and children of each node.
+///
+/// The index lets tools navigate from a location in the source to the innermost node at that location, and up the
+/// chain of its ancestors. Since node IDs are assigned in the order in which nodes are parsed, they are the same each
+/// time a program is parsed. The index must be rebuilt after a pass creates or removes nodes.
+#[derive(Clone, Debug, Default)]
+pub struct NodeIndex {
+    /// The indexed nodes.
+    nodes: IndexMap<NodeID, IndexedNode>,
+    /// The declarations of the program scopes, which have no parent.
+    roots: Vec<NodeID>,
+    /// The IDs of the nodes being indexed, from the outermost to the innermost.
+    parents: Vec<NodeID>,
+}
+
+impl NodeIndex {
+    /// Indexes the nodes of the program and its imports.
+    pub fn new(program: &Program) -> Self {
+        let mut index = Self::default();
+        index.program(program);
+        index
+    }
+
+    /// Returns the node with the given ID.
+    pub fn get(&self, id: NodeID) -> Option<&IndexedNode> {
+        self.nodes.get(&id)
+    }
+
+    /// Returns the indexed nodes, in the order in which they appear in the source.
+    pub fn iter(&self) -> impl Iterator<Item = (NodeID, &IndexedNode)> {
+        self.nodes.iter().map(|(id, node)| (*id, node))
+    }
+
+    /// Returns the number of indexed nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if no nodes are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the ID of the node containing the node with the given ID.
+    pub fn parent(&self, id: NodeID) -> Option<NodeID> {
+        self.get(id).and_then(|node| node.parent)
+    }
+
+    /// Returns the IDs of the nodes contained in the node with the given ID.
+    pub fn children(&self, id: NodeID) -> &[NodeID] {
+        self.get(id).map_or(&[], |node| node.children.as_slice())
+    }
+
+    /// Returns the IDs of the ancestors of the node with the given ID, from its parent to the declaration containing it.
+    pub fn ancestors(&self, id: NodeID) -> impl Iterator<Item = NodeID> + '_ {
+        std::iter::successors(self.parent(id), move |id| self.parent(*id))
+    }
+
+    /// Returns the ID of the innermost node whose span contains `span`, e.g. the identifier under a cursor.
+    pub fn innermost(&self, span: Span) -> Option<NodeID> {
+        let contains = |id: &&NodeID| {
+            let extent = self.nodes[*id].extent;
+            extent.lo <= span.lo && span.hi <= extent.hi
+        };
+        let mut innermost = *self.roots.iter().find(contains)?;
+        while let Some(child) = self.children(innermost).iter().find(contains) {
+            innermost = *child;
+        }
+        Some(innermost)
+    }
+
+    /// Records a node, and then the nodes it contains with `children`.
+    fn node(&mut self, kind: NodeKind, span: Span, id: NodeID, children: impl FnOnce(&mut Self)) {
+        let parent = self.parents.last().copied();
+        self.nodes.insert(id, IndexedNode { kind, span, parent, children: Vec::new(), extent: span });
+        match parent {
+            Some(parent) => self.nodes[&parent].children.push(id),
+            None => self.roots.push(id),
+        }
+        self.parents.push(id);
+        children(self);
+        self.parents.pop();
+        if let Some(parent) = parent {
+            let extent = self.nodes[&id].extent;
+            self.nodes[&parent].extent = self.nodes[&parent].extent + extent;
+        }
+    }
+
+    fn program(&mut self, program: &Program) {
+        program.imports.values().for_each(|(import, _)| self.program(import));
+        for scope in program.program_scopes.values() {
+            scope.consts.iter().for_each(|(_, const_)| self.const_declaration(const_));
+            scope.structs.iter().for_each(|(_, struct_)| self.struct_(struct_));
+            scope.mappings.iter().for_each(|(_, mapping)| self.mapping(mapping));
+            scope.functions.iter().for_each(|(_, function)| self.function(function));
+        }
+    }
+
+    fn identifier(&mut self, identifier: &Identifier) {
+        self.node(NodeKind::Identifier, identifier.span, identifier.id, |_| {});
+    }
+
+    fn annotation(&mut self, annotation: &Annotation) {
+        self.node(NodeKind::Annotation, annotation.span, annotation.id, |index| {
+            index.identifier(&annotation.identifier)
+        });
+    }
+
+    fn struct_(&mut self, struct_: &Struct) {
+        self.node(NodeKind::Struct, struct_.span, struct_.id, |index| {
+            struct_.annotations.iter().for_each(|annotation| index.annotation(annotation));
+            index.identifier(&struct_.identifier);
+            for member in struct_.members.iter() {
+                index.node(NodeKind::Member, member.span, member.id, |index| index.identifier(&member.identifier));
+            }
+        });
+    }
+
+    fn mapping(&mut self, mapping: &Mapping) {
+        self.node(NodeKind::Mapping, mapping.span, mapping.id, |index| index.identifier(&mapping.identifier));
+    }
+
+    fn function(&mut self, function: &Function) {
+        self.node(NodeKind::Function, function.span, function.id, |index| {
+            function.annotations.iter().for_each(|annotation| index.annotation(annotation));
+            index.identifier(&function.identifier);
+            function.input.iter().for_each(|input| index.input(input));
+            function.output.iter().for_each(|output| index.output(output));
+            index.block(&function.block);
+            if let Some(finalize) = &function.finalize {
+                index.node(NodeKind::Finalize, finalize.span, finalize.id, |index| {
+                    index.identifier(&finalize.identifier);
+                    finalize.input.iter().for_each(|input| index.input(input));
+                    finalize.output.iter().for_each(|output| index.output(output));
+                    index.block(&finalize.block);
+                });
+            }
+        });
+    }
+
+    fn input(&mut self, input: &Input) {
+        self.node(NodeKind::Input, input.span(), input.id(), |index| match input {
+            Input::Internal(input) => index.identifier(&input.identifier),
+            Input::External(input) => index.external(input),
+        });
+    }
+
+    fn output(&mut self, output: &Output) {
+        self.node(NodeKind::Output, output.span(), output.id(), |index| {
+            if let Output::External(output) = output {
+                index.external(output);
+            }
+        });
+    }
+
+    fn external(&mut self, external: &External) {
+        self.identifier(&external.identifier);
+        self.identifier(&external.program_name);
+        self.identifier(&external.record);
+    }
+
+    fn block(&mut self, block: &Block) {
+        self.node(NodeKind::Block, block.span, block.id, |index| {
+            block.statements.iter().for_each(|statement| index.statement(statement));
+        });
+    }
+
+    fn const_declaration(&mut self, const_: &ConstDeclaration) {
+        self.node(NodeKind::Statement, const_.span, const_.id, |index| {
+            index.identifier(&const_.place);
+            index.expression(&const_.value);
+        });
+    }
+
+    fn statement(&mut self, statement: &Statement) {
+        if let Statement::Block(block) = statement {
+            return self.block(block);
+        }
+        if let Statement::Const(const_) = statement {
+            return self.const_declaration(const_);
+        }
+        self.node(NodeKind::Statement, statement.span(), statement.id(), |index| match statement {
+            Statement::Assert(assert) => match &assert.variant {
+                AssertVariant::Assert(expression) => index.expression(expression),
+                AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
+                    index.expression(left);
+                    index.expression(right);
+                }
+                AssertVariant::Fail(_) => {}
+            },
+            Statement::Assign(assign) => {
+                index.expression(&assign.place);
+                index.expression(&assign.value);
+            }
+            Statement::Conditional(conditional) => {
+                index.expression(&conditional.condition);
+                index.block(&conditional.then);
+                if let Some(otherwise) = &conditional.otherwise {
+                    index.statement(otherwise);
+                }
+            }
+            Statement::Console(console) => match &console.function {
+                ConsoleFunction::Assert(expression) => index.expression(expression),
+                ConsoleFunction::AssertEq(left, right) | ConsoleFunction::AssertNeq(left, right) => {
+                    index.expression(left);
+                    index.expression(right);
+                }
+                ConsoleFunction::Error(_, arguments) | ConsoleFunction::Log(_, arguments) => {
+                    arguments.iter().for_each(|argument| index.expression(argument));
+                }
+            },
+            Statement::Definition(definition) => {
+                index.expression(&definition.place);
+                index.expression(&definition.value);
+            }
+            Statement::Expression(expression) => index.expression(&expression.expression),
+            Statement::Iteration(iteration) => {
+                index.identifier(&iteration.variable);
+                index.expression(&iteration.start);
+                index.expression(&iteration.stop);
+                index.block(&iteration.block);
+            }
+            Statement::Return(return_) => {
+                index.expression(&return_.expression);
+                return_.finalize_arguments.iter().flatten().for_each(|argument| index.expression(argument));
+            }
+            Statement::Block(_) | Statement::Const(_) => unreachable!("Blocks and constants are indexed above."),
+        });
+    }
+
+    fn expression(&mut self, expression: &Expression) {
+        // Identifiers are indexed as such, and not as expressions containing an identifier.
+        if let Expression::Identifier(identifier) = expression {
+            return self.identifier(identifier);
+        }
+        self.node(NodeKind::Expression, expression.span(), expression.id(), |index| match expression {
+            Expression::Access(access) => match access {
+                AccessExpression::Array(array) => {
+                    index.expression(&array.array);
+                    index.expression(&array.index);
+                }
+                AccessExpression::AssociatedConstant(constant) => index.identifier(&constant.name),
+                AccessExpression::AssociatedFunction(function) => {
+                    index.identifier(&function.name);
+                    function.arguments.iter().for_each(|argument| index.expression(argument));
+                }
+                AccessExpression::Member(member) => {
+                    index.expression(&member.inner);
+                    index.identifier(&member.name);
+                }
+                AccessExpression::Tuple(tuple) => index.expression(&tuple.tuple),
+            },
+            Expression::Array(array) => array.elements.iter().for_each(|element| index.expression(element)),
+            Expression::Binary(binary) => {
+                index.expression(&binary.left);
+                index.expression(&binary.right);
+            }
+            Expression::Call(call) => {
+                index.expression(&call.function);
+                call.arguments.iter().for_each(|argument| index.expression(argument));
+                if let Some(external) = &call.external {
+                    index.expression(external);
+                }
+            }
+            Expression::Cast(cast) => index.expression(&cast.expression),
+            Expression::Struct(struct_) => {
+                index.identifier(&struct_.name);
+                for member in struct_.members.iter() {
+                    index.node(NodeKind::StructInitializer, member.span, member.id, |index| {
+                        index.identifier(&member.identifier);
+                        if let Some(expression) = &member.expression {
+                            index.expression(expression);
+                        }
+                    });
+                }
+            }
+            Expression::Ternary(ternary) => {
+                index.expression(&ternary.condition);
+                index.expression(&ternary.if_true);
+                index.expression(&ternary.if_false);
+            }
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| index.expression(element)),
+            Expression::Unary(unary) => index.expression(&unary.receiver),
+            Expression::Err(_) | Expression::Identifier(_) | Expression::Literal(_) | Expression::Unit(_) => {}
+        });
+    }
+}
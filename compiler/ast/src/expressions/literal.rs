@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{GroupLiteral, IntegerType};
+use crate::{GroupLiteral, IntegerType, Type};
 
 use super::*;
 
@@ -39,6 +39,9 @@ pub enum Literal {
     Scalar(String, #[serde(with = "leo_span::span_json")] Span, NodeID),
     /// A string literal, e.g., `"foobar"`.
     String(String, #[serde(with = "leo_span::span_json")] Span, NodeID),
+    /// A number without a type suffix, e.g., `42`.
+    /// Its type is inferred during type checking.
+    Unsuffixed(String, #[serde(with = "leo_span::span_json")] Span, NodeID),
 }
 
 impl fmt::Display for Literal {
@@ -51,6 +54,7 @@ impl fmt::Display for Literal {
             Self::Integer(type_, value, _, _) => write!(f, "{value}{type_}"),
             Self::Scalar(scalar, _, _) => write!(f, "{scalar}scalar"),
             Self::String(string, _, _) => write!(f, "\"{string}\""),
+            Self::Unsuffixed(value, _, _) => write!(f, "{value}"),
         }
     }
 }
@@ -63,7 +67,8 @@ impl Node for Literal {
             | Self::Field(_, span, _)
             | Self::Integer(_, _, span, _)
             | Self::Scalar(_, span, _)
-            | Self::String(_, span, _) => *span,
+            | Self::String(_, span, _)
+            | Self::Unsuffixed(_, span, _) => *span,
             Self::Group(group) => *group.span(),
         }
     }
@@ -75,7 +80,8 @@ impl Node for Literal {
             | Self::Field(_, span, _)
             | Self::Integer(_, _, span, _)
             | Self::Scalar(_, span, _)
-            | Self::String(_, span, _) => *span = new_span,
+            | Self::String(_, span, _)
+            | Self::Unsuffixed(_, span, _) => *span = new_span,
             Self::Group(group) => group.set_span(new_span),
         }
     }
@@ -87,7 +93,8 @@ impl Node for Literal {
             | Self::Field(_, _, id)
             | Self::Integer(_, _, _, id)
             | Self::Scalar(_, _, id)
-            | Self::String(_, _, id) => *id,
+            | Self::String(_, _, id)
+            | Self::Unsuffixed(_, _, id) => *id,
             Self::Group(group) => *group.id(),
         }
     }
@@ -99,8 +106,52 @@ impl Node for Literal {
             | Self::Field(_, _, old_id)
             | Self::Integer(_, _, _, old_id)
             | Self::Scalar(_, _, old_id)
-            | Self::String(_, _, old_id) => *old_id = id,
+            | Self::String(_, _, old_id)
+            | Self::Unsuffixed(_, _, old_id) => *old_id = id,
             Self::Group(group) => group.set_id(id),
         }
     }
 }
+
+impl Literal {
+    /// Returns the literal with the suffix of `type_` if it is unsuffixed, e.g., `42` becomes `42u8` for `u8`.
+    /// Returns `None` if the literal is unsuffixed and `type_` is not an integer, field, group, or scalar type.
+    /// Hexadecimal, octal, and binary literals can only be inferred to have an integer type.
+    pub fn with_inferred_suffix(self, type_: &Type) -> Option<Self> {
+        match self {
+            Self::Unsuffixed(value, span, id) => match type_ {
+                Type::Integer(integer_type) => Some(Self::Integer(*integer_type, value, span, id)),
+                _ if Self::has_radix_prefix(&value) => None,
+                Type::Field => Some(Self::Field(value, span, id)),
+                Type::Group => Some(Self::Group(Box::new(GroupLiteral::Single(value, span, id)))),
+                Type::Scalar => Some(Self::Scalar(value, span, id)),
+                _ => None,
+            },
+            literal => Some(literal),
+        }
+    }
+
+    /// Returns `true` if the digits of a number are written in hexadecimal, octal, or binary, e.g., `0xFF`.
+    pub fn has_radix_prefix(digits: &str) -> bool {
+        matches!(digits.trim_start_matches('-').get(..2), Some("0x" | "0o" | "0b"))
+    }
+
+    /// Returns the digits of an integer in decimal without separators, e.g., `0xF_F` becomes `255`.
+    /// Returns `None` if the digits are malformed or if their magnitude does not fit in a `u128`.
+    pub fn integer_to_decimal(digits: &str) -> Option<String> {
+        let (sign, digits) = match digits.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", digits),
+        };
+        let digits = digits.replace('_', "");
+        let (radix, digits) = match digits.get(..2) {
+            Some("0x") => (16, &digits[2..]),
+            Some("0o") => (8, &digits[2..]),
+            Some("0b") => (2, &digits[2..]),
+            _ => (10, digits.as_str()),
+        };
+        // Note that `from_str_radix` accepts a leading `+`, which the lexer never produces.
+        let magnitude = u128::from_str_radix(digits, radix).ok()?;
+        Some(format!("{sign}{magnitude}"))
+    }
+}
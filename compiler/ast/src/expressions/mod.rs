@@ -56,6 +56,11 @@ pub use unit::*;
 mod literal;
 pub use literal::*;
 
+/// The depth to which expressions may be nested in one another, unless configured otherwise. The parser bounds the
+/// nesting of statements and types by the same depth. The passes visit all of them recursively, so much deeper nesting
+/// would overflow the stack.
+pub const DEFAULT_MAX_EXPRESSION_DEPTH: usize = 128;
+
 /// Expression that evaluates to a value.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Expression {
@@ -88,6 +93,18 @@ pub enum Expression {
     Unit(UnitExpression),
 }
 
+impl Expression {
+    /// Returns the variable at the root of a path of member accesses, e.g., `a` in `a.b.c`.
+    /// Returns `None` if the expression is not such a path.
+    pub fn root_identifier(&self) -> Option<Identifier> {
+        match self {
+            Expression::Identifier(identifier) => Some(*identifier),
+            Expression::Access(AccessExpression::Member(access)) => access.inner.root_identifier(),
+            _ => None,
+        }
+    }
+}
+
 impl Node for Expression {
     fn span(&self) -> Span {
         use Expression::*;
@@ -36,6 +36,10 @@ pub enum UnaryOperation {
     Square,
     /// Square root operation, i.e. `.sqrt()`.
     SquareRoot,
+    /// Decomposes an integer into its bits, least significant bit first, i.e. `.to_bits_le()`.
+    ToBitsLe,
+    /// Propagates the error of a result, or evaluates to its value, i.e. `?`.
+    Try,
     /// Converts a group element to its x-coordinate, i.e. `.to_x_coordinate()`.
     ToXCoordinate,
     /// Converts a group element to its y-coordinate, i.e. `.to_y_coordinate()`.
@@ -54,6 +58,7 @@ impl UnaryOperation {
             sym::not => Self::Not,
             sym::square => Self::Square,
             sym::square_root => Self::SquareRoot,
+            sym::to_bits_le => Self::ToBitsLe,
             sym::to_x_coordinate => Self::ToXCoordinate,
             sym::to_y_coordinate => Self::ToYCoordinate,
             _ => return None,
@@ -61,7 +66,7 @@ impl UnaryOperation {
     }
 
     /// Represents the opera.tor as a string.
-    fn as_str(self) -> &'static str {
+    pub fn as_str(self) -> &'static str {
         match self {
             Self::Abs => "abs",
             Self::AbsWrapped => "abs_wrapped",
@@ -71,6 +76,8 @@ impl UnaryOperation {
             Self::Not => "not",
             Self::Square => "square",
             Self::SquareRoot => "square_root",
+            Self::ToBitsLe => "to_bits_le",
+            Self::Try => "?",
             Self::ToXCoordinate => "to_x_coordinate",
             Self::ToYCoordinate => "to_y_coordinate",
         }
@@ -92,7 +99,10 @@ pub struct UnaryExpression {
 
 impl fmt::Display for UnaryExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}{}", self.op.as_str(), self.receiver)
+        match self.op {
+            UnaryOperation::Try => write!(f, "{}?", self.receiver),
+            _ => write!(f, "{}{}", self.op.as_str(), self.receiver),
+        }
     }
 }
 
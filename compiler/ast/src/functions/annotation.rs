@@ -16,17 +16,20 @@
 
 use crate::{simple_node_impl, Identifier, Node, NodeID};
 
-use leo_span::Span;
+use leo_span::{sym, Span, Symbol};
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-/// An annotation, e.g. @program.
+/// An annotation, e.g. `@test`, `@deprecated("Use `bar` instead.")`, or `@max_constraints(50000)`.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Annotation {
     // TODO: Consider using a symbol instead of an identifier.
     /// The name of the annotation.
     pub identifier: Identifier,
+    /// The value of the annotation, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
     /// A span locating where the annotation occurred in the source.
     pub span: Span,
     /// The ID of the node.
@@ -35,8 +38,26 @@ pub struct Annotation {
 
 simple_node_impl!(Annotation);
 
+impl Annotation {
+    /// Returns whether an annotation with the given name may have a value, or `None` if the compiler does not
+    /// recognize the annotation.
+    pub fn accepts_value(name: Symbol) -> Option<bool> {
+        match name {
+            sym::deprecated | sym::max_constraints => Some(true),
+            sym::inline | sym::should_fail | sym::test => Some(false),
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for Annotation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "@{}", self.identifier)
+        match &self.value {
+            Some(value) if !value.is_empty() && value.chars().all(|c| c.is_ascii_digit()) => {
+                write!(f, "@{}({value})", self.identifier)
+            }
+            Some(value) => write!(f, "@{}(\"{value}\")", self.identifier),
+            None => write!(f, "@{}", self.identifier),
+        }
     }
 }
@@ -287,7 +287,43 @@ pub enum CoreFunction {
     GroupToXCoordinate,
     GroupToYCoordinate,
 
+    I8FromBitsLe,
+    I16FromBitsLe,
+    I32FromBitsLe,
+    I64FromBitsLe,
+    I128FromBitsLe,
+    U8FromBitsLe,
+    U16FromBitsLe,
+    U32FromBitsLe,
+    U64FromBitsLe,
+    U128FromBitsLe,
+
+    I8FromField,
+    I16FromField,
+    I32FromField,
+    I64FromField,
+    I128FromField,
+    U8FromField,
+    U16FromField,
+    U32FromField,
+    U64FromField,
+    U128FromField,
+
     SignatureVerify,
+
+    MerkleTreeVerify,
+    MerkleTreeVerifyBHP256,
+
+    OptionSome,
+    OptionNone,
+    OptionIsSome,
+    OptionIsNone,
+    OptionUnwrapOr,
+
+    ResultOk,
+    ResultErr,
+    ResultIsOk,
+    ResultIsErr,
 }
 
 impl CoreFunction {
@@ -563,7 +599,43 @@ impl CoreFunction {
             (sym::group, sym::to_x_coordinate) => Self::GroupToXCoordinate,
             (sym::group, sym::to_y_coordinate) => Self::GroupToYCoordinate,
 
+            (sym::i8, sym::from_bits_le) => Self::I8FromBitsLe,
+            (sym::i16, sym::from_bits_le) => Self::I16FromBitsLe,
+            (sym::i32, sym::from_bits_le) => Self::I32FromBitsLe,
+            (sym::i64, sym::from_bits_le) => Self::I64FromBitsLe,
+            (sym::i128, sym::from_bits_le) => Self::I128FromBitsLe,
+            (sym::u8, sym::from_bits_le) => Self::U8FromBitsLe,
+            (sym::u16, sym::from_bits_le) => Self::U16FromBitsLe,
+            (sym::u32, sym::from_bits_le) => Self::U32FromBitsLe,
+            (sym::u64, sym::from_bits_le) => Self::U64FromBitsLe,
+            (sym::u128, sym::from_bits_le) => Self::U128FromBitsLe,
+
+            (sym::i8, sym::from_field) => Self::I8FromField,
+            (sym::i16, sym::from_field) => Self::I16FromField,
+            (sym::i32, sym::from_field) => Self::I32FromField,
+            (sym::i64, sym::from_field) => Self::I64FromField,
+            (sym::i128, sym::from_field) => Self::I128FromField,
+            (sym::u8, sym::from_field) => Self::U8FromField,
+            (sym::u16, sym::from_field) => Self::U16FromField,
+            (sym::u32, sym::from_field) => Self::U32FromField,
+            (sym::u64, sym::from_field) => Self::U64FromField,
+            (sym::u128, sym::from_field) => Self::U128FromField,
+
             (sym::signature, sym::verify) => Self::SignatureVerify,
+
+            (sym::MerkleTree, sym::verify) => Self::MerkleTreeVerify,
+            (sym::MerkleTree, sym::verify_bhp256) => Self::MerkleTreeVerifyBHP256,
+
+            (sym::Option, sym::some) => Self::OptionSome,
+            (sym::Option, sym::none) => Self::OptionNone,
+            (sym::Option, sym::is_some) => Self::OptionIsSome,
+            (sym::Option, sym::is_none) => Self::OptionIsNone,
+            (sym::Option, sym::unwrap_or) => Self::OptionUnwrapOr,
+
+            (sym::Result, sym::ok) => Self::ResultOk,
+            (sym::Result, sym::err) => Self::ResultErr,
+            (sym::Result, sym::is_ok) => Self::ResultIsOk,
+            (sym::Result, sym::is_err) => Self::ResultIsErr,
             _ => return None,
         })
     }
@@ -840,7 +912,43 @@ impl CoreFunction {
             Self::GroupToXCoordinate => 1,
             Self::GroupToYCoordinate => 1,
 
+            Self::I8FromBitsLe => 1,
+            Self::I16FromBitsLe => 1,
+            Self::I32FromBitsLe => 1,
+            Self::I64FromBitsLe => 1,
+            Self::I128FromBitsLe => 1,
+            Self::U8FromBitsLe => 1,
+            Self::U16FromBitsLe => 1,
+            Self::U32FromBitsLe => 1,
+            Self::U64FromBitsLe => 1,
+            Self::U128FromBitsLe => 1,
+
+            Self::I8FromField => 1,
+            Self::I16FromField => 1,
+            Self::I32FromField => 1,
+            Self::I64FromField => 1,
+            Self::I128FromField => 1,
+            Self::U8FromField => 1,
+            Self::U16FromField => 1,
+            Self::U32FromField => 1,
+            Self::U64FromField => 1,
+            Self::U128FromField => 1,
+
             Self::SignatureVerify => 3,
+
+            Self::MerkleTreeVerify => 4,
+            Self::MerkleTreeVerifyBHP256 => 4,
+
+            Self::OptionSome => 1,
+            Self::OptionNone => 0,
+            Self::OptionIsSome => 1,
+            Self::OptionIsNone => 1,
+            Self::OptionUnwrapOr => 2,
+
+            Self::ResultOk => 1,
+            Self::ResultErr => 1,
+            Self::ResultIsOk => 1,
+            Self::ResultIsErr => 1,
         }
     }
 
@@ -1097,7 +1205,59 @@ impl CoreFunction {
             | CoreFunction::SHA3_512HashToScalar
             | CoreFunction::GroupToXCoordinate
             | CoreFunction::GroupToYCoordinate
-            | CoreFunction::SignatureVerify => false,
+            | CoreFunction::I8FromBitsLe
+            | CoreFunction::I16FromBitsLe
+            | CoreFunction::I32FromBitsLe
+            | CoreFunction::I64FromBitsLe
+            | CoreFunction::I128FromBitsLe
+            | CoreFunction::U8FromBitsLe
+            | CoreFunction::U16FromBitsLe
+            | CoreFunction::U32FromBitsLe
+            | CoreFunction::U64FromBitsLe
+            | CoreFunction::U128FromBitsLe
+            | CoreFunction::I8FromField
+            | CoreFunction::I16FromField
+            | CoreFunction::I32FromField
+            | CoreFunction::I64FromField
+            | CoreFunction::I128FromField
+            | CoreFunction::U8FromField
+            | CoreFunction::U16FromField
+            | CoreFunction::U32FromField
+            | CoreFunction::U64FromField
+            | CoreFunction::U128FromField
+            | CoreFunction::SignatureVerify
+            | CoreFunction::MerkleTreeVerify
+            | CoreFunction::MerkleTreeVerifyBHP256
+            | CoreFunction::OptionSome
+            | CoreFunction::OptionNone
+            | CoreFunction::OptionIsSome
+            | CoreFunction::OptionIsNone
+            | CoreFunction::OptionUnwrapOr
+            | CoreFunction::ResultOk
+            | CoreFunction::ResultErr
+            | CoreFunction::ResultIsOk
+            | CoreFunction::ResultIsErr => false,
         }
     }
+
+    /// Returns whether or not this function operates on an `Option`, and is therefore generic in its element type.
+    pub fn is_option_function(&self) -> bool {
+        matches!(
+            self,
+            CoreFunction::OptionSome
+                | CoreFunction::OptionNone
+                | CoreFunction::OptionIsSome
+                | CoreFunction::OptionIsNone
+                | CoreFunction::OptionUnwrapOr
+        )
+    }
+
+    /// Returns whether or not this function operates on a `Result`, and is therefore generic in its value and error types.
+    /// Note that `unwrap_or` is shared with `Option`, since the parser cannot tell the type of the receiver.
+    pub fn is_result_function(&self) -> bool {
+        matches!(
+            self,
+            CoreFunction::ResultOk | CoreFunction::ResultErr | CoreFunction::ResultIsOk | CoreFunction::ResultIsErr
+        )
+    }
 }
@@ -35,6 +35,9 @@ pub use input::*;
 pub mod output;
 pub use output::*;
 
+pub mod receiver;
+pub use receiver::*;
+
 pub mod mode;
 pub use mode::*;
 
@@ -63,6 +66,9 @@ pub struct Function {
     pub block: Block,
     /// An optional finalize block
     pub finalize: Option<Finalize>,
+    /// The receiver, if the function is a method of a struct.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub receiver: Option<Receiver>,
     /// The entire span of the function definition.
     pub span: Span,
     /// The ID of the node.
@@ -103,7 +109,19 @@ impl Function {
             _ => Type::Tuple(TupleType::new(output.iter().map(get_output_type).collect())),
         };
 
-        Function { annotations, variant, identifier, input, output, output_type, block, finalize, span, id }
+        Function {
+            annotations,
+            variant,
+            identifier,
+            input,
+            output,
+            output_type,
+            block,
+            finalize,
+            receiver: None,
+            span,
+            id,
+        }
     }
 
     /// Returns function name.
@@ -111,6 +129,24 @@ impl Function {
         self.identifier.name
     }
 
+    /// Returns the annotation with the given name, if the function has one.
+    pub fn annotation(&self, name: Symbol) -> Option<&Annotation> {
+        self.annotations.iter().find(|annotation| annotation.identifier.name == name)
+    }
+
+    /// Returns the deprecation message, if the function is annotated with `@deprecated`.
+    pub fn deprecation(&self) -> Option<String> {
+        self.annotation(sym::deprecated).map(|annotation| annotation.value.clone().unwrap_or_default())
+    }
+
+    /// Returns the constraint budget, if the function is annotated with `@max_constraints` and a valid budget.
+    pub fn max_constraints(&self) -> Option<u64> {
+        self.annotation(sym::max_constraints)
+            .and_then(|annotation| annotation.value.as_ref())
+            .and_then(|value| value.parse::<u64>().ok())
+            .filter(|budget| *budget > 0)
+    }
+
     /// Returns `true` if the function name is `main`.
     pub fn is_main(&self) -> bool {
         self.name() == sym::main
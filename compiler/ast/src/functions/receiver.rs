@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_span::Symbol;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The receiver of a struct method, i.e., `self` or `mut self` in `function magnitude(self) -> u32`.
+/// Methods are lowered to free functions that take the receiver as their first input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Receiver {
+    /// The name of the struct the method is defined on.
+    pub struct_name: Symbol,
+    /// The name of the method, as written in the struct definition.
+    pub method_name: Symbol,
+    /// Was the receiver declared as `mut self`?
+    pub is_mutable: bool,
+}
+
+impl Receiver {
+    /// Returns the name of the free function that a method of `struct_name` is lowered to, e.g., `Point__magnitude`.
+    pub fn lowered_name(struct_name: Symbol, method_name: Symbol) -> Symbol {
+        Symbol::intern(&format!("{struct_name}__{method_name}"))
+    }
+}
+
+impl fmt::Display for Receiver {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.is_mutable {
+            true => write!(f, "mut self"),
+            false => write!(f, "self"),
+        }
+    }
+}
@@ -34,6 +34,13 @@ impl TryFrom<(Type, Expression)> for InputValue {
 
     fn try_from(value: (Type, Expression)) -> Result<Self> {
         Ok(match value {
+            // Infer the suffix of an unsuffixed literal from the declared type, e.g. `a: u32 = 5;`.
+            (type_, Expression::Literal(lit @ Literal::Unsuffixed(..))) => {
+                match lit.clone().with_inferred_suffix(&type_) {
+                    Some(lit) => InputValue::try_from((type_, Expression::Literal(lit)))?,
+                    None => return Err(InputError::unexpected_type(&type_, &lit, lit.span()).into()),
+                }
+            }
             (type_, Expression::Literal(lit)) => match (type_, lit) {
                 (Type::Address, Literal::Address(value, _, _)) => Self::Address(value),
                 (Type::Boolean, Literal::Boolean(value, _, _)) => Self::Boolean(value),
@@ -41,7 +48,11 @@ impl TryFrom<(Type, Expression)> for InputValue {
                 (Type::Group, Literal::Group(value)) => Self::Group(*value),
                 (Type::Integer(expected), Literal::Integer(actual, value, span, _)) => {
                     if expected == actual {
-                        Self::Integer(expected, value)
+                        // Hexadecimal, octal, and binary inputs are converted to decimal.
+                        match Literal::integer_to_decimal(&value) {
+                            Some(decimal) if Literal::has_radix_prefix(&value) => Self::Integer(expected, decimal),
+                            _ => Self::Integer(expected, value),
+                        }
                     } else {
                         return Err(InputError::unexpected_type(expected.to_string(), actual, span).into());
                     }
@@ -272,6 +272,7 @@ pub trait StatementReconstructor: ExpressionReconstructor {
                         self.reconstruct_expression(left).0,
                         self.reconstruct_expression(right).0,
                     ),
+                    AssertVariant::Fail(reason) => AssertVariant::Fail(reason),
                 },
                 span: input.span,
                 id: input.id,
@@ -329,6 +330,14 @@ pub trait StatementReconstructor: ExpressionReconstructor {
                         self.reconstruct_expression(left).0,
                         self.reconstruct_expression(right).0,
                     ),
+                    ConsoleFunction::Error(format, arguments) => ConsoleFunction::Error(
+                        format,
+                        arguments.into_iter().map(|argument| self.reconstruct_expression(argument).0).collect(),
+                    ),
+                    ConsoleFunction::Log(format, arguments) => ConsoleFunction::Log(
+                        format,
+                        arguments.into_iter().map(|argument| self.reconstruct_expression(argument).0).collect(),
+                    ),
                 },
                 span: input.span,
                 id: input.id,
@@ -412,6 +421,7 @@ pub trait StatementReconstructor: ExpressionReconstructor {
 pub trait ProgramReconstructor: StatementReconstructor {
     fn reconstruct_program(&mut self, input: Program) -> Program {
         Program {
+            metadata: input.metadata,
             imports: input
                 .imports
                 .into_iter()
@@ -461,6 +471,7 @@ pub trait ProgramReconstructor: StatementReconstructor {
                 span: finalize.span,
                 id: finalize.id,
             }),
+            receiver: input.receiver,
             span: input.span,
             id: input.id,
         }
@@ -80,6 +80,10 @@ pub trait ExpressionVisitor<'a> {
     }
 
     fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // Visit the receiver of a method call.
+        if let Expression::Access(AccessExpression::Member(access)) = &*input.function {
+            self.visit_expression(&access.inner, additional);
+        }
         input.arguments.iter().for_each(|expr| {
             self.visit_expression(expr, additional);
         });
@@ -150,12 +154,15 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
 
     fn visit_assert(&mut self, input: &'a AssertStatement) {
         match &input.variant {
-            AssertVariant::Assert(expr) => self.visit_expression(expr, &Default::default()),
+            AssertVariant::Assert(expr) => {
+                self.visit_expression(expr, &Default::default());
+            }
             AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
                 self.visit_expression(left, &Default::default());
-                self.visit_expression(right, &Default::default())
+                self.visit_expression(right, &Default::default());
             }
-        };
+            AssertVariant::Fail(_) => {}
+        }
     }
 
     fn visit_assign(&mut self, input: &'a AssignStatement) {
@@ -187,6 +194,11 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
                 self.visit_expression(left, &Default::default());
                 self.visit_expression(right, &Default::default());
             }
+            ConsoleFunction::Error(_, arguments) | ConsoleFunction::Log(_, arguments) => {
+                arguments.iter().for_each(|argument| {
+                    self.visit_expression(argument, &Default::default());
+                });
+            }
         };
     }
 
@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-//! A Leo program consists of import statements and program scopes.
+//! A Leo program consists of metadata, import statements, and program scopes.
+
+pub mod metadata;
+pub use metadata::*;
 
 pub mod program_id;
 pub use program_id::*;
@@ -31,6 +34,9 @@ use std::fmt;
 /// Stores the Leo program abstract syntax tree.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Program {
+    /// The metadata at the top of the file, e.g. `@version("1.2.0")`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub metadata: Vec<Metadata>,
     /// A map from import names to import definitions.
     pub imports: IndexMap<Symbol, (Program, Span)>,
     /// A map from program names to program scopes.
@@ -39,6 +45,9 @@ pub struct Program {
 
 impl fmt::Display for Program {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for metadata in self.metadata.iter() {
+            writeln!(f, "{metadata}")?;
+        }
         for (id, _import) in self.imports.iter() {
             writeln!(f, "import {id}.leo;")?;
         }
@@ -53,6 +62,6 @@ impl fmt::Display for Program {
 impl Default for Program {
     /// Constructs an empty program node.
     fn default() -> Self {
-        Self { imports: IndexMap::new(), program_scopes: IndexMap::new() }
+        Self { metadata: Vec::new(), imports: IndexMap::new(), program_scopes: IndexMap::new() }
     }
 }
@@ -30,9 +30,11 @@ pub enum AssertVariant {
     AssertEq(Expression, Expression),
     /// A `assert_neq(expr1, expr2)` variant, asserting that the operands are not equal.
     AssertNeq(Expression, Expression),
+    /// A `fail("reason")` or `abort` variant, asserting that the execution path is never taken.
+    Fail(Option<String>),
 }
 
-/// An assert statement, `assert(<expr>)`, `assert_eq(<expr>)` or `assert_neq(<expr>)`.
+/// An assert statement, `assert(<expr>)`, `assert_eq(<expr>)`, `assert_neq(<expr>)`, `fail("reason")` or `abort`.
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub struct AssertStatement {
     /// The variant of the assert statement.
@@ -49,6 +51,8 @@ impl fmt::Display for AssertStatement {
             AssertVariant::Assert(ref expr) => write!(f, "assert({expr});"),
             AssertVariant::AssertEq(ref expr1, ref expr2) => write!(f, "assert_eq({expr1}, {expr2});"),
             AssertVariant::AssertNeq(ref expr1, ref expr2) => write!(f, "assert_neq({expr1}, {expr2});"),
+            AssertVariant::Fail(Some(ref reason)) => write!(f, "fail(\"{reason}\");"),
+            AssertVariant::Fail(None) => write!(f, "abort;"),
         }
     }
 }
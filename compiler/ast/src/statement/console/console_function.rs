@@ -20,6 +20,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// A console logging function to invoke.
+/// Console assertions and errors are checked by the Aleo VM when it executes the function, like `assert` and `fail`.
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub enum ConsoleFunction {
     /// A `console.assert(expr)` call to invoke, asserting that the expression evaluates to true.
@@ -28,6 +29,12 @@ pub enum ConsoleFunction {
     AssertEq(Expression, Expression),
     /// A `console.assert_neq(expr1, expr2)` call to invoke, asserting that the operands are not equal.
     AssertNeq(Expression, Expression),
+    /// A `console.error("x = {}", x)` call to invoke, failing the execution when it is reached.
+    /// The arguments are type checked, but the Aleo VM cannot report their values.
+    Error(String, Vec<Expression>),
+    /// A `console.log("x = {}", x)` call to invoke, printing the format string with each `{}` replaced by an argument.
+    /// It is rejected by the type checker, as the Aleo VM has no instruction to print values.
+    Log(String, Vec<Expression>),
 }
 
 impl fmt::Display for ConsoleFunction {
@@ -36,6 +43,12 @@ impl fmt::Display for ConsoleFunction {
             ConsoleFunction::Assert(expr) => write!(f, "assert({expr})"),
             ConsoleFunction::AssertEq(expr1, expr2) => write!(f, "assert_eq({expr1}, {expr2})"),
             ConsoleFunction::AssertNeq(expr1, expr2) => write!(f, "assert_neq({expr1}, {expr2})"),
+            ConsoleFunction::Error(format, arguments) | ConsoleFunction::Log(format, arguments) => {
+                let name = if matches!(self, ConsoleFunction::Error(..)) { "error" } else { "log" };
+                write!(f, "{name}(\"{format}\"")?;
+                arguments.iter().try_for_each(|argument| write!(f, ", {argument}"))?;
+                write!(f, ")")
+            }
         }
     }
 }
@@ -17,8 +17,8 @@
 pub mod member;
 pub use member::*;
 
-use crate::{Identifier, Node, NodeID};
-use leo_span::{Span, Symbol};
+use crate::{Annotation, Identifier, Node, NodeID};
+use leo_span::{sym, Span, Symbol};
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -31,6 +31,9 @@ use std::fmt;
 /// The fields are named so `struct Foo(u8, u16)` is not allowed.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct Struct {
+    /// Annotations on the struct, e.g. `@deprecated`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Annotation>,
     /// The name of the type in the type system in this module.
     pub identifier: Identifier,
     /// The fields, constant variables, and functions of this structure.
@@ -57,6 +60,16 @@ impl Struct {
     pub fn name(&self) -> Symbol {
         self.identifier.name
     }
+
+    /// Returns the annotation with the given name, if the struct has one.
+    pub fn annotation(&self, name: Symbol) -> Option<&Annotation> {
+        self.annotations.iter().find(|annotation| annotation.identifier.name == name)
+    }
+
+    /// Returns the deprecation message, if the struct is annotated with `@deprecated`.
+    pub fn deprecation(&self) -> Option<String> {
+        self.annotation(sym::deprecated).map(|annotation| annotation.value.clone().unwrap_or_default())
+    }
 }
 
 impl fmt::Debug for Struct {
@@ -67,6 +80,9 @@ impl fmt::Debug for Struct {
 
 impl fmt::Display for Struct {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for annotation in self.annotations.iter() {
+            writeln!(f, "{annotation}")?;
+        }
         f.write_str(if self.is_record { "record" } else { "struct" })?;
         writeln!(f, " {} {{ ", self.identifier)?;
         for field in self.members.iter() {
@@ -26,6 +26,12 @@ pub use integer_type::*;
 pub mod mapping;
 pub use mapping::*;
 
+pub mod option;
+pub use option::*;
+
+pub mod result;
+pub use result::*;
+
 pub mod tuple;
 pub use tuple::*;
 
@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Type;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An optional value of a type, e.g. `Option<u8>`.
+/// Options are lowered to structs with an `is_some` flag and a `value` before code generation.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OptionType {
+    element_type: Box<Type>,
+}
+
+impl OptionType {
+    /// Creates a new option type.
+    pub fn new(element: Type) -> Self {
+        Self { element_type: Box::new(element) }
+    }
+
+    /// Returns the type of the value held by the option.
+    pub fn element_type(&self) -> &Type {
+        &self.element_type
+    }
+}
+
+impl fmt::Display for OptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Option<{}>", self.element_type)
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Type;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The result of a fallible computation, holding either a value or an error, e.g. `Result<u8, u16>`.
+/// Results are lowered to structs with an `is_ok` flag, a `value`, and an `error` before code generation.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ResultType {
+    ok_type: Box<Type>,
+    err_type: Box<Type>,
+}
+
+impl ResultType {
+    /// Creates a new result type.
+    pub fn new(ok_type: Type, err_type: Type) -> Self {
+        Self { ok_type: Box::new(ok_type), err_type: Box::new(err_type) }
+    }
+
+    /// Returns the type of the value held by a successful result.
+    pub fn ok_type(&self) -> &Type {
+        &self.ok_type
+    }
+
+    /// Returns the type of the error held by a failed result.
+    pub fn err_type(&self) -> &Type {
+        &self.err_type
+    }
+}
+
+impl fmt::Display for ResultType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Result<{}, {}>", self.ok_type, self.err_type)
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ArrayType, Identifier, IntegerType, MappingType, TupleType};
+use crate::{ArrayType, Identifier, IntegerType, MappingType, OptionType, ResultType, TupleType};
 
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
@@ -39,6 +39,10 @@ pub enum Type {
     Integer(IntegerType),
     /// A mapping type.
     Mapping(MappingType),
+    /// An optional value, e.g. `Option<u8>`.
+    Option(OptionType),
+    /// The result of a fallible computation, e.g. `Result<u8, u16>`.
+    Result(ResultType),
     /// The `scalar` type.
     Scalar,
     /// The `signature` type.
@@ -78,6 +82,10 @@ impl Type {
             (Type::Mapping(left), Type::Mapping(right)) => {
                 left.key.eq_flat(&right.key) && left.value.eq_flat(&right.value)
             }
+            (Type::Option(left), Type::Option(right)) => left.element_type().eq_flat(right.element_type()),
+            (Type::Result(left), Type::Result(right)) => {
+                left.ok_type().eq_flat(right.ok_type()) && left.err_type().eq_flat(right.err_type())
+            }
             (Type::Tuple(left), Type::Tuple(right)) if left.length() == right.length() => left
                 .elements()
                 .iter()
@@ -99,6 +107,8 @@ impl fmt::Display for Type {
             Type::Identifier(ref variable) => write!(f, "{variable}"),
             Type::Integer(ref integer_type) => write!(f, "{integer_type}"),
             Type::Mapping(ref mapping_type) => write!(f, "{mapping_type}"),
+            Type::Option(ref option_type) => write!(f, "{option_type}"),
+            Type::Result(ref result_type) => write!(f, "{result_type}"),
             Type::Scalar => write!(f, "scalar"),
             Type::Signature => write!(f, "signature"),
             Type::String => write!(f, "string"),
@@ -28,9 +28,6 @@ use std::{
 
 // TODO: Consider refactoring this module to use the console implementations from snarkVM.
 
-// This is temporary since the currently unused code is used in constant folding.
-#[allow(dead_code)]
-
 // Macro for making implementing unary operations over appropriate types easier.
 macro_rules! implement_const_unary {
     (
@@ -71,9 +68,7 @@ macro_rules! implement_const_unary {
             l: $logic:expr
         ]),+]
     ) => {
-        // TODO: This is temporary since the currently unused code is used in constant folding.
-        #[allow(dead_code)]
-        pub(crate) fn $name(self, span: Span) -> Result<Self> {
+        pub fn $name(self, span: Span) -> Result<Self> {
             use Value::*;
 
             match self {
@@ -157,9 +152,7 @@ macro_rules! implement_const_binary {
             logic: $logic:expr
         ]),+]
     ) => {
-        // This is temporary since the currently unused code is used in constant folding.
-        #[allow(dead_code)]
-        pub(crate) fn $name(self, other: Self, span: Span) -> Result<Self> {
+        pub fn $name(self, other: Self, span: Span) -> Result<Self> {
             use Value::*;
 
             match (self, other) {
@@ -701,26 +694,6 @@ impl Value {
             [U128, [U128], U128, u128, u128]
         ]
     );
-
-    // TODO: This is temporary since the currently unused code is used in constant folding.
-    #[allow(dead_code)]
-    pub(crate) fn is_supported_const_fold_type(&self) -> bool {
-        use Value::*;
-        matches!(
-            self,
-            Boolean(_, _)
-                | I8(_, _)
-                | I16(_, _)
-                | I32(_, _)
-                | I64(_, _)
-                | I128(_, _)
-                | U8(_, _)
-                | U16(_, _)
-                | U32(_, _)
-                | U64(_, _)
-                | U128(_, _)
-        )
-    }
 }
 
 impl Display for Value {
@@ -866,9 +839,10 @@ impl From<&Value> for Type {
 }
 
 impl TryFrom<&Literal> for Value {
-    type Error = ParseIntError;
+    type Error = LeoError;
 
     /// Converts a literal to a value.
+    /// Fails if the literal is an integer out of the range of its type, or an unsuffixed literal whose type has not been inferred.
     fn try_from(literal: &Literal) -> Result<Self, Self::Error> {
         Ok(match literal {
             Literal::Address(string, span, _) => Self::Address(string.clone(), *span),
@@ -878,20 +852,25 @@ impl TryFrom<&Literal> for Value {
             Literal::Scalar(string, span, _) => Self::Scalar(string.clone(), *span),
             Literal::String(string, span, _) => Self::String(string.clone(), *span),
             Literal::Integer(integer_type, raw_string, span, _) => {
-                let string = raw_string.replace('_', "");
+                let string = Literal::integer_to_decimal(raw_string).unwrap_or_else(|| raw_string.replace('_', ""));
+                let error = |error: ParseIntError| FlattenError::literal_cannot_be_evaluated(literal, error, *span);
                 match integer_type {
-                    IntegerType::U8 => Self::U8(string.parse()?, *span),
-                    IntegerType::U16 => Self::U16(string.parse()?, *span),
-                    IntegerType::U32 => Self::U32(string.parse()?, *span),
-                    IntegerType::U64 => Self::U64(string.parse()?, *span),
-                    IntegerType::U128 => Self::U128(string.parse()?, *span),
-                    IntegerType::I8 => Self::I8(string.parse()?, *span),
-                    IntegerType::I16 => Self::I16(string.parse()?, *span),
-                    IntegerType::I32 => Self::I32(string.parse()?, *span),
-                    IntegerType::I64 => Self::I64(string.parse()?, *span),
-                    IntegerType::I128 => Self::I128(string.parse()?, *span),
+                    IntegerType::U8 => Self::U8(string.parse().map_err(error)?, *span),
+                    IntegerType::U16 => Self::U16(string.parse().map_err(error)?, *span),
+                    IntegerType::U32 => Self::U32(string.parse().map_err(error)?, *span),
+                    IntegerType::U64 => Self::U64(string.parse().map_err(error)?, *span),
+                    IntegerType::U128 => Self::U128(string.parse().map_err(error)?, *span),
+                    IntegerType::I8 => Self::I8(string.parse().map_err(error)?, *span),
+                    IntegerType::I16 => Self::I16(string.parse().map_err(error)?, *span),
+                    IntegerType::I32 => Self::I32(string.parse().map_err(error)?, *span),
+                    IntegerType::I64 => Self::I64(string.parse().map_err(error)?, *span),
+                    IntegerType::I128 => Self::I128(string.parse().map_err(error)?, *span),
                 }
             }
+            Literal::Unsuffixed(_, span, _) => {
+                return Err(FlattenError::literal_cannot_be_evaluated(literal, "its type has not been inferred", *span)
+                    .into());
+            }
         })
     }
 }
@@ -0,0 +1,199 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The call graph of a Leo program and its imports.
+//!
+//! The call graph is built by the type checker, which records an edge for every call, including calls into imported
+//! programs. This module labels each function with the program that defines it and exports the graph as JSON or DOT.
+
+use leo_ast::{Program, Variant};
+use leo_errors::{CompilerError, Result};
+use leo_passes::CallGraph;
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+use serde::Serialize;
+use std::{fmt::Write, path::PathBuf};
+
+/// A function in the call graph.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CallGraphFunction {
+    /// The name of the function.
+    pub name: String,
+    /// The program that defines the function, e.g. `token.aleo`.
+    pub program: String,
+    /// The variant of the function, one of `inline`, `function`, or `transition`.
+    pub variant: String,
+}
+
+/// A call from one function to another.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CallGraphCall {
+    /// The name of the calling function.
+    pub caller: String,
+    /// The name of the called function.
+    pub callee: String,
+}
+
+/// The call graph of a program and its imports, in a form suitable for export.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CallGraphExport {
+    /// The functions in the graph.
+    pub functions: Vec<CallGraphFunction>,
+    /// The calls between the functions.
+    pub calls: Vec<CallGraphCall>,
+}
+
+impl CallGraphExport {
+    /// Labels the functions of `call_graph` with the programs in `program` and its imports that define them.
+    pub fn new(program: &Program, call_graph: &CallGraph) -> Self {
+        let mut definitions = IndexMap::new();
+        collect_functions(program, &mut definitions);
+
+        let functions = call_graph
+            .nodes()
+            .map(|name| {
+                let (program, variant) = match definitions.get(name) {
+                    Some((program, variant)) => (program.clone(), variant_name(*variant)),
+                    None => (String::new(), "unknown"),
+                };
+                CallGraphFunction { name: name.to_string(), program, variant: variant.to_string() }
+            })
+            .collect();
+        let calls = call_graph
+            .edges()
+            .map(|(caller, callee)| CallGraphCall { caller: caller.to_string(), callee: callee.to_string() })
+            .collect();
+
+        Self { functions, calls }
+    }
+
+    /// Serializes the call graph into a JSON string.
+    pub fn to_json_string(&self) -> String {
+        // Note that this unwrap is safe since the call graph only contains strings.
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// Returns the call graph in the DOT format, with the functions of each program grouped into a cluster.
+    pub fn to_dot(&self) -> String {
+        let mut programs: IndexMap<&str, Vec<&CallGraphFunction>> = IndexMap::new();
+        for function in self.functions.iter() {
+            programs.entry(function.program.as_str()).or_default().push(function);
+        }
+
+        // Note that writing to a `String` cannot fail.
+        let mut dot = String::from("digraph calls {\n");
+        for (index, (program, functions)) in programs.iter().enumerate() {
+            writeln!(dot, "    subgraph cluster_{index} {{").unwrap();
+            writeln!(dot, "        label = \"{program}\";").unwrap();
+            for function in functions.iter() {
+                writeln!(dot, "        \"{}\" [label = \"{} {}\"];", function.name, function.variant, function.name)
+                    .unwrap();
+            }
+            writeln!(dot, "    }}").unwrap();
+        }
+        for call in self.calls.iter() {
+            writeln!(dot, "    \"{}\" -> \"{}\";", call.caller, call.callee).unwrap();
+        }
+        dot.push('}');
+        dot
+    }
+
+    /// Writes the call graph to a JSON file and a DOT file, named `{file_stem}.json` and `{file_stem}.dot`.
+    pub fn to_files(&self, path: PathBuf, file_stem: &str) -> Result<()> {
+        for (extension, contents) in [("json", self.to_json_string()), ("dot", self.to_dot())] {
+            let path = path.join(format!("{file_stem}.{extension}"));
+            std::fs::write(&path, contents).map_err(|e| CompilerError::failed_to_write_call_graph(&path, e))?;
+        }
+        Ok(())
+    }
+}
+
+/// Maps each function defined in `program` and its imports to the name of its program and its variant.
+fn collect_functions(program: &Program, definitions: &mut IndexMap<Symbol, (String, Variant)>) {
+    for (import, _) in program.imports.values() {
+        collect_functions(import, definitions);
+    }
+    for scope in program.program_scopes.values() {
+        for (name, function) in scope.functions.iter() {
+            definitions.insert(*name, (scope.program_id.to_string(), function.variant));
+        }
+    }
+}
+
+/// Returns the keyword that declares a function of the given variant.
+fn variant_name(variant: Variant) -> &'static str {
+    match variant {
+        Variant::Inline => "inline",
+        Variant::Standard => "function",
+        Variant::Transition => "transition",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utils::with_parsed_program;
+
+    const PROGRAM: &str = "
+program test.aleo {
+    inline double(a: u32) -> u32 {
+        return a + a;
+    }
+
+    inline quadruple(a: u32) -> u32 {
+        return double(double(a));
+    }
+
+    transition main(a: u32) -> u32 {
+        return quadruple(a);
+    }
+}
+";
+
+    fn export() -> CallGraphExport {
+        with_parsed_program(PROGRAM, |compiler, _| {
+            let symbol_table = compiler.symbol_table_pass().unwrap();
+            let (_, _, call_graph) = compiler.type_checker_pass(symbol_table).unwrap();
+            CallGraphExport::new(&compiler.ast.ast, &call_graph)
+        })
+    }
+
+    #[test]
+    fn test_functions_and_calls() {
+        let export = export();
+
+        let functions: Vec<_> =
+            export.functions.iter().map(|f| (f.name.as_str(), f.program.as_str(), f.variant.as_str())).collect();
+        assert_eq!(functions, [
+            ("double", "test.aleo", "inline"),
+            ("quadruple", "test.aleo", "inline"),
+            ("main", "test.aleo", "transition")
+        ]);
+
+        let calls: Vec<_> = export.calls.iter().map(|c| (c.caller.as_str(), c.callee.as_str())).collect();
+        assert_eq!(calls, [("quadruple", "double"), ("main", "quadruple")]);
+    }
+
+    #[test]
+    fn test_dot() {
+        let dot = export().to_dot();
+
+        assert!(dot.starts_with("digraph calls {\n    subgraph cluster_0 {\n        label = \"test.aleo\";\n"));
+        assert!(dot.contains("        \"main\" [label = \"transition main\"];\n"));
+        assert!(dot.ends_with("    \"quadruple\" -> \"double\";\n    \"main\" -> \"quadruple\";\n}"));
+    }
+}
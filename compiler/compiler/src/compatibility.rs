@@ -0,0 +1,321 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Upgradability checks for compiled Aleo programs.
+//!
+//! The interface of a deployed program consists of its functions, including the types and visibilities of their
+//! inputs and outputs, and the layouts of its structs, records, and mappings. A new version of the program is
+//! compatible with the old one if every part of the old interface still exists unchanged.
+
+use crate::{
+    equivalence::{Item, Shape},
+    ItemKind,
+};
+
+use std::fmt;
+
+/// A change to the interface of a program that breaks existing callers or on-chain state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BreakingChange {
+    /// The program was renamed.
+    ProgramRenamed { before: String, after: String },
+    /// A function, struct, record, or mapping was removed.
+    Removed { kind: ItemKind, name: String },
+    /// The types or visibilities of the inputs of a function changed.
+    InputsChanged { function: String, before: Vec<String>, after: Vec<String> },
+    /// The types or visibilities of the outputs of a function changed.
+    OutputsChanged { function: String, before: Vec<String>, after: Vec<String> },
+    /// The members of a struct or record, or the key or value of a mapping, changed.
+    LayoutChanged { kind: ItemKind, name: String, before: Vec<String>, after: Vec<String> },
+}
+
+impl fmt::Display for BreakingChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BreakingChange::ProgramRenamed { before, after } => {
+                write!(f, "program `{before}` was renamed to `{after}`")
+            }
+            BreakingChange::Removed { kind, name } => write!(f, "{kind} `{name}` was removed"),
+            BreakingChange::InputsChanged { function, before, after } => {
+                write!(
+                    f,
+                    "the inputs of function `{function}` changed from ({}) to ({})",
+                    before.join(", "),
+                    after.join(", ")
+                )
+            }
+            BreakingChange::OutputsChanged { function, before, after } => {
+                write!(
+                    f,
+                    "the outputs of function `{function}` changed from ({}) to ({})",
+                    before.join(", "),
+                    after.join(", ")
+                )
+            }
+            BreakingChange::LayoutChanged { kind, name, before, after } => {
+                write!(
+                    f,
+                    "the layout of {kind} `{name}` changed from {{ {} }} to {{ {} }}",
+                    before.join(", "),
+                    after.join(", ")
+                )
+            }
+        }
+    }
+}
+
+/// The result of checking whether a new version of a program is compatible with the old one.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CompatibilityReport {
+    /// The breaking changes, in the order of the items of the old program.
+    pub breaking_changes: Vec<BreakingChange>,
+    /// The functions that were added in the new program. Adding functions does not break compatibility.
+    pub added_functions: Vec<String>,
+}
+
+impl CompatibilityReport {
+    /// Returns `true` if the new program can replace the old one without breaking callers or on-chain state.
+    pub fn is_compatible(&self) -> bool {
+        self.breaking_changes.is_empty()
+    }
+}
+
+impl fmt::Display for CompatibilityReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.is_compatible() {
+            true => write!(f, "The new program is compatible with the old program.")?,
+            false => {
+                write!(f, "The new program is not compatible with the old program:")?;
+                for change in self.breaking_changes.iter() {
+                    write!(f, "\n  - {change}")?;
+                }
+            }
+        }
+        for function in self.added_functions.iter() {
+            write!(f, "\n  + function `{function}` was added")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the declarations in the body of an item that start with `keyword`, e.g. `input`, without their registers.
+/// For example, `input r0 as u8.public;` becomes `u8.public`.
+fn declarations(item: &Item, keyword: &str) -> Vec<String> {
+    item.body
+        .iter()
+        .filter_map(|line| line.strip_prefix(keyword))
+        .filter(|rest| rest.starts_with(' '))
+        .map(|rest| {
+            let declaration = rest.trim().trim_end_matches(';');
+            declaration.split_once(" as ").map_or(declaration, |(_, type_)| type_).trim().to_string()
+        })
+        .collect()
+}
+
+/// A function of the interface of a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionInterface {
+    /// The name of the function.
+    pub name: String,
+    /// The types and visibilities of its inputs, e.g. `u64.private`.
+    pub inputs: Vec<String>,
+    /// The types and visibilities of its outputs.
+    pub outputs: Vec<String>,
+}
+
+/// The layout of a struct, record, or mapping of the interface of a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Layout {
+    /// Whether the layout is that of a struct, a record, or a mapping.
+    pub kind: ItemKind,
+    /// The name of the struct, record, or mapping.
+    pub name: String,
+    /// The members of the struct or record, or the key and value of the mapping, e.g. `amount as u64.private`.
+    pub members: Vec<String>,
+}
+
+/// The interface of a compiled program, i.e. the parts of it that callers and on-chain state depend on.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProgramInterface {
+    /// The name of the program, e.g. `token.aleo`.
+    pub program: String,
+    /// The functions, in the order of the program.
+    pub functions: Vec<FunctionInterface>,
+    /// The layouts of the structs, records, and mappings, in the order of the program.
+    pub layouts: Vec<Layout>,
+}
+
+/// Returns the interface of the compiled Aleo program `program`.
+pub fn program_interface(program: &str) -> ProgramInterface {
+    let shape = Shape::parse(program);
+    let mut interface = ProgramInterface { program: shape.name.clone(), ..Default::default() };
+    for item in shape.items.iter() {
+        match item.kind {
+            ItemKind::Function => interface.functions.push(FunctionInterface {
+                name: item.name.clone(),
+                inputs: declarations(item, "input"),
+                outputs: declarations(item, "output"),
+            }),
+            ItemKind::Struct | ItemKind::Record | ItemKind::Mapping => interface.layouts.push(Layout {
+                kind: item.kind,
+                name: item.name.clone(),
+                members: item.body.iter().map(|line| line.trim_end_matches(';').to_string()).collect(),
+            }),
+            _ => {}
+        }
+    }
+    interface
+}
+
+/// Checks whether the compiled Aleo program `new` can be deployed as an upgrade of the compiled Aleo program `old`.
+pub fn check_compatibility(old: &str, new: &str) -> CompatibilityReport {
+    let old = Shape::parse(old);
+    let new = Shape::parse(new);
+
+    let mut report = CompatibilityReport::default();
+
+    if old.name != new.name {
+        report
+            .breaking_changes
+            .push(BreakingChange::ProgramRenamed { before: old.name.clone(), after: new.name.clone() });
+    }
+
+    for item in old.items.iter() {
+        // Closures, finalize blocks, and imports are not part of the interface of a program.
+        if !matches!(item.kind, ItemKind::Function | ItemKind::Struct | ItemKind::Record | ItemKind::Mapping) {
+            continue;
+        }
+
+        let Some(other) = new.find(item.kind, &item.name) else {
+            report.breaking_changes.push(BreakingChange::Removed { kind: item.kind, name: item.name.clone() });
+            continue;
+        };
+
+        match item.kind {
+            ItemKind::Function => {
+                let (before, after) = (declarations(item, "input"), declarations(other, "input"));
+                if before != after {
+                    report.breaking_changes.push(BreakingChange::InputsChanged {
+                        function: item.name.clone(),
+                        before,
+                        after,
+                    });
+                }
+                let (before, after) = (declarations(item, "output"), declarations(other, "output"));
+                if before != after {
+                    report.breaking_changes.push(BreakingChange::OutputsChanged {
+                        function: item.name.clone(),
+                        before,
+                        after,
+                    });
+                }
+            }
+            // The body of a struct, record, or mapping only consists of its layout.
+            _ => {
+                if item.body != other.body {
+                    report.breaking_changes.push(BreakingChange::LayoutChanged {
+                        kind: item.kind,
+                        name: item.name.clone(),
+                        before: item.body.iter().map(|line| line.trim_end_matches(';').to_string()).collect(),
+                        after: other.body.iter().map(|line| line.trim_end_matches(';').to_string()).collect(),
+                    });
+                }
+            }
+        }
+    }
+
+    report.added_functions = new
+        .items
+        .iter()
+        .filter(|item| item.kind == ItemKind::Function && old.find(ItemKind::Function, &item.name).is_none())
+        .map(|item| item.name.clone())
+        .collect();
+
+    report
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PROGRAM: &str = "
+program token.aleo;
+
+record Token:
+    owner as address.private;
+    amount as u64.private;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as Token.record;
+    output r2 as Token.record;
+";
+
+    #[test]
+    fn test_program_interface() {
+        let interface = program_interface(PROGRAM);
+        assert_eq!(interface.program, "token.aleo");
+        assert_eq!(interface.functions, vec![FunctionInterface {
+            name: "mint".to_string(),
+            inputs: vec!["address.private".to_string(), "u64.private".to_string()],
+            outputs: vec!["Token.record".to_string()],
+        }]);
+        assert_eq!(interface.layouts[1], Layout {
+            kind: ItemKind::Mapping,
+            name: "balances".to_string(),
+            members: vec!["key as address.public".to_string(), "value as u64.public".to_string()],
+        });
+    }
+
+    #[test]
+    fn test_implementation_changes_are_compatible() {
+        let new = PROGRAM.replace(
+            "cast r0 r1 into r2 as Token.record;",
+            "add r1 1u64 into r3;\n    cast r0 r3 into r2 as Token.record;",
+        ) + "\nfunction burn:\n    input r0 as Token.record;\n";
+        let report = check_compatibility(PROGRAM, &new);
+        assert!(report.is_compatible());
+        assert_eq!(report.added_functions, vec!["burn".to_string()]);
+    }
+
+    #[test]
+    fn test_interface_changes_are_breaking() {
+        let new = PROGRAM
+            .replace("input r1 as u64.private;", "input r1 as u64.public;")
+            .replace("amount as u64.private;", "amount as u128.private;")
+            .replace("mapping balances:\n    key as address.public;\n    value as u64.public;\n", "");
+        let report = check_compatibility(PROGRAM, &new);
+        assert_eq!(report.breaking_changes, vec![
+            BreakingChange::LayoutChanged {
+                kind: ItemKind::Record,
+                name: "Token".to_string(),
+                before: vec!["owner as address.private".to_string(), "amount as u64.private".to_string()],
+                after: vec!["owner as address.private".to_string(), "amount as u128.private".to_string()],
+            },
+            BreakingChange::Removed { kind: ItemKind::Mapping, name: "balances".to_string() },
+            BreakingChange::InputsChanged {
+                function: "mint".to_string(),
+                before: vec!["address.private".to_string(), "u64.private".to_string()],
+                after: vec!["address.private".to_string(), "u64.public".to_string()],
+            },
+        ]);
+    }
+}
@@ -18,16 +18,26 @@
 //!
 //! The [`Compiler`] type compiles Leo programs into R1CS circuits.
 pub use leo_ast::{Ast, InputAst};
-use leo_ast::{NodeBuilder, Program};
+use leo_ast::{NodeBuilder, NodeIndex, Program, DEFAULT_MAX_EXPRESSION_DEPTH};
 use leo_errors::{emitter::Handler, CompilerError, Result};
+use leo_parser::{with_parser_limits, ImportPaths, ParserLimits};
 pub use leo_passes::SymbolTable;
 use leo_passes::*;
 use leo_span::{source_map::FileName, symbol::with_session_globals};
 
 use sha2::{Digest, Sha256};
-use std::{fs, path::PathBuf};
-
-use crate::CompilerOptions;
+use std::{fs, path::PathBuf, rc::Rc, time::Instant};
+
+use crate::{
+    order_passes,
+    CallGraphExport,
+    CompilerOptions,
+    CompilerPass,
+    PassContext,
+    PassStage,
+    ResourceLimits,
+    Timings,
+};
 
 /// The primary entry point of the Leo compiler.
 #[derive(Clone)]
@@ -54,6 +64,18 @@ pub struct Compiler<'a> {
     assigner: Assigner,
     /// The type table.
     type_table: TypeTable,
+    /// The table of resolved names.
+    resolution_table: ResolutionTable,
+    /// The time spent in each stage of compilation.
+    timings: Timings,
+    /// Where imported programs are looked up, besides the `imports/` directory.
+    import_paths: ImportPaths,
+    /// The custom passes, in the order in which they were registered.
+    passes: Vec<Rc<dyn CompilerPass>>,
+    /// The index of the nodes of the program, as it was type checked.
+    node_index: Option<NodeIndex>,
+    /// The limits on the resources compilation may use.
+    resource_limits: ResourceLimits,
 }
 
 impl<'a> Compiler<'a> {
@@ -69,6 +91,7 @@ impl<'a> Compiler<'a> {
         let node_builder = NodeBuilder::default();
         let assigner = Assigner::default();
         let type_table = TypeTable::default();
+        let resolution_table = ResolutionTable::default();
         Self {
             handler,
             main_file_path,
@@ -81,6 +104,128 @@ impl<'a> Compiler<'a> {
             node_builder,
             assigner,
             type_table,
+            resolution_table,
+            timings: Timings::default(),
+            import_paths: ImportPaths::default(),
+            passes: Vec::new(),
+            node_index: None,
+            resource_limits: ResourceLimits::default(),
+        }
+    }
+
+    /// Registers a custom pass, which runs after the built-in stage given by [`CompilerPass::stage`].
+    pub fn with_pass(mut self, pass: impl CompilerPass + 'static) -> Self {
+        self.passes.push(Rc::new(pass));
+        self
+    }
+
+    /// Looks up imported programs through the given import paths, e.g. the members of a workspace.
+    pub fn with_import_paths(mut self, import_paths: ImportPaths) -> Self {
+        self.import_paths = import_paths;
+        self
+    }
+
+    /// Limits the resources compilation may use, which are checked after each stage.
+    pub fn with_resource_limits(mut self, resource_limits: ResourceLimits) -> Self {
+        self.resource_limits = resource_limits;
+        self
+    }
+
+    /// Returns where imported programs are looked up, besides the `imports/` directory.
+    pub fn import_paths(&self) -> &ImportPaths {
+        &self.import_paths
+    }
+
+    /// Returns the names resolved by the name resolution pass.
+    pub fn resolution_table(&self) -> &ResolutionTable {
+        &self.resolution_table
+    }
+
+    /// Returns the types inferred by the type checker.
+    pub fn type_table(&self) -> &TypeTable {
+        &self.type_table
+    }
+
+    /// Returns the index of the nodes of the program as it was type checked, if type checking has been run.
+    pub fn node_index(&self) -> Option<&NodeIndex> {
+        self.node_index.as_ref()
+    }
+
+    /// Returns the time spent in each stage of compilation so far.
+    pub fn timings(&self) -> &Timings {
+        &self.timings
+    }
+
+    /// Runs the custom passes of the given stage, in order.
+    fn custom_passes(
+        &mut self,
+        passes: &[Rc<dyn CompilerPass>],
+        stage: PassStage,
+        symbol_table: &SymbolTable,
+    ) -> Result<()> {
+        for pass in passes.iter().filter(|pass| pass.stage() == stage) {
+            self.timed(pass.name(), |compiler| {
+                pass.run(PassContext {
+                    ast: &mut compiler.ast,
+                    symbol_table,
+                    type_table: &compiler.type_table,
+                    node_builder: &compiler.node_builder,
+                    handler: compiler.handler,
+                })?;
+                compiler.handler.last_err().map_err(|e| *e)
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Runs a stage of compilation and records the time spent in it.
+    pub(crate) fn timed<T>(&mut self, stage: &'static str, run: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("stage", program = %self.program_name, stage).entered();
+
+        let start = Instant::now();
+        let output = run(self);
+        let elapsed = start.elapsed();
+        self.timings.record(stage, elapsed);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            duration_us = elapsed.as_micros() as u64,
+            nodes = self.node_builder.peek(),
+            failed = output.is_err(),
+            "finished stage"
+        );
+
+        let output = output?;
+        self.check_resource_limits(stage)?;
+        Ok(output)
+    }
+
+    /// Checks that compilation has not exceeded its time limit or its limit on AST nodes by the end of `stage`.
+    fn check_resource_limits(&self, stage: &str) -> Result<()> {
+        if self.resource_limits.deadline.map_or(false, |deadline| Instant::now() > deadline) {
+            return Err(CompilerError::time_limit_exceeded(stage).into());
+        }
+        match self.resource_limits.max_nodes {
+            Some(max_nodes) if self.node_builder.peek() > max_nodes => {
+                Err(CompilerError::node_limit_exceeded(self.node_builder.peek(), max_nodes, stage).into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks that the estimated number of constraints of each transition is within the limit, if any.
+    pub fn check_constraint_limit(&self) -> Result<()> {
+        let Some(max_constraints) = self.resource_limits.max_constraints else { return Ok(()) };
+        match self.estimated_constraints().into_iter().find(|(_, constraints)| *constraints > max_constraints) {
+            Some((transition, constraints)) => Err(CompilerError::constraint_limit_exceeded(
+                transition.name,
+                constraints,
+                max_constraints,
+                transition.span,
+            )
+            .into()),
+            None => Ok(()),
         }
     }
 
@@ -104,7 +249,23 @@ impl<'a> Compiler<'a> {
         let prg_sf = with_session_globals(|s| s.source_map.new_source(program_string, name));
 
         // Use the parser to construct the abstract syntax tree (ast).
-        self.ast = leo_parser::parse_ast(self.handler, &self.node_builder, &prg_sf.src, prg_sf.start_pos)?;
+        let limits = ParserLimits {
+            max_expression_depth: self
+                .compiler_options
+                .build
+                .max_expression_depth
+                .unwrap_or(DEFAULT_MAX_EXPRESSION_DEPTH),
+            max_identifiers: self.compiler_options.build.max_identifiers,
+        };
+        self.ast = with_parser_limits(&limits, || {
+            leo_parser::parse_ast_with_import_paths(
+                self.handler,
+                &self.node_builder,
+                &prg_sf.src,
+                prg_sf.start_pos,
+                &self.import_paths,
+            )
+        })?;
 
         // If the program is imported, then check that the name of its program scope matches the file name.
         // Note that parsing enforces that there is exactly one program scope in a file.
@@ -120,6 +281,13 @@ impl<'a> Compiler<'a> {
             .into());
         }
 
+        #[cfg(feature = "tracing")]
+        {
+            tracing::info!(file = %prg_sf.name, bytes = program_string.len(), "parsed file");
+            let imports = self.ast.ast.imports.keys().map(|name| name.to_string()).collect::<Vec<_>>();
+            tracing::info!(?imports, "resolved imports");
+        }
+
         if self.compiler_options.output.initial_ast {
             self.write_ast_to_json("initial_ast.json")?;
         }
@@ -176,16 +344,54 @@ impl<'a> Compiler<'a> {
         Ok(symbol_table)
     }
 
+    /// Runs the name resolution pass.
+    pub fn name_resolution_pass(&self) -> Result<()> {
+        NameResolver::do_pass((&self.ast, &self.resolution_table))
+    }
+
     /// Runs the type checker pass.
     pub fn type_checker_pass(&'a self, symbol_table: SymbolTable) -> Result<(SymbolTable, StructGraph, CallGraph)> {
-        let (symbol_table, struct_graph, call_graph) =
-            TypeChecker::do_pass((&self.ast, self.handler, symbol_table, &self.type_table))?;
+        let (symbol_table, struct_graph, call_graph) = TypeChecker::do_pass((
+            &self.ast,
+            self.handler,
+            symbol_table,
+            &self.type_table,
+            &self.compiler_options.build.record_schema,
+            self.compiler_options.build.max_expression_depth.unwrap_or(DEFAULT_MAX_EXPRESSION_DEPTH),
+            self.compiler_options.build.max_array_size,
+        ))?;
         if self.compiler_options.output.type_checked_symbol_table {
             self.write_symbol_table_to_json("type_checked_symbol_table.json", &symbol_table)?;
         }
+        if self.compiler_options.output.call_graph {
+            CallGraphExport::new(&self.ast.ast, &call_graph)
+                .to_files(self.output_directory.clone(), &format!("{}.call_graph", self.program_name))?;
+        }
         Ok((symbol_table, struct_graph, call_graph))
     }
 
+    /// Runs the move checking pass.
+    pub fn move_checking_pass(&self) -> Result<()> {
+        MoveChecker::do_pass((&self.ast, self.handler, &self.type_table))
+    }
+
+    /// Runs the static analysis pass.
+    pub fn static_analysis_pass(&self) -> Result<()> {
+        StaticAnalyzer::do_pass((&self.ast, self.handler, &self.type_table))
+    }
+
+    /// Runs the loop-invariant code motion pass.
+    pub fn loop_invariant_code_motion_pass(&mut self) -> Result<()> {
+        self.ast = LoopInvariantHoister::do_pass((
+            std::mem::take(&mut self.ast),
+            &self.node_builder,
+            &self.assigner,
+            &self.type_table,
+        ))?;
+
+        Ok(())
+    }
+
     /// Runs the loop unrolling pass.
     pub fn loop_unrolling_pass(&mut self, symbol_table: SymbolTable) -> Result<SymbolTable> {
         let (ast, symbol_table) = Unroller::do_pass((
@@ -194,6 +400,9 @@ impl<'a> Compiler<'a> {
             &self.node_builder,
             symbol_table,
             &self.type_table,
+            self.compiler_options.build.max_unrolled_statements,
+            self.compiler_options.build.max_unrolled_iterations,
+            self.resource_limits.deadline,
         ))?;
         self.ast = ast;
 
@@ -208,6 +417,44 @@ impl<'a> Compiler<'a> {
         Ok(symbol_table)
     }
 
+    /// Runs the dynamic indexing pass.
+    pub fn dynamic_indexing_pass(&mut self) -> Result<()> {
+        self.ast = DynamicIndexer::do_pass((
+            std::mem::take(&mut self.ast),
+            &self.node_builder,
+            &self.assigner,
+            &self.type_table,
+        ))?;
+
+        Ok(())
+    }
+
+    /// Runs the boolean simplification pass.
+    pub fn boolean_simplification_pass(&mut self) -> Result<()> {
+        self.ast = BooleanSimplifier::do_pass((std::mem::take(&mut self.ast), &self.node_builder, &self.type_table))?;
+
+        Ok(())
+    }
+
+    /// Runs the option lowering pass.
+    pub fn option_lowering_pass(
+        &mut self,
+        symbol_table: SymbolTable,
+        struct_graph: StructGraph,
+    ) -> Result<(SymbolTable, StructGraph)> {
+        let (ast, symbol_table, struct_graph) = OptionLowerer::do_pass((
+            std::mem::take(&mut self.ast),
+            &self.node_builder,
+            &self.assigner,
+            &self.type_table,
+            symbol_table,
+            struct_graph,
+        ))?;
+        self.ast = ast;
+
+        Ok((symbol_table, struct_graph))
+    }
+
     /// Runs the static single assignment pass.
     pub fn static_single_assignment_pass(&mut self, symbol_table: &SymbolTable) -> Result<()> {
         self.ast = StaticSingleAssigner::do_pass((
@@ -266,6 +513,7 @@ impl<'a> Compiler<'a> {
             call_graph,
             &self.assigner,
             &self.type_table,
+            self.resource_limits.deadline,
         ))?;
         self.ast = ast;
 
@@ -276,6 +524,26 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
+    /// Runs the information flow analysis pass, writing its report if enabled.
+    /// The pass must be run on the AST produced by the function inlining pass.
+    pub fn information_flow_pass(&self) -> Result<InformationFlowReport> {
+        let report = InformationFlowAnalyzer::do_pass(&self.ast)?;
+        if self.compiler_options.output.information_flow {
+            let path = self.output_directory.join(format!("{}.information_flow.json", self.program_name));
+            fs::write(&path, report.to_json_string())
+                .map_err(|e| CompilerError::failed_to_write_information_flow_report(&path, e))?;
+        }
+        Ok(report)
+    }
+
+    /// Runs the semantic highlighting pass, classifying every token of the given source file.
+    /// The name resolution pass must have been run.
+    pub fn semantic_highlighting_pass(&self, file: &FileName) -> Result<Vec<SemanticToken>> {
+        let source_file = with_session_globals(|s| s.source_map.find_source_file_by_name(file))
+            .ok_or_else(|| CompilerError::source_file_not_found(file))?;
+        SemanticHighlighter::do_pass((&self.ast, &self.resolution_table, &source_file))
+    }
+
     /// Runs the dead code elimination pass.
     pub fn dead_code_elimination_pass(&mut self) -> Result<()> {
         if self.compiler_options.build.dce_enabled {
@@ -286,9 +554,21 @@ impl<'a> Compiler<'a> {
             self.write_ast_to_json("dce_ast.json")?;
         }
 
+        if self.compiler_options.output.expanded {
+            let path = self.output_directory.join(format!("{}.expanded.leo", self.program_name));
+            fs::write(&path, self.expanded_source())
+                .map_err(|e| CompilerError::failed_to_write_expanded_source(&path, e))?;
+        }
+
         Ok(())
     }
 
+    /// Returns the program as Leo source, as it is after the passes run so far.
+    /// After dead code elimination, this is the program after constant propagation, unrolling, and inlining.
+    pub fn expanded_source(&self) -> String {
+        PrettyPrinter::do_pass((&self.ast, &self.type_table))
+    }
+
     /// Runs the code generation pass.
     pub fn code_generation_pass(
         &mut self,
@@ -296,26 +576,83 @@ impl<'a> Compiler<'a> {
         struct_graph: &StructGraph,
         call_graph: &CallGraph,
     ) -> Result<String> {
-        CodeGenerator::do_pass((&self.ast, symbol_table, &self.type_table, struct_graph, call_graph, &self.ast.ast))
+        let bytecode = CodeGenerator::do_pass((
+            &self.ast,
+            symbol_table,
+            &self.type_table,
+            struct_graph,
+            call_graph,
+            &self.ast.ast,
+        ))?;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(bytes = bytecode.len(), "generated bytecode");
+
+        Ok(bytecode)
+    }
+
+    /// Runs the stages up to and including type checking, and indexes the nodes of the type checked program.
+    pub fn type_checking_stages(&mut self) -> Result<(SymbolTable, StructGraph, CallGraph)> {
+        let st = self.timed("symbol table creation", |compiler| compiler.symbol_table_pass())?;
+        self.timed("name resolution", |compiler| compiler.name_resolution_pass())?;
+        let output = self.timed("type checking", |compiler| compiler.type_checker_pass(st))?;
+        // Later passes rewrite the AST, so the index is built before them to answer queries about the source.
+        self.node_index = Some(NodeIndex::new(self.ast.as_repr()));
+        Ok(output)
     }
 
     /// Runs the compiler stages.
     pub fn compiler_stages(&mut self) -> Result<(SymbolTable, StructGraph, CallGraph)> {
-        let st = self.symbol_table_pass()?;
-        let (st, struct_graph, call_graph) = self.type_checker_pass(st)?;
+        let passes = order_passes(&self.passes)?;
+
+        let (st, struct_graph, call_graph) = self.type_checking_stages()?;
+
+        // The estimates walk the whole program, so they are only computed if they are recorded.
+        #[cfg(feature = "tracing")]
+        if tracing::enabled!(tracing::Level::INFO) {
+            for (transition, constraints) in self.estimated_constraints() {
+                let (program, transition) = (&self.program_name, transition.name);
+                tracing::info!(%program, %transition, constraints, "estimated constraints");
+            }
+        }
+
+        self.check_constraint_limit()?;
+        self.custom_passes(&passes, PassStage::TypeChecking, &st)?;
+
+        self.timed("move checking", |compiler| compiler.move_checking_pass())?;
+
+        self.timed("static analysis", |compiler| compiler.static_analysis_pass())?;
+
+        self.timed("loop-invariant code motion", |compiler| compiler.loop_invariant_code_motion_pass())?;
 
         // TODO: Make this pass optional.
-        let st = self.loop_unrolling_pass(st)?;
+        let st = self.timed("loop unrolling", |compiler| compiler.loop_unrolling_pass(st))?;
+        self.custom_passes(&passes, PassStage::LoopUnrolling, &st)?;
+
+        self.timed("dynamic indexing", |compiler| compiler.dynamic_indexing_pass())?;
+
+        self.timed("boolean simplification", |compiler| compiler.boolean_simplification_pass())?;
 
-        self.static_single_assignment_pass(&st)?;
+        let (st, struct_graph) =
+            self.timed("option lowering", |compiler| compiler.option_lowering_pass(st, struct_graph))?;
 
-        self.flattening_pass(&st)?;
+        self.timed("static single assignment", |compiler| compiler.static_single_assignment_pass(&st))?;
+        self.custom_passes(&passes, PassStage::StaticSingleAssignment, &st)?;
 
-        self.destructuring_pass()?;
+        self.timed("flattening", |compiler| compiler.flattening_pass(&st))?;
+        self.custom_passes(&passes, PassStage::Flattening, &st)?;
 
-        self.function_inlining_pass(&call_graph)?;
+        self.timed("destructuring", |compiler| compiler.destructuring_pass())?;
 
-        self.dead_code_elimination_pass()?;
+        self.timed("function inlining", |compiler| compiler.function_inlining_pass(&call_graph))?;
+        self.custom_passes(&passes, PassStage::FunctionInlining, &st)?;
+
+        if self.compiler_options.output.information_flow {
+            self.timed("information flow analysis", |compiler| compiler.information_flow_pass())?;
+        }
+
+        self.timed("dead code elimination", |compiler| compiler.dead_code_elimination_pass())?;
+        self.custom_passes(&passes, PassStage::DeadCodeElimination, &st)?;
 
         Ok((st, struct_graph, call_graph))
     }
@@ -323,14 +660,65 @@ impl<'a> Compiler<'a> {
     /// Returns a compiled Leo program.
     pub fn compile(&mut self) -> Result<(SymbolTable, String)> {
         // Parse the program.
-        self.parse_program()?;
+        self.timed("parsing", |compiler| compiler.parse_program())?;
         // Run the intermediate compiler stages.
         let (symbol_table, struct_graph, call_graph) = self.compiler_stages()?;
         // Run code generation.
-        let bytecode = self.code_generation_pass(&symbol_table, &struct_graph, &call_graph)?;
+        let bytecode = self.timed("code generation", |compiler| {
+            compiler.code_generation_pass(&symbol_table, &struct_graph, &call_graph)
+        })?;
+        // Write the metadata at the top of the file, so that it is available alongside the bytecode.
+        if !self.ast.as_repr().metadata.is_empty() {
+            self.write_metadata_to_json()?;
+        }
+        // Write the timing report, if enabled.
+        if self.compiler_options.output.timings {
+            self.timings.to_json_file(self.output_directory.clone(), &format!("{}.timings.json", self.program_name))?;
+        }
         Ok((symbol_table, bytecode))
     }
 
+    /// Parses and checks the program without lowering it, which is much faster than compiling it.
+    /// Runs the passes that report errors in the source, and the custom passes of the type checking stage,
+    /// but neither generates code nor writes any output other than the timing report.
+    pub fn check(&mut self) -> Result<SymbolTable> {
+        // Parse the program.
+        self.timed("parsing", |compiler| compiler.parse_program())?;
+        // Run the checking stages.
+        let symbol_table = self.checking_stages()?;
+        // Write the timing report, if enabled.
+        if self.compiler_options.output.timings {
+            self.timings.to_json_file(self.output_directory.clone(), &format!("{}.timings.json", self.program_name))?;
+        }
+        Ok(symbol_table)
+    }
+
+    /// Runs the stages that report errors in the source of a parsed program, along with the custom passes of the
+    /// type checking stage.
+    pub fn checking_stages(&mut self) -> Result<SymbolTable> {
+        let passes = order_passes(&self.passes)?;
+        let (symbol_table, ..) = self.type_checking_stages()?;
+        self.custom_passes(&passes, PassStage::TypeChecking, &symbol_table)?;
+        self.timed("move checking", |compiler| compiler.move_checking_pass())?;
+        self.timed("static analysis", |compiler| compiler.static_analysis_pass())?;
+        Ok(symbol_table)
+    }
+
+    /// Writes the metadata of the program to a JSON file, as an object from keys to values.
+    fn write_metadata_to_json(&self) -> Result<()> {
+        let metadata = self
+            .ast
+            .as_repr()
+            .metadata
+            .iter()
+            .map(|metadata| (metadata.key.to_string(), serde_json::Value::String(metadata.value.clone())))
+            .collect::<serde_json::Map<_, _>>();
+        let path = self.output_directory.join(format!("{}.metadata.json", self.program_name));
+        // Note that this unwrap is safe since the metadata only contains strings.
+        std::fs::write(&path, serde_json::to_string_pretty(&metadata).unwrap())
+            .map_err(|e| CompilerError::failed_to_write_metadata(&path, e).into())
+    }
+
     /// Writes the AST to a JSON file.
     fn write_ast_to_json(&self, file_suffix: &str) -> Result<()> {
         // Remove `Span`s if they are not enabled.
@@ -0,0 +1,403 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Completion candidates at a position in a Leo program.
+//!
+//! The candidates are computed from the parsed program, so that a language server can offer them from the last
+//! version of a file that parsed. After a `.`, the candidates are the members of the value before it. Otherwise, they
+//! are the variables in scope at the position, the declarations of the program and its imports, the programs that
+//! can be imported, and keywords. Only the candidates starting with the partial name before the position are returned.
+
+use crate::Compiler;
+
+use leo_ast::*;
+use leo_errors::{CompilerError, Result};
+use leo_parser::KEYWORD_TOKENS;
+use leo_span::{
+    source_map::FileName,
+    span::{BytePos, Pos},
+    sym,
+    symbol::with_session_globals,
+    Span,
+    Symbol,
+};
+
+use serde::Serialize;
+
+/// The kind of a completion candidate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompletionKind {
+    /// A variable or an input.
+    Variable,
+    /// A constant.
+    Constant,
+    /// A function, or a transition of an imported program.
+    Function,
+    /// A struct or a record.
+    Struct,
+    /// A member of a struct or a record.
+    Member,
+    /// A mapping.
+    Mapping,
+    /// A program that can be imported.
+    Program,
+    /// A keyword.
+    Keyword,
+}
+
+/// A completion candidate.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Completion {
+    /// The text inserted by the completion.
+    pub label: String,
+    /// The kind of the candidate.
+    pub kind: CompletionKind,
+    /// The type or the signature of the candidate, if any.
+    pub detail: Option<String>,
+}
+
+impl Completion {
+    fn new(label: impl ToString, kind: CompletionKind, detail: Option<String>) -> Self {
+        Self { label: label.to_string(), kind, detail }
+    }
+}
+
+impl Compiler<'_> {
+    /// Returns the completion candidates at the byte `offset` in the parsed source `file`.
+    pub fn completions(&self, file: &FileName, offset: usize) -> Result<Vec<Completion>> {
+        let source_file = with_session_globals(|s| s.source_map.find_source_file_by_name(file))
+            .ok_or_else(|| CompilerError::source_file_not_found(file))?;
+        let Some(before) = source_file.src.get(..offset.min(source_file.src.len())) else { return Ok(Vec::new()) };
+        let position = source_file.start_pos + BytePos::from_usize(before.len());
+
+        // The partial name before the position, and the value before it if it follows a `.`.
+        let prefix = &before[before.trim_end_matches(|c: char| c.is_ascii_alphanumeric() || c == '_').len()..];
+        let before_prefix = &before[..before.len() - prefix.len()];
+        let receiver = before_prefix.strip_suffix('.').map(|receiver| {
+            let start = receiver.trim_end_matches(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '.').len();
+            &receiver[start..]
+        });
+
+        let program = self.ast.as_repr();
+        let scopes = Self::program_scopes(program);
+        let scope = scopes.iter().copied().find(|scope| Self::contains(scope.span, position));
+        let variables = scope.map_or_else(Vec::new, |scope| Self::variables_in_scope(scope, position));
+
+        let candidates = match receiver {
+            Some(receiver) => Self::member_completions(&scopes, &variables, receiver),
+            None => {
+                let mut candidates = variables
+                    .iter()
+                    .map(|(name, type_)| Completion::new(name, CompletionKind::Variable, Some(type_.to_string())))
+                    .collect::<Vec<_>>();
+                if let Some(scope) = scope {
+                    candidates.extend(Self::declaration_completions(scope));
+                }
+                // Imported programs are referred to by their program IDs.
+                for (name, (import, _)) in program.imports.iter() {
+                    for (_, function) in import.program_scopes.values().flat_map(|scope| scope.functions.iter()) {
+                        if function.variant == Variant::Transition {
+                            let label = format!("{name}.aleo/{}", function.identifier);
+                            candidates.push(Completion::new(
+                                label,
+                                CompletionKind::Function,
+                                Some(Self::signature(function)),
+                            ));
+                        }
+                    }
+                }
+                candidates.extend(
+                    leo_parser::importable_programs(self.import_paths())
+                        .into_iter()
+                        .map(|name| Completion::new(format!("{name}.aleo"), CompletionKind::Program, None)),
+                );
+                candidates
+                    .extend(KEYWORD_TOKENS.iter().map(|token| Completion::new(token, CompletionKind::Keyword, None)));
+                candidates
+            }
+        };
+
+        let mut completions: Vec<Completion> = Vec::new();
+        for candidate in candidates.into_iter().filter(|candidate| candidate.label.starts_with(prefix)) {
+            if !completions.iter().any(|completion| completion.label == candidate.label) {
+                completions.push(candidate);
+            }
+        }
+        Ok(completions)
+    }
+
+    /// Returns the program scopes of a program and its imports, the program's own scopes first.
+    fn program_scopes(program: &Program) -> Vec<&ProgramScope> {
+        let mut scopes = program.program_scopes.values().collect::<Vec<_>>();
+        scopes.extend(program.imports.values().flat_map(|(import, _)| Self::program_scopes(import)));
+        scopes
+    }
+
+    /// Returns whether the span contains the position, including its end, where a name being typed ends.
+    fn contains(span: Span, position: BytePos) -> bool {
+        span.lo <= position && position <= span.hi
+    }
+
+    /// Returns the variables in scope at the position, with their declared types, the innermost ones last.
+    fn variables_in_scope(scope: &ProgramScope, position: BytePos) -> Vec<(Symbol, Type)> {
+        let mut variables = Vec::new();
+        for (_, function) in scope.functions.iter() {
+            let (inputs, block) = match &function.finalize {
+                Some(finalize) if Self::contains(finalize.span, position) => (&finalize.input, &finalize.block),
+                _ if Self::contains(function.span, position) => (&function.input, &function.block),
+                _ => continue,
+            };
+            variables.extend(inputs.iter().map(|input| (input.identifier().name, input.type_())));
+            Self::variables_in_block(block, position, &mut variables);
+        }
+        variables
+    }
+
+    /// Collects the variables declared in a block before the position.
+    fn variables_in_block(block: &Block, position: BytePos, variables: &mut Vec<(Symbol, Type)>) {
+        for statement in block.statements.iter().take_while(|statement| statement.span().lo < position) {
+            Self::variables_in_statement(statement, position, variables);
+        }
+    }
+
+    /// Collects the variables declared by a statement before the position, including those declared in the blocks
+    /// nested in it that contain the position.
+    fn variables_in_statement(statement: &Statement, position: BytePos, variables: &mut Vec<(Symbol, Type)>) {
+        match statement {
+            // A declaration is in scope after the end of the statement.
+            Statement::Const(_) | Statement::Definition(_) if position <= statement.span().hi => {}
+            Statement::Const(const_) => variables.push((const_.place.name, const_.type_.clone())),
+            Statement::Definition(definition) => match (&definition.place, &definition.type_) {
+                (Expression::Identifier(identifier), type_) => variables.push((identifier.name, type_.clone())),
+                (Expression::Tuple(tuple), Type::Tuple(types)) => {
+                    for (element, type_) in tuple.elements.iter().zip(types.elements()) {
+                        if let Expression::Identifier(identifier) = element {
+                            variables.push((identifier.name, type_.clone()));
+                        }
+                    }
+                }
+                _ => {}
+            },
+            Statement::Block(block) if Self::contains(block.span, position) => {
+                Self::variables_in_block(block, position, variables)
+            }
+            Statement::Conditional(conditional) => {
+                if Self::contains(conditional.then.span, position) {
+                    Self::variables_in_block(&conditional.then, position, variables);
+                }
+                if let Some(otherwise) = conditional.otherwise.as_deref() {
+                    if Self::contains(otherwise.span(), position) {
+                        Self::variables_in_statement(otherwise, position, variables);
+                    }
+                }
+            }
+            Statement::Iteration(iteration) if Self::contains(iteration.block.span, position) => {
+                variables.push((iteration.variable.name, iteration.type_.clone()));
+                Self::variables_in_block(&iteration.block, position, variables);
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the constants, mappings, functions, and structs declared in a program scope.
+    fn declaration_completions(scope: &ProgramScope) -> Vec<Completion> {
+        let consts = scope
+            .consts
+            .iter()
+            .map(|(name, const_)| Completion::new(name, CompletionKind::Constant, Some(const_.type_.to_string())));
+        let mappings = scope.mappings.iter().map(|(name, mapping)| {
+            let detail = format!("{} => {}", mapping.key_type, mapping.value_type);
+            Completion::new(name, CompletionKind::Mapping, Some(detail))
+        });
+        // Methods are called on their receivers, so they are only candidates after a `.`.
+        let functions =
+            scope.functions.iter().filter(|(_, function)| function.receiver.is_none()).map(|(name, function)| {
+                Completion::new(name, CompletionKind::Function, Some(Self::signature(function)))
+            });
+        let structs = scope.structs.iter().map(|(name, _)| Completion::new(name, CompletionKind::Struct, None));
+        consts.chain(mappings).chain(functions).chain(structs).collect()
+    }
+
+    /// Returns the members of the value named by `receiver`, e.g. `token` or `self` or `order.owner`.
+    fn member_completions(scopes: &[&ProgramScope], variables: &[(Symbol, Type)], receiver: &str) -> Vec<Completion> {
+        let address = || Some(Type::Address.to_string());
+        let mut names = receiver.split('.');
+        let type_ = match names.next() {
+            Some("self") => {
+                return vec![
+                    Completion::new(sym::caller, CompletionKind::Member, address()),
+                    Completion::new(sym::signer, CompletionKind::Member, address()),
+                ];
+            }
+            Some("block") => {
+                let height = Some(Type::Integer(IntegerType::U32).to_string());
+                return vec![Completion::new(sym::height, CompletionKind::Member, height)];
+            }
+            Some(name) => variables.iter().find(|(variable, _)| variable.to_string() == name),
+            None => None,
+        };
+        let Some((_, mut type_)) = type_.cloned() else { return Vec::new() };
+
+        let find_struct = |type_: &Type| match type_ {
+            Type::Identifier(name) => scopes
+                .iter()
+                .find_map(|scope| scope.structs.iter().find(|(other, _)| *other == name.name))
+                .map(|(_, struct_)| struct_),
+            _ => None,
+        };
+        for name in names {
+            let member = find_struct(&type_)
+                .and_then(|struct_| struct_.members.iter().find(|member| member.name().to_string() == name));
+            match member {
+                Some(member) => type_ = member.type_.clone(),
+                None => return Vec::new(),
+            }
+        }
+
+        let Some(struct_) = find_struct(&type_) else { return Vec::new() };
+        let members = struct_
+            .members
+            .iter()
+            .map(|member| Completion::new(member.name(), CompletionKind::Member, Some(member.type_.to_string())));
+        let methods = scopes
+            .iter()
+            .flat_map(|scope| scope.functions.iter())
+            .filter_map(|(_, function)| Some((function, function.receiver.as_ref()?)))
+            .filter(|(_, receiver)| receiver.struct_name == struct_.name())
+            .map(|(function, receiver)| {
+                Completion::new(receiver.method_name, CompletionKind::Function, Some(Self::signature(function)))
+            });
+        members.chain(methods).collect()
+    }
+
+    /// Returns the signature of a function, e.g. `(a: u32, b: u32) -> u32`.
+    fn signature(function: &Function) -> String {
+        let inputs = function.input.iter().map(|input| format!("{}: {}", input.identifier(), input.type_()));
+        format!("({}) -> {}", inputs.collect::<Vec<_>>().join(", "), function.output_type)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utils::with_parsed_program_and_imports;
+
+    use leo_parser::ImportPaths;
+    use std::path::PathBuf;
+
+    const PROGRAM: &str = "
+program test.aleo {
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    struct Line {
+        start: Point,
+        end: Point,
+    }
+
+    record Token {
+        owner: address,
+        amount: u64,
+    }
+
+    mapping balances: address => u64;
+
+    const SCALE: u32 = 10u32;
+
+    inline length(line: Line) -> u32 {
+        let dx: u32 = line.end.x - line.start.x;
+        return dx * SCALE;
+    }
+
+    transition mint(amount: u64, line: Line) -> Token {
+        let scaled: u64 = amount * 2u64;
+        if scaled > 10u64 {
+            let large: bool = true;
+        }
+        for i: u8 in 0u8..4u8 {
+            let square: u8 = i * i;
+        }
+        let total: u64 = scaled;
+        return Token { owner: self.caller, amount: total };
+    }
+}
+";
+
+    /// Returns the completions at the end of the first occurrence of `context` in `PROGRAM`, as their labels, kinds,
+    /// and details, keeping only those of the given kind if any.
+    fn complete(context: &str, kind: Option<CompletionKind>) -> Vec<(String, CompletionKind, Option<String>)> {
+        let import_paths = ImportPaths {
+            programs: [("token".to_string(), PathBuf::new())].into_iter().collect(),
+            ..Default::default()
+        };
+        with_parsed_program_and_imports(PROGRAM, import_paths, |compiler, file| {
+            let offset = PROGRAM.find(context).unwrap() + context.len();
+            let completions = compiler.completions(file, offset).unwrap();
+            completions
+                .into_iter()
+                .filter(|completion| kind.map_or(true, |kind| completion.kind == kind))
+                .map(|completion| (completion.label, completion.kind, completion.detail))
+                .collect()
+        })
+    }
+
+    /// Returns the labels of the completions.
+    fn labels(context: &str, kind: Option<CompletionKind>) -> Vec<String> {
+        complete(context, kind).into_iter().map(|(label, _, _)| label).collect()
+    }
+
+    #[test]
+    fn test_member_completions() {
+        let u32 = Some("u32".to_string());
+        assert_eq!(complete("let dx: u32 = line.end.", None), [
+            ("x".to_string(), CompletionKind::Member, u32.clone()),
+            ("y".to_string(), CompletionKind::Member, u32),
+        ]);
+        assert_eq!(labels("u32 = line.end.x - line.st", None), ["start"]);
+        assert_eq!(labels("owner: self.", None), ["caller", "signer"]);
+    }
+
+    #[test]
+    fn test_variable_completions() {
+        use CompletionKind::Variable;
+
+        // Variables are in scope after their declaration, and in the blocks that contain the position.
+        assert_eq!(labels("let large: bool = ", Some(Variable)), ["amount", "line", "scaled"]);
+        assert_eq!(labels("let square: u8 = ", Some(Variable)), ["amount", "line", "scaled", "i"]);
+        assert_eq!(labels("return Token { owner: ", Some(Variable)), ["amount", "line", "scaled", "total"]);
+        assert_eq!(complete("return d", None), [("dx".to_string(), Variable, Some("u32".to_string()))]);
+    }
+
+    #[test]
+    fn test_declaration_completions() {
+        // The declarations of the program, the programs that can be imported, and keywords.
+        let detail = |detail: &str| Some(detail.to_string());
+        assert_eq!(complete("return dx * SC", None), [("SCALE".to_string(), CompletionKind::Constant, detail("u32"))]);
+        assert_eq!(complete("mapping b", None), [
+            ("balances".to_string(), CompletionKind::Mapping, detail("address => u64")),
+            ("bool".to_string(), CompletionKind::Keyword, None),
+        ]);
+        assert_eq!(complete("inline le", None), [
+            ("length".to_string(), CompletionKind::Function, detail("(line: Line) -> u32")),
+            ("let".to_string(), CompletionKind::Keyword, None),
+        ]);
+        assert_eq!(labels("let to", None), ["token.aleo"]);
+        assert_eq!(labels("return T", None), ["Token"]);
+    }
+}
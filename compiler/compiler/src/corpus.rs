@@ -0,0 +1,441 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Input corpus generation for compiled Aleo programs.
+//!
+//! The interface of a compiled program declares the types and visibilities of the inputs of each function, and the
+//! layouts of its structs and records. This is enough to generate random, well-typed inputs for each function, e.g.
+//! to seed a fuzzer or to compare the results of different backends on the same inputs.
+//!
+//! Integers, fields, and scalars are drawn uniformly at random from their range, with a bias towards edge cases such as
+//! `0`, `1`, and the minimum and maximum values. Addresses and groups are drawn from a fixed pool of valid values,
+//! since generating them requires curve arithmetic. Functions with inputs that cannot be generated, i.e. signatures and
+//! records of other programs, are skipped.
+
+use crate::{
+    equivalence::{Item, Shape},
+    ItemKind,
+};
+
+use std::fmt;
+
+/// The modulus of the base field, i.e. the number of `field` values.
+const FIELD_MODULUS: &str = "8444461749428370424248824938781546531375899335154063827935233455917409239041";
+
+/// The modulus of the scalar field, i.e. the number of `scalar` values.
+const SCALAR_MODULUS: &str = "2111115437357092606062206234695386632838870926408408195193685246394721360383";
+
+/// Valid addresses, including the zero address.
+const ADDRESSES: &[&str] = &[
+    "aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc",
+    "aleo1fxs9s0w97lmkwlcmgn0z3nuxufdee5yck9wqrs0umevp7qs0sg9q5xxxzh",
+    "aleo1y7065c2jxkra5yzu9jfxq55klweqev0zas89lt9nxmfrqrafmq9qw2ktdg",
+];
+
+/// Valid group elements, given by their x-coordinates, including the identity.
+const GROUPS: &[&str] = &[
+    "0",
+    "6480683131255842390406647532838179519970794442201387718334686863304493823461",
+    "1511010328912449299156978046557700301564153667442988008615502964863620401388",
+];
+
+/// The type of an input, as declared in a compiled Aleo program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum InputType {
+    Address,
+    Boolean,
+    Field,
+    Group,
+    Scalar,
+    Signature,
+    /// An integer type, given by its signedness and its number of bits.
+    Integer(bool, u32),
+    Array(Box<InputType>, usize),
+    Struct(String),
+    Record(String),
+    /// A record of another program, e.g. `credits.aleo/credits`.
+    ExternalRecord(String),
+}
+
+impl InputType {
+    /// Parses a type declaration, e.g. `u8.private`, `Token.record`, or `[u8; 4u32].public`.
+    fn parse(declaration: &str) -> Option<Self> {
+        let (type_, visibility) = match declaration.rsplit_once('.') {
+            Some((type_, visibility)) if ["private", "public", "constant", "record"].contains(&visibility) => {
+                (type_, visibility)
+            }
+            _ => (declaration, ""),
+        };
+        if visibility == "record" {
+            return Some(match type_.contains('/') {
+                true => InputType::ExternalRecord(type_.to_string()),
+                false => InputType::Record(type_.to_string()),
+            });
+        }
+        Self::parse_plaintext(type_)
+    }
+
+    /// Parses a plaintext type, e.g. `u8`, `Point`, or `[u8; 4u32]`.
+    fn parse_plaintext(type_: &str) -> Option<Self> {
+        if let Some(array) = type_.strip_prefix('[').and_then(|type_| type_.strip_suffix(']')) {
+            let (element, length) = array.rsplit_once(';')?;
+            let length = length.trim().trim_end_matches("u32").parse().ok()?;
+            return Some(InputType::Array(Box::new(Self::parse_plaintext(element.trim())?), length));
+        }
+        Some(match type_ {
+            "address" => InputType::Address,
+            "boolean" => InputType::Boolean,
+            "field" => InputType::Field,
+            "group" => InputType::Group,
+            "scalar" => InputType::Scalar,
+            "signature" => InputType::Signature,
+            _ => match type_.split_at(1) {
+                ("u", bits) | ("i", bits) if bits.parse::<u32>().is_ok() => {
+                    InputType::Integer(type_.starts_with('i'), bits.parse().unwrap())
+                }
+                _ => InputType::Struct(type_.to_string()),
+            },
+        })
+    }
+
+    /// Returns the type in the syntax of Leo input files, e.g. `bool` or `[u8; 4]`.
+    fn to_leo_string(&self) -> String {
+        match self {
+            InputType::Address => "address".to_string(),
+            InputType::Boolean => "bool".to_string(),
+            InputType::Field => "field".to_string(),
+            InputType::Group => "group".to_string(),
+            InputType::Scalar => "scalar".to_string(),
+            InputType::Signature => "signature".to_string(),
+            InputType::Integer(signed, bits) => format!("{}{bits}", if *signed { "i" } else { "u" }),
+            InputType::Array(element, length) => format!("[{}; {length}]", element.to_leo_string()),
+            InputType::Struct(name) | InputType::Record(name) | InputType::ExternalRecord(name) => name.clone(),
+        }
+    }
+}
+
+/// A generated input value.
+#[derive(Clone, Debug)]
+enum Value {
+    /// A literal, e.g. `1u8`.
+    Literal(String),
+    Array(Vec<Value>),
+    /// A struct or record, with its name and its members. The members of a record also have a visibility.
+    Struct(String, Vec<(String, Value, Option<String>)>),
+}
+
+impl Value {
+    /// Returns the value in the syntax of Aleo values, as passed to `leo run`, e.g. `{ x: 1u8, y: 2u8 }`.
+    fn to_aleo_string(&self) -> String {
+        match self {
+            Value::Literal(literal) => literal.clone(),
+            Value::Array(elements) => {
+                format!("[{}]", elements.iter().map(|element| element.to_aleo_string()).collect::<Vec<_>>().join(", "))
+            }
+            Value::Struct(_, members) => {
+                let members = members
+                    .iter()
+                    .map(|(name, value, visibility)| match visibility {
+                        Some(visibility) => format!("{name}: {}.{visibility}", value.to_aleo_string()),
+                        None => format!("{name}: {}", value.to_aleo_string()),
+                    })
+                    .collect::<Vec<_>>();
+                format!("{{ {} }}", members.join(", "))
+            }
+        }
+    }
+
+    /// Returns the value in the syntax of Leo input files, e.g. `Point { x: 1u8, y: 2u8 }`.
+    fn to_leo_string(&self) -> String {
+        match self {
+            Value::Literal(literal) => literal.clone(),
+            Value::Array(elements) => {
+                format!("[{}]", elements.iter().map(|element| element.to_leo_string()).collect::<Vec<_>>().join(", "))
+            }
+            Value::Struct(name, members) => {
+                let members = members
+                    .iter()
+                    .map(|(member, value, _)| format!("{member}: {}", value.to_leo_string()))
+                    .collect::<Vec<_>>();
+                format!("{name} {{ {} }}", members.join(", "))
+            }
+        }
+    }
+}
+
+/// A set of inputs for one function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputCase {
+    /// The name of the function.
+    pub function: String,
+    /// The inputs in the syntax of Aleo values, in the order of the inputs of the function, e.g. as passed to `leo run`.
+    pub arguments: Vec<String>,
+    /// The definitions of the inputs in the syntax of Leo input files, e.g. `r0: u8 = 1u8;`.
+    pub definitions: Vec<String>,
+}
+
+impl InputCase {
+    /// Returns the contents of a Leo input file holding the inputs.
+    pub fn to_input_file(&self) -> String {
+        let mut file = format!("[{}]\n", self.function);
+        for definition in self.definitions.iter() {
+            file.push_str(definition);
+            file.push('\n');
+        }
+        file
+    }
+}
+
+/// The inputs generated for a compiled program.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InputCorpus {
+    /// The input sets, grouped by function in the order of the functions of the program.
+    pub cases: Vec<InputCase>,
+    /// The functions that were skipped, with the inputs that could not be generated.
+    pub skipped: Vec<(String, String)>,
+}
+
+impl fmt::Display for InputCorpus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Generated {} input sets.", self.cases.len())?;
+        for (function, type_) in self.skipped.iter() {
+            write!(f, "\n  - function `{function}` was skipped, since inputs of type `{type_}` cannot be generated")?;
+        }
+        Ok(())
+    }
+}
+
+/// A small, deterministic pseudorandom number generator, so that a corpus can be regenerated from its seed.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn next_u128(&mut self) -> u128 {
+        ((self.next() as u128) << 64) | self.next() as u128
+    }
+
+    /// Returns a random index below `length`.
+    fn below(&mut self, length: usize) -> usize {
+        (self.next() % length as u64) as usize
+    }
+}
+
+/// Generates random values for the inputs of the functions of a compiled program.
+struct Generator<'a> {
+    shape: &'a Shape,
+    rng: SplitMix64,
+}
+
+impl Generator<'_> {
+    /// Returns a random value of the given type, or `None` if values of the type cannot be generated.
+    fn value(&mut self, type_: &InputType) -> Option<Value> {
+        // One in four values is an edge case.
+        let edge = self.rng.below(4) == 0;
+        Some(match type_ {
+            InputType::Address => Value::Literal(ADDRESSES[self.rng.below(ADDRESSES.len())].to_string()),
+            InputType::Boolean => Value::Literal((self.rng.below(2) == 0).to_string()),
+            InputType::Field => Value::Literal(format!("{}field", self.modular(FIELD_MODULUS, edge))),
+            InputType::Group => Value::Literal(format!("{}group", GROUPS[self.rng.below(GROUPS.len())])),
+            InputType::Scalar => Value::Literal(format!("{}scalar", self.modular(SCALAR_MODULUS, edge))),
+            InputType::Integer(signed, bits) => Value::Literal(self.integer(*signed, *bits, edge)),
+            InputType::Array(element, length) => {
+                Value::Array((0..*length).map(|_| self.value(element)).collect::<Option<_>>()?)
+            }
+            InputType::Struct(name) => Value::Struct(name.clone(), self.members(ItemKind::Struct, name)?),
+            InputType::Record(name) => {
+                let mut members = self.members(ItemKind::Record, name)?;
+                members.push(("_nonce".to_string(), self.value(&InputType::Group)?, Some("public".to_string())));
+                Value::Struct(name.clone(), members)
+            }
+            InputType::Signature | InputType::ExternalRecord(_) => return None,
+        })
+    }
+
+    /// Returns random values for the members of a struct or record, with their visibilities.
+    fn members(&mut self, kind: ItemKind, name: &str) -> Option<Vec<(String, Value, Option<String>)>> {
+        let members = declarations(self.shape.find(kind, name)?);
+        members
+            .into_iter()
+            .map(|(member, declaration)| {
+                let (type_, visibility) = match declaration.rsplit_once('.') {
+                    Some((type_, visibility)) if ["private", "public", "constant"].contains(&visibility) => {
+                        (type_.to_string(), Some(visibility.to_string()))
+                    }
+                    _ => (declaration, None),
+                };
+                Some((member, self.value(&InputType::parse_plaintext(&type_)?)?, visibility))
+            })
+            .collect()
+    }
+
+    /// Returns a random integer of the given type, e.g. `-3i8`.
+    fn integer(&mut self, signed: bool, bits: u32, edge: bool) -> String {
+        let suffix = format!("{}{bits}", if signed { "i" } else { "u" });
+        // The values of the type, as an offset from the minimum value.
+        let mask = if bits == 128 { u128::MAX } else { (1u128 << bits) - 1 };
+        let offset = match edge {
+            // The minimum, `-1`, `0`, `1`, or the maximum value.
+            true => {
+                let zero = if signed { 1u128 << (bits - 1) } else { 0 };
+                [0, zero.wrapping_sub(1) & mask, zero, zero + 1, mask][self.rng.below(5)]
+            }
+            false => self.rng.next_u128() & mask,
+        };
+        match signed {
+            // Shift the offset by the minimum value, i.e. `-2^(bits - 1)`.
+            true => format!("{}{suffix}", (offset as i128).wrapping_add(i128::MIN >> (128 - bits))),
+            false => format!("{offset}{suffix}"),
+        }
+    }
+
+    /// Returns a random element below the modulus, e.g. of the base field.
+    fn modular(&mut self, modulus: &str, edge: bool) -> String {
+        match edge {
+            // `0`, `1`, or `-1`, i.e. the modulus minus one.
+            true => match self.rng.below(3) {
+                0 => "0".to_string(),
+                1 => "1".to_string(),
+                _ => {
+                    // The moduli are odd, so subtracting one only changes the last digit.
+                    let (digits, last) = modulus.split_at(modulus.len() - 1);
+                    format!("{digits}{}", last.parse::<u8>().unwrap() - 1)
+                }
+            },
+            // Note that every 128-bit value is below the moduli.
+            false => self.rng.next_u128().to_string(),
+        }
+    }
+}
+
+/// Returns the names and type declarations of the members of a struct or record, e.g. `("amount", "u64.private")`.
+fn declarations(item: &Item) -> Vec<(String, String)> {
+    item.body
+        .iter()
+        .filter_map(|line| {
+            let (name, declaration) = line.trim_end_matches(';').split_once(" as ")?;
+            Some((name.trim().to_string(), declaration.trim().to_string()))
+        })
+        .collect()
+}
+
+/// Generates `count` random input sets for each function of the compiled Aleo program.
+/// The same program, count, and seed always produce the same corpus.
+pub fn generate_input_corpus(program: &str, count: usize, seed: u64) -> InputCorpus {
+    let shape = Shape::parse(program);
+    let mut generator = Generator { shape: &shape, rng: SplitMix64(seed) };
+    let mut corpus = InputCorpus::default();
+
+    for function in shape.items.iter().filter(|item| item.kind == ItemKind::Function) {
+        // Parse the types of the inputs, e.g. `input r0 as u8.private;`.
+        let declarations = function
+            .body
+            .iter()
+            .filter_map(|line| line.strip_prefix("input "))
+            .map(|input| {
+                let input = input.trim_end_matches(';');
+                input.split_once(" as ").map_or(input, |(_, type_)| type_).trim().to_string()
+            })
+            .collect::<Vec<_>>();
+
+        let mut cases = Vec::with_capacity(count);
+        'cases: for _ in 0..count {
+            let mut case =
+                InputCase { function: function.name.clone(), arguments: Vec::new(), definitions: Vec::new() };
+            for (index, declaration) in declarations.iter().enumerate() {
+                let value = InputType::parse(declaration).and_then(|type_| Some((generator.value(&type_)?, type_)));
+                let Some((value, type_)) = value else {
+                    // Skip the function if values of the type cannot be generated.
+                    corpus.skipped.push((function.name.clone(), declaration.clone()));
+                    cases.clear();
+                    break 'cases;
+                };
+                case.arguments.push(value.to_aleo_string());
+                case.definitions.push(format!("r{index}: {} = {};", type_.to_leo_string(), value.to_leo_string()));
+            }
+            cases.push(case);
+        }
+        corpus.cases.extend(cases);
+    }
+
+    corpus
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PROGRAM: &str = "
+program test.aleo;
+
+struct Point:
+    x as i8;
+    y as i8;
+
+record Token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as u8.private;
+    input r1 as [Point; 2u32].public;
+    input r2 as Token.record;
+    output r2 as Token.record;
+
+function verify:
+    input r0 as signature.private;
+    input r1 as field.private;
+";
+
+    #[test]
+    fn test_corpus_respects_types() {
+        let corpus = generate_input_corpus(PROGRAM, 20, 7);
+        assert_eq!(corpus.cases.len(), 20);
+        assert_eq!(corpus.skipped, vec![("verify".to_string(), "signature.private".to_string())]);
+
+        for case in corpus.cases.iter() {
+            assert_eq!(case.function, "mint");
+            assert_eq!(case.arguments.len(), 3);
+            let value = case.arguments[0].strip_suffix("u8").unwrap();
+            assert!(value.parse::<u8>().is_ok(), "{value} is not a u8");
+            for value in case.arguments[1].split(|c| [':', ',', '}'].contains(&c)).filter_map(|s| s.strip_suffix("i8"))
+            {
+                assert!(value.trim().parse::<i8>().is_ok(), "{value} is not an i8");
+            }
+            assert!(case.arguments[2].starts_with("{ owner: aleo1"));
+            assert!(case.arguments[2].contains("u64.private, _nonce: "));
+            assert!(case.definitions[1].starts_with("r1: [Point; 2] = [Point { x: "));
+            assert!(case.definitions[2].starts_with("r2: Token = Token { owner: aleo1"));
+        }
+    }
+
+    #[test]
+    fn test_corpus_is_deterministic() {
+        assert_eq!(generate_input_corpus(PROGRAM, 5, 1), generate_input_corpus(PROGRAM, 5, 1));
+        assert_ne!(generate_input_corpus(PROGRAM, 5, 1), generate_input_corpus(PROGRAM, 5, 2));
+
+        let case = &generate_input_corpus(PROGRAM, 1, 3).cases[0];
+        let file = case.to_input_file();
+        assert!(file.starts_with("[mint]\nr0: u8 = "));
+        assert_eq!(file.lines().count(), 4);
+    }
+}
@@ -0,0 +1,314 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Structural equivalence checking for compiled Aleo programs.
+//!
+//! Two programs are structurally equivalent if they declare the same items, e.g. structs, records, mappings,
+//! closures, functions, and finalize blocks, and each item has the same instructions up to a consistent renaming of
+//! registers. This is useful for checking that an optimization or a refactor did not change the compiled program.
+
+use std::fmt;
+
+/// The kind of a top-level item in an Aleo program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ItemKind {
+    Import,
+    Struct,
+    Record,
+    Mapping,
+    Closure,
+    Function,
+    Finalize,
+}
+
+impl fmt::Display for ItemKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ItemKind::Import => write!(f, "import"),
+            ItemKind::Struct => write!(f, "struct"),
+            ItemKind::Record => write!(f, "record"),
+            ItemKind::Mapping => write!(f, "mapping"),
+            ItemKind::Closure => write!(f, "closure"),
+            ItemKind::Function => write!(f, "function"),
+            ItemKind::Finalize => write!(f, "finalize"),
+        }
+    }
+}
+
+/// A difference between two compiled programs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Difference {
+    /// The program name differs.
+    ProgramName { before: String, after: String },
+    /// An item only exists in the second program.
+    Added { kind: ItemKind, name: String },
+    /// An item only exists in the first program.
+    Removed { kind: ItemKind, name: String },
+    /// An item exists in both programs, but its body differs.
+    /// `line` is the index of the first differing line in the body, after the header of the item.
+    Changed { kind: ItemKind, name: String, line: usize },
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Difference::ProgramName { before, after } => write!(f, "program `{before}` was renamed to `{after}`"),
+            Difference::Added { kind, name } => write!(f, "{kind} `{name}` was added"),
+            Difference::Removed { kind, name } => write!(f, "{kind} `{name}` was removed"),
+            Difference::Changed { kind, name, line } => {
+                write!(f, "{kind} `{name}` differs, starting at line {} of its body", line + 1)
+            }
+        }
+    }
+}
+
+/// The result of checking two compiled programs for structural equivalence.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EquivalenceReport {
+    /// The differences between the two programs, in the order of the items of the first program,
+    /// followed by the items that were added in the second program.
+    pub differences: Vec<Difference>,
+}
+
+impl EquivalenceReport {
+    /// Returns `true` if the two programs are structurally equivalent.
+    pub fn is_equivalent(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+impl fmt::Display for EquivalenceReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.is_equivalent() {
+            true => write!(f, "The programs are structurally equivalent."),
+            false => {
+                write!(f, "The programs are not structurally equivalent:")?;
+                for difference in self.differences.iter() {
+                    write!(f, "\n  - {difference}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A top-level item of an Aleo program, with its body normalized.
+#[derive(Debug)]
+pub(crate) struct Item {
+    pub(crate) kind: ItemKind,
+    pub(crate) name: String,
+    pub(crate) body: Vec<String>,
+}
+
+/// An Aleo program, split into its top-level items.
+#[derive(Debug, Default)]
+pub(crate) struct Shape {
+    pub(crate) name: String,
+    pub(crate) items: Vec<Item>,
+}
+
+impl Shape {
+    /// Returns the item with the given kind and name, if it exists.
+    pub(crate) fn find(&self, kind: ItemKind, name: &str) -> Option<&Item> {
+        self.items.iter().find(|item| item.kind == kind && item.name == name)
+    }
+
+    /// Splits the text of an Aleo program into its top-level items.
+    pub(crate) fn parse(program: &str) -> Self {
+        let mut shape = Shape::default();
+
+        for line in program.lines() {
+            let line = line.trim();
+            // Skip empty lines and comments.
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+
+            let (keyword, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            let name = rest.trim().trim_end_matches([':', ';']).trim().to_string();
+            let kind = match keyword {
+                "program" => {
+                    shape.name = name;
+                    continue;
+                }
+                "import" => ItemKind::Import,
+                "struct" => ItemKind::Struct,
+                "record" => ItemKind::Record,
+                "mapping" => ItemKind::Mapping,
+                "closure" => ItemKind::Closure,
+                "function" => ItemKind::Function,
+                "finalize" => ItemKind::Finalize,
+                // Otherwise, the line belongs to the body of the current item.
+                _ => {
+                    if let Some(item) = shape.items.last_mut() {
+                        item.body.push(line.split_whitespace().collect::<Vec<_>>().join(" "));
+                    }
+                    continue;
+                }
+            };
+            shape.items.push(Item { kind, name, body: Vec::new() });
+        }
+
+        // Rename the registers of each item in the order in which they first appear.
+        shape.items.iter_mut().for_each(|item| item.body = canonicalize_registers(&item.body));
+
+        shape
+    }
+}
+
+/// Renames the registers in `body` in the order in which they first appear, i.e. the first register is renamed to
+/// `r0`, the second to `r1`, and so on.
+fn canonicalize_registers(body: &[String]) -> Vec<String> {
+    let mut registers: Vec<String> = Vec::new();
+    let mut rename = |token: &str| -> String {
+        // Split off any member accesses, e.g. `r0.owner`.
+        let (register, members) = token.split_once('.').map_or((token, None), |(r, m)| (r, Some(m)));
+        let is_register = register.len() > 1
+            && register.starts_with('r')
+            && register[1..].chars().all(|character| character.is_ascii_digit());
+        if !is_register {
+            return token.to_string();
+        }
+        let index = match registers.iter().position(|existing| existing == register) {
+            Some(index) => index,
+            None => {
+                registers.push(register.to_string());
+                registers.len() - 1
+            }
+        };
+        match members {
+            Some(members) => format!("r{index}.{members}"),
+            None => format!("r{index}"),
+        }
+    };
+
+    body.iter()
+        .map(|line| {
+            line.split(' ')
+                .map(|token| {
+                    // Preserve punctuation that may be attached to a register, e.g. `r0;` or `[r1]`.
+                    let start = token.find(|character: char| character.is_ascii_alphanumeric()).unwrap_or(0);
+                    let end = token
+                        .rfind(|character: char| character.is_ascii_alphanumeric() || character == '_')
+                        .map_or(token.len(), |end| end + 1);
+                    format!("{}{}{}", &token[..start], rename(&token[start..end]), &token[end..])
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect()
+}
+
+/// Checks whether two compiled Aleo programs are structurally equivalent.
+/// The programs are compared item by item, and registers are compared up to a consistent renaming.
+pub fn check_equivalence(before: &str, after: &str) -> EquivalenceReport {
+    let before = Shape::parse(before);
+    let after = Shape::parse(after);
+
+    let mut differences = Vec::new();
+
+    if before.name != after.name {
+        differences.push(Difference::ProgramName { before: before.name.clone(), after: after.name.clone() });
+    }
+
+    for item in before.items.iter() {
+        match after.find(item.kind, &item.name) {
+            None => differences.push(Difference::Removed { kind: item.kind, name: item.name.clone() }),
+            Some(other) => {
+                let first_difference =
+                    item.body.iter().zip(other.body.iter()).position(|(line, other_line)| line != other_line).or_else(
+                        || (item.body.len() != other.body.len()).then(|| item.body.len().min(other.body.len())),
+                    );
+                if let Some(line) = first_difference {
+                    differences.push(Difference::Changed { kind: item.kind, name: item.name.clone(), line });
+                }
+            }
+        }
+    }
+
+    for item in after.items.iter() {
+        if before.find(item.kind, &item.name).is_none() {
+            differences.push(Difference::Added { kind: item.kind, name: item.name.clone() });
+        }
+    }
+
+    EquivalenceReport { differences }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PROGRAM: &str = "
+program test.aleo;
+
+record Token:
+    owner as address.private;
+    amount as u64.private;
+
+function transfer:
+    input r0 as Token.record;
+    input r1 as address.private;
+    input r2 as u64.private;
+    sub r0.amount r2 into r3;
+    cast r1 r2 into r4 as Token.record;
+    cast r0.owner r3 into r5 as Token.record;
+    output r4 as Token.record;
+    output r5 as Token.record;
+";
+
+    #[test]
+    fn test_identical_programs_are_equivalent() {
+        assert!(check_equivalence(PROGRAM, PROGRAM).is_equivalent());
+    }
+
+    #[test]
+    fn test_registers_are_compared_up_to_renaming() {
+        // Consistently swapping `r3` and `r4`, and changing the whitespace, does not change the program.
+        let renamed = PROGRAM.replace("r3", "r9").replace("r4", "r3").replace("r9", "r4").replace(" into ", "  into ");
+        assert!(check_equivalence(PROGRAM, &renamed).is_equivalent());
+
+        // Swapping the order of the outputs does.
+        let swapped = PROGRAM
+            .replace("output r4", "output r9")
+            .replace("output r5", "output r4")
+            .replace("output r9", "output r5");
+        assert_eq!(check_equivalence(PROGRAM, &swapped).differences, vec![Difference::Changed {
+            kind: ItemKind::Function,
+            name: "transfer".to_string(),
+            line: 6,
+        }]);
+    }
+
+    #[test]
+    fn test_differences_are_reported() {
+        let after = PROGRAM
+            .replace("sub r0.amount r2 into r3;", "sub.w r0.amount r2 into r3;")
+            .replace("record Token:", "struct Pair:\n    a as u8;\n    b as u8;\n\nrecord Token:");
+        let report = check_equivalence(PROGRAM, &after.replace("function transfer:", "function send:"));
+        assert_eq!(report.differences, vec![
+            Difference::Removed { kind: ItemKind::Function, name: "transfer".to_string() },
+            Difference::Added { kind: ItemKind::Struct, name: "Pair".to_string() },
+            Difference::Added { kind: ItemKind::Function, name: "send".to_string() },
+        ]);
+
+        let report = check_equivalence(PROGRAM, &after);
+        assert_eq!(report.differences, vec![
+            Difference::Changed { kind: ItemKind::Function, name: "transfer".to_string(), line: 3 },
+            Difference::Added { kind: ItemKind::Struct, name: "Pair".to_string() },
+        ]);
+    }
+}
@@ -0,0 +1,527 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Inlay hints, the annotations an editor shows inline with a Leo program.
+//!
+//! The standard hints are the inferred types of unsuffixed literals and the names of the parameters at call sites.
+//! The cost hints estimate the constraints each statement of a circuit adds. The estimates assume that integers,
+//! and fields and scalars that are compared, are decomposed into one constraint per bit, and that other operations
+//! cost one constraint, except for the additions of fields and groups, which are free. They are meant to compare
+//! statements with each other, and not to predict the size of the synthesized circuit.
+//!
+//! The same estimates bound the size of the circuits of untrusted programs, by adding up the costs of the statements
+//! of each transition, the functions it calls, and every iteration of its loops.
+
+use crate::Compiler;
+
+use leo_ast::*;
+use leo_errors::{CompilerError, Result};
+use leo_passes::{ResolutionTable, TypeTable};
+use leo_span::{
+    source_map::{FileName, SourceFile},
+    span::{BytePos, Pos},
+    symbol::with_session_globals,
+    Span,
+};
+
+use indexmap::IndexMap;
+use serde::Serialize;
+use std::cell::RefCell;
+
+/// The hints to compute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InlayHintMode {
+    /// Type and parameter hints.
+    Standard,
+    /// Cost hints.
+    Costs,
+}
+
+/// The kind of an inlay hint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InlayHintKind {
+    /// The inferred type of an unsuffixed literal, shown after the literal.
+    Type,
+    /// The name of the parameter an argument is passed to, shown before the argument.
+    Parameter,
+    /// The estimated number of constraints of a statement, shown after the statement.
+    Cost,
+}
+
+/// An inlay hint in a source file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct InlayHint {
+    /// The kind of the hint.
+    pub kind: InlayHintKind,
+    /// The byte offset of the hint in the file.
+    pub offset: usize,
+    /// The line of the hint, starting from 1.
+    pub line: usize,
+    /// The column of the hint, starting from 1.
+    pub column: usize,
+    /// The text of the hint.
+    pub label: String,
+}
+
+impl Compiler<'_> {
+    /// Returns the inlay hints of the source `file`, in the order in which they appear in the file.
+    /// Type checking is run unless it has already been run. Since the hints are computed from the AST, they must be
+    /// requested before the later compiler stages rewrite it.
+    pub fn inlay_hints(&mut self, file: &FileName, mode: InlayHintMode) -> Result<Vec<InlayHint>> {
+        let source_file = with_session_globals(|s| s.source_map.find_source_file_by_name(file))
+            .ok_or_else(|| CompilerError::source_file_not_found(file))?;
+        if self.node_index().is_none() {
+            self.type_checking_stages()?;
+        }
+
+        let mut functions = IndexMap::new();
+        Self::collect_functions(self.ast.as_repr(), &mut functions);
+        let mut collector = InlayHintCollector {
+            type_table: self.type_table(),
+            resolution_table: self.resolution_table(),
+            functions: &functions,
+            source_file: &source_file,
+            mode,
+            recording: true,
+            cost: 0,
+            function_costs: IndexMap::new(),
+            hints: Vec::new(),
+        };
+        collector.visit_program(self.ast.as_repr());
+
+        let mut hints = collector.hints;
+        hints.sort_by_key(|hint| hint.offset);
+        Ok(hints)
+    }
+
+    /// Returns the estimated number of constraints of each transition of the program, which must have been type
+    /// checked. Unlike cost hints, the estimates count every iteration of a loop.
+    pub fn estimated_constraints(&self) -> Vec<(Identifier, u64)> {
+        let mut functions = IndexMap::new();
+        Self::collect_functions(self.ast.as_repr(), &mut functions);
+        let mut estimates = Vec::new();
+        for scope in self.ast.as_repr().program_scopes.values() {
+            let Some(location) = with_session_globals(|s| s.source_map.span_to_location(scope.span)) else { continue };
+            let mut collector = InlayHintCollector {
+                type_table: self.type_table(),
+                resolution_table: self.resolution_table(),
+                functions: &functions,
+                source_file: &location.source_file,
+                mode: InlayHintMode::Costs,
+                recording: false,
+                cost: 0,
+                function_costs: IndexMap::new(),
+                hints: Vec::new(),
+            };
+            for (_, function) in scope.functions.iter().filter(|(_, function)| function.variant == Variant::Transition)
+            {
+                collector.cost = 0;
+                collector.visit_block(&function.block);
+                estimates.push((function.identifier, collector.cost));
+            }
+        }
+        estimates
+    }
+
+    /// Collects the functions of a program and its imports, by the ID of their names.
+    fn collect_functions<'a>(program: &'a Program, functions: &mut IndexMap<NodeID, &'a Function>) {
+        program.imports.values().for_each(|(import, _)| Self::collect_functions(import, functions));
+        for scope in program.program_scopes.values() {
+            functions.extend(scope.functions.iter().map(|(_, function)| (function.identifier.id, function)));
+        }
+    }
+}
+
+/// Collects the inlay hints of a source file, and estimates the costs of statements.
+struct InlayHintCollector<'a> {
+    type_table: &'a TypeTable,
+    resolution_table: &'a ResolutionTable,
+    /// The functions of the program and its imports, by the ID of their names.
+    functions: &'a IndexMap<NodeID, &'a Function>,
+    source_file: &'a SourceFile,
+    mode: InlayHintMode,
+    /// Whether hints are recorded, which they are not while estimating the cost of a called function.
+    recording: bool,
+    /// The estimated cost of the expressions visited since the start of the current statement.
+    cost: u64,
+    /// The estimated costs of the functions called so far, by the ID of their names.
+    function_costs: IndexMap<NodeID, u64>,
+    hints: Vec<InlayHint>,
+}
+
+impl<'a> InlayHintCollector<'a> {
+    /// Records a hint at the given position, if it is in the source file.
+    fn hint(&mut self, kind: InlayHintKind, position: BytePos, label: String) {
+        if !self.recording || position < self.source_file.start_pos || position > self.source_file.end_pos {
+            return;
+        }
+        let Some(location) = with_session_globals(|s| s.source_map.span_to_location(Span::new(position, position)))
+        else {
+            return;
+        };
+        self.hints.push(InlayHint {
+            kind,
+            offset: (position - self.source_file.start_pos).to_usize(),
+            line: location.line_start,
+            column: location.col_start,
+            label,
+        });
+    }
+
+    /// Returns the position after the given statement, including its semicolon.
+    fn end_of_statement(&self, statement: &Statement) -> BytePos {
+        let end = statement.span().hi;
+        let offset = end.to_usize().saturating_sub(self.source_file.start_pos.to_usize());
+        match self.source_file.src.as_bytes().get(offset) {
+            Some(b';') => end + BytePos(1),
+            _ => end,
+        }
+    }
+
+    /// Returns the type inferred for the given expression.
+    fn type_of(&self, expression: &Expression) -> Option<Type> {
+        self.type_table.get(&expression.id())
+    }
+
+    /// Returns the number of bits a value of the given type is decomposed into.
+    fn bits(type_: &Type) -> u64 {
+        match type_ {
+            Type::Boolean => 1,
+            Type::Integer(integer_type) => integer_type.size_in_bits() as u64,
+            Type::Address | Type::Field | Type::Group => 253,
+            Type::Scalar => 251,
+            Type::Array(array) => Self::bits(array.element_type()) * array.length() as u64,
+            Type::Tuple(tuple) => tuple.elements().iter().map(Self::bits).sum(),
+            _ => 1,
+        }
+    }
+
+    /// Returns the estimated cost of an operation on operands of the given type.
+    fn operation_cost(type_: Option<Type>, linear: bool) -> u64 {
+        match type_ {
+            Some(Type::Field | Type::Group) if linear => 0,
+            Some(Type::Field | Type::Group | Type::Scalar | Type::Address | Type::Boolean) => 1,
+            Some(type_) => Self::bits(&type_),
+            None => 0,
+        }
+    }
+
+    /// Returns the estimated cost of a call to the given function, whose body is part of the calling circuit unless
+    /// it is a transition.
+    fn function_cost(&mut self, function: &'a Function) -> u64 {
+        if function.variant == Variant::Transition {
+            return 0;
+        }
+        // Functions may be called many times, e.g. by each other, so their costs are only estimated once.
+        if let Some(cost) = self.function_costs.get(&function.identifier.id) {
+            return *cost;
+        }
+        let (recording, cost) = (self.recording, self.cost);
+        self.recording = false;
+        self.cost = 0;
+        self.visit_block(&function.block);
+        let function_cost = self.cost;
+        self.recording = recording;
+        self.cost = cost;
+        self.function_costs.insert(function.identifier.id, function_cost);
+        function_cost
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for InlayHintCollector<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(array) => {
+                self.visit_expression(&array.array, additional);
+                self.visit_expression(&array.index, additional);
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                // Hashes and commitments decompose their arguments into bits.
+                for argument in function.arguments.iter() {
+                    self.cost += self.type_of(argument).map_or(0, |type_| Self::bits(&type_));
+                    self.visit_expression(argument, additional);
+                }
+            }
+            AccessExpression::Member(member) => self.visit_expression(&member.inner, additional),
+            AccessExpression::Tuple(tuple) => self.visit_expression(&tuple.tuple, additional),
+            AccessExpression::AssociatedConstant(_) => {}
+        }
+    }
+
+    fn visit_binary(&mut self, input: &'a BinaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        let type_ = self.type_of(&input.left);
+        self.cost += match input.op {
+            BinaryOperation::Eq | BinaryOperation::Neq => 1,
+            BinaryOperation::Add | BinaryOperation::AddWrapped | BinaryOperation::Sub | BinaryOperation::SubWrapped => {
+                Self::operation_cost(type_, true)
+            }
+            BinaryOperation::Mul | BinaryOperation::Div => match type_ {
+                Some(Type::Field) => 1,
+                // Multiplying a group by a scalar decomposes the scalar.
+                Some(Type::Group) => Self::bits(&Type::Scalar),
+                type_ => Self::operation_cost(type_, false),
+            },
+            BinaryOperation::Gt | BinaryOperation::Gte | BinaryOperation::Lt | BinaryOperation::Lte => {
+                type_.map_or(0, |type_| Self::bits(&type_))
+            }
+            _ => Self::operation_cost(type_, false),
+        };
+        self.visit_expression(&input.left, additional);
+        self.visit_expression(&input.right, additional);
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        let function = match &*input.function {
+            Expression::Identifier(name) => self
+                .resolution_table
+                .get(&name.id)
+                .and_then(|reference| self.functions.get(&reference.definition.id).copied()),
+            _ => None,
+        };
+        if let Some(function) = function {
+            for (argument, input) in input.arguments.iter().zip(function.input.iter()) {
+                let parameter = input.identifier();
+                // An argument named like its parameter needs no hint.
+                let named = matches!(argument, Expression::Identifier(identifier) if identifier.name == parameter.name);
+                if self.mode == InlayHintMode::Standard && !named {
+                    self.hint(InlayHintKind::Parameter, argument.span().lo, format!("{}:", parameter.name));
+                }
+            }
+            if self.mode == InlayHintMode::Costs {
+                self.cost = self.cost.saturating_add(self.function_cost(function));
+            }
+        }
+        if let Expression::Access(AccessExpression::Member(access)) = &*input.function {
+            self.visit_expression(&access.inner, additional);
+        }
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+
+    fn visit_cast(&mut self, input: &'a CastExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        let from = self.type_of(&input.expression).map_or(0, |type_| Self::bits(&type_));
+        self.cost += from.max(Self::bits(&input.type_));
+        self.visit_expression(&input.expression, additional);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input
+            .members
+            .iter()
+            .filter_map(|member| member.expression.as_ref())
+            .for_each(|expression| self.visit_expression(expression, additional));
+    }
+
+    fn visit_literal(&mut self, input: &'a Literal, _additional: &Self::AdditionalInput) -> Self::Output {
+        if let Literal::Unsuffixed(_, span, id) = input {
+            if let (InlayHintMode::Standard, Some(type_)) = (self.mode, self.type_table.get(id)) {
+                self.hint(InlayHintKind::Type, span.hi, type_.to_string());
+            }
+        }
+    }
+
+    fn visit_ternary(&mut self, input: &'a TernaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.cost += Self::operation_cost(self.type_of(&input.if_true), false);
+        self.visit_expression(&input.condition, additional);
+        self.visit_expression(&input.if_true, additional);
+        self.visit_expression(&input.if_false, additional);
+    }
+
+    fn visit_unary(&mut self, input: &'a UnaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        let linear = matches!(input.op, UnaryOperation::Negate | UnaryOperation::Double);
+        self.cost += Self::operation_cost(self.type_of(&input.receiver), linear);
+        self.visit_expression(&input.receiver, additional);
+    }
+}
+
+impl<'a> StatementVisitor<'a> for InlayHintCollector<'a> {
+    fn visit_statement(&mut self, input: &'a Statement) {
+        // The cost of a statement does not include the costs of the statements nested in it.
+        let outer = std::mem::take(&mut self.cost);
+        match input {
+            Statement::Assert(stmt) => self.visit_assert(stmt),
+            Statement::Assign(stmt) => self.visit_assign(stmt),
+            Statement::Block(stmt) => self.visit_block(stmt),
+            Statement::Conditional(stmt) => self.visit_conditional(stmt),
+            Statement::Console(stmt) => self.visit_console(stmt),
+            Statement::Const(stmt) => self.visit_const(stmt),
+            Statement::Definition(stmt) => self.visit_definition(stmt),
+            Statement::Expression(stmt) => self.visit_expression_statement(stmt),
+            Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Return(stmt) => self.visit_return(stmt),
+        }
+        // While estimating the cost of a function, the costs of all of its statements are added up.
+        if !self.recording {
+            self.cost = self.cost.saturating_add(outer);
+            return;
+        }
+        let cost = std::mem::replace(&mut self.cost, outer);
+
+        if self.mode == InlayHintMode::Costs && cost > 0 {
+            let position = match input {
+                Statement::Conditional(stmt) => stmt.condition.span().hi,
+                Statement::Iteration(stmt) => stmt.stop.span().hi,
+                _ => self.end_of_statement(input),
+            };
+            let label = if cost == 1 { "~1 constraint".to_string() } else { format!("~{cost} constraints") };
+            self.hint(InlayHintKind::Cost, position, label);
+        }
+    }
+
+    fn visit_assert(&mut self, input: &'a AssertStatement) {
+        // An assertion constrains its condition.
+        self.cost += 1;
+        match &input.variant {
+            AssertVariant::Assert(expression) => self.visit_expression(expression, &()),
+            AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
+                self.visit_expression(left, &());
+                self.visit_expression(right, &());
+            }
+            AssertVariant::Fail(_) => {}
+        }
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.visit_expression(&input.start, &());
+        self.visit_expression(&input.stop, &());
+        let before = std::mem::take(&mut self.cost);
+        self.visit_block(&input.block);
+        // While estimating the cost of a function, the body of a loop is counted once for each iteration.
+        if !self.recording {
+            let bound =
+                |value: &RefCell<Option<Value>>| value.borrow().as_ref().and_then(|value| i128::try_from(value).ok());
+            let iterations = match (bound(&input.start_value), bound(&input.stop_value)) {
+                (Some(start), Some(stop)) => stop.saturating_sub(start).saturating_add(input.inclusive as i128).max(0),
+                _ => 1,
+            };
+            self.cost = self.cost.saturating_mul(u64::try_from(iterations).unwrap_or(u64::MAX));
+        }
+        self.cost = self.cost.saturating_add(before);
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for InlayHintCollector<'a> {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        input.consts.iter().for_each(|(_, const_)| self.visit_const(const_));
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.visit_block(&input.block);
+        // Finalize blocks are executed on chain, so they add no constraints.
+        if let (InlayHintMode::Standard, Some(finalize)) = (self.mode, &input.finalize) {
+            self.visit_block(&finalize.block);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utils::with_parsed_program;
+
+    const PROGRAM: &str = "
+program test.aleo {
+    const LIMIT: u8 = 10;
+
+    inline double(a: u32) -> u32 {
+        return a + a;
+    }
+
+    transition main(a: u32, b: u32, x: field) -> u32 {
+        let c: u32 = double(b) + 1;
+        let d: u32 = double(a);
+        let y: field = x * x + x;
+        if c > d {
+            c = c * 2;
+        }
+        return c + d;
+    }
+}
+";
+
+    /// Returns `PROGRAM` with its inlay hints inserted as comments.
+    fn annotate(mode: InlayHintMode) -> String {
+        with_parsed_program(PROGRAM, |compiler, file| {
+            let mut annotated = PROGRAM.to_string();
+            for hint in compiler.inlay_hints(file, mode).unwrap().iter().rev() {
+                let line = annotated[..hint.offset].matches('\n').count() + 1;
+                assert_eq!(
+                    (hint.line, hint.column),
+                    (line, hint.offset - annotated[..hint.offset].rfind('\n').unwrap())
+                );
+                annotated.insert_str(hint.offset, &format!("/*{}*/", hint.label));
+            }
+            annotated
+        })
+    }
+
+    #[test]
+    fn test_inlay_hints() {
+        assert_eq!(
+            annotate(InlayHintMode::Standard),
+            "
+program test.aleo {
+    const LIMIT: u8 = 10/*u8*/;
+
+    inline double(a: u32) -> u32 {
+        return a + a;
+    }
+
+    transition main(a: u32, b: u32, x: field) -> u32 {
+        let c: u32 = double(/*a:*/b) + 1/*u32*/;
+        let d: u32 = double(a);
+        let y: field = x * x + x;
+        if c > d {
+            c = c * 2/*u32*/;
+        }
+        return c + d;
+    }
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_cost_hints() {
+        // The cost of a call includes the cost of the called function.
+        assert_eq!(
+            annotate(InlayHintMode::Costs),
+            "
+program test.aleo {
+    const LIMIT: u8 = 10;
+
+    inline double(a: u32) -> u32 {
+        return a + a;/*~32 constraints*/
+    }
+
+    transition main(a: u32, b: u32, x: field) -> u32 {
+        let c: u32 = double(b) + 1;/*~64 constraints*/
+        let d: u32 = double(a);/*~32 constraints*/
+        let y: field = x * x + x;/*~1 constraint*/
+        if c > d/*~32 constraints*/ {
+            c = c * 2;/*~32 constraints*/
+        }
+        return c + d;/*~32 constraints*/
+    }
+}
+"
+        );
+    }
+}
@@ -19,8 +19,47 @@
 #![allow(clippy::upper_case_acronyms)]
 #![doc = include_str!("../README.md")]
 
+mod call_graph;
+pub use call_graph::*;
+
+mod compatibility;
+pub use compatibility::*;
+
 mod compiler;
 pub use compiler::*;
 
+mod completion;
+pub use completion::*;
+
+mod corpus;
+pub use corpus::*;
+
+mod equivalence;
+pub use equivalence::*;
+
+mod inlay_hints;
+pub use inlay_hints::*;
+
+mod rename;
+pub use rename::*;
+
+mod semantic_diff;
+pub use semantic_diff::*;
+
+mod sandbox;
+pub use sandbox::*;
+
 mod options;
 pub use options::*;
+
+mod plugin;
+pub use plugin::*;
+
+mod timings;
+pub use timings::*;
+
+mod type_at;
+pub use type_at::*;
+
+#[cfg(test)]
+mod test_utils;
@@ -16,6 +16,8 @@
 
 // NOTE: If compiler passes are made optional, pass preconditions and invariants may not necessarily hold true.
 
+use leo_passes::RecordSchema;
+
 #[derive(Clone, Default)]
 pub struct CompilerOptions {
     /// Build options.
@@ -28,6 +30,18 @@ pub struct CompilerOptions {
 pub struct BuildOptions {
     /// Whether to enable dead code elimination.
     pub dce_enabled: bool,
+    /// The maximum number of statements a single function may unroll to, if any.
+    pub max_unrolled_statements: Option<usize>,
+    /// The maximum number of loop iterations a single function may unroll, if any.
+    pub max_unrolled_iterations: Option<usize>,
+    /// The depth to which expressions, statements, and types may be nested, if not the default.
+    pub max_expression_depth: Option<usize>,
+    /// The maximum number of elements of an array type, counting the elements of nested arrays, if any.
+    pub max_array_size: Option<usize>,
+    /// The maximum number of distinct identifiers in a file, if any.
+    pub max_identifiers: Option<usize>,
+    /// The requirements on the members of records, which depend on the compilation target.
+    pub record_schema: RecordSchema,
 }
 
 #[derive(Clone, Default)]
@@ -58,4 +72,12 @@ pub struct OutputOptions {
     pub inlined_ast: bool,
     /// If enabled writes the AST after dead code elimination.
     pub dce_ast: bool,
+    /// If enabled writes a report of the time spent in each compiler pass.
+    pub timings: bool,
+    /// If enabled writes the call graph of the program and its imports, as JSON and DOT.
+    pub call_graph: bool,
+    /// If enabled writes a report of the public outputs that depend on private inputs.
+    pub information_flow: bool,
+    /// If enabled writes the program as Leo source after dead code elimination.
+    pub expanded: bool,
 }
@@ -0,0 +1,182 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The extension point for passes defined outside of the compiler, e.g. lints specific to a code base.
+//!
+//! A [`CompilerPass`] is registered with [`Compiler::with_pass`](crate::Compiler::with_pass), and runs after one of
+//! the built-in stages listed in [`PassStage`]. Passes that run after the same stage run in the order in which they
+//! were registered, unless [`CompilerPass::after`] requires otherwise.
+
+use leo_ast::{Ast, NodeBuilder};
+use leo_errors::{emitter::Handler, CompilerError, Result};
+use leo_passes::{SymbolTable, TypeTable};
+
+use std::rc::Rc;
+
+/// The built-in stages after which custom passes can run, in the order in which they are run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PassStage {
+    /// After type checking, when every expression of the AST has a type in the type table.
+    TypeChecking,
+    /// After loop unrolling, when the AST no longer contains loops.
+    LoopUnrolling,
+    /// After static single assignment, when every variable is assigned exactly once.
+    StaticSingleAssignment,
+    /// After flattening, when transitions and functions no longer contain conditional statements.
+    Flattening,
+    /// After function inlining, when the AST no longer contains calls to inline functions.
+    FunctionInlining,
+    /// After dead code elimination, right before code generation.
+    DeadCodeElimination,
+}
+
+/// The state of the compilation that a custom pass has access to.
+pub struct PassContext<'a> {
+    /// The AST, which the pass may transform.
+    pub ast: &'a mut Ast,
+    /// The symbol table as of the stage the pass runs after.
+    pub symbol_table: &'a SymbolTable,
+    /// The types of the nodes of the AST. Nodes created by the pass should be given a type.
+    pub type_table: &'a TypeTable,
+    /// The builder of node IDs, used to create new nodes.
+    pub node_builder: &'a NodeBuilder,
+    /// The handler, through which the pass reports errors and warnings.
+    pub handler: &'a Handler,
+}
+
+/// A compiler pass defined outside of the compiler.
+pub trait CompilerPass {
+    /// The name of the pass, which identifies it in ordering constraints and timings.
+    fn name(&self) -> &'static str;
+
+    /// The built-in stage after which the pass runs.
+    fn stage(&self) -> PassStage;
+
+    /// The names of the custom passes that must run before this one.
+    /// These must run after the same stage as this pass, or an earlier one.
+    fn after(&self) -> &[&'static str] {
+        &[]
+    }
+
+    /// Runs the pass. Compilation stops after the pass if it returns an error or emits one through the handler.
+    fn run(&self, context: PassContext) -> Result<()>;
+}
+
+/// Orders the given passes by stage, and within a stage, so that each pass runs after the passes it names in
+/// [`CompilerPass::after`]. Passes are otherwise kept in the order in which they were registered.
+pub(crate) fn order_passes(passes: &[Rc<dyn CompilerPass>]) -> Result<Vec<Rc<dyn CompilerPass>>> {
+    // Check that the names of the passes are unique, and that every dependency is registered and does not run after
+    // a later stage.
+    for (index, pass) in passes.iter().enumerate() {
+        if passes[..index].iter().any(|other| other.name() == pass.name()) {
+            return Err(CompilerError::duplicate_pass_name(pass.name()).into());
+        }
+        for dependency in pass.after() {
+            match passes.iter().find(|other| other.name() == *dependency) {
+                None => return Err(CompilerError::unknown_pass_dependency(pass.name(), dependency).into()),
+                Some(other) if other.stage() > pass.stage() => {
+                    return Err(CompilerError::pass_dependency_runs_later(pass.name(), dependency).into());
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    // Repeatedly pick the first pass, by stage and then registration order, whose dependencies have all run.
+    let mut remaining: Vec<_> = passes.to_vec();
+    remaining.sort_by_key(|pass| pass.stage());
+    let mut ordered: Vec<Rc<dyn CompilerPass>> = Vec::with_capacity(passes.len());
+    while !remaining.is_empty() {
+        let ready = remaining.iter().position(|pass| {
+            pass.after().iter().all(|dependency| ordered.iter().any(|other| other.name() == *dependency))
+        });
+        match ready {
+            // A pass can only become ready before a pass of an earlier stage if the dependencies are cyclic.
+            Some(index) if remaining[index].stage() == remaining[0].stage() => ordered.push(remaining.remove(index)),
+            _ => {
+                let stage = remaining[0].stage();
+                let cycle = remaining
+                    .iter()
+                    .filter(|pass| pass.stage() == stage)
+                    .map(|pass| format!("`{}`", pass.name()))
+                    .collect::<Vec<_>>();
+                return Err(CompilerError::cyclic_pass_dependencies(cycle.join(", ")).into());
+            }
+        }
+    }
+
+    Ok(ordered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Named {
+        name: &'static str,
+        stage: PassStage,
+        after: Vec<&'static str>,
+    }
+
+    impl CompilerPass for Named {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn stage(&self) -> PassStage {
+            self.stage
+        }
+
+        fn after(&self) -> &[&'static str] {
+            &self.after
+        }
+
+        fn run(&self, _: PassContext) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn order(passes: &[(&'static str, PassStage, &[&'static str])]) -> Result<Vec<&'static str>> {
+        let passes: Vec<Rc<dyn CompilerPass>> = passes
+            .iter()
+            .map(|(name, stage, after)| Rc::new(Named { name, stage: *stage, after: after.to_vec() }) as _)
+            .collect();
+        Ok(order_passes(&passes)?.iter().map(|pass| pass.name()).collect())
+    }
+
+    #[test]
+    fn test_order_passes() {
+        use PassStage::*;
+
+        // Passes run in the order of their stages, then in the order of their constraints and registration.
+        assert_eq!(
+            order(&[
+                ("lint", DeadCodeElimination, &["count"]),
+                ("count", TypeChecking, &[]),
+                ("check", TypeChecking, &["prepare"]),
+                ("prepare", TypeChecking, &[]),
+                ("report", TypeChecking, &[]),
+            ])
+            .unwrap(),
+            ["count", "prepare", "check", "report", "lint"]
+        );
+
+        assert!(order(&[("lint", TypeChecking, &["missing"])]).is_err());
+        assert!(order(&[("lint", TypeChecking, &["count"]), ("count", Flattening, &[])]).is_err());
+        assert!(order(&[("lint", TypeChecking, &["count"]), ("count", TypeChecking, &["lint"])]).is_err());
+        assert!(order(&[("lint", TypeChecking, &[]), ("lint", Flattening, &[])]).is_err());
+    }
+}
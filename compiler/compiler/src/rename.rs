@@ -0,0 +1,235 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Renaming of the names defined in a Leo program.
+//!
+//! A rename starts from any occurrence of a name, finds its definition using the results of the name resolution
+//! pass, and returns an edit for every occurrence of that definition, across the program and its imports.
+
+use crate::Compiler;
+
+use leo_ast::Program;
+use leo_errors::{CompilerError, Result};
+use leo_passes::{Definition, DefinitionKind};
+use leo_span::{source_map::FileName, span::Pos, symbol::with_session_globals, Span};
+
+use serde::Serialize;
+
+/// A replacement of an occurrence of a name in a source file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct RenameEdit {
+    /// The file containing the occurrence.
+    pub file: String,
+    /// The byte offsets of the start and the end of the occurrence in the file.
+    pub range: (usize, usize),
+    /// The line of the occurrence, starting from 1.
+    pub line: usize,
+    /// The column of the occurrence, starting from 1.
+    pub column: usize,
+    /// The text replacing the occurrence.
+    pub new_text: String,
+}
+
+impl Compiler<'_> {
+    /// Returns the edits that rename the name at `occurrence` to `new_name`.
+    /// `occurrence` may point at the definition of the name or at any of its uses.
+    /// The name resolution pass must have been run.
+    pub fn rename(&self, occurrence: Span, new_name: &str) -> Result<Vec<RenameEdit>> {
+        // Find the definition of the name at the given location.
+        let references = self.resolution_table().references();
+        let definition = references
+            .iter()
+            .find(|reference| reference.span.lo <= occurrence.lo && occurrence.hi <= reference.span.hi)
+            .map(|reference| reference.definition)
+            .ok_or_else(|| CompilerError::rename_target_not_found(occurrence))?;
+
+        // Check that the rename is safe.
+        if definition.kind == DefinitionKind::Member {
+            return Err(CompilerError::cannot_rename_member(definition.name, occurrence).into());
+        }
+        if !leo_parser::is_identifier(new_name) {
+            return Err(CompilerError::invalid_rename(new_name, occurrence).into());
+        }
+        if let Some(conflict) = self.rename_conflict(&definition, new_name) {
+            return Err(CompilerError::rename_conflict(new_name, conflict.span).into());
+        }
+
+        // Note that an occurrence may be resolved more than once, e.g. `x` in `x += 1u8`.
+        let mut spans = references
+            .iter()
+            .filter(|reference| reference.definition.id == definition.id)
+            .map(|reference| reference.span)
+            .collect::<Vec<_>>();
+        spans.sort_by_key(|span| span.lo);
+        spans.dedup();
+
+        Ok(spans.into_iter().filter_map(|span| Self::rename_edit(span, new_name)).collect())
+    }
+
+    /// Returns an existing definition named `new_name` that would conflict with `definition` after renaming it.
+    fn rename_conflict(&self, definition: &Definition, new_name: &str) -> Option<Definition> {
+        // Functions, structs, and values are in separate namespaces.
+        let namespace = |kind| match kind {
+            DefinitionKind::Function => 0,
+            DefinitionKind::Struct => 1,
+            DefinitionKind::Member => 2,
+            DefinitionKind::Const | DefinitionKind::Input | DefinitionKind::Mapping | DefinitionKind::Variable => 3,
+        };
+        // Local definitions are visible in the function or finalize block that declares them.
+        // Since Leo does not allow shadowing, a local definition also conflicts with any global definition.
+        let mut bodies = Vec::new();
+        Self::function_bodies(self.ast.as_repr(), &mut bodies);
+        let body = |definition: &Definition| match definition.kind {
+            DefinitionKind::Const | DefinitionKind::Input | DefinitionKind::Variable => {
+                bodies.iter().copied().find(|body| body.lo <= definition.span.lo && definition.span.hi <= body.hi)
+            }
+            _ => None,
+        };
+
+        self.resolution_table().definitions().into_iter().find(|other| {
+            other.name.to_string() == new_name
+                && other.id != definition.id
+                && namespace(other.kind) == namespace(definition.kind)
+                && match (body(definition), body(other)) {
+                    (Some(body), Some(other_body)) => body == other_body,
+                    _ => true,
+                }
+        })
+    }
+
+    /// Collects the spans of the functions and finalize blocks of a program and its imports.
+    fn function_bodies(program: &Program, bodies: &mut Vec<Span>) {
+        program.imports.values().for_each(|(import, _)| Self::function_bodies(import, bodies));
+        for scope in program.program_scopes.values() {
+            for (_, function) in scope.functions.iter() {
+                bodies.push(function.span);
+                bodies.extend(function.finalize.as_ref().map(|finalize| finalize.span));
+            }
+        }
+    }
+
+    /// Returns the edit replacing the text at `span` with `new_text`.
+    fn rename_edit(span: Span, new_text: &str) -> Option<RenameEdit> {
+        let location = with_session_globals(|s| s.source_map.span_to_location(span))?;
+        let start = location.source_file.start_pos;
+        Some(RenameEdit {
+            file: match &location.source_file.name {
+                FileName::Real(path) => path.display().to_string(),
+                FileName::Custom(name) => name.clone(),
+            },
+            range: ((span.lo - start).to_usize(), (span.hi - start).to_usize()),
+            line: location.line_start,
+            column: location.col_start,
+            new_text: new_text.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utils::with_parsed_program;
+
+    use leo_span::span::BytePos;
+
+    const PROGRAM: &str = "
+program test.aleo {
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    inline double(a: u32) -> u32 {
+        return a + a;
+    }
+
+    transition main(a: u32, b: u32) -> Point {
+        let c: u32 = double(a);
+        c += b;
+        return Point { x: c, y: double(c) };
+    }
+
+    transition shorthand(x: u32) -> Point {
+        let y: u32 = x;
+        return Point { x, y };
+    }
+}
+";
+
+    /// Renames the name at the start of the first occurrence of `context` in `PROGRAM` to `new_name`, and returns the
+    /// renamed program.
+    fn rename(context: &str, new_name: &str) -> Result<String> {
+        with_parsed_program(PROGRAM, |compiler, _| {
+            compiler.symbol_table_pass()?;
+            compiler.name_resolution_pass()?;
+
+            let start = PROGRAM.find(context).unwrap();
+            let end = start + context.find(|c: char| !c.is_alphanumeric() && c != '_').unwrap_or(context.len());
+            let occurrence = Span::new(BytePos::from_usize(start), BytePos::from_usize(end));
+            let edits = compiler.rename(occurrence, new_name)?;
+
+            // Apply the edits from the last to the first, so that the offsets remain valid.
+            let mut renamed = PROGRAM.to_string();
+            for edit in edits.iter().rev() {
+                assert_eq!(edit.file, "test");
+                renamed.replace_range(edit.range.0..edit.range.1, &edit.new_text);
+            }
+            Ok(renamed)
+        })
+    }
+
+    #[test]
+    fn test_rename_variable() {
+        // Rename `c` from its use in `c += b`, which is resolved twice.
+        let renamed = rename("c += b", "total").unwrap();
+        assert!(renamed.contains("let total: u32 = double(a);"));
+        assert!(renamed.contains("total += b;"));
+        assert!(renamed.contains("return Point { x: total, y: double(total) };"));
+    }
+
+    #[test]
+    fn test_rename_function_and_struct() {
+        let renamed = rename("double(a)", "twice").unwrap();
+        assert_eq!(renamed.matches("twice").count(), 3);
+        assert!(!renamed.contains("double"));
+
+        let renamed = rename("Point {", "Vector").unwrap();
+        assert_eq!(renamed.matches("Vector").count(), 5);
+    }
+
+    #[test]
+    fn test_rename_input_used_in_shorthand() {
+        let renamed = rename("x: u32)", "z").unwrap();
+        assert!(renamed.contains("transition shorthand(z: u32) -> Point {"));
+        assert!(renamed.contains("let y: u32 = z;"));
+        assert!(renamed.contains("return Point { z, y };"));
+    }
+
+    #[test]
+    fn test_unsafe_renames_are_rejected() {
+        // `y` is already defined in `shorthand`, but not in `main`.
+        assert!(rename("a: u32, b", "y").is_ok());
+        assert!(rename("x: u32)", "y").is_err());
+        // `double` is a function, and `c` is a local of `main`.
+        assert!(rename("Point {", "double").is_ok());
+        assert!(rename("double(a: u32)", "main").is_err());
+        assert!(rename("b: u32)", "c").is_err());
+        // Members cannot be renamed, and the new name must be an identifier.
+        assert!(rename("x: u32,", "z").is_err());
+        assert!(rename("c += b", "let").is_err());
+        assert!(rename("c += b", "1c").is_err());
+    }
+}
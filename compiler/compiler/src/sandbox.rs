@@ -0,0 +1,381 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Compilation of untrusted programs, e.g. by a playground service.
+//!
+//! A sandboxed compilation reads and writes no files: it only imports the programs given to the sandbox, and it
+//! rejects `include_constants!`. It fails with an error, rather than exhausting the resources of the service, when the
+//! program exceeds the limits of the sandbox on the size of its sources, on the size of its expressions, arrays, and
+//! loops, on the time and memory spent compiling it, or on the estimated constraints of its transitions.
+//! A panic of the compiler is reported as an error rather than unwinding into the service, as long as the service is
+//! not built with `panic = "abort"`. The panic message is still printed by the panic hook of the service.
+//!
+//! The time limit is checked after each compiler stage, and during loop unrolling and function inlining, whose output
+//! can grow far beyond the program. The memory limit is only checked after each stage. Any other stage runs to
+//! completion before the compilation is abandoned, so the limits on the nesting of expressions and on the size of
+//! arrays and loops bound the size of the AST a stage visits, and the depth of its recursion, but not its running time.
+
+use crate::{BuildOptions, Compiler, CompilerOptions};
+
+use leo_errors::{
+    emitter::{DiagnosticRecord, DiagnosticRecords, DiagnosticSink, Handler},
+    CompilerError,
+    Result,
+};
+use leo_parser::{ImportPaths, TokenClass};
+use leo_span::{source_map::FileName, symbol::SESSION_GLOBALS};
+
+use indexmap::IndexMap;
+use std::{
+    any::Any,
+    io,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+/// Limits on the resources compilation may use. Unlike the build options, they do not affect the compiled program.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ResourceLimits {
+    /// The time by which compilation must end, checked after each stage, and during loop unrolling and inlining.
+    pub deadline: Option<Instant>,
+    /// The number of AST nodes compilation may create, checked after each stage. It bounds the memory used.
+    pub max_nodes: Option<usize>,
+    /// The number of constraints each transition is estimated to have, checked after type checking.
+    /// See [`Compiler::estimated_constraints`].
+    pub max_constraints: Option<u64>,
+}
+
+/// Compiles untrusted programs with limited resources and without access to the file system.
+#[derive(Clone)]
+pub struct Sandbox {
+    /// The build options, including the limits on the size of expressions, arrays, loops, and identifiers.
+    pub build: BuildOptions,
+    /// The sources of the programs that can be imported, keyed by program name.
+    pub imports: IndexMap<String, String>,
+    /// The size of each source, in bytes.
+    pub max_source_size: usize,
+    /// The time a compilation may take.
+    pub time_limit: Duration,
+    /// The number of AST nodes a compilation may create.
+    pub max_nodes: usize,
+    /// The number of constraints each transition is estimated to have.
+    pub max_constraints: u64,
+}
+
+impl Default for Sandbox {
+    fn default() -> Self {
+        Self {
+            build: BuildOptions {
+                dce_enabled: true,
+                max_unrolled_statements: Some(100_000),
+                max_unrolled_iterations: Some(10_000),
+                max_expression_depth: None,
+                max_array_size: Some(4_096),
+                max_identifiers: Some(10_000),
+                record_schema: Default::default(),
+            },
+            imports: IndexMap::new(),
+            max_source_size: 1 << 20,
+            time_limit: Duration::from_secs(10),
+            max_nodes: 1_000_000,
+            max_constraints: 4_000_000,
+        }
+    }
+}
+
+/// The outcome of a sandboxed compilation.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SandboxOutput {
+    /// The bytecode of the program, if it compiled.
+    pub bytecode: Option<String>,
+    /// The AST of the program as JSON, if it was parsed by [`Sandbox::parse`].
+    pub ast: Option<String>,
+    /// The program as Leo source, if it was formatted by [`Sandbox::format`] or expanded by [`Sandbox::expand`].
+    pub source: Option<String>,
+    /// The errors and warnings reported while compiling the program.
+    pub diagnostics: Vec<DiagnosticRecord>,
+}
+
+/// How far a sandboxed compilation goes.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Goal {
+    Parse,
+    Format,
+    Check,
+    Expand,
+    Compile,
+}
+
+impl Sandbox {
+    /// Compiles the program `program_name` from its `source`, and returns its bytecode along with the diagnostics.
+    /// Each compilation runs in a session of its own, so compilations share no state and never panic.
+    pub fn compile(&self, program_name: &str, source: &str) -> SandboxOutput {
+        self.run(program_name, source, Goal::Compile)
+    }
+
+    /// Checks the program `program_name` like `leo check`, without lowering it, and returns the diagnostics.
+    /// The constraint limit does not apply, since no circuit is built.
+    pub fn check(&self, program_name: &str, source: &str) -> SandboxOutput {
+        self.run(program_name, source, Goal::Check)
+    }
+
+    /// Parses the program `program_name`, and returns its AST as JSON along with the diagnostics.
+    pub fn parse(&self, program_name: &str, source: &str) -> SandboxOutput {
+        self.run(program_name, source, Goal::Parse)
+    }
+
+    /// Formats the program `program_name`, and returns it as Leo source along with the diagnostics.
+    /// The program is printed from its AST, so it is only formatted if it has no comments, which the AST does not keep.
+    pub fn format(&self, program_name: &str, source: &str) -> SandboxOutput {
+        self.run(program_name, source, Goal::Format)
+    }
+
+    /// Compiles the program `program_name` up to code generation, and returns it as Leo source after constant
+    /// propagation, loop unrolling, and inlining, like `leo build --emit expanded`, along with the diagnostics.
+    pub fn expand(&self, program_name: &str, source: &str) -> SandboxOutput {
+        self.run(program_name, source, Goal::Expand)
+    }
+
+    /// Runs the compiler on the program in a session of its own.
+    fn run(&self, program_name: &str, source: &str, goal: Goal) -> SandboxOutput {
+        let records = DiagnosticRecords::default();
+        let sink = DiagnosticSink::with_output(None, Box::new(io::sink())).with_records(records.clone());
+        let handler = Handler::new(Box::new(sink));
+        let output = SESSION_GLOBALS.set(&Default::default(), || {
+            let result =
+                panic::catch_unwind(AssertUnwindSafe(|| self.run_in_session(program_name, source, goal, &handler)))
+                    .unwrap_or_else(|payload| Err(CompilerError::compiler_panicked(panic_message(&*payload)).into()));
+            // Errors returned by the compiler have not been emitted yet, unlike those reported by the passes.
+            result.map_err(|err| handler.emit_err(err)).ok().flatten()
+        });
+        match goal {
+            Goal::Parse => SandboxOutput { ast: output, diagnostics: records.take(), ..Default::default() },
+            Goal::Format | Goal::Expand => {
+                SandboxOutput { source: output, diagnostics: records.take(), ..Default::default() }
+            }
+            _ => SandboxOutput { bytecode: output, diagnostics: records.take(), ..Default::default() },
+        }
+    }
+
+    /// Runs the compiler on the program within the current session, and returns its AST as JSON if it was only
+    /// parsed, its source if it was formatted or expanded, or its bytecode if it was compiled.
+    fn run_in_session(
+        &self,
+        program_name: &str,
+        source: &str,
+        goal: Goal,
+        handler: &Handler,
+    ) -> Result<Option<String>> {
+        let imports = self.imports.iter().map(|(name, source)| (name.as_str(), source.as_str()));
+        for (name, source) in std::iter::once((program_name, source)).chain(imports) {
+            if source.len() > self.max_source_size {
+                return Err(CompilerError::source_too_large(name, source.len(), self.max_source_size).into());
+            }
+        }
+
+        let import_paths = ImportPaths { sources: self.imports.clone(), sandboxed: true, ..Default::default() };
+        let resource_limits = ResourceLimits {
+            deadline: Some(Instant::now() + self.time_limit),
+            max_nodes: Some(self.max_nodes),
+            max_constraints: Some(self.max_constraints),
+        };
+        let options = CompilerOptions { build: self.build.clone(), output: Default::default() };
+        let mut compiler = Compiler::new(
+            program_name.to_string(),
+            "aleo".to_string(),
+            handler,
+            PathBuf::new(),
+            PathBuf::new(),
+            Some(options),
+        )
+        .with_import_paths(import_paths)
+        .with_resource_limits(resource_limits);
+
+        let name = FileName::Custom(format!("{program_name}.leo"));
+        compiler.timed("parsing", |compiler| compiler.parse_program_from_string(source, name))?;
+        match goal {
+            Goal::Parse => return compiler.ast.to_json_string().map(Some),
+            Goal::Format => {
+                let tokens = leo_parser::classify_tokens(source, Default::default())?;
+                if tokens.iter().any(|(class, _)| *class == TokenClass::Comment) {
+                    return Err(CompilerError::cannot_format_comments(program_name).into());
+                }
+                // Before type checking, the type table is empty, and the program is printed as it was parsed.
+                return Ok(Some(compiler.expanded_source()));
+            }
+            Goal::Check => return compiler.checking_stages().map(|_| None),
+            Goal::Expand | Goal::Compile => {}
+        }
+        let (symbol_table, struct_graph, call_graph) = compiler.compiler_stages()?;
+        if goal == Goal::Expand {
+            return Ok(Some(compiler.expanded_source()));
+        }
+        compiler
+            .timed("code generation", |compiler| {
+                compiler.code_generation_pass(&symbol_table, &struct_graph, &call_graph)
+            })
+            .map(Some)
+    }
+}
+
+/// Returns the message of a panic, which is a string unless the panic was raised with another payload.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "unknown panic".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use leo_errors::{emitter::Severity, LeoMessageCode, ParserError};
+
+    /// Returns the codes of the errors of the output.
+    fn errors(output: &SandboxOutput) -> Vec<String> {
+        output
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == Severity::Error)
+            .filter_map(|diagnostic| diagnostic.code.clone())
+            .collect()
+    }
+
+    #[test]
+    fn compiles_with_in_memory_imports() {
+        let mut sandbox = Sandbox::default();
+        sandbox.imports.insert(
+            "token".to_string(),
+            "program token.aleo {\n    transition mint(a: u32) -> u32 {\n        return a;\n    }\n}\n".to_string(),
+        );
+        let output = sandbox.compile(
+            "test",
+            "import token.leo;\nprogram test.aleo {\n    transition main(a: u32) -> u32 {\n        return token.leo/mint(a);\n    }\n}\n",
+        );
+        assert_eq!(errors(&output), Vec::<String>::new());
+        assert!(output.bytecode.unwrap().contains("import token.aleo;"));
+    }
+
+    #[test]
+    fn checks_without_generating_code() {
+        let sandbox = Sandbox::default();
+        let output = sandbox
+            .check("test", "program test.aleo {\n    transition main(a: u32) -> u32 {\n        return a;\n    }\n}\n");
+        assert_eq!(output, SandboxOutput::default());
+
+        let output = sandbox
+            .check("test", "program test.aleo {\n    transition main(a: u32) -> u8 {\n        return a;\n    }\n}\n");
+        assert_eq!(errors(&output).len(), 1);
+    }
+
+    #[test]
+    fn parses_to_json() {
+        let output = Sandbox::default().parse("test", "program test.aleo {\n    transition main() {}\n}\n");
+        assert!(output.ast.unwrap().contains("\"program_scopes\""));
+        assert_eq!(output.bytecode, None);
+    }
+
+    #[test]
+    fn formats_programs() {
+        let source = "program test.aleo {\n  transition main(a:u32)->u32{let b:u32=a+1u32;\n  return b*2u32;}\n}\n";
+        let formatted = Sandbox::default().format("test", source).source.unwrap();
+        assert_eq!(
+            formatted,
+            "program test.aleo {\n    transition main(a: u32) -> u32 {\n        let b: u32 = a + 1u32;\n        return b * 2u32;\n    }\n}\n"
+        );
+        assert_eq!(Sandbox::default().format("test", &formatted).source.as_ref(), Some(&formatted));
+
+        let output =
+            Sandbox::default().format("test", "// A comment.\nprogram test.aleo {\n    transition main() {}\n}\n");
+        assert_eq!(output.source, None);
+        assert_eq!(errors(&output), vec![CompilerError::cannot_format_comments("").error_code()]);
+    }
+
+    #[test]
+    fn expands_programs() {
+        let source = "program test.aleo {\n    transition main(a: u32) -> u32 {\n        for i: u32 in 0u32..2u32 {\n            a = a + i;\n        }\n        return a;\n    }\n}\n";
+        let expanded = Sandbox::default().expand("test", source).source.unwrap();
+        assert!(!expanded.contains("for "));
+        assert!(Sandbox::default().compile("test", &expanded).bytecode.is_some());
+    }
+
+    #[test]
+    fn reads_no_files() {
+        let output =
+            Sandbox::default().compile("test", "import token.leo;\nprogram test.aleo {\n    transition main() {}\n}\n");
+        assert_eq!(output.bytecode, None);
+        assert_eq!(errors(&output), vec![
+            CompilerError::import_not_in_sandbox("token", Default::default()).error_code()
+        ]);
+
+        let output = Sandbox::default().compile(
+            "test",
+            "program test.aleo {\n    const C: u32 = include_constants!(\"/etc/passwd\");\n    transition main() {}\n}\n",
+        );
+        assert_eq!(output.bytecode, None);
+        assert_eq!(errors(&output).len(), 1);
+    }
+
+    #[test]
+    fn enforces_limits() {
+        let source = "program test.aleo {\n    transition main(a: u32) -> u32 {\n        return a * a;\n    }\n}\n";
+        let sandbox = Sandbox { max_source_size: 16, ..Default::default() };
+        assert_eq!(errors(&sandbox.compile("test", source)), vec![
+            CompilerError::source_too_large("test", 0, 0).error_code()
+        ]);
+
+        let sandbox = Sandbox { max_nodes: 4, ..Default::default() };
+        assert_eq!(errors(&sandbox.compile("test", source)), vec![
+            CompilerError::node_limit_exceeded(0, 0, "").error_code()
+        ]);
+
+        let sandbox = Sandbox { time_limit: Duration::ZERO, ..Default::default() };
+        assert_eq!(errors(&sandbox.compile("test", source)), vec![CompilerError::time_limit_exceeded("").error_code()]);
+
+        // The loop multiplies the cost of its body by the number of its iterations.
+        let source = "program test.aleo {\n    transition main(a: u32) -> u32 {\n        for i: u32 in 0u32..100u32 {\n            a = a * a;\n        }\n        return a;\n    }\n}\n";
+        let sandbox = Sandbox { max_constraints: 1_000, ..Default::default() };
+        assert_eq!(errors(&sandbox.compile("test", source)), vec![CompilerError::constraint_limit_exceeded(
+            "",
+            0,
+            0,
+            Default::default()
+        )
+        .error_code()]);
+        assert!(Sandbox::default().compile("test", source).bytecode.is_some());
+    }
+
+    #[test]
+    fn rejects_deeply_nested_blocks() {
+        let source = format!(
+            "program test.aleo {{\n    transition main() {{\n        {}{}\n    }}\n}}\n",
+            "{".repeat(1_000),
+            "}".repeat(1_000)
+        );
+        // Unoptimized builds use more stack for each nested block than a test thread has for the default depth.
+        let output = std::thread::Builder::new()
+            .stack_size(16 << 20)
+            .spawn(move || Sandbox::default().compile("test", &source))
+            .unwrap()
+            .join()
+            .unwrap();
+        assert_eq!(output.bytecode, None);
+        assert_eq!(errors(&output), vec![ParserError::too_deeply_nested(0, Default::default()).error_code()]);
+    }
+}
@@ -0,0 +1,352 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Semantic diffs between two versions of a Leo program, e.g. for code review tools.
+//!
+//! Both versions are parsed, and their declarations are compared instead of their text, so that changes to formatting
+//! and comments are ignored. Functions are compared by signature and by body, structs and records by their members.
+
+use leo_ast::{Function, NodeBuilder, ProgramScope, Variant};
+use leo_errors::{emitter::Handler, Result};
+use leo_span::{source_map::FileName, symbol::with_session_globals};
+
+use serde::Serialize;
+use std::fmt;
+
+/// The kind of a declaration in a program scope.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeclarationKind {
+    Const,
+    Struct,
+    Record,
+    Mapping,
+    Function,
+}
+
+impl fmt::Display for DeclarationKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DeclarationKind::Const => write!(f, "const"),
+            DeclarationKind::Struct => write!(f, "struct"),
+            DeclarationKind::Record => write!(f, "record"),
+            DeclarationKind::Mapping => write!(f, "mapping"),
+            DeclarationKind::Function => write!(f, "function"),
+        }
+    }
+}
+
+/// A change to a program that is not only a change of formatting or comments.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum SemanticChange {
+    /// The program was renamed.
+    ProgramRenamed { before: String, after: String },
+    /// A declaration only exists in the new version.
+    Added { kind: DeclarationKind, name: String },
+    /// A declaration only exists in the old version.
+    Removed { kind: DeclarationKind, name: String },
+    /// The variant, inputs, or outputs of a function changed.
+    SignatureChanged { function: String, before: String, after: String },
+    /// The body of a function or of its finalize block changed.
+    BodyChanged { function: String },
+    /// The members of a struct or record are the same, but in a different order.
+    MembersReordered { kind: DeclarationKind, name: String, before: Vec<String>, after: Vec<String> },
+    /// Members of a struct or record were added, removed, or changed.
+    MembersChanged { kind: DeclarationKind, name: String, before: Vec<String>, after: Vec<String> },
+    /// The type or value of a constant, or the key or value type of a mapping, changed.
+    Changed { kind: DeclarationKind, name: String, before: String, after: String },
+}
+
+impl fmt::Display for SemanticChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SemanticChange::ProgramRenamed { before, after } => {
+                write!(f, "program `{before}` was renamed to `{after}`")
+            }
+            SemanticChange::Added { kind, name } => write!(f, "{kind} `{name}` was added"),
+            SemanticChange::Removed { kind, name } => write!(f, "{kind} `{name}` was removed"),
+            SemanticChange::SignatureChanged { function, before, after } => {
+                write!(f, "the signature of function `{function}` changed from `{before}` to `{after}`")
+            }
+            SemanticChange::BodyChanged { function } => write!(f, "the body of function `{function}` changed"),
+            SemanticChange::MembersReordered { kind, name, before, after } => {
+                write!(
+                    f,
+                    "the members of {kind} `{name}` were reordered from {{ {} }} to {{ {} }}",
+                    before.join(", "),
+                    after.join(", ")
+                )
+            }
+            SemanticChange::MembersChanged { kind, name, before, after } => {
+                write!(
+                    f,
+                    "the members of {kind} `{name}` changed from {{ {} }} to {{ {} }}",
+                    before.join(", "),
+                    after.join(", ")
+                )
+            }
+            SemanticChange::Changed { kind, name, before, after } => {
+                write!(f, "{kind} `{name}` changed from `{before}` to `{after}`")
+            }
+        }
+    }
+}
+
+/// The semantic changes between two versions of a program.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct SemanticDiff {
+    /// The changes, in the order of the declarations of the old version, followed by the added declarations.
+    pub changes: Vec<SemanticChange>,
+}
+
+impl SemanticDiff {
+    /// Returns `true` if the versions only differ in formatting and comments.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl fmt::Display for SemanticDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "The programs are semantically the same.");
+        }
+        write!(f, "The programs differ:")?;
+        for change in self.changes.iter() {
+            write!(f, "\n  - {change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A declaration of a program scope, reduced to what is compared.
+struct Declaration {
+    kind: DeclarationKind,
+    name: String,
+    /// The signature of a function, the members of a struct or record, or the rest of a constant or mapping.
+    shape: Vec<String>,
+    /// The body of a function and of its finalize block.
+    body: String,
+}
+
+/// Returns the signature of a function, e.g. `transition mint(public amount: u64) -> Token`.
+fn signature(function: &Function) -> String {
+    // Note that inputs and outputs without a mode are displayed with a leading space.
+    let join = |items: Vec<String>| items.iter().map(|item| item.trim()).collect::<Vec<_>>().join(", ");
+    let variant = match function.variant {
+        Variant::Inline => "inline",
+        Variant::Standard => "function",
+        Variant::Transition => "transition",
+    };
+    let inputs = join(function.input.iter().map(|input| input.to_string()).collect());
+    let outputs = join(function.output.iter().map(|output| output.to_string()).collect());
+    match function.output.len() {
+        1 => format!("{variant} {}({inputs}) -> {outputs}", function.identifier),
+        _ => format!("{variant} {}({inputs}) -> ({outputs})", function.identifier),
+    }
+}
+
+/// Returns the declarations of a program scope, in the order in which they appear in the source.
+fn declarations(scope: &ProgramScope) -> Vec<Declaration> {
+    let mut declarations = Vec::new();
+    for (name, const_) in scope.consts.iter() {
+        let shape = vec![format!("const {const_}")];
+        declarations.push(Declaration {
+            kind: DeclarationKind::Const,
+            name: name.to_string(),
+            shape,
+            body: String::new(),
+        });
+    }
+    for (name, struct_) in scope.structs.iter() {
+        declarations.push(Declaration {
+            kind: if struct_.is_record { DeclarationKind::Record } else { DeclarationKind::Struct },
+            name: name.to_string(),
+            shape: struct_.members.iter().map(|member| member.to_string()).collect(),
+            body: String::new(),
+        });
+    }
+    for (name, mapping) in scope.mappings.iter() {
+        let shape = vec![mapping.to_string()];
+        declarations.push(Declaration {
+            kind: DeclarationKind::Mapping,
+            name: name.to_string(),
+            shape,
+            body: String::new(),
+        });
+    }
+    for (name, function) in scope.functions.iter() {
+        let finalize = function.finalize.as_ref().map_or(String::new(), |finalize| finalize.to_string());
+        declarations.push(Declaration {
+            kind: DeclarationKind::Function,
+            name: name.to_string(),
+            shape: vec![signature(function)],
+            body: format!("{}{finalize}", function.block),
+        });
+    }
+    declarations
+}
+
+/// Returns the semantic changes from the program scope `old` to the program scope `new`.
+pub fn diff_program_scopes(old: &ProgramScope, new: &ProgramScope) -> SemanticDiff {
+    let mut diff = SemanticDiff::default();
+
+    // Note that program ids are compared by name, since their spans differ.
+    let (before, after) = (old.program_id.to_string(), new.program_id.to_string());
+    if before != after {
+        diff.changes.push(SemanticChange::ProgramRenamed { before, after });
+    }
+
+    let (old, new) = (declarations(old), declarations(new));
+    let find = |declarations: &'_ [Declaration], declaration: &Declaration| {
+        declarations.iter().position(|other| other.kind == declaration.kind && other.name == declaration.name)
+    };
+
+    for declaration in old.iter() {
+        let Some(index) = find(&new, declaration) else {
+            diff.changes.push(SemanticChange::Removed { kind: declaration.kind, name: declaration.name.clone() });
+            continue;
+        };
+        let (kind, name, other) = (declaration.kind, declaration.name.clone(), &new[index]);
+        let (before, after) = (declaration.shape.clone(), other.shape.clone());
+
+        match kind {
+            DeclarationKind::Function => {
+                if before != after {
+                    let (before, after) = (before.concat(), after.concat());
+                    diff.changes.push(SemanticChange::SignatureChanged { function: name.clone(), before, after });
+                }
+                if declaration.body != other.body {
+                    diff.changes.push(SemanticChange::BodyChanged { function: name });
+                }
+            }
+            DeclarationKind::Struct | DeclarationKind::Record if before != after => {
+                let (mut sorted_before, mut sorted_after) = (before.clone(), after.clone());
+                sorted_before.sort();
+                sorted_after.sort();
+                diff.changes.push(match sorted_before == sorted_after {
+                    true => SemanticChange::MembersReordered { kind, name, before, after },
+                    false => SemanticChange::MembersChanged { kind, name, before, after },
+                });
+            }
+            DeclarationKind::Const | DeclarationKind::Mapping if before != after => {
+                let (before, after) = (before.concat(), after.concat());
+                diff.changes.push(SemanticChange::Changed { kind, name, before, after });
+            }
+            _ => {}
+        }
+    }
+
+    for declaration in new.iter().filter(|declaration| find(&old, declaration).is_none()) {
+        diff.changes.push(SemanticChange::Added { kind: declaration.kind, name: declaration.name.clone() });
+    }
+
+    diff
+}
+
+/// Parses two versions of a Leo program and returns the semantic changes from `old` to `new`.
+/// Imports are resolved as when compiling the program, so a program with imports must be diffed from its package.
+pub fn semantic_diff(handler: &Handler, old: &str, new: &str) -> Result<SemanticDiff> {
+    let parse = |source: &str, name: &str| -> Result<ProgramScope> {
+        let source_file = with_session_globals(|s| s.source_map.new_source(source, FileName::Custom(name.into())));
+        let ast = leo_parser::parse_ast(handler, &NodeBuilder::default(), &source_file.src, source_file.start_pos)?;
+        // Note that parsing enforces that there is exactly one program scope in a file.
+        Ok(ast.ast.program_scopes.into_values().next().unwrap())
+    };
+    Ok(diff_program_scopes(&parse(old, "old")?, &parse(new, "new")?))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    const PROGRAM: &str = "
+program token.aleo {
+    const LIMIT: u64 = 100u64;
+
+    record Token {
+        owner: address,
+        amount: u64,
+    }
+
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+
+    mapping balances: address => u64;
+
+    transition mint(amount: u64) -> Token {
+        return Token { owner: self.caller, amount };
+    }
+
+    transition burn(token: Token) -> u64 {
+        return token.amount;
+    }
+
+    transition total(a: u64, b: u64) -> u64 {
+        return a + b;
+    }
+}
+";
+
+    fn diff(old: &str, new: &str) -> String {
+        create_session_if_not_set_then(|_| semantic_diff(&Handler::default(), old, new).unwrap().to_string())
+    }
+
+    #[test]
+    fn test_formatting_and_comments_are_ignored() {
+        let new = PROGRAM
+            .replace("    transition burn", "    // Burns a token.\n    transition burn")
+            .replace("return a + b;", "return a+b; /* The total. */")
+            .replace("x: u8,\n        y: u8,", "x: u8, y: u8");
+        assert_eq!(diff(PROGRAM, &new), "The programs are semantically the same.");
+    }
+
+    #[test]
+    fn test_semantic_changes() {
+        let new = PROGRAM
+            .replace("100u64", "200u64")
+            .replace("owner: address,\n        amount: u64,", "amount: u64,\n        owner: address,")
+            .replace("y: u8,", "y: u16,")
+            .replace("address => u64", "address => u128")
+            .replace("mint(amount: u64)", "mint(public amount: u64)")
+            .replace("return a + b;", "return a * b;")
+            .replace("    transition burn(token: Token) -> u64 {\n        return token.amount;\n    }\n\n", "")
+            .replace("}\n}", "}\n\n    transition split(a: u64) -> (u64, u64) {\n        return (a, a);\n    }\n}");
+        assert_eq!(
+            diff(PROGRAM, &new),
+            "The programs differ:
+  - const `LIMIT` changed from `const LIMIT: u64 = 100u64;` to `const LIMIT: u64 = 200u64;`
+  - the members of record `Token` were reordered from { owner: address, amount: u64 } to { amount: u64, owner: address }
+  - the members of struct `Point` changed from { x: u8, y: u8 } to { x: u8, y: u16 }
+  - mapping `balances` changed from `mapping balances: address => u64` to `mapping balances: address => u128`
+  - the signature of function `mint` changed from `transition mint(amount: u64) -> Token` to `transition mint(public amount: u64) -> Token`
+  - function `burn` was removed
+  - the body of function `total` changed
+  - function `split` was added"
+        );
+
+        let renamed = PROGRAM.replace("token.aleo", "coin.aleo");
+        assert_eq!(
+            diff(PROGRAM, &renamed),
+            "The programs differ:\n  - program `token.aleo` was renamed to `coin.aleo`"
+        );
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Helpers shared by the unit tests of the compiler API.
+
+use crate::Compiler;
+
+use leo_errors::emitter::Handler;
+use leo_parser::ImportPaths;
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+use std::path::PathBuf;
+
+/// Parses `program` as the file `test`, and runs `f` on the compiler and the name of the file.
+///
+/// Each test runs on its own thread, and so in its own session, in which `program` is the first source. The byte
+/// positions of the spans in the program are therefore its byte offsets, which the tests rely on.
+pub(crate) fn with_parsed_program<T>(program: &str, f: impl FnOnce(&mut Compiler, &FileName) -> T) -> T {
+    with_parsed_program_and_imports(program, ImportPaths::default(), f)
+}
+
+/// Like `with_parsed_program`, but with the given import paths.
+pub(crate) fn with_parsed_program_and_imports<T>(
+    program: &str,
+    import_paths: ImportPaths,
+    f: impl FnOnce(&mut Compiler, &FileName) -> T,
+) -> T {
+    create_session_if_not_set_then(|_| {
+        let handler = Handler::default();
+        let mut compiler = Compiler::new("test".into(), "aleo".into(), &handler, PathBuf::new(), PathBuf::new(), None)
+            .with_import_paths(import_paths);
+        let file = FileName::Custom("test".into());
+        compiler.parse_program_from_string(program, file.clone()).unwrap();
+        f(&mut compiler, &file)
+    })
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_errors::{CompilerError, Result};
+
+use serde::Serialize;
+use std::{fmt, path::PathBuf, time::Duration};
+
+/// The wall-clock time spent in each stage of compilation, in the order in which the stages were run.
+#[derive(Clone, Debug, Default)]
+pub struct Timings {
+    stages: Vec<(&'static str, Duration)>,
+}
+
+/// A serializable entry of the timing report.
+#[derive(Serialize)]
+struct StageTiming {
+    stage: &'static str,
+    milliseconds: f64,
+}
+
+/// The serializable form of the timing report.
+#[derive(Serialize)]
+struct TimingReport {
+    stages: Vec<StageTiming>,
+    total_milliseconds: f64,
+}
+
+impl Timings {
+    /// Records the time spent in a stage of compilation.
+    pub fn record(&mut self, stage: &'static str, elapsed: Duration) {
+        self.stages.push((stage, elapsed));
+    }
+
+    /// Returns the recorded stages and the time spent in each of them.
+    pub fn stages(&self) -> &[(&'static str, Duration)] {
+        &self.stages
+    }
+
+    /// Returns the total time spent across all recorded stages.
+    pub fn total(&self) -> Duration {
+        self.stages.iter().map(|(_, elapsed)| *elapsed).sum()
+    }
+
+    fn report(&self) -> TimingReport {
+        TimingReport {
+            stages: self
+                .stages
+                .iter()
+                .map(|(stage, elapsed)| StageTiming { stage, milliseconds: elapsed.as_secs_f64() * 1000.0 })
+                .collect(),
+            total_milliseconds: self.total().as_secs_f64() * 1000.0,
+        }
+    }
+
+    /// Serializes the timings into a JSON string.
+    pub fn to_json_string(&self) -> String {
+        // Note that this unwrap is safe since the report only contains strings and numbers.
+        serde_json::to_string_pretty(&self.report()).unwrap()
+    }
+
+    /// Writes the timings to a JSON file.
+    pub fn to_json_file(&self, mut path: PathBuf, file_name: &str) -> Result<()> {
+        path.push(file_name);
+        std::fs::write(&path, self.to_json_string())
+            .map_err(|e| CompilerError::failed_to_write_timings(&path, e).into())
+    }
+}
+
+impl fmt::Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Align the durations by padding the stage names to the longest name.
+        let width = self.stages.iter().map(|(stage, _)| stage.len()).max().unwrap_or(0).max("total".len());
+        for (stage, elapsed) in self.stages.iter() {
+            writeln!(f, "{stage:<width$}  {:>10.3} ms", elapsed.as_secs_f64() * 1000.0)?;
+        }
+        write!(f, "{:<width$}  {:>10.3} ms", "total", self.total().as_secs_f64() * 1000.0)
+    }
+}
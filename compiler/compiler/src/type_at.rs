@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Queries about the types in a Leo program, such as the type of the name under a cursor.
+
+use crate::Compiler;
+
+use leo_ast::{NodeID, NodeKind, Type};
+use leo_errors::{CompilerError, Result};
+use leo_span::{
+    source_map::FileName,
+    span::{BytePos, Pos},
+    symbol::with_session_globals,
+    Span,
+};
+
+/// The type of the node at a position in a source file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeAt {
+    /// The type of the node.
+    pub type_: Type,
+    /// The span of the node, e.g. the expression containing the position.
+    pub span: Span,
+    /// The span of the definition of the name at the position, if the position is on a name.
+    pub definition: Option<Span>,
+}
+
+impl Compiler<'_> {
+    /// Returns the type of the innermost typed node at the byte `offset` in the source `file`, along with the definition
+    /// of the name at that offset. Type checking is run on the first query, and its results are reused afterwards.
+    /// Returns `None` if the offset is not on an expression or a declared name, e.g. on a keyword.
+    pub fn type_at(&mut self, file: &FileName, offset: usize) -> Result<Option<TypeAt>> {
+        let source_file = with_session_globals(|s| s.source_map.find_source_file_by_name(file))
+            .ok_or_else(|| CompilerError::source_file_not_found(file))?;
+        let position = source_file.start_pos + BytePos::from_usize(offset);
+        if position > source_file.end_pos {
+            return Ok(None);
+        }
+
+        if self.node_index().is_none() {
+            self.type_checking_stages()?;
+        }
+        // Note that this unwrap is safe since the type checking stages index the program.
+        let index = self.node_index().unwrap();
+        let Some(id) = index.innermost(Span::new(position, position)) else { return Ok(None) };
+        let definition = self.resolution_table().get(&id).map(|reference| reference.definition);
+        let typed = |id: NodeID| self.type_table().get(&id).map(|type_| (type_, index.get(id).unwrap().span));
+
+        // Names are typed as expressions, so the type is that of the innermost expression containing the position.
+        let in_expression =
+            |id: &NodeID| matches!(index.get(*id).unwrap().kind, NodeKind::Identifier | NodeKind::Expression);
+        let found = std::iter::once(id).chain(index.ancestors(id)).take_while(in_expression).find_map(typed);
+        // A name in a declaration is not typed itself, so its type is that of the uses of the declared name.
+        let found = found.or_else(|| {
+            let definition = definition?;
+            let (type_, _) = index.iter().find_map(|(other, node)| {
+                let resolved = self.resolution_table().get(&other)?.definition.id == definition.id;
+                (node.kind == NodeKind::Identifier && resolved).then(|| typed(other)).flatten()
+            })?;
+            Some((type_, index.get(id).unwrap().span))
+        });
+
+        Ok(found.map(|(type_, span)| TypeAt { type_, span, definition: definition.map(|definition| definition.span) }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utils::with_parsed_program;
+
+    const PROGRAM: &str = "
+program test.aleo {
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    inline double(a: u32) -> u32 {
+        return a + a;
+    }
+
+    transition main(a: u32, b: u32) -> Point {
+        let c: u32 = double(a);
+        let flag: bool = c > b;
+        return Point { x: c, y: flag ? b : c };
+    }
+}
+";
+
+    /// Queries the types at the offsets of the given occurrences in `PROGRAM`, and returns the type, the text of the
+    /// typed node, and the text of the definition found at each offset.
+    fn types_at(occurrences: &[&str]) -> Vec<Option<(String, String, Option<String>)>> {
+        with_parsed_program(PROGRAM, |compiler, file| {
+            let text = |span: Span| PROGRAM[span.lo.to_usize()..span.hi.to_usize()].to_string();
+            let types = occurrences
+                .iter()
+                .map(|occurrence| {
+                    let type_at = compiler.type_at(file, PROGRAM.find(occurrence).unwrap()).unwrap()?;
+                    Some((type_at.type_.to_string(), text(type_at.span), type_at.definition.map(text)))
+                })
+                .collect();
+
+            // Type checking is only run for the first query.
+            let stages = compiler.timings().stages().iter().filter(|(stage, _)| *stage == "type checking").count();
+            assert_eq!(stages, 1);
+            assert!(compiler.type_at(&FileName::Custom("other".into()), 0).is_err());
+            types
+        })
+    }
+
+    #[test]
+    fn test_type_at() {
+        let typed = |type_: &str, text: &str, definition: Option<&str>| {
+            Some((type_.to_string(), text.to_string(), definition.map(str::to_string)))
+        };
+        let types = types_at(&["a + a", "c: u32 = double", "> b", "double(a)", "Point { x", "x: c", "transition main"]);
+        assert_eq!(types, [
+            // A use of an input.
+            typed("u32", "a", Some("a")),
+            // The declaration of a variable.
+            typed("u32", "c", Some("c")),
+            // An operator.
+            typed("boolean", "c > b", None),
+            // The name of a called function.
+            typed("u32", "double(a)", Some("double")),
+            // The name of a struct.
+            typed("Point", "Point { x: c, y: flag ? b : c }", Some("Point")),
+            // A member name in a struct expression, which is not typed.
+            None,
+            // A keyword.
+            None,
+        ]);
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+mod common;
+use common::PROGRAM;
+
+use leo_compiler::Compiler;
+use leo_errors::{emitter::Handler, LeoError};
+use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+use std::fs;
+
+#[test]
+fn test_check() {
+    let directory = tempfile::tempdir().unwrap();
+    let main_file = directory.path().join("main.leo");
+    // Returns whether the program declares `mint`, and the checked program.
+    let check = |program: &str| {
+        fs::write(&main_file, program).unwrap();
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let mut compiler =
+                Compiler::new("test".into(), "aleo".into(), &handler, main_file.clone(), "/tmp".into(), None);
+            let symbol_table = compiler.check()?;
+            Ok::<_, LeoError>((
+                symbol_table.lookup_fn_symbol(Symbol::intern("mint")).is_some(),
+                compiler.ast.ast.to_string(),
+            ))
+        })
+    };
+
+    // The program is checked, but not lowered.
+    let (has_mint, program) = check(PROGRAM).unwrap();
+    assert!(has_mint);
+    assert!(program.contains("double(secret)"));
+
+    // Type errors are reported.
+    assert!(check(&PROGRAM.replace("return token.issuer;", "return token.amount;")).is_err());
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Items shared by the tests of the compiler API.
+
+use leo_compiler::Compiler;
+use leo_errors::emitter::Handler;
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+/// A program that uses records, mappings, inline functions, and finalize blocks.
+pub const PROGRAM: &str = "
+program test.aleo {
+    record Token {
+        owner: address,
+        public issuer: address,
+        amount: u64,
+    }
+
+    mapping supply: u8 => u64;
+
+    inline double(a: u64) -> u64 {
+        return a + a;
+    }
+
+    transition mint(secret: u64, public salt: u64, flag: bool) -> (public u64, Token, public u64) {
+        let hidden: u64 = double(secret);
+        let token: Token = Token { owner: self.caller, issuer: self.caller, amount: hidden };
+        let revealed: u64 = flag ? salt : 0u64;
+        return (salt * 2u64, token, revealed) then finalize(secret);
+    }
+
+    finalize mint(public amount: u64) {
+        let current: u64 = Mapping::get_or_use(supply, 0u8, 0u64);
+        Mapping::set(supply, 0u8, current + amount);
+    }
+
+    transition reveal(token: Token) -> public address {
+        return token.issuer;
+    }
+}
+";
+
+/// Parses `PROGRAM` as the file `test`, and runs `f` on the compiler.
+///
+/// Each test runs on its own thread, and so in its own session, in which `PROGRAM` is the first source. The byte
+/// positions of the spans in the program are therefore its byte offsets, which the tests rely on.
+#[allow(dead_code)]
+pub fn with_parsed_program<T>(f: impl FnOnce(&mut Compiler) -> T) -> T {
+    create_session_if_not_set_then(|_| {
+        let handler = Handler::default();
+        let mut compiler = Compiler::new("test".into(), "aleo".into(), &handler, "".into(), "/tmp".into(), None);
+        compiler.parse_program_from_string(PROGRAM, FileName::Custom("test".into())).unwrap();
+        f(&mut compiler)
+    })
+}
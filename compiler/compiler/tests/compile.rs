@@ -39,7 +39,7 @@ use snarkvm::console::prelude::*;
 
 use serde::{Deserialize, Serialize};
 use serde_yaml::Value;
-use std::{fs, path::Path, rc::Rc};
+use std::{fs, path::Path};
 
 struct CompileNamespace;
 
@@ -49,10 +49,10 @@ impl Namespace for CompileNamespace {
     }
 
     fn run_test(&self, test: Test) -> Result<Value, String> {
-        let buf = BufferEmitter(Rc::default(), Rc::default());
+        let buf = BufferEmitter::default();
         let handler = Handler::new(Box::new(buf.clone()));
         create_session_if_not_set_then(|_| {
-            run_test(test, &handler, &buf).map_err(|()| buf.0.take().to_string() + &buf.1.take().to_string())
+            run_test(test, &handler, &buf).map_err(|()| buf.extract_errs().to_string() + &buf.extract_warnings().to_string())
         })
     }
 }
@@ -99,6 +99,10 @@ fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value,
                 destructured_ast: true,
                 inlined_ast: true,
                 dce_ast: true,
+                timings: false,
+                call_graph: false,
+                information_flow: false,
+                expanded: false,
             },
         };
 
@@ -140,7 +144,7 @@ fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value,
             inlined_ast,
             dce_ast,
             bytecode: hash_content(&bytecode),
-            warnings: buf.1.take().to_string(),
+            warnings: buf.extract_warnings().to_string(),
         };
 
         outputs.push(final_output);
@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A regression suite for the number of constraints of the example programs.
+//!
+//! The circuit of every transition of the programs in `examples/` is synthesized, and its number of constraints is
+//! compared against the baseline in `tests/constraint-counts.yml`. The test fails if a count grows by more than
+//! `LEO_CONSTRAINT_THRESHOLD` percent, which defaults to 5, or if a transition is missing from the baseline or is
+//! no longer in the examples. The baseline is never written unless it is being updated.
+//!
+//! To accept the current counts, e.g. after an intended change to code generation or a new example, run the test
+//! with `UPDATE_CONSTRAINT_COUNTS` set:
+//!
+//! ```shell
+//! UPDATE_CONSTRAINT_COUNTS=1 cargo test -p leo-compiler --test constraint_counts
+//! ```
+
+mod utilities;
+use utilities::{compile_and_process, setup_build_directory, Aleo, BufferEmitter, Network};
+
+use leo_compiler::Compiler;
+use leo_errors::emitter::Handler;
+use leo_span::symbol::create_session_if_not_set_then;
+
+use snarkvm::prelude::*;
+
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+/// The percentage by which a constraint count may grow before the test fails, unless overridden.
+const DEFAULT_THRESHOLD: f64 = 5.0;
+
+/// Returns the path of the baseline file.
+fn baseline_path() -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "../../tests/constraint-counts.yml"].iter().collect()
+}
+
+/// Returns the programs of the examples, keyed by program name.
+/// Examples that import other programs are left out, as their imports are compiled separately.
+fn corpus() -> BTreeMap<String, PathBuf> {
+    let examples: PathBuf = [env!("CARGO_MANIFEST_DIR"), "../../examples"].iter().collect();
+    fs::read_dir(examples)
+        .expect("failed to read the examples directory")
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.join("src/main.leo").exists() && !path.join("imports").exists())
+        .map(|path| (path.file_name().unwrap().to_str().unwrap().to_string(), path.join("src/main.leo")))
+        .collect()
+}
+
+/// Compiles the program, and returns the number of constraints of each of its transitions,
+/// keyed by `{program}.aleo/{transition}`.
+fn count_constraints(program_name: &str, path: PathBuf) -> BTreeMap<String, u64> {
+    let buf = BufferEmitter::default();
+    let handler = Handler::new(Box::new(buf.clone()));
+    let output_directory = tempfile::tempdir().expect("failed to create a temporary directory");
+
+    let bytecode = create_session_if_not_set_then(|_| {
+        let mut compiler = Compiler::new(
+            program_name.to_string(),
+            "aleo".to_string(),
+            &handler,
+            path,
+            output_directory.path().to_path_buf(),
+            None,
+        );
+        compiler.parse_program().and_then(|_| compile_and_process(&mut compiler))
+    })
+    .unwrap_or_else(|err| panic!("failed to compile `{program_name}`: {err}"));
+
+    let program_id = format!("{program_name}.aleo");
+    let package = setup_build_directory(&program_id, &bytecode, &handler)
+        .unwrap_or_else(|()| panic!("failed to build `{program_id}`: {}", buf.extract_errs()));
+    let process = package.get_process().unwrap();
+    let program_id = ProgramID::<Network>::from_str(&program_id).unwrap();
+    let rng = &mut TestRng::default();
+
+    let function_names: Vec<_> = process.get_program(program_id).unwrap().functions().keys().copied().collect();
+    function_names
+        .into_iter()
+        .map(|function_name| {
+            process.synthesize_key::<Aleo, _>(&program_id, &function_name, rng).unwrap();
+            let verifying_key = process.get_verifying_key(program_id, function_name).unwrap();
+            (format!("{program_id}/{function_name}"), verifying_key.circuit_info.num_constraints as u64)
+        })
+        .collect()
+}
+
+#[test]
+fn constraint_counts() {
+    let update = std::env::var("UPDATE_CONSTRAINT_COUNTS").is_ok();
+    let threshold = std::env::var("LEO_CONSTRAINT_THRESHOLD")
+        .map(|threshold| threshold.parse::<f64>().expect("`LEO_CONSTRAINT_THRESHOLD` must be a percentage"))
+        .unwrap_or(DEFAULT_THRESHOLD);
+
+    let baseline: BTreeMap<String, u64> = match fs::read_to_string(baseline_path()) {
+        Ok(baseline) => serde_yaml::from_str(&baseline).expect("invalid yaml in the constraint count baseline"),
+        Err(_) => BTreeMap::new(),
+    };
+
+    let mut counts = BTreeMap::new();
+    for (program_name, path) in corpus() {
+        counts.extend(count_constraints(&program_name, path));
+    }
+
+    if update {
+        fs::write(baseline_path(), serde_yaml::to_string(&counts).expect("failed to serialize the baseline"))
+            .expect("failed to write the constraint count baseline");
+        return;
+    }
+
+    // The changes within the threshold are only reported alongside the failures.
+    let mut failures = Vec::new();
+    let mut changes = Vec::new();
+    for (transition, count) in counts.iter() {
+        match baseline.get(transition) {
+            Some(previous) if *count as f64 > *previous as f64 * (1.0 + threshold / 100.0) => {
+                failures.push(format!("{transition}: {previous} -> {count} constraints, more than {threshold}% more"))
+            }
+            Some(previous) if count != previous => {
+                changes.push(format!("{transition}: {previous} -> {count} constraints"))
+            }
+            Some(_) => {}
+            None => failures.push(format!("{transition}: {count} constraints, missing from the baseline")),
+        }
+    }
+    for transition in baseline.keys().filter(|transition| !counts.contains_key(*transition)) {
+        failures.push(format!("{transition}: in the baseline, but not in the examples"));
+    }
+
+    assert!(
+        failures.is_empty(),
+        "the constraint counts of {} transitions do not match the baseline:\n{}\n{}\
+         If this is intended, update the baseline with `UPDATE_CONSTRAINT_COUNTS=1`.",
+        failures.len(),
+        failures.join("\n"),
+        changes.iter().map(|change| format!("{change}\n")).collect::<String>(),
+    );
+}
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+mod common;
+use common::PROGRAM;
+
+use leo_compiler::{Compiler, CompilerPass, PassContext, PassStage};
+use leo_errors::{emitter::Handler, CompilerWarning, Result};
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+use std::{cell::RefCell, rc::Rc};
+
+/// Warns about transitions whose names start with `debug_`.
+struct NoDebugTransitions;
+
+impl CompilerPass for NoDebugTransitions {
+    fn name(&self) -> &'static str {
+        "no-debug-transitions"
+    }
+
+    fn stage(&self) -> PassStage {
+        PassStage::TypeChecking
+    }
+
+    fn run(&self, context: PassContext) -> Result<()> {
+        for (name, function) in context.ast.ast.program_scopes.values().flat_map(|scope| scope.functions.iter()) {
+            if name.to_string().starts_with("debug_") {
+                let message = format!("`{name}` is a debugging transition.");
+                context
+                    .handler
+                    .emit_warning(CompilerWarning::custom_pass_warning(self.name(), message, function.span).into());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Records the names of the functions of the program, once calls to inline functions are inlined.
+struct RemainingFunctions(Rc<RefCell<Vec<String>>>);
+
+impl CompilerPass for RemainingFunctions {
+    fn name(&self) -> &'static str {
+        "remaining-functions"
+    }
+
+    fn stage(&self) -> PassStage {
+        PassStage::FunctionInlining
+    }
+
+    fn after(&self) -> &[&'static str] {
+        &["no-debug-transitions"]
+    }
+
+    fn run(&self, context: PassContext) -> Result<()> {
+        let scopes = context.ast.ast.program_scopes.values();
+        self.0.borrow_mut().extend(scopes.flat_map(|scope| scope.functions.iter()).map(|(name, _)| name.to_string()));
+        Ok(())
+    }
+}
+
+#[test]
+fn test_custom_passes() {
+    let program = PROGRAM.replace("transition reveal", "transition debug_reveal");
+    let functions = Rc::new(RefCell::new(Vec::new()));
+    let (handler, buffer) = Handler::new_with_buf();
+    let (timings, warnings) = create_session_if_not_set_then(|_| {
+        let mut compiler = Compiler::new("test".into(), "aleo".into(), &handler, "".into(), "/tmp".into(), None)
+            .with_pass(RemainingFunctions(functions.clone()))
+            .with_pass(NoDebugTransitions);
+        compiler.parse_program_from_string(&program, FileName::Custom("test".into())).unwrap();
+        compiler.compiler_stages().unwrap();
+        let timings = compiler.timings().stages().iter().map(|(stage, _)| *stage).collect::<Vec<_>>();
+        (timings, buffer.extract_warnings().to_string())
+    });
+
+    assert!(warnings.contains("`debug_reveal` is a debugging transition."));
+    assert_eq!(*functions.borrow(), ["double", "mint", "debug_reveal"]);
+    let position = |stage| timings.iter().position(|other| *other == stage).unwrap();
+    assert!(position("type checking") < position("no-debug-transitions"));
+    assert!(position("function inlining") < position("remaining-functions"));
+}
@@ -45,7 +45,7 @@ use snarkvm::{console, prelude::*};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_yaml::Value;
-use std::{collections::BTreeMap, fs, path::Path, rc::Rc};
+use std::{collections::BTreeMap, fs, path::Path};
 
 // TODO: Evaluate namespace.
 struct ExecuteNamespace;
@@ -56,10 +56,10 @@ impl Namespace for ExecuteNamespace {
     }
 
     fn run_test(&self, test: Test) -> Result<Value, String> {
-        let buf = BufferEmitter(Rc::default(), Rc::default());
+        let buf = BufferEmitter::default();
         let handler = Handler::new(Box::new(buf.clone()));
         create_session_if_not_set_then(|_| {
-            run_test(test, &handler, &buf).map_err(|()| buf.0.take().to_string() + &buf.1.take().to_string())
+            run_test(test, &handler, &buf).map_err(|()| buf.extract_errs().to_string() + &buf.extract_warnings().to_string())
         })
     }
 }
@@ -113,6 +113,10 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
                 destructured_ast: true,
                 inlined_ast: true,
                 dce_ast: true,
+                timings: false,
+                call_graph: false,
+                information_flow: false,
+                expanded: false,
             },
         };
 
@@ -224,7 +228,7 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
             inlined_ast,
             dce_ast,
             bytecode: hash_content(&bytecode),
-            warnings: err_buf.1.take().to_string(),
+            warnings: err_buf.extract_warnings().to_string(),
             results,
         };
         outputs.push(final_output);
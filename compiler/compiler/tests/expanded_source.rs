@@ -0,0 +1,42 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+mod common;
+use common::PROGRAM;
+
+use leo_compiler::Compiler;
+use leo_errors::emitter::Handler;
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+#[test]
+fn test_expanded_source() {
+    create_session_if_not_set_then(|_| {
+        let handler = Handler::default();
+        let mut compiler = Compiler::new("test".into(), "aleo".into(), &handler, "".into(), "/tmp".into(), None);
+        compiler.parse_program_from_string(PROGRAM, FileName::Custom("test".into())).unwrap();
+        compiler.compiler_stages().unwrap();
+        let expanded = compiler.expanded_source();
+
+        // The call to `double` is inlined, and the names introduced by the compiler are valid identifiers.
+        assert!(!expanded.contains("double(secret)"));
+        assert!(!expanded.contains('$'));
+
+        // The expanded program is itself a valid program.
+        let mut recompiled = Compiler::new("test".into(), "aleo".into(), &handler, "".into(), "/tmp".into(), None);
+        recompiled.parse_program_from_string(&expanded, FileName::Custom("expanded".into())).unwrap();
+        recompiled.compiler_stages().unwrap();
+    })
+}
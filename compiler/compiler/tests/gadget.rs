@@ -0,0 +1,223 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the circuits of single gadgets, e.g. an integer addition or a ternary select.
+//!
+//! Each line of a `Gadget` test is a transition without the `transition` keyword, e.g.
+//! `add_u8(a: u8, b: u8) -> u8 { return a + b; }`. The transition is compiled on its own, its circuit is synthesized,
+//! and it is run on each of its cases. The expectations record the number of constraints of the gadget, and whether
+//! each case satisfies it. Most constraints of a transition check its inputs and outputs, so the constraints of the
+//! gadget are those of its circuit minus those of a baseline transition, which has the same inputs and outputs but
+//! returns constants. They still include the decomposition of each output into bits, e.g. about 500 constraints for
+//! a field. The test config can also assert both, for instance:
+//!
+//! ```yaml
+//! constraints:
+//!     add_u8: 9
+//! cases:
+//!     add_u8:
+//!     - input: ["255u8", "1u8"]
+//!       satisfied: false
+//! ```
+
+mod utilities;
+use utilities::{
+    buffer_if_err,
+    compile_and_process,
+    dotenv_private_key,
+    get_build_options,
+    parse_program,
+    setup_build_directory,
+    Aleo,
+    BufferEmitter,
+    Network,
+};
+
+use leo_compiler::{CompilerOptions, OutputOptions};
+use leo_errors::emitter::Handler;
+use leo_span::symbol::create_session_if_not_set_then;
+use leo_test_framework::{
+    runner::{Namespace, ParseType, Runner},
+    test::TestExpectationMode,
+    Test,
+};
+
+use snarkvm::{console, prelude::*};
+
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+use std::collections::BTreeMap;
+
+struct GadgetNamespace;
+
+impl Namespace for GadgetNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Line
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        let buf = BufferEmitter::default();
+        let handler = Handler::new(Box::new(buf.clone()));
+        create_session_if_not_set_then(|_| {
+            run_test(test, &handler, &buf).map_err(|()| buf.extract_errs().to_string() + &buf.extract_warnings().to_string())
+        })
+    }
+}
+
+#[derive(Deserialize, PartialEq, Eq, Serialize)]
+struct GadgetOutput {
+    pub gadget: String,
+    pub constraints: u64,
+    pub cases: Vec<BTreeMap<String, String>>,
+}
+
+fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Value, ()> {
+    // Check that config expectation is always pass.
+    if test.config.expectation != TestExpectationMode::Pass {
+        buffer_if_err(err_buf, Err("Test expectation must be `Pass` for `Gadget` tests.".to_string()))?;
+    }
+
+    // The name of the gadget is the name of its transition.
+    let gadget = test.content.split('(').next().unwrap_or_default().trim().to_string();
+    let program = format!(
+        "program test.aleo {{\n    transition {}\n\n    transition {}\n}}\n",
+        test.content,
+        baseline(&test.content)
+    );
+
+    // Extract the expected constraint count and the cases of the gadget from the test config.
+    let expected_constraints = test.config.extra.get("constraints").and_then(|counts| counts.get(&gadget));
+    let cases = test
+        .config
+        .extra
+        .get("cases")
+        .and_then(|cases| cases.get(&gadget))
+        .and_then(Value::as_sequence)
+        .cloned()
+        .unwrap_or_default();
+
+    let mut outputs = Vec::new();
+
+    for build in get_build_options(&test.config) {
+        let compiler_options = CompilerOptions { build, output: OutputOptions::default() };
+
+        // Compile the gadget to bytecode.
+        let mut parsed = handler.extend_if_error(parse_program(handler, &program, None, Some(compiler_options)))?;
+        let program_name = format!("{}.{}", parsed.program_name, parsed.network);
+        let bytecode = handler.extend_if_error(compile_and_process(&mut parsed))?;
+
+        // Setup the build directory.
+        let package = setup_build_directory(&program_name, &bytecode, handler)?;
+        let program_id = ProgramID::<Network>::from_str(&program_name).unwrap();
+        let function_name = Identifier::<Network>::from_str(&gadget).unwrap();
+        let baseline_name = Identifier::<Network>::from_str("baseline").unwrap();
+
+        // Initialize an rng.
+        let rng = &mut TestRng::default();
+
+        // Synthesize the circuits of the gadget and of the baseline, and count the constraints of the gadget.
+        let process = package.get_process().unwrap();
+        let mut num_constraints = |function_name: Identifier<Network>| {
+            process.synthesize_key::<Aleo, _>(&program_id, &function_name, rng).unwrap();
+            process.get_verifying_key(program_id, function_name).unwrap().circuit_info.num_constraints as u64
+        };
+        let constraints = num_constraints(function_name) - num_constraints(baseline_name);
+        if let Some(expected) = expected_constraints.and_then(Value::as_u64) {
+            if constraints != expected {
+                buffer_if_err(
+                    err_buf,
+                    Err(format!("`{gadget}` has {constraints} constraints, but {expected} were expected.")),
+                )?;
+            }
+        }
+
+        // Run each case, which satisfies the circuit if the gadget can be executed on its inputs.
+        let private_key = dotenv_private_key(package.directory()).unwrap();
+        let mut results = Vec::with_capacity(cases.len());
+        for case in cases.iter() {
+            let inputs: Vec<_> = case
+                .get("input")
+                .and_then(Value::as_sequence)
+                .expect("A case must have an `input` field.")
+                .iter()
+                .map(|input| console::program::Value::<Network>::from_str(input.as_str().unwrap()).unwrap())
+                .collect();
+            let input_string = format!("[{}]", inputs.iter().map(|input| input.to_string()).join(", "));
+
+            let (satisfied, output_string) = match package.run::<Aleo, _>(&private_key, function_name, &inputs, rng) {
+                Ok((response, _)) => {
+                    (true, format!("[{}]", response.outputs().iter().map(|output| output.to_string()).join(", ")))
+                }
+                Err(err) => (false, format!("SnarkVMError({err})")),
+            };
+            if let Some(expected) = case.get("satisfied").and_then(Value::as_bool) {
+                if satisfied != expected {
+                    buffer_if_err(
+                        err_buf,
+                        Err(format!("`{gadget}` on {input_string} was expected to be satisfied: {expected}.")),
+                    )?;
+                }
+            }
+
+            let mut result = BTreeMap::new();
+            result.insert("input".to_string(), input_string);
+            result.insert("satisfied".to_string(), satisfied.to_string());
+            result.insert("output".to_string(), output_string);
+            results.push(result);
+        }
+
+        outputs.push(GadgetOutput { gadget: gadget.clone(), constraints, cases: results });
+    }
+    Ok(serde_yaml::to_value(outputs).expect("serialization failed"))
+}
+
+/// Returns the transition `baseline`, with the same inputs and outputs as the given gadget, and which returns the
+/// zero value of each of its outputs.
+fn baseline(gadget: &str) -> String {
+    let signature = gadget.split('{').next().unwrap_or_default();
+    let signature = &signature[signature.find('(').unwrap_or_default()..];
+    let outputs = match signature.split_once("->") {
+        Some((_, outputs)) => outputs.trim().trim_start_matches('(').trim_end_matches(')'),
+        None => "",
+    };
+    let zeros: Vec<_> = outputs
+        .split(',')
+        .map(|output| output.trim().rsplit(' ').next().unwrap_or_default())
+        .filter(|type_| !type_.is_empty())
+        .map(|type_| if type_ == "bool" { "false".to_string() } else { format!("0{type_}") })
+        .collect();
+    match zeros.len() {
+        0 => format!("baseline{signature}{{}}"),
+        1 => format!("baseline{signature}{{ return {}; }}", zeros[0]),
+        _ => format!("baseline{signature}{{ return ({}); }}", zeros.join(", ")),
+    }
+}
+
+struct TestRunner;
+
+impl Runner for TestRunner {
+    fn resolve_namespace(&self, name: &str) -> Option<Box<dyn Namespace>> {
+        Some(match name {
+            "Gadget" => Box::new(GadgetNamespace),
+            _ => return None,
+        })
+    }
+}
+
+#[test]
+pub fn gadget_tests() {
+    leo_test_framework::run_tests(&TestRunner, "gadget");
+}
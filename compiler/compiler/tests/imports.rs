@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::Compiler;
+use leo_errors::emitter::Handler;
+use leo_parser::ImportPaths;
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+use std::fs;
+
+#[test]
+fn test_import_paths() {
+    let directory = tempfile::tempdir().unwrap();
+    let directory = directory.path();
+    fs::create_dir_all(directory.join("token")).unwrap();
+    fs::create_dir_all(directory.join("imports")).unwrap();
+    fs::write(
+        directory.join("token").join("main.leo"),
+        "program token.aleo { transition mint(amount: u64) -> u64 { return amount; } }",
+    )
+    .unwrap();
+    fs::write(
+        directory.join("imports").join("fees.leo"),
+        "import token.leo; program fees.aleo { transition fee(amount: u64) -> u64 { return amount / 100u64; } }",
+    )
+    .unwrap();
+    let program = "
+import token.leo;
+import fees.leo;
+
+program test.aleo {
+    transition main(a: u64) -> u64 {
+        let minted: u64 = token.leo/mint(a);
+        return fees.leo/fee(minted);
+    }
+}
+";
+
+    // `token.leo` is imported from its local path, and `fees.leo` from the additional imports directory.
+    let import_paths = ImportPaths {
+        programs: [("token".to_string(), directory.join("token").join("main.leo"))].into_iter().collect(),
+        directories: vec![directory.join("imports")],
+        ..Default::default()
+    };
+    let compile = |import_paths: ImportPaths| {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let mut compiler = Compiler::new("test".into(), "aleo".into(), &handler, "".into(), "/tmp".into(), None)
+                .with_import_paths(import_paths);
+            compiler.parse_program_from_string(program, FileName::Custom("test".into()))?;
+            let (st, struct_graph, call_graph) = compiler.compiler_stages()?;
+            compiler.code_generation_pass(&st, &struct_graph, &call_graph)
+        })
+    };
+
+    let bytecode = compile(import_paths).unwrap();
+    assert!(bytecode.contains("import token.aleo;\nimport fees.aleo;"));
+    assert!(bytecode.contains("call fees.aleo/fee"));
+    assert!(compile(ImportPaths::default()).is_err());
+}
+
+#[test]
+fn test_imported_programs_are_type_checked() {
+    let directory = tempfile::tempdir().unwrap();
+    let token_file = directory.path().join("token.leo");
+    fs::write(
+        &token_file,
+        "program token.aleo {\n    transition mint(amount: u64) -> u64 {\n        return amount as u8;\n    }\n}\n",
+    )
+    .unwrap();
+    let program = "
+import token.leo;
+
+program test.aleo {
+    transition main(a: u64) -> u64 {
+        return token.leo/mint(a);
+    }
+}
+";
+
+    let (result, errors) = create_session_if_not_set_then(|_| {
+        let (handler, buffer) = Handler::new_with_buf();
+        let mut compiler = Compiler::new("test".into(), "aleo".into(), &handler, "".into(), "/tmp".into(), None)
+            .with_import_paths(ImportPaths {
+                programs: [("token".to_string(), token_file)].into_iter().collect(),
+                ..Default::default()
+            });
+        compiler.parse_program_from_string(program, FileName::Custom("test".into())).unwrap();
+        (compiler.type_checking_stages().map(|_| ()), buffer.extract_errs().to_string())
+    });
+
+    // The error in the imported program stops compilation, and points into the imported file.
+    assert!(result.is_err());
+    assert!(errors.contains("Expected type `u64` but type `u8` was found"));
+    assert!(errors.contains("token.leo:3:16"));
+    assert!(errors.contains("note: imported from test:2:1"));
+}
@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::Compiler;
+use leo_errors::{emitter::Handler, Result};
+use leo_span::symbol::create_session_if_not_set_then;
+
+use std::fs;
+
+/// Compiles `program` as the main file of a directory that also contains `table.json` with the given `table`.
+fn compile_with_table(program: &str, table: &str) -> Result<String> {
+    let directory = tempfile::tempdir().unwrap();
+    let main_file = directory.path().join("main.leo");
+    fs::write(&main_file, program).unwrap();
+    fs::write(directory.path().join("table.json"), table).unwrap();
+
+    create_session_if_not_set_then(|_| {
+        let handler = Handler::default();
+        let mut compiler = Compiler::new("test".into(), "aleo".into(), &handler, main_file, "/tmp".into(), None);
+        compiler.parse_program()?;
+        let (st, struct_graph, call_graph) = compiler.compiler_stages()?;
+        compiler.code_generation_pass(&st, &struct_graph, &call_graph)
+    })
+}
+
+#[test]
+fn test_include_constants() {
+    let program = "
+program test.aleo {
+    const ROUND_CONSTANTS: [[field; 2]; 2] = include_constants!(\"table.json\");
+
+    transition main(a: field) -> field {
+        return a + ROUND_CONSTANTS[1u8][0u8];
+    }
+}
+";
+    // Numbers that do not fit in 64 bits are written as strings.
+    let bytecode = compile_with_table(
+        program,
+        r#"[[1, "2field"], ["8444461749428370424248824938781546531375899335154063827935233455917409239040", 4]]"#,
+    )
+    .unwrap();
+    assert!(bytecode.contains("8444461749428370424248824938781546531375899335154063827935233455917409239040field"));
+
+    // The constants are checked against the declared type.
+    assert!(compile_with_table(program, "[[1, 2], [3]]").is_err());
+    assert!(compile_with_table(program, "[[1, 2], [3, 4u8]]").is_err());
+    assert!(compile_with_table(program, "[[1, 2], [3, 4.5]]").is_err());
+}
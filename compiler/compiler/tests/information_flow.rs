@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+mod common;
+use common::with_parsed_program;
+
+use leo_passes::InformationFlowReport;
+
+fn report() -> InformationFlowReport {
+    with_parsed_program(|compiler| {
+        let st = compiler.symbol_table_pass().unwrap();
+        let (st, _, call_graph) = compiler.type_checker_pass(st).unwrap();
+        let st = compiler.loop_unrolling_pass(st).unwrap();
+        compiler.static_single_assignment_pass(&st).unwrap();
+        compiler.flattening_pass(&st).unwrap();
+        compiler.destructuring_pass().unwrap();
+        compiler.function_inlining_pass(&call_graph).unwrap();
+        compiler.information_flow_pass().unwrap()
+    })
+}
+
+#[test]
+fn test_information_flow() {
+    let report = report();
+    let flows: Vec<_> = report
+        .transitions
+        .iter()
+        .map(|transition| {
+            let flows: Vec<_> = transition
+                .flows
+                .iter()
+                .map(|flow| format!("{} <- {} {:?}", flow.sink, flow.source, flow.operations))
+                .collect();
+            (transition.name.as_str(), flows)
+        })
+        .collect();
+
+    // The public salt, the private amount of the record, and the public issuer of the input record are not reported.
+    assert_eq!(flows, [
+        ("mint", vec!["output 2 <- flag [\"condition\"]".to_string(), "finalize amount <- secret []".to_string()]),
+        ("reveal", vec![]),
+    ]);
+}
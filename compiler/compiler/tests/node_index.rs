@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+mod common;
+use common::{with_parsed_program, PROGRAM};
+
+use leo_ast::{NodeIndex, NodeKind::*};
+use leo_span::{
+    span::{BytePos, Pos},
+    symbol::create_session_if_not_set_then,
+    Span,
+};
+
+#[test]
+fn test_node_index() {
+    create_session_if_not_set_then(|_| {
+        let parse = || with_parsed_program(|compiler| NodeIndex::new(compiler.ast.as_repr()));
+        let index = parse();
+
+        let start = PROGRAM.find("salt * 2u64").unwrap();
+        let salt = index.innermost(Span::new(BytePos::from_usize(start), BytePos::from_usize(start + 4))).unwrap();
+        let kinds = |id| std::iter::once(id).chain(index.ancestors(id)).map(|id| index.get(id).unwrap().kind);
+        assert_eq!(kinds(salt).collect::<Vec<_>>(), [Identifier, Expression, Expression, Statement, Block, Function]);
+
+        // The finalize block of a function follows the span of the function, but is still found within it.
+        let start = PROGRAM.find("current + amount").unwrap();
+        let current = index.innermost(Span::new(BytePos::from_usize(start), BytePos::from_usize(start + 7))).unwrap();
+        assert_eq!(kinds(current).collect::<Vec<_>>(), [
+            Identifier, Expression, Expression, Statement, Block, Finalize, Function
+        ]);
+
+        // Each node is a child of its parent.
+        for (id, node) in index.iter() {
+            if let Some(parent) = node.parent {
+                assert!(index.children(parent).contains(&id));
+            }
+        }
+
+        // Parsing the program again assigns the same IDs to the same nodes.
+        let shape = |index: &NodeIndex| index.iter().map(|(id, node)| (id, node.kind, node.parent)).collect::<Vec<_>>();
+        assert_eq!(shape(&index), shape(&parse()));
+    });
+}
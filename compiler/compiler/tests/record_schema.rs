@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::{Compiler, CompilerOptions};
+use leo_errors::emitter::Handler;
+use leo_passes::RecordSchema;
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+#[test]
+fn test_record_schema() {
+    let program = "
+program test.aleo {
+    record Token {
+        amount: u64,
+        owner: field,
+        reserved_memo: field,
+    }
+
+    transition main(a: u64) -> u64 {
+        return a;
+    }
+}
+";
+    let errors = create_session_if_not_set_then(|_| {
+        let (handler, buffer) = Handler::new_with_buf();
+        let mut options = CompilerOptions::default();
+        options.build.record_schema =
+            RecordSchema { reserved_prefixes: vec!["reserved_".to_string()], ..RecordSchema::strict() };
+        let mut compiler =
+            Compiler::new("test".into(), "aleo".into(), &handler, "".into(), "/tmp".into(), Some(options));
+        compiler.parse_program_from_string(program, FileName::Custom("test".into())).unwrap();
+        assert!(compiler.type_checking_stages().is_err());
+        buffer.extract_errs().to_string()
+    });
+
+    // All the violations are reported at once.
+    assert!(errors.contains("The record `Token` does not follow the `strict` record schema."));
+    assert!(errors.contains("- the member `owner` must have type `address`"));
+    assert!(errors.contains("- the member `owner` must be declared at position 1"));
+    assert!(errors.contains("- the name of the member `reserved_memo` starts with the reserved prefix `reserved_`"));
+    assert_eq!(errors.matches("Error [").count(), 1);
+}
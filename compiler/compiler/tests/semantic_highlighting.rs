@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+mod common;
+use common::{with_parsed_program, PROGRAM};
+
+use leo_passes::SemanticTokenKind::*;
+use leo_span::{
+    source_map::FileName,
+    span::{BytePos, Pos},
+};
+
+#[test]
+fn test_semantic_highlighting() {
+    with_parsed_program(|compiler| {
+        compiler.symbol_table_pass().unwrap();
+        compiler.name_resolution_pass().unwrap();
+        let tokens = compiler.semantic_highlighting_pass(&FileName::Custom("test".into())).unwrap();
+
+        let kind = |context: &str, text: &str| {
+            let start = PROGRAM.find(context).unwrap() + context.find(text).unwrap();
+            tokens.iter().find(|token| token.span.lo == BytePos::from_usize(start)).map(|token| token.kind).unwrap()
+        };
+        assert_eq!(kind("record Token", "record"), Keyword);
+        assert_eq!(kind("record Token", "Token"), Record);
+        assert_eq!(kind("amount: hidden", "amount"), Member);
+        assert_eq!(kind("amount: hidden", "hidden"), Variable);
+        assert_eq!(kind("mapping supply", "supply"), Mapping);
+        assert_eq!(kind("double(secret)", "double"), Function);
+        assert_eq!(kind("double(secret)", "secret"), Parameter);
+        assert_eq!(kind("self.caller,", "caller"), Builtin);
+        assert_eq!(kind("Mapping::get_or_use", "get_or_use"), Builtin);
+        assert_eq!(kind("finalize mint", "mint"), Function);
+        assert_eq!(kind("token.issuer", "issuer"), Member);
+        assert_eq!(kind("0u8, 0u64", "0u8"), Literal);
+        assert_eq!(kind("-> u64", "u64"), Type);
+        assert_eq!(kind("-> u64", "->"), Punctuation);
+
+        // Unknown files are reported.
+        assert!(compiler.semantic_highlighting_pass(&FileName::Custom("missing".into())).is_err());
+    });
+}
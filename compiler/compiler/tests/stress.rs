@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Smoke tests that compile large generated programs, to catch passes whose running time grows much faster than the
+//! size of the program, e.g. quadratically.
+
+use leo_compiler::{Compiler, CompilerOptions};
+use leo_errors::emitter::Handler;
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+use leo_test_framework::generator::ProgramShape;
+
+use std::{collections::BTreeMap, path::PathBuf, time::Duration};
+
+/// The factor by which the programs grow.
+const GROWTH: u32 = 4;
+
+/// The factor by which the time spent in a stage may grow, which is well below `GROWTH` squared.
+const MAX_SLOWDOWN: u32 = 3 * GROWTH;
+
+/// Stages that take less time than this on the larger program are not compared, as their timings are mostly noise.
+const MIN_DURATION: Duration = Duration::from_millis(20);
+
+/// Compiles the program of the given shape, and returns the least time spent in each stage over three runs.
+fn stage_timings(shape: ProgramShape) -> BTreeMap<&'static str, Duration> {
+    let program = shape.generate();
+    let mut timings = BTreeMap::<&'static str, Duration>::new();
+    for _ in 0..3 {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let mut options = CompilerOptions::default();
+            options.build.dce_enabled = true;
+            let mut compiler = Compiler::new(
+                "test".into(),
+                "aleo".into(),
+                &handler,
+                PathBuf::from("stress-test"),
+                std::env::temp_dir(),
+                Some(options),
+            );
+            compiler
+                .parse_program_from_string(&program, FileName::Custom("stress-test".into()))
+                .and_then(|_| compiler.compiler_stages())
+                .unwrap_or_else(|err| panic!("failed to compile {shape:?}: {err}"));
+            for (stage, elapsed) in compiler.timings().stages() {
+                let least = timings.entry(stage).or_insert(*elapsed);
+                *least = (*least).min(*elapsed);
+            }
+        });
+    }
+    timings
+}
+
+/// Checks that no stage slows down by more than `MAX_SLOWDOWN` when the program grows from `shape` to `grown`.
+fn assert_scales(shape: ProgramShape, grown: ProgramShape) {
+    let small = stage_timings(shape);
+    let large = stage_timings(grown);
+    for (stage, elapsed) in large.iter() {
+        let baseline = small.get(stage).copied().unwrap_or_default().max(MIN_DURATION / MAX_SLOWDOWN);
+        assert!(
+            *elapsed < MIN_DURATION || *elapsed < baseline * MAX_SLOWDOWN,
+            "`{stage}` took {elapsed:?} on {grown:?}, up from {baseline:?} on {shape:?}"
+        );
+    }
+}
+
+#[test]
+fn many_functions_scale() {
+    let shape = ProgramShape::default().with_functions(100);
+    assert_scales(shape, shape.with_functions(100 * GROWTH as usize));
+}
+
+#[test]
+fn call_chains_scale() {
+    // Inline functions keep their own copy of the functions they inline, so the inlined program grows with the square
+    // of the depth of the chains. The chains are therefore made more numerous rather than deeper.
+    let shape = ProgramShape::default().with_functions(64).with_call_depth(16);
+    assert_scales(shape, shape.with_functions(64 * GROWTH as usize));
+}
+
+#[test]
+fn large_arrays_scale() {
+    let shape = ProgramShape::default().with_functions(50).with_array_len(8).with_statements(8);
+    assert_scales(shape, shape.with_array_len(8 * GROWTH as usize).with_statements(8 * GROWTH as usize));
+}
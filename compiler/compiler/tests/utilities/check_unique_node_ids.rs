@@ -173,12 +173,15 @@ impl<'a> ExpressionVisitor<'a> for CheckUniqueNodeIds<'a> {
 impl<'a> StatementVisitor<'a> for CheckUniqueNodeIds<'a> {
     fn visit_assert(&mut self, input: &'a AssertStatement) {
         match &input.variant {
-            AssertVariant::Assert(expr) => self.visit_expression(expr, &Default::default()),
+            AssertVariant::Assert(expr) => {
+                self.visit_expression(expr, &Default::default());
+            }
             AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
                 self.visit_expression(left, &Default::default());
-                self.visit_expression(right, &Default::default())
+                self.visit_expression(right, &Default::default());
             }
-        };
+            AssertVariant::Fail(_) => {}
+        }
         self.check(input.id)
     }
 
@@ -215,6 +218,11 @@ impl<'a> StatementVisitor<'a> for CheckUniqueNodeIds<'a> {
                 self.visit_expression(left, &Default::default());
                 self.visit_expression(right, &Default::default());
             }
+            ConsoleFunction::Error(_, arguments) | ConsoleFunction::Log(_, arguments) => {
+                arguments.iter().for_each(|argument| {
+                    self.visit_expression(argument, &Default::default());
+                });
+            }
         };
         self.check(input.id)
     }
@@ -253,7 +261,12 @@ impl<'a> StatementVisitor<'a> for CheckUniqueNodeIds<'a> {
 
 impl<'a> ProgramVisitor<'a> for CheckUniqueNodeIds<'a> {
     fn visit_struct(&mut self, input: &'a Struct) {
-        let Struct { identifier, members, id, .. } = input;
+        let Struct { annotations, identifier, members, id, .. } = input;
+        // Check the annotations.
+        for Annotation { identifier, id, .. } in annotations {
+            self.visit_identifier(identifier, &Default::default());
+            self.check(*id);
+        }
         self.visit_identifier(identifier, &Default::default());
         for Member { identifier, type_, id, .. } in members {
             self.visit_identifier(identifier, &Default::default());
@@ -24,6 +24,7 @@ use leo_errors::{
     LeoWarning,
 };
 use leo_package::root::env::Env;
+use leo_passes::RecordSchema;
 use leo_span::source_map::FileName;
 use leo_test_framework::{test::TestConfig, Test};
 
@@ -32,18 +33,19 @@ use snarkvm::prelude::*;
 use leo_ast::ProgramVisitor;
 use snarkvm::{file::Manifest, package::Package};
 use std::{
-    cell::RefCell,
     fmt,
     fs,
     fs::File,
+    mem,
     path::{Path, PathBuf},
-    rc::Rc,
+    sync::{Arc, Mutex},
 };
 
 pub type Network = Testnet3;
 #[allow(unused)]
 pub type Aleo = snarkvm::circuit::AleoV0;
 
+#[allow(unused)]
 pub fn hash_asts() -> (String, String, String, String, String, String, String) {
     let initial_ast = hash_file("/tmp/output/test.initial_ast.json");
     let unrolled_ast = hash_file("/tmp/output/test.unrolled_ast.json");
@@ -56,6 +58,7 @@ pub fn hash_asts() -> (String, String, String, String, String, String, String) {
     (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, dce_ast)
 }
 
+#[allow(unused)]
 pub fn hash_symbol_tables() -> (String, String, String) {
     let initial_symbol_table = hash_file("/tmp/output/test.initial_symbol_table.json");
     let type_checked_symbol_table = hash_file("/tmp/output/test.type_checked_symbol_table.json");
@@ -63,6 +66,7 @@ pub fn hash_symbol_tables() -> (String, String, String) {
     (initial_symbol_table, type_checked_symbol_table, unrolled_symbol_table)
 }
 
+#[allow(unused)]
 pub fn get_cwd_option(test: &Test) -> Option<PathBuf> {
     // Check for CWD option:
     // ``` cwd: import ```
@@ -75,6 +79,7 @@ pub fn get_cwd_option(test: &Test) -> Option<PathBuf> {
     })
 }
 
+#[allow(unused)]
 pub fn get_build_options(test_config: &TestConfig) -> Vec<BuildOptions> {
     match test_config.extra.get("configs") {
         Some(configs) => {
@@ -85,22 +90,49 @@ pub fn get_build_options(test_config: &TestConfig) -> Vec<BuildOptions> {
                 .iter()
                 .map(|config| {
                     let config = config.as_mapping().expect("Expected the compiler configuration to be a mapping.");
-                    assert_eq!(
-                        config.len(),
-                        1,
-                        "A compiler configuration must have exactly one key-value pair. e.g. `dce_enabled`: true"
-                    );
                     BuildOptions {
                         dce_enabled: config
                             .get(&serde_yaml::Value::String("dce_enabled".to_string()))
                             .expect("Expected key `dce_enabled`")
                             .as_bool()
                             .expect("Expected value to be a boolean."),
+                        max_unrolled_statements: config
+                            .get(&serde_yaml::Value::String("max_unrolled_statements".to_string()))
+                            .map(|limit| limit.as_u64().expect("Expected value to be an integer.") as usize),
+                        max_unrolled_iterations: config
+                            .get(&serde_yaml::Value::String("max_unrolled_iterations".to_string()))
+                            .map(|limit| limit.as_u64().expect("Expected value to be an integer.") as usize),
+                        max_expression_depth: config
+                            .get(&serde_yaml::Value::String("max_expression_depth".to_string()))
+                            .map(|limit| limit.as_u64().expect("Expected value to be an integer.") as usize),
+                        max_array_size: config
+                            .get(&serde_yaml::Value::String("max_array_size".to_string()))
+                            .map(|limit| limit.as_u64().expect("Expected value to be an integer.") as usize),
+                        max_identifiers: config
+                            .get(&serde_yaml::Value::String("max_identifiers".to_string()))
+                            .map(|limit| limit.as_u64().expect("Expected value to be an integer.") as usize),
+                        record_schema: config
+                            .get(&serde_yaml::Value::String("record_schema".to_string()))
+                            .map(|profile| {
+                                let profile = profile.as_str().expect("Expected value to be a string.");
+                                RecordSchema::profile(profile).expect("Expected a record schema profile.")
+                            })
+                            .unwrap_or_default(),
                     }
                 })
                 .collect()
         }
-        None => vec![BuildOptions { dce_enabled: true }],
+        None => {
+            vec![BuildOptions {
+                dce_enabled: true,
+                max_unrolled_statements: None,
+                max_unrolled_iterations: None,
+                max_expression_depth: None,
+                max_array_size: None,
+                max_identifiers: None,
+                record_schema: Default::default(),
+            }]
+        }
     }
 }
 
@@ -134,6 +166,7 @@ pub fn setup_build_directory(program_name: &str, bytecode: &String, handler: &Ha
     handler.extend_if_error(Package::<Network>::open(&directory).map_err(LeoError::Anyhow))
 }
 
+#[allow(unused)]
 pub fn new_compiler(
     handler: &Handler,
     main_file_path: PathBuf,
@@ -145,6 +178,7 @@ pub fn new_compiler(
     Compiler::new(String::from("test"), String::from("aleo"), handler, main_file_path, output_dir, compiler_options)
 }
 
+#[allow(unused)]
 pub fn parse_program<'a>(
     handler: &'a Handler,
     program_string: &str,
@@ -160,6 +194,7 @@ pub fn parse_program<'a>(
     Ok(compiler)
 }
 
+#[allow(unused)]
 pub fn hash_content(content: &str) -> String {
     use sha2::{Digest, Sha256};
     let mut hasher = Sha256::new();
@@ -169,6 +204,7 @@ pub fn hash_content(content: &str) -> String {
     format!("{hash:x}")
 }
 
+#[allow(unused)]
 pub fn hash_file(path: &str) -> String {
     let file = fs::read_to_string(Path::new(path)).unwrap();
     hash_content(&file)
@@ -190,30 +226,41 @@ impl Display for LeoOrString {
 }
 
 /// A buffer used to emit errors into.
-#[derive(Clone)]
-pub struct BufferEmitter(pub Rc<RefCell<Buffer<LeoOrString>>>, pub Rc<RefCell<Buffer<LeoWarning>>>);
+#[derive(Clone, Default)]
+pub struct BufferEmitter(Arc<Mutex<Buffer<LeoOrString>>>, Arc<Mutex<Buffer<LeoWarning>>>);
+
+impl BufferEmitter {
+    /// Extracts all the errors collected in this emitter.
+    pub fn extract_errs(&self) -> Buffer<LeoOrString> {
+        mem::take(&mut *self.0.lock().unwrap())
+    }
+
+    /// Extracts all the warnings collected in this emitter.
+    pub fn extract_warnings(&self) -> Buffer<LeoWarning> {
+        mem::take(&mut *self.1.lock().unwrap())
+    }
+}
 
 impl Emitter for BufferEmitter {
     fn emit_err(&mut self, err: LeoError) {
-        self.0.borrow_mut().push(LeoOrString::Leo(err));
+        self.0.lock().unwrap().push(LeoOrString::Leo(err));
     }
 
     fn last_emitted_err_code(&self) -> Option<i32> {
-        let temp = &*self.0.borrow();
-        temp.last_entry().map(|entry| match entry {
+        self.0.lock().unwrap().last_entry().map(|entry| match entry {
             LeoOrString::Leo(err) => err.exit_code(),
             _ => 0,
         })
     }
 
     fn emit_warning(&mut self, warning: leo_errors::LeoWarning) {
-        self.1.borrow_mut().push(warning);
+        self.1.lock().unwrap().push(warning);
     }
 }
 
 #[allow(unused)]
 pub fn buffer_if_err<T>(buf: &BufferEmitter, res: Result<T, String>) -> Result<T, ()> {
-    res.map_err(|err| buf.0.borrow_mut().push(LeoOrString::String(err)))
+    res.map_err(|err| buf.0.lock().unwrap().push(LeoOrString::String(err)))
 }
 
 #[allow(unused)]
@@ -224,14 +271,30 @@ pub fn temp_dir() -> PathBuf {
 pub fn compile_and_process<'a>(parsed: &'a mut Compiler<'a>) -> Result<String, LeoError> {
     let st = parsed.symbol_table_pass()?;
 
+    parsed.name_resolution_pass()?;
+
     CheckUniqueNodeIds::new().visit_program(&parsed.ast.ast);
 
     let (st, struct_graph, call_graph) = parsed.type_checker_pass(st)?;
 
     CheckUniqueNodeIds::new().visit_program(&parsed.ast.ast);
 
+    parsed.check_constraint_limit()?;
+
+    parsed.move_checking_pass()?;
+
+    parsed.static_analysis_pass()?;
+
+    parsed.loop_invariant_code_motion_pass()?;
+
     let st = parsed.loop_unrolling_pass(st)?;
 
+    parsed.dynamic_indexing_pass()?;
+
+    parsed.boolean_simplification_pass()?;
+
+    let (st, struct_graph) = parsed.option_lowering_pass(st, struct_graph)?;
+
     parsed.static_single_assignment_pass(&st)?;
 
     parsed.flattening_pass(&st)?;
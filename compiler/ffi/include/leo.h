@@ -0,0 +1,117 @@
+/*
+ * Copyright (C) 2019-2023 Aleo Systems Inc.
+ * This file is part of the Leo library.
+ *
+ * The Leo library is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * The Leo library is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+/*
+ * The C interface of the Leo compiler, implemented by the `leo-ffi` crate.
+ *
+ * Strings passed to the library are borrowed for the duration of the call. Strings returned by the library are owned
+ * by the caller, who frees them with `leo_string_free`. A context must not be used by two threads at once.
+ */
+
+#ifndef LEO_H
+#define LEO_H
+
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The version of the interface, which changes whenever the layout of a struct or the signature of a function does. */
+#define LEO_FFI_VERSION 1
+
+/* The outcome of a call. */
+typedef enum LeoStatus {
+    /* The call succeeded. */
+    LEO_STATUS_OK = 0,
+    /* The program has errors, which are reported by `leo_get_diagnostics_json`. */
+    LEO_STATUS_FAILED = 1,
+    /* An argument is a null pointer, or a string that is not valid UTF-8. */
+    LEO_STATUS_INVALID_ARGUMENT = 2,
+    /* The library panicked, which is a bug. */
+    LEO_STATUS_PANICKED = 3,
+} LeoStatus;
+
+/* The options of a context, which are initialized by `leo_options_default`. */
+typedef struct LeoOptions {
+    /* Whether dead code elimination is enabled, as 1, or disabled, as 0. */
+    uint8_t dce_enabled;
+    /* The size of each source, in bytes. */
+    uint64_t max_source_size;
+    /* The time a call may take, in milliseconds. */
+    uint64_t time_limit_ms;
+    /* The number of AST nodes a call may create. */
+    uint64_t max_nodes;
+    /* The number of constraints each transition is estimated to have. */
+    uint64_t max_constraints;
+} LeoOptions;
+
+/*
+ * A compiler context, which holds the options, the programs that can be imported, and the diagnostics of the last
+ * call.
+ */
+typedef struct LeoContext LeoContext;
+
+/* Returns the version of the interface implemented by the library, which should equal `LEO_FFI_VERSION`. */
+uint32_t leo_ffi_version(void);
+
+/* Returns the default options. */
+LeoOptions leo_options_default(void);
+
+/*
+ * Returns a new context with the given options, or with the default options if `options` is null.
+ * The context is freed with `leo_context_free`. Returns null if the library panics.
+ */
+LeoContext *leo_context_new(const LeoOptions *options);
+
+/* Frees a context. Does nothing if `context` is null. */
+void leo_context_free(LeoContext *context);
+
+/*
+ * Makes the program `name` importable as `import {name}.leo;`, from its `source`.
+ * A program added again under the same name replaces the previous one.
+ */
+LeoStatus leo_context_add_import(LeoContext *context, const char *name, const char *source);
+
+/*
+ * Compiles the program `program_name` from its `source`. On success, stores its bytecode in `*bytecode` unless
+ * `bytecode` is null, and the caller frees it with `leo_string_free`. Otherwise, `*bytecode` is set to null.
+ * The diagnostics of the compilation are returned by `leo_get_diagnostics_json`.
+ */
+LeoStatus leo_compile(LeoContext *context, const char *program_name, const char *source, char **bytecode);
+
+/*
+ * Checks the program `program_name` from its `source`, like `leo check`, without generating its bytecode.
+ * The diagnostics of the check are returned by `leo_get_diagnostics_json`.
+ */
+LeoStatus leo_check(LeoContext *context, const char *program_name, const char *source);
+
+/*
+ * Returns the diagnostics of the last call to `leo_compile` or `leo_check` as a JSON array, which the caller frees
+ * with `leo_string_free`. Returns null if `context` is null or the library panics.
+ */
+char *leo_get_diagnostics_json(const LeoContext *context);
+
+/* Frees a string returned by the library. Does nothing if `string` is null. */
+void leo_string_free(char *string);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* LEO_H */
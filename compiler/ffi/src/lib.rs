@@ -0,0 +1,330 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+#![doc = include_str!("../README.md")]
+
+use leo_compiler::{Sandbox, SandboxOutput};
+use leo_errors::emitter::{DiagnosticRecord, Severity};
+
+use std::{
+    ffi::{c_char, CStr, CString},
+    panic::{self, AssertUnwindSafe},
+    ptr,
+    time::Duration,
+};
+
+/// The version of the interface, which changes whenever the layout of a struct or the signature of a function does.
+pub const LEO_FFI_VERSION: u32 = 1;
+
+/// The outcome of a call.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LeoStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// The program has errors, which are reported by `leo_get_diagnostics_json`.
+    Failed = 1,
+    /// An argument is a null pointer, or a string that is not valid UTF-8.
+    InvalidArgument = 2,
+    /// The library panicked, which is a bug.
+    Panicked = 3,
+}
+
+/// The options of a context, which are initialized by `leo_options_default`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LeoOptions {
+    /// Whether dead code elimination is enabled, as 1, or disabled, as 0.
+    pub dce_enabled: u8,
+    /// The size of each source, in bytes.
+    pub max_source_size: u64,
+    /// The time a call may take, in milliseconds.
+    pub time_limit_ms: u64,
+    /// The number of AST nodes a call may create.
+    pub max_nodes: u64,
+    /// The number of constraints each transition is estimated to have.
+    pub max_constraints: u64,
+}
+
+impl From<&Sandbox> for LeoOptions {
+    fn from(sandbox: &Sandbox) -> Self {
+        Self {
+            dce_enabled: sandbox.build.dce_enabled.into(),
+            max_source_size: sandbox.max_source_size as u64,
+            time_limit_ms: sandbox.time_limit.as_millis().try_into().unwrap_or(u64::MAX),
+            max_nodes: sandbox.max_nodes as u64,
+            max_constraints: sandbox.max_constraints,
+        }
+    }
+}
+
+impl From<&LeoOptions> for Sandbox {
+    fn from(options: &LeoOptions) -> Self {
+        let mut sandbox = Sandbox::default();
+        sandbox.build.dce_enabled = options.dce_enabled != 0;
+        sandbox.max_source_size = options.max_source_size.try_into().unwrap_or(usize::MAX);
+        sandbox.time_limit = Duration::from_millis(options.time_limit_ms);
+        sandbox.max_nodes = options.max_nodes.try_into().unwrap_or(usize::MAX);
+        sandbox.max_constraints = options.max_constraints;
+        sandbox
+    }
+}
+
+/// A compiler context, which holds the options, the programs that can be imported, and the diagnostics of the last
+/// call. It is opaque to callers, which only handle pointers to it.
+pub struct LeoContext {
+    sandbox: Sandbox,
+    diagnostics: Vec<DiagnosticRecord>,
+}
+
+impl LeoContext {
+    /// Records the diagnostics of a call, and returns its status along with the bytecode, if any.
+    fn finish(&mut self, output: SandboxOutput) -> (LeoStatus, Option<String>) {
+        self.diagnostics = output.diagnostics;
+        match self.diagnostics.iter().any(|diagnostic| diagnostic.severity == Severity::Error) {
+            true => (LeoStatus::Failed, None),
+            false => (LeoStatus::Ok, output.bytecode),
+        }
+    }
+}
+
+/// Runs `call`, returning `panicked` if it panics, since a panic must not unwind into the caller.
+fn guard<T>(panicked: T, call: impl FnOnce() -> T) -> T {
+    panic::catch_unwind(AssertUnwindSafe(call)).unwrap_or(panicked)
+}
+
+/// Returns the string at `string`, or `None` if it is null or not valid UTF-8.
+///
+/// # Safety
+///
+/// `string` must be null or point to a NUL-terminated string that outlives `'a`.
+unsafe fn to_str<'a>(string: *const c_char) -> Option<&'a str> {
+    match string.is_null() {
+        true => None,
+        false => CStr::from_ptr(string).to_str().ok(),
+    }
+}
+
+/// Returns a string owned by the caller, who frees it with `leo_string_free`.
+fn into_raw(string: String) -> *mut c_char {
+    // Neither bytecode nor JSON contains NUL bytes, which would end the string early.
+    CString::new(string).unwrap_or_default().into_raw()
+}
+
+/// Returns `LEO_FFI_VERSION`.
+#[no_mangle]
+pub extern "C" fn leo_ffi_version() -> u32 {
+    LEO_FFI_VERSION
+}
+
+/// Returns the default options.
+#[no_mangle]
+pub extern "C" fn leo_options_default() -> LeoOptions {
+    LeoOptions::from(&Sandbox::default())
+}
+
+/// Returns a new context with the given options, or with the default options if `options` is null.
+/// The context is freed with `leo_context_free`. Returns null if the library panics.
+///
+/// # Safety
+///
+/// `options` must be null or point to a `LeoOptions`.
+#[no_mangle]
+pub unsafe extern "C" fn leo_context_new(options: *const LeoOptions) -> *mut LeoContext {
+    guard(ptr::null_mut(), || {
+        let sandbox = options.as_ref().map(Sandbox::from).unwrap_or_default();
+        Box::into_raw(Box::new(LeoContext { sandbox, diagnostics: Vec::new() }))
+    })
+}
+
+/// Frees a context. Does nothing if `context` is null.
+///
+/// # Safety
+///
+/// `context` must be null or a context returned by `leo_context_new` that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn leo_context_free(context: *mut LeoContext) {
+    if !context.is_null() {
+        drop(Box::from_raw(context));
+    }
+}
+
+/// Makes the program `name` importable as `import {name}.leo;`, from its `source`.
+/// A program added again under the same name replaces the previous one.
+///
+/// # Safety
+///
+/// `context` must be null or a live context, and `name` and `source` must be null or NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn leo_context_add_import(
+    context: *mut LeoContext,
+    name: *const c_char,
+    source: *const c_char,
+) -> LeoStatus {
+    guard(LeoStatus::Panicked, || {
+        let (Some(context), Some(name), Some(source)) = (context.as_mut(), to_str(name), to_str(source)) else {
+            return LeoStatus::InvalidArgument;
+        };
+        context.sandbox.imports.insert(name.to_string(), source.to_string());
+        LeoStatus::Ok
+    })
+}
+
+/// Compiles the program `program_name` from its `source`. On success, stores its bytecode in `*bytecode` unless
+/// `bytecode` is null, and the caller frees it with `leo_string_free`. Otherwise, `*bytecode` is set to null.
+/// The diagnostics of the compilation are returned by `leo_get_diagnostics_json`.
+///
+/// # Safety
+///
+/// `context` must be null or a live context, `program_name` and `source` must be null or NUL-terminated strings,
+/// and `bytecode` must be null or point to a writable pointer.
+#[no_mangle]
+pub unsafe extern "C" fn leo_compile(
+    context: *mut LeoContext,
+    program_name: *const c_char,
+    source: *const c_char,
+    bytecode: *mut *mut c_char,
+) -> LeoStatus {
+    if let Some(bytecode) = bytecode.as_mut() {
+        *bytecode = ptr::null_mut();
+    }
+    guard(LeoStatus::Panicked, || {
+        let (Some(context), Some(program_name), Some(source)) =
+            (context.as_mut(), to_str(program_name), to_str(source))
+        else {
+            return LeoStatus::InvalidArgument;
+        };
+        let (status, output) = context.finish(context.sandbox.compile(program_name, source));
+        if let (Some(bytecode), Some(output)) = (bytecode.as_mut(), output) {
+            *bytecode = into_raw(output);
+        }
+        status
+    })
+}
+
+/// Checks the program `program_name` from its `source`, like `leo check`, without generating its bytecode.
+/// The diagnostics of the check are returned by `leo_get_diagnostics_json`.
+///
+/// # Safety
+///
+/// `context` must be null or a live context, and `program_name` and `source` must be null or NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn leo_check(
+    context: *mut LeoContext,
+    program_name: *const c_char,
+    source: *const c_char,
+) -> LeoStatus {
+    guard(LeoStatus::Panicked, || {
+        let (Some(context), Some(program_name), Some(source)) =
+            (context.as_mut(), to_str(program_name), to_str(source))
+        else {
+            return LeoStatus::InvalidArgument;
+        };
+        context.finish(context.sandbox.check(program_name, source)).0
+    })
+}
+
+/// Returns the diagnostics of the last call to `leo_compile` or `leo_check` as a JSON array, which the caller frees
+/// with `leo_string_free`. Returns null if `context` is null or the library panics.
+///
+/// # Safety
+///
+/// `context` must be null or a live context.
+#[no_mangle]
+pub unsafe extern "C" fn leo_get_diagnostics_json(context: *const LeoContext) -> *mut c_char {
+    guard(ptr::null_mut(), || match context.as_ref() {
+        Some(context) => into_raw(serde_json::to_string(&context.diagnostics).unwrap_or_else(|_| "[]".to_string())),
+        None => ptr::null_mut(),
+    })
+}
+
+/// Frees a string returned by the library. Does nothing if `string` is null.
+///
+/// # Safety
+///
+/// `string` must be null or a string returned by the library that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn leo_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TOKEN: &str = "program token.aleo {\n    transition mint(a: u32) -> u32 {\n        return a;\n    }\n}\n";
+    const MAIN: &str = "import token.leo;\nprogram main.aleo {\n    transition main(a: u32) -> u32 {\n        return token.leo/mint(a);\n    }\n}\n";
+
+    /// Returns `string` as a NUL-terminated string.
+    fn c(string: &str) -> CString {
+        CString::new(string).unwrap()
+    }
+
+    /// Returns the diagnostics of the last call as JSON.
+    unsafe fn diagnostics(context: *const LeoContext) -> String {
+        let json = leo_get_diagnostics_json(context);
+        let diagnostics = CStr::from_ptr(json).to_str().unwrap().to_string();
+        leo_string_free(json);
+        diagnostics
+    }
+
+    #[test]
+    fn compiles_and_checks() {
+        unsafe {
+            let context = leo_context_new(ptr::null());
+            assert_eq!(leo_context_add_import(context, c("token").as_ptr(), c(TOKEN).as_ptr()), LeoStatus::Ok);
+
+            let mut bytecode = ptr::null_mut();
+            assert_eq!(leo_compile(context, c("main").as_ptr(), c(MAIN).as_ptr(), &mut bytecode), LeoStatus::Ok);
+            assert!(CStr::from_ptr(bytecode).to_str().unwrap().contains("call token.aleo/mint"));
+            leo_string_free(bytecode);
+            assert_eq!(diagnostics(context), "[]");
+
+            assert_eq!(leo_check(context, c("main").as_ptr(), c(MAIN).as_ptr()), LeoStatus::Ok);
+            leo_context_free(context);
+        }
+    }
+
+    #[test]
+    fn reports_diagnostics() {
+        unsafe {
+            let context = leo_context_new(ptr::null());
+            let mut bytecode = ptr::null_mut();
+            assert_eq!(leo_compile(context, c("main").as_ptr(), c(MAIN).as_ptr(), &mut bytecode), LeoStatus::Failed);
+            assert!(bytecode.is_null());
+
+            let diagnostics: serde_json::Value = serde_json::from_str(&diagnostics(context)).unwrap();
+            assert_eq!(diagnostics[0]["severity"], "error");
+            assert!(diagnostics[0]["message"].as_str().unwrap().contains("token.leo"));
+            leo_context_free(context);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        unsafe {
+            let options = LeoOptions { max_source_size: 8, ..leo_options_default() };
+            let context = leo_context_new(&options);
+            assert_eq!(leo_check(ptr::null_mut(), c("main").as_ptr(), c(MAIN).as_ptr()), LeoStatus::InvalidArgument);
+            assert_eq!(leo_check(context, c("main").as_ptr(), ptr::null()), LeoStatus::InvalidArgument);
+            assert_eq!(leo_check(context, c("main").as_ptr(), c(MAIN).as_ptr()), LeoStatus::Failed);
+            assert!(leo_get_diagnostics_json(ptr::null()).is_null());
+            leo_context_free(context);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, LintLevel, LintLevels};
+
+use leo_errors::{emitter::Handler, LintError, LintWarning};
+use leo_passes::{SymbolTable, TypeTable};
+use leo_span::Span;
+
+use std::fmt::Display;
+
+/// What a [`LintPass`](crate::LintPass) knows about the node it checks, and how it reports violations.
+pub struct LintContext<'a> {
+    pub(crate) handler: &'a Handler,
+    pub(crate) levels: &'a LintLevels,
+    pub(crate) symbol_table: &'a SymbolTable,
+    pub(crate) type_table: &'a TypeTable,
+    pub(crate) nesting: usize,
+    pub(crate) in_constant: bool,
+}
+
+impl<'a> LintContext<'a> {
+    /// Reports a violation of `lint` at `span`, as a warning or an error depending on the level of the lint.
+    pub fn report(&self, lint: &'static Lint, message: impl Display, span: Span) {
+        match self.levels.level(lint) {
+            LintLevel::Allow => {}
+            LintLevel::Warn => self.handler.emit_warning(LintWarning::lint(lint.name, message, span).into()),
+            LintLevel::Deny => self.handler.emit_err(LintError::denied_lint(lint.name, message, span)),
+        }
+    }
+
+    /// Returns whether violations of `lint` are reported, so that passes can skip expensive checks otherwise.
+    pub fn is_enabled(&self, lint: &'static Lint) -> bool {
+        self.levels.level(lint) != LintLevel::Allow
+    }
+
+    /// Returns the number of blocks the current node is nested in, not counting the body of its function.
+    pub fn nesting(&self) -> usize {
+        self.nesting
+    }
+
+    /// Returns whether the current node is part of the value of a constant.
+    pub fn in_constant(&self) -> bool {
+        self.in_constant
+    }
+
+    /// Returns the symbol table of the program.
+    pub fn symbol_table(&self) -> &SymbolTable {
+        self.symbol_table
+    }
+
+    /// Returns the types of the expressions of the program.
+    pub fn type_table(&self) -> &TypeTable {
+        self.type_table
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_errors::{LeoError, LintError, Result};
+
+use indexmap::IndexMap;
+use std::{fmt, str::FromStr};
+
+/// How violations of a lint are reported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LintLevel {
+    /// Violations are not reported.
+    Allow,
+    /// Violations are reported as warnings.
+    Warn,
+    /// Violations are reported as errors, which fail the compilation.
+    Deny,
+}
+
+impl FromStr for LintLevel {
+    type Err = LeoError;
+
+    fn from_str(level: &str) -> Result<Self> {
+        match level {
+            "allow" => Ok(Self::Allow),
+            "warn" => Ok(Self::Warn),
+            "deny" => Ok(Self::Deny),
+            _ => Err(LintError::invalid_lint_level(level).into()),
+        }
+    }
+}
+
+impl fmt::Display for LintLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Allow => write!(f, "allow"),
+            Self::Warn => write!(f, "warn"),
+            Self::Deny => write!(f, "deny"),
+        }
+    }
+}
+
+/// The declaration of a lint, created with [`declare_lint!`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct Lint {
+    /// The name of the lint, e.g. `magic-numbers`, by which its level is configured.
+    pub name: &'static str,
+    /// The level of the lint, unless configured otherwise.
+    pub default_level: LintLevel,
+    /// What the lint reports.
+    pub description: &'static str,
+}
+
+/// Declares a [`Lint`] as a static, given its name, default level, and description.
+///
+/// ```
+/// leo_lints::declare_lint! {
+///     /// Reports transitions without inputs.
+///     pub NO_INPUTS, "no-inputs", Warn, "transitions without inputs"
+/// }
+/// assert_eq!(NO_INPUTS.default_level, leo_lints::LintLevel::Warn);
+/// ```
+#[macro_export]
+macro_rules! declare_lint {
+    ($(#[$docs:meta])* $vis:vis $static_name:ident, $name:literal, $level:ident, $description:literal) => {
+        $(#[$docs])*
+        $vis static $static_name: $crate::Lint =
+            $crate::Lint { name: $name, default_level: $crate::LintLevel::$level, description: $description };
+    };
+}
+
+/// The levels of lints that differ from their default levels.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LintLevels {
+    levels: IndexMap<String, LintLevel>,
+}
+
+impl LintLevels {
+    /// Parses levels given as `<lint>=<level>`, e.g. `magic-numbers=deny`. Later levels override earlier ones.
+    pub fn parse<S: AsRef<str>>(levels: &[S]) -> Result<Self> {
+        let mut parsed = Self::default();
+        for level in levels {
+            let level = level.as_ref();
+            match level.split_once('=') {
+                Some((lint, level)) => parsed.set(lint.trim(), level.trim().parse()?),
+                None => return Err(LintError::invalid_lint_level(level).into()),
+            }
+        }
+        Ok(parsed)
+    }
+
+    /// Sets the level of the lint with the given name.
+    pub fn set(&mut self, lint: impl Into<String>, level: LintLevel) {
+        self.levels.insert(lint.into(), level);
+    }
+
+    /// Returns the level of the given lint.
+    pub fn level(&self, lint: &Lint) -> LintLevel {
+        self.levels.get(lint.name).copied().unwrap_or(lint.default_level)
+    }
+
+    /// Returns the names of the lints whose levels are set.
+    pub fn lints(&self) -> impl Iterator<Item = &str> {
+        self.levels.keys().map(String::as_str)
+    }
+}
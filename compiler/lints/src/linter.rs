@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{lints, Lint, LintContext, LintLevels};
+
+use leo_ast::*;
+use leo_compiler::{CompilerPass, PassContext, PassStage};
+use leo_errors::{LintError, Result};
+
+/// The checks for one or more lints, which the [`Linter`] runs on every function, statement, and expression.
+pub trait LintPass {
+    /// The lints reported by the pass.
+    fn lints(&self) -> Vec<&'static Lint>;
+
+    /// Checks a function, before its statements are checked.
+    fn check_function(&self, _context: &LintContext, _function: &Function) {}
+
+    /// Checks a statement, before the statements and expressions it contains are checked.
+    fn check_statement(&self, _context: &LintContext, _statement: &Statement) {}
+
+    /// Checks an expression, before the expressions it contains are checked.
+    fn check_expression(&self, _context: &LintContext, _expression: &Expression) {}
+}
+
+/// The compiler pass running the lint passes on the program, after type checking.
+/// Imported programs are not linted.
+pub struct Linter {
+    passes: Vec<Box<dyn LintPass>>,
+    levels: LintLevels,
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        Self::new(LintLevels::default())
+    }
+}
+
+impl Linter {
+    /// Returns a linter running the built-in lints, at the given levels.
+    pub fn new(levels: LintLevels) -> Self {
+        Self { passes: lints::builtin(), levels }
+    }
+
+    /// Adds a lint pass, e.g. for lints specific to a code base.
+    pub fn with_lint_pass(mut self, pass: impl LintPass + 'static) -> Self {
+        self.passes.push(Box::new(pass));
+        self
+    }
+
+    /// Returns the lints reported by the lint passes.
+    pub fn lints(&self) -> impl Iterator<Item = &'static Lint> + '_ {
+        self.passes.iter().flat_map(|pass| pass.lints())
+    }
+}
+
+impl CompilerPass for Linter {
+    fn name(&self) -> &'static str {
+        "lints"
+    }
+
+    fn stage(&self) -> PassStage {
+        PassStage::TypeChecking
+    }
+
+    fn run(&self, context: PassContext) -> Result<()> {
+        if let Some(unknown) = self.levels.lints().find(|name| !self.lints().any(|lint| lint.name == *name)) {
+            return Err(LintError::unknown_lint(unknown).into());
+        }
+
+        let mut walker = Walker {
+            context: LintContext {
+                handler: context.handler,
+                levels: &self.levels,
+                symbol_table: context.symbol_table,
+                type_table: context.type_table,
+                nesting: 0,
+                in_constant: false,
+            },
+            passes: &self.passes,
+        };
+        context.ast.ast.program_scopes.values().for_each(|scope| walker.visit_program_scope(scope));
+
+        Ok(())
+    }
+}
+
+/// Walks the AST, running the checks of the lint passes on each node.
+struct Walker<'a> {
+    context: LintContext<'a>,
+    passes: &'a [Box<dyn LintPass>],
+}
+
+impl<'a> ExpressionVisitor<'a> for Walker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_expression(&mut self, input: &'a Expression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.passes.iter().for_each(|pass| pass.check_expression(&self.context, input));
+        match input {
+            Expression::Access(access) => self.visit_access(access, additional),
+            Expression::Array(array) => self.visit_array(array, additional),
+            Expression::Binary(binary) => self.visit_binary(binary, additional),
+            Expression::Call(call) => self.visit_call(call, additional),
+            Expression::Cast(cast) => self.visit_cast(cast, additional),
+            Expression::Struct(struct_) => self.visit_struct_init(struct_, additional),
+            Expression::Err(err) => self.visit_err(err, additional),
+            Expression::Identifier(identifier) => self.visit_identifier(identifier, additional),
+            Expression::Literal(literal) => self.visit_literal(literal, additional),
+            Expression::Ternary(ternary) => self.visit_ternary(ternary, additional),
+            Expression::Tuple(tuple) => self.visit_tuple(tuple, additional),
+            Expression::Unary(unary) => self.visit_unary(unary, additional),
+            Expression::Unit(unit) => self.visit_unit(unit, additional),
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input
+            .members
+            .iter()
+            .filter_map(|member| member.expression.as_ref())
+            .for_each(|expression| self.visit_expression(expression, additional));
+    }
+}
+
+impl<'a> StatementVisitor<'a> for Walker<'a> {
+    fn visit_statement(&mut self, input: &'a Statement) {
+        self.passes.iter().for_each(|pass| pass.check_statement(&self.context, input));
+        match input {
+            Statement::Assert(stmt) => self.visit_assert(stmt),
+            Statement::Assign(stmt) => self.visit_assign(stmt),
+            Statement::Block(stmt) => self.visit_block(stmt),
+            Statement::Conditional(stmt) => self.visit_conditional(stmt),
+            Statement::Console(stmt) => self.visit_console(stmt),
+            Statement::Const(stmt) => self.visit_const(stmt),
+            Statement::Definition(stmt) => self.visit_definition(stmt),
+            Statement::Expression(stmt) => self.visit_expression_statement(stmt),
+            Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Return(stmt) => self.visit_return(stmt),
+        }
+    }
+
+    fn visit_block(&mut self, input: &'a Block) {
+        self.context.nesting += 1;
+        input.statements.iter().for_each(|stmt| self.visit_statement(stmt));
+        self.context.nesting -= 1;
+    }
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.context.in_constant = true;
+        self.visit_expression(&input.value, &());
+        self.context.in_constant = false;
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for Walker<'a> {
+    fn visit_function(&mut self, input: &'a Function) {
+        self.passes.iter().for_each(|pass| pass.check_function(&self.context, input));
+        // The bodies of the function and its finalize block are not counted as nesting.
+        input.block.statements.iter().for_each(|stmt| self.visit_statement(stmt));
+        if let Some(finalize) = &input.finalize {
+            finalize.block.statements.iter().for_each(|stmt| self.visit_statement(stmt));
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{declare_lint, Lint, LintContext, LintPass};
+
+use leo_ast::Statement;
+
+declare_lint! {
+    /// Reports blocks nested more deeply than [`DeepNesting::max_nesting`] within a function.
+    pub DEEP_NESTING, "deep-nesting", Warn, "blocks nested too deeply"
+}
+
+/// The pass of the [`DEEP_NESTING`] lint.
+pub struct DeepNesting {
+    /// How deeply blocks may be nested. Blocks directly in the body of a function are one level deep.
+    pub max_nesting: usize,
+}
+
+impl Default for DeepNesting {
+    fn default() -> Self {
+        Self { max_nesting: 4 }
+    }
+}
+
+impl LintPass for DeepNesting {
+    fn lints(&self) -> Vec<&'static Lint> {
+        vec![&DEEP_NESTING]
+    }
+
+    fn check_statement(&self, context: &LintContext, statement: &Statement) {
+        // Only the outermost blocks that are nested too deeply are reported.
+        if context.nesting() != self.max_nesting {
+            return;
+        }
+        let span = match statement {
+            Statement::Block(block) => block.span,
+            Statement::Conditional(conditional) => conditional.then.span,
+            Statement::Iteration(iteration) => iteration.block.span,
+            _ => return,
+        };
+        let message = format!(
+            "This block is nested {} levels deep, more than the {} allowed.",
+            self.max_nesting + 1,
+            self.max_nesting
+        );
+        context.report(&DEEP_NESTING, message, span);
+    }
+}
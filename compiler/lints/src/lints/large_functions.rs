@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{declare_lint, Lint, LintContext, LintPass};
+
+use leo_ast::{Block, Function, Statement};
+
+declare_lint! {
+    /// Reports functions and finalize blocks with more than [`LargeFunctions::max_statements`] statements.
+    pub LARGE_FUNCTIONS, "large-functions", Warn, "functions with too many statements"
+}
+
+/// The pass of the [`LARGE_FUNCTIONS`] lint.
+pub struct LargeFunctions {
+    /// The number of statements a function or finalize block may contain, including nested statements.
+    pub max_statements: usize,
+}
+
+impl Default for LargeFunctions {
+    fn default() -> Self {
+        Self { max_statements: 100 }
+    }
+}
+
+impl LintPass for LargeFunctions {
+    fn lints(&self) -> Vec<&'static Lint> {
+        vec![&LARGE_FUNCTIONS]
+    }
+
+    fn check_function(&self, context: &LintContext, function: &Function) {
+        let mut bodies = vec![(function.identifier.to_string(), &function.block, function.span)];
+        if let Some(finalize) = &function.finalize {
+            bodies.push((format!("finalize {}", finalize.identifier), &finalize.block, finalize.span));
+        }
+        for (name, block, span) in bodies {
+            let statements = count_statements(block);
+            if statements > self.max_statements {
+                let message = format!(
+                    "`{name}` contains {statements} statements, more than the {} allowed.",
+                    self.max_statements
+                );
+                context.report(&LARGE_FUNCTIONS, message, span);
+            }
+        }
+    }
+}
+
+/// Returns the number of statements in the block, including nested statements but not the blocks themselves.
+fn count_statements(block: &Block) -> usize {
+    block.statements.iter().map(count_statement).sum()
+}
+
+fn count_statement(statement: &Statement) -> usize {
+    match statement {
+        Statement::Block(block) => count_statements(block),
+        Statement::Conditional(conditional) => {
+            1 + count_statements(&conditional.then) + conditional.otherwise.as_deref().map_or(0, count_statement)
+        }
+        Statement::Iteration(iteration) => 1 + count_statements(&iteration.block),
+        _ => 1,
+    }
+}
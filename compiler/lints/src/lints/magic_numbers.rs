@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{declare_lint, Lint, LintContext, LintPass};
+
+use leo_ast::{Expression, Literal};
+
+declare_lint! {
+    /// Reports numbers other than `0`, `1`, and `2` outside of constants, where they are not given a name.
+    pub MAGIC_NUMBERS, "magic-numbers", Allow, "numbers that are not given a name with a constant"
+}
+
+/// The numbers that are clear enough without a name.
+const ALLOWED_NUMBERS: [&str; 3] = ["0", "1", "2"];
+
+/// The pass of the [`MAGIC_NUMBERS`] lint.
+pub struct MagicNumbers;
+
+impl LintPass for MagicNumbers {
+    fn lints(&self) -> Vec<&'static Lint> {
+        vec![&MAGIC_NUMBERS]
+    }
+
+    fn check_expression(&self, context: &LintContext, expression: &Expression) {
+        let Expression::Literal(literal) = expression else {
+            return;
+        };
+        let (value, span) = match literal {
+            Literal::Field(value, span, _)
+            | Literal::Integer(_, value, span, _)
+            | Literal::Scalar(value, span, _)
+            | Literal::Unsuffixed(value, span, _) => (value, span),
+            _ => return,
+        };
+        if !context.in_constant() && !ALLOWED_NUMBERS.contains(&value.as_str()) {
+            context.report(&MAGIC_NUMBERS, format!("The number `{literal}` is not given a name."), *span);
+        }
+    }
+}
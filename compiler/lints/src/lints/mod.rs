@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The built-in lints.
+
+mod deep_nesting;
+pub use deep_nesting::*;
+
+mod large_functions;
+pub use large_functions::*;
+
+mod magic_numbers;
+pub use magic_numbers::*;
+
+use crate::LintPass;
+
+/// Returns the passes of the built-in lints, with their default settings.
+pub fn builtin() -> Vec<Box<dyn LintPass>> {
+    vec![Box::<DeepNesting>::default(), Box::<LargeFunctions>::default(), Box::new(MagicNumbers)]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{declare_lint, Lint, LintContext, LintLevels, LintPass, Linter};
+
+    use leo_ast::Statement;
+    use leo_compiler::Compiler;
+    use leo_errors::emitter::Handler;
+    use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+    /// Compiles the program with the given linter, returning the error compilation stopped with, if any, and the
+    /// errors and warnings emitted.
+    fn lint(program: &str, linter: Linter) -> (Result<(), String>, String, String) {
+        let (handler, buffer) = Handler::new_with_buf();
+        create_session_if_not_set_then(|_| {
+            let mut compiler =
+                Compiler::new("test".into(), "aleo".into(), &handler, "".into(), "/tmp".into(), None).with_pass(linter);
+            let compiled = compiler
+                .parse_program_from_string(program, FileName::Custom("test".into()))
+                .and_then(|_| compiler.compiler_stages())
+                .map(|_| ())
+                .map_err(|error| error.to_string());
+            (compiled, buffer.extract_errs().to_string(), buffer.extract_warnings().to_string())
+        })
+    }
+
+    fn linter(levels: &[&str]) -> Linter {
+        Linter::new(LintLevels::parse(levels).unwrap())
+    }
+
+    fn program(body: &str) -> String {
+        format!(
+            "program test.aleo {{ const LIMIT: u64 = 10u64; transition main(a: u64) -> u64 {{ {body} return a; }} }}"
+        )
+    }
+
+    #[test]
+    fn test_magic_numbers() {
+        let source = program("let b: u64 = a * 2u64 + LIMIT; let c: u64 = b + 42u64; a = c;");
+        let (compiled, _, warnings) = lint(&source, linter(&[]));
+        assert!(compiled.is_ok() && warnings.is_empty());
+
+        let (compiled, _, warnings) = lint(&source, linter(&["magic-numbers=warn"]));
+        assert!(compiled.is_ok());
+        assert!(warnings.contains("The number `42u64` is not given a name."));
+        assert!(!warnings.contains("`10u64`") && !warnings.contains("`2u64`"));
+    }
+
+    #[test]
+    fn test_deep_nesting() {
+        let nested = (0..5).fold("a = a + 1u64;".to_string(), |body, _| format!("if a > 1u64 {{ {body} }}"));
+        let source = program(&nested);
+        let (compiled, _, warnings) = lint(&source, linter(&[]));
+        assert!(compiled.is_ok());
+        assert_eq!(warnings.matches("This block is nested 5 levels deep, more than the 4 allowed.").count(), 1);
+
+        let (compiled, errors, warnings) = lint(&source, linter(&["deep-nesting=deny"]));
+        assert!(compiled.is_err() && warnings.is_empty());
+        assert!(errors.contains("This block is nested 5 levels deep") && errors.contains("whose level is `deny`"));
+
+        let (compiled, errors, warnings) = lint(&program(""), linter(&["deep-nesting=deny"]));
+        assert!(compiled.is_ok() && errors.is_empty() && warnings.is_empty());
+    }
+
+    #[test]
+    fn test_large_functions() {
+        let (_, _, warnings) = lint(&program(&"a = a + 1u64;".repeat(99)), linter(&[]));
+        assert!(warnings.is_empty());
+
+        let (_, _, warnings) = lint(&program(&"a = a + 1u64;".repeat(100)), linter(&[]));
+        assert!(warnings.contains("`main` contains 101 statements, more than the 100 allowed."));
+    }
+
+    declare_lint! {
+        /// Reports assertions, for the sake of the test.
+        NO_ASSERTIONS, "no-assertions", Deny, "assertions"
+    }
+
+    struct NoAssertions;
+
+    impl LintPass for NoAssertions {
+        fn lints(&self) -> Vec<&'static Lint> {
+            vec![&NO_ASSERTIONS]
+        }
+
+        fn check_statement(&self, context: &LintContext, statement: &Statement) {
+            if let Statement::Assert(assert) = statement {
+                context.report(&NO_ASSERTIONS, "Assertions are not allowed.", assert.span);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lint_levels() {
+        let source = program("assert(a > 1u64);");
+        let (compiled, errors, _) = lint(&source, linter(&[]).with_lint_pass(NoAssertions));
+        assert!(compiled.is_err() && errors.contains("Assertions are not allowed."));
+
+        let levels = ["no-assertions=deny", "no-assertions = allow"];
+        let (compiled, errors, warnings) = lint(&source, linter(&levels).with_lint_pass(NoAssertions));
+        assert!(compiled.is_ok() && errors.is_empty() && warnings.is_empty());
+
+        // Levels of lints that are not registered are rejected, as are levels that do not exist.
+        let (compiled, _, _) = lint(&source, linter(&["no-assertions=allow"]));
+        assert!(compiled.unwrap_err().contains("There is no lint named `no-assertions`."));
+        assert!(LintLevels::parse(&["deep-nesting=forbid"]).is_err());
+        assert!(LintLevels::parse(&["deep-nesting"]).is_err());
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+#![doc = include_str!("../README.md")]
+
+use leo_compiler::Sandbox;
+use leo_errors::emitter::{DiagnosticRecord, Severity};
+
+use napi_derive::napi;
+use std::{collections::HashMap, time::Duration};
+
+/// The options of a call. Omitted options take the defaults of `leo_compiler::Sandbox`.
+#[napi(object)]
+#[derive(Default)]
+pub struct Options {
+    /// The sources of the programs that can be imported, keyed by program name, e.g. `token` for `import token.leo;`.
+    pub imports: Option<HashMap<String, String>>,
+    /// The size of each source, in bytes.
+    pub max_source_size: Option<u32>,
+    /// The time a call may take, in milliseconds.
+    pub time_limit_ms: Option<u32>,
+    /// The number of AST nodes a call may create.
+    pub max_nodes: Option<u32>,
+}
+
+impl From<Options> for Sandbox {
+    fn from(options: Options) -> Self {
+        let mut sandbox = Sandbox::default();
+        sandbox.imports.extend(options.imports.unwrap_or_default());
+        if let Some(max_source_size) = options.max_source_size {
+            sandbox.max_source_size = max_source_size as usize;
+        }
+        if let Some(time_limit_ms) = options.time_limit_ms {
+            sandbox.time_limit = Duration::from_millis(time_limit_ms.into());
+        }
+        if let Some(max_nodes) = options.max_nodes {
+            sandbox.max_nodes = max_nodes as usize;
+        }
+        sandbox
+    }
+}
+
+/// An error or a warning reported by the compiler.
+#[napi(object)]
+pub struct Diagnostic {
+    /// `error` or `warning`.
+    pub severity: String,
+    /// The code of the diagnostic, e.g. `ETYC0372003`, if it has one.
+    pub code: Option<String>,
+    /// The text of the diagnostic.
+    pub message: String,
+    /// Where the diagnostic points, as `file:line:column`, if it points anywhere.
+    pub location: Option<String>,
+}
+
+impl From<DiagnosticRecord> for Diagnostic {
+    fn from(record: DiagnosticRecord) -> Self {
+        let severity = match record.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        Self { severity: severity.to_string(), code: record.code, message: record.message, location: record.location }
+    }
+}
+
+/// Converts the diagnostics of a call.
+fn diagnostics(records: Vec<DiagnosticRecord>) -> Vec<Diagnostic> {
+    records.into_iter().map(Diagnostic::from).collect()
+}
+
+/// The outcome of `parse`.
+#[napi(object)]
+pub struct ParseResult {
+    /// The AST of the program, or `undefined` if it has syntax errors.
+    pub ast: Option<serde_json::Value>,
+    /// The errors and warnings reported while parsing the program.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// The outcome of `check`.
+#[napi(object)]
+pub struct CheckResult {
+    /// The errors and warnings reported while checking the program.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// The outcome of `format`.
+#[napi(object)]
+pub struct FormatResult {
+    /// The formatted program, or `undefined` if it has errors or comments.
+    pub source: Option<String>,
+    /// The errors reported while formatting the program.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Parses the program `programName` from its `source`, and returns its AST.
+#[napi]
+pub fn parse(program_name: String, source: String, options: Option<Options>) -> ParseResult {
+    let output = Sandbox::from(options.unwrap_or_default()).parse(&program_name, &source);
+    // The AST was serialized by the compiler, so it is valid JSON.
+    let ast = output.ast.and_then(|ast| serde_json::from_str(&ast).ok());
+    ParseResult { ast, diagnostics: diagnostics(output.diagnostics) }
+}
+
+/// Checks the program `programName` from its `source`, like `leo check`, and returns its errors and warnings.
+#[napi]
+pub fn check(program_name: String, source: String, options: Option<Options>) -> CheckResult {
+    let output = Sandbox::from(options.unwrap_or_default()).check(&program_name, &source);
+    CheckResult { diagnostics: diagnostics(output.diagnostics) }
+}
+
+/// Formats the program `programName` from its `source`. Programs with comments are not formatted, since the
+/// formatter prints the program from its AST, which does not keep them.
+#[napi]
+pub fn format(program_name: String, source: String, options: Option<Options>) -> FormatResult {
+    let output = Sandbox::from(options.unwrap_or_default()).format(&program_name, &source);
+    FormatResult { source: output.source, diagnostics: diagnostics(output.diagnostics) }
+}
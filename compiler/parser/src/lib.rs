@@ -25,8 +25,8 @@
 
 pub(crate) mod tokenizer;
 use leo_span::span::BytePos;
-pub use tokenizer::KEYWORD_TOKENS;
 pub(crate) use tokenizer::*;
+pub use tokenizer::{classify_tokens, TokenClass, KEYWORD_TOKENS};
 
 pub mod parser;
 pub use parser::*;
@@ -42,6 +42,25 @@ pub fn parse_ast(handler: &Handler, node_builder: &NodeBuilder, source: &str, st
     Ok(Ast::new(parser::parse(handler, node_builder, source, start_pos)?))
 }
 
+/// Creates a new AST from a given file path and source code text, resolving its imports through `import_paths`.
+pub fn parse_ast_with_import_paths(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+    import_paths: &ImportPaths,
+) -> Result<Ast> {
+    Ok(Ast::new(parser::parse_with_import_paths(handler, node_builder, source, start_pos, import_paths)?))
+}
+
+/// Returns `true` if `name` is a valid identifier, and not a keyword.
+pub fn is_identifier(name: &str) -> bool {
+    match tokenize(name, BytePos(0)).as_deref() {
+        Ok([SpannedToken { token: Token::Identifier(symbol), .. }]) => symbol.to_string() == name,
+        _ => false,
+    }
+}
+
 /// Parses program inputs from the input file path
 pub fn parse_program_inputs(
     handler: &Handler,
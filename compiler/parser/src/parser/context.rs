@@ -14,12 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use super::{limits::parser_limits, Imports, Macro, ParserLimits};
 use crate::{tokenizer::*, Token};
 
 use leo_ast::*;
 use leo_errors::{emitter::Handler, ParserError, ParserWarning, Result};
-use leo_span::{Span, Symbol};
+use leo_span::{sym, Span, Symbol};
 
+use indexmap::IndexMap;
 use std::{fmt::Display, mem};
 
 /// Stores a program in tokenized format plus additional context.
@@ -39,8 +41,20 @@ pub(crate) struct ParserContext<'a> {
     pub(crate) prev_token: SpannedToken,
     /// true if parsing an expression for if and loop statements -- means struct inits are not legal
     pub(crate) disallow_struct_construction: bool,
-    /// true if parsing an identifier inside an input file.
-    pub(crate) allow_identifier_underscores: bool,
+    /// The struct whose method is being parsed, if any, which `Self` refers to.
+    pub(crate) self_type: Option<Identifier>,
+    /// The macros declared so far, which can be invoked in the rest of the file.
+    pub(crate) macros: IndexMap<Symbol, Macro>,
+    /// The number of macro expansions being parsed, each nested in the previous one.
+    pub(crate) macro_depth: usize,
+    /// The number of macro invocations expanded so far, used to rename the variables declared in each expansion.
+    pub(crate) macro_expansions: usize,
+    /// The number of expressions, statements, and types being parsed, each nested in the previous one.
+    pub(crate) nesting_depth: usize,
+    /// The limits on the file being parsed.
+    pub(crate) limits: ParserLimits,
+    /// The state of import resolution, shared with the parsers of the imported programs.
+    pub(crate) imports: &'a Imports<'a>,
 }
 
 /// Dummy span used to appease borrow checker.
@@ -48,7 +62,12 @@ const DUMMY_EOF: SpannedToken = SpannedToken { token: Token::Eof, span: Span::du
 
 impl<'a> ParserContext<'a> {
     /// Returns a new [`ParserContext`] type given a vector of tokens.
-    pub fn new(handler: &'a Handler, node_builder: &'a NodeBuilder, mut tokens: Vec<SpannedToken>) -> Self {
+    pub fn new(
+        handler: &'a Handler,
+        node_builder: &'a NodeBuilder,
+        mut tokens: Vec<SpannedToken>,
+        imports: &'a Imports<'a>,
+    ) -> Self {
         // Strip out comments.
         tokens.retain(|x| !matches!(x.token, Token::CommentLine(_) | Token::CommentBlock(_)));
         // For performance we reverse so that we get cheap `.pop()`s.
@@ -59,7 +78,13 @@ impl<'a> ParserContext<'a> {
             handler,
             node_builder,
             disallow_struct_construction: false,
-            allow_identifier_underscores: false,
+            self_type: None,
+            macros: IndexMap::new(),
+            macro_depth: 0,
+            macro_expansions: 0,
+            nesting_depth: 0,
+            limits: parser_limits(),
+            imports,
             prev_token: token.clone(),
             token,
             tokens,
@@ -85,6 +110,15 @@ impl<'a> ParserContext<'a> {
         self.prev_token = mem::replace(&mut self.token, next_token);
     }
 
+    /// Inserts `tokens` before the current token, making the first of them the current token.
+    pub(super) fn prepend_tokens(&mut self, tokens: Vec<SpannedToken>) {
+        let current = mem::replace(&mut self.token, SpannedToken::dummy());
+        self.tokens.push(current);
+        self.tokens.extend(tokens.into_iter().rev());
+        // Note that this unwrap is safe since the current token was just pushed.
+        self.token = self.tokens.pop().unwrap();
+    }
+
     /// Checks whether the current token is `tok`.
     pub(super) fn check(&self, tok: &Token) -> bool {
         &self.token.token == tok
@@ -95,6 +129,16 @@ impl<'a> ParserContext<'a> {
         matches!(&self.token.token, Token::Integer(_))
     }
 
+    /// Checks whether the current tokens are the record member `_nonce`, which the lexer splits into an
+    /// underscore and the identifier `nonce`. The two must not be separated, e.g. by whitespace.
+    pub(super) fn check_nonce(&self) -> bool {
+        self.check(&Token::Underscore)
+            && self.look_ahead(1, |next| {
+                let is_nonce = matches!(&next.token, Token::Identifier(name) if *name == sym::nonce);
+                is_nonce && next.span.lo == self.token.span.hi
+            })
+    }
+
     /// Returns `true` if the next token is equal to the given token.
     /// Advances the parser to the next token.
     pub(super) fn eat(&mut self, token: &Token) -> bool {
@@ -152,6 +196,17 @@ impl<'a> ParserContext<'a> {
             .ok_or_else(|| ParserError::unexpected_str(&self.token.token, "identifier", self.token.span).into())
     }
 
+    /// Resolves `Self` to the struct whose method is being parsed, keeping the span of `Self`.
+    /// Outside of a method, `Self` is left as is and reported during type checking.
+    pub(super) fn resolve_self_type(&self, identifier: Identifier) -> Identifier {
+        match self.self_type {
+            Some(struct_name) if identifier.name == sym::SelfUpper => {
+                Identifier { name: struct_name.name, ..identifier }
+            }
+            _ => identifier,
+        }
+    }
+
     ///
     /// Removes the next token if it is a [`Token::Integer(_)`] and returns it, or [None] if
     /// the next token is not a [`Token::Integer(_)`] or if the next token does not exist.
@@ -181,6 +236,18 @@ impl<'a> ParserContext<'a> {
         Err(ParserError::unexpected(&self.token.token, expected, self.token.span).into())
     }
 
+    /// Runs `parse` on an expression, statement, or type nested in the ones being parsed.
+    /// Every nested expression, statement, and type is parsed through here, so this bounds the recursion of the parser.
+    pub(super) fn parse_nested<T>(&mut self, parse: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        if self.nesting_depth == self.limits.max_expression_depth {
+            return Err(ParserError::too_deeply_nested(self.limits.max_expression_depth, self.token.span).into());
+        }
+        self.nesting_depth += 1;
+        let result = parse(self);
+        self.nesting_depth -= 1;
+        result
+    }
+
     /// Eats the expected `token`, or errors.
     pub(super) fn expect(&mut self, token: &Token) -> Result<Span> {
         if self.eat(token) { Ok(self.prev_token.span) } else { self.unexpected(token) }
@@ -20,7 +20,7 @@ use leo_errors::{ParserError, Result};
 use leo_span::{sym, Symbol};
 use snarkvm::console::{account::Address, network::Testnet3};
 
-const INT_TYPES: &[Token] = &[
+pub(super) const INT_TYPES: &[Token] = &[
     Token::I8,
     Token::I16,
     Token::I32,
@@ -36,6 +36,9 @@ const INT_TYPES: &[Token] = &[
     Token::Scalar,
 ];
 
+/// The number of characters in an `aleo1...` address literal.
+const ADDRESS_LITERAL_LENGTH: usize = 63;
+
 impl ParserContext<'_> {
     /// Returns an [`Expression`] AST node if the next token is an expression.
     /// Includes struct init expressions.
@@ -47,7 +50,7 @@ impl ParserContext<'_> {
         self.disallow_struct_construction = false;
 
         // Parse expression.
-        let result = self.parse_conditional_expression();
+        let result = self.parse_nested(Self::parse_conditional_expression);
 
         // Restore prior parser state.
         self.disallow_struct_construction = prior_fuzzy_state;
@@ -283,6 +286,12 @@ impl ParserContext<'_> {
                     // Construct a negative field literal.
                     inner = Expression::Literal(Literal::Field(format!("-{string}"), op_span + span, id));
                 }
+                Expression::Literal(Literal::Unsuffixed(string, span, id)) => {
+                    // Remove the negation from the operations.
+                    let (_, op_span) = ops.pop().unwrap();
+                    // Construct a negative unsuffixed literal.
+                    inner = Expression::Literal(Literal::Unsuffixed(format!("-{string}"), op_span + span, id));
+                }
                 Expression::Literal(Literal::Group(group_literal)) => {
                     // Remove the negation from the operations.
                     let (_, op_span) = ops.pop().unwrap();
@@ -357,6 +366,33 @@ impl ParserContext<'_> {
                 span,
                 id: self.node_builder.next_id(),
             })))
+        } else if let (0, Some(CoreFunction::OptionIsSome | CoreFunction::OptionIsNone))
+        | (1, Some(CoreFunction::OptionUnwrapOr)) =
+            (args.len(), CoreFunction::from_symbols(sym::Option, method.name))
+        {
+            // Found an instance of `<option>.is_some`, `<option>.is_none`, or `<option>.unwrap_or`.
+            Ok(Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+                ty: Type::Identifier(Identifier::new(sym::Option, self.node_builder.next_id())),
+                name: method,
+                arguments: {
+                    let mut arguments = vec![receiver];
+                    arguments.extend(args);
+                    arguments
+                },
+                span,
+                id: self.node_builder.next_id(),
+            })))
+        } else if let (0, Some(CoreFunction::ResultIsOk | CoreFunction::ResultIsErr)) =
+            (args.len(), CoreFunction::from_symbols(sym::Result, method.name))
+        {
+            // Found an instance of `<result>.is_ok` or `<result>.is_err`.
+            Ok(Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+                ty: Type::Identifier(Identifier::new(sym::Result, self.node_builder.next_id())),
+                name: method,
+                arguments: vec![receiver],
+                span,
+                id: self.node_builder.next_id(),
+            })))
         } else {
             // Attempt to parse the method call as a mapping operation.
             match (args.len(), CoreFunction::from_symbols(sym::Mapping, method.name)) {
@@ -378,15 +414,41 @@ impl ParserContext<'_> {
                         id: self.node_builder.next_id(),
                     })))
                 }
-                _ => {
+                _ if Self::is_builtin_method(method.name) => {
                     // Either an invalid unary/binary operator, or more arguments given.
                     self.emit_err(ParserError::invalid_method_call(receiver, method, args.len(), span));
                     Ok(Expression::Err(ErrExpression { span, id: self.node_builder.next_id() }))
                 }
+                _ => {
+                    // Otherwise, this is a call to a struct method, which is resolved during type checking.
+                    let function = Expression::Access(AccessExpression::Member(MemberAccess {
+                        span: receiver.span() + method.span,
+                        inner: Box::new(receiver),
+                        name: method,
+                        id: self.node_builder.next_id(),
+                    }));
+                    Ok(Expression::Call(CallExpression {
+                        function: Box::new(function),
+                        arguments: args,
+                        external: None,
+                        span,
+                        id: self.node_builder.next_id(),
+                    }))
+                }
             }
         }
     }
 
+    /// Returns `true` if `name` is a built-in operation that can be invoked with method syntax, e.g., `add` in `a.add(b)`.
+    pub(super) fn is_builtin_method(name: Symbol) -> bool {
+        UnaryOperation::from_symbol(name).is_some()
+            || BinaryOperation::from_symbol(name).is_some()
+            || CoreFunction::from_symbols(sym::signature, name).is_some()
+            || CoreFunction::from_symbols(sym::Mapping, name).is_some()
+            || CoreFunction::from_symbols(sym::Option, name).is_some()
+            || CoreFunction::from_symbols(sym::Result, name).is_some()
+    }
+
     /// Returns an [`Expression`] AST node if the next tokens represent a
     /// static access expression.
     fn parse_associated_access_expression(&mut self, module_name: Expression) -> Result<Expression> {
@@ -465,6 +527,15 @@ impl ParserContext<'_> {
                         arguments,
                         id: self.node_builder.next_id(),
                     });
+                } else if self.check_nonce() {
+                    // Eat a record `_nonce` member access.
+                    let name = self.expect_nonce()?;
+                    expr = Expression::Access(AccessExpression::Member(MemberAccess {
+                        span: expr.span() + name.span(),
+                        inner: Box::new(expr),
+                        name,
+                        id: self.node_builder.next_id(),
+                    }))
                 } else {
                     // Parse identifier name.
                     let name = self.expect_identifier()?;
@@ -511,6 +582,16 @@ impl ParserContext<'_> {
                     id: self.node_builder.next_id(),
                 });
             }
+            // Eat a `?` that propagates the error of a result, e.g. `foo(x)?`.
+            while self.check_try_operator() {
+                let span = self.expect(&Token::Question)?;
+                expr = Expression::Unary(UnaryExpression {
+                    span: expr.span() + span,
+                    op: UnaryOperation::Try,
+                    receiver: Box::new(expr),
+                    id: self.node_builder.next_id(),
+                });
+            }
             // Check if next token is a dot to see if we are calling recursive method.
             if !(self.check(&Token::Dot) || self.check(&Token::LeftSquare)) {
                 break;
@@ -519,9 +600,26 @@ impl ParserContext<'_> {
         Ok(expr)
     }
 
+    /// Returns `true` if the next token is a `?` that propagates the error of a result, rather than the `?` of a ternary expression.
+    /// The two are distinguished by the token that follows, since a ternary expression continues with an expression.
+    fn check_try_operator(&self) -> bool {
+        self.check(&Token::Question)
+            && self.look_ahead(1, |next| {
+                matches!(
+                    next.token,
+                    Token::Semicolon
+                        | Token::Comma
+                        | Token::Dot
+                        | Token::RightParen
+                        | Token::RightSquare
+                        | Token::RightCurly
+                )
+            })
+    }
+
     /// Returns an [`Expression`] AST node if the next tokens represent a
     /// tuple initialization expression or an affine group literal.
-    fn parse_tuple_expression(&mut self) -> Result<Expression> {
+    pub(super) fn parse_tuple_expression(&mut self) -> Result<Expression> {
         if let Some(gt) = self.eat_group_partial().transpose()? {
             return Ok(Expression::Literal(Literal::Group(Box::new(GroupLiteral::Tuple(gt)))));
         }
@@ -612,20 +710,35 @@ impl ParserContext<'_> {
             return Some(Err(e));
         }
 
+        // Hexadecimal, octal, and binary numbers must be integers.
+        for coordinate in [&gt.x, &gt.y] {
+            if let GroupCoordinate::Number(value, span) = coordinate {
+                if Literal::has_radix_prefix(value) {
+                    return Some(Err(ParserError::radix_literal_must_be_integer(value, "group", *span).into()));
+                }
+            }
+        }
+
         Some(Ok(gt))
     }
 
+    /// Returns an [`Identifier`] AST node for the record member `_nonce`, which is reserved by the VM.
+    /// The caller must have checked the next tokens with `check_nonce`.
+    fn expect_nonce(&mut self) -> Result<Identifier> {
+        let start = self.expect(&Token::Underscore)?;
+        let nonce = self.expect_identifier()?;
+        Ok(Identifier {
+            name: sym::_nonce,
+            span: start + nonce.span,
+            id: self.node_builder.next_id(),
+        })
+    }
+
     fn parse_struct_member(&mut self) -> Result<StructVariableInitializer> {
-        let identifier = if self.allow_identifier_underscores && self.eat(&Token::Underscore) {
-            // Allow `_nonce` for struct records.
-            let identifier_without_underscore = self.expect_identifier()?;
-            Identifier::new(
-                Symbol::intern(&format!("_{}", identifier_without_underscore.name)),
-                self.node_builder.next_id(),
-            )
-        } else {
-            self.expect_identifier()?
-        };
+        // Allow `_nonce` for struct records.
+        // Its use outside of input files is rejected by the type checker.
+        let identifier =
+            if self.check_nonce() { self.expect_nonce()? } else { self.expect_identifier()? };
 
         let (expression, span) = if self.eat(&Token::Colon) {
             // Parse individual struct variable declarations.
@@ -662,6 +775,14 @@ impl ParserContext<'_> {
     ///
     /// Returns an expression error if the token cannot be matched.
     fn parse_primary_expression(&mut self) -> Result<Expression> {
+        if self.peek_is_include_constants() {
+            return self.parse_include_constants();
+        }
+        // A macro invoked as an expression expands to a parenthesized expression.
+        if self.peek_is_macro_invocation() {
+            return self.parse_macro_invocation(Delimiter::Parenthesis, Self::parse_tuple_expression);
+        }
+
         if let Token::LeftParen = self.token.token {
             return self.parse_tuple_expression();
         } else if let Token::LeftSquare = self.token.token {
@@ -676,7 +797,14 @@ impl ParserContext<'_> {
                 let suffix_span = self.token.span;
                 let full_span = span + suffix_span;
                 let assert_no_whitespace = |x| assert_no_whitespace(span, suffix_span, &value, x);
-                match self.eat_any(INT_TYPES).then_some(&self.prev_token.token) {
+                let suffix = self.eat_any(INT_TYPES).then_some(&self.prev_token.token);
+                // Hexadecimal, octal, and binary numbers must be integers.
+                if let Some(suffix @ (Token::Field | Token::Group | Token::Scalar)) = suffix {
+                    if Literal::has_radix_prefix(&value) {
+                        return Err(ParserError::radix_literal_must_be_integer(&value, suffix, full_span).into());
+                    }
+                }
+                match suffix {
                     // Literal followed by `field`, e.g., `42field`.
                     Some(Token::Field) => {
                         assert_no_whitespace("field")?;
@@ -702,13 +830,22 @@ impl ParserContext<'_> {
                         let int_ty = Self::token_to_int_type(suffix).expect("unknown int type token");
                         Expression::Literal(Literal::Integer(int_ty, value, full_span, self.node_builder.next_id()))
                     }
-                    None => return Err(ParserError::implicit_values_not_allowed(value, span).into()),
+                    // Literal without a suffix, e.g., `42`, whose type is inferred during type checking.
+                    None => Expression::Literal(Literal::Unsuffixed(value, span, self.node_builder.next_id())),
                 }
             }
             Token::True => Expression::Literal(Literal::Boolean(true, span, self.node_builder.next_id())),
             Token::False => Expression::Literal(Literal::Boolean(false, span, self.node_builder.next_id())),
             Token::AddressLit(address_string) => {
-                if address_string.parse::<Address<Testnet3>>().is_err() {
+                if address_string.len() != ADDRESS_LITERAL_LENGTH {
+                    self.emit_err(ParserError::invalid_address_lit_length(
+                        &address_string,
+                        address_string.len(),
+                        ADDRESS_LITERAL_LENGTH,
+                        span,
+                    ));
+                } else if address_string.parse::<Address<Testnet3>>().is_err() {
+                    // The address is not a valid bech32m encoding, e.g. its checksum is incorrect.
                     self.emit_err(ParserError::invalid_address_lit(&address_string, span));
                 }
                 Expression::Literal(Literal::Address(address_string, span, self.node_builder.next_id()))
@@ -721,7 +858,7 @@ impl ParserContext<'_> {
                 if !self.disallow_struct_construction && self.check(&Token::LeftCurly) {
                     // Parse struct and records inits as struct expressions.
                     // Enforce struct or record type later at type checking.
-                    self.parse_struct_init_expression(ident)?
+                    self.parse_struct_init_expression(self.resolve_self_type(ident))?
                 } else {
                     Expression::Identifier(ident)
                 }
@@ -15,27 +15,220 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use crate::parse_ast;
 use leo_errors::{CompilerError, ParserError, Result};
 use leo_span::{source_map::FileName, symbol::with_session_globals};
 
-use std::fs;
+use indexmap::IndexSet;
+
+use std::{
+    cell::RefCell,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The files that serve as the entry point of a package directory, in order of preference.
+const ENTRY_FILE_NAMES: [&str; 2] = ["lib.leo", "main.leo"];
+
+/// Looks up `file_name` in `directory`, comparing names exactly rather than deferring to the file system.
+/// Returns the name of a file that differs only in case if there is no exact match.
+/// Entries whose names are not valid UTF-8 can never match, since import names are ASCII.
+fn find_import_file(directory: &Path, file_name: &str) -> std::result::Result<PathBuf, Option<String>> {
+    let entries = fs::read_dir(directory).map_err(|_| None)?;
+    let mut case_mismatch = None;
+    for entry in entries.flatten() {
+        match entry.file_name().to_str() {
+            Some(name) if name == file_name => return Ok(entry.path()),
+            Some(name) if name.eq_ignore_ascii_case(file_name) => case_mismatch = Some(name.to_string()),
+            _ => {}
+        }
+    }
+    Err(case_mismatch)
+}
+
+/// Looks up the file of the program `name`, nested in `directories`, in the imports directory `imports`.
+/// A package directory `name/` with an entry file takes precedence over `name.leo`.
+fn find_import(mut imports: PathBuf, directories: &[Identifier], name: Identifier, span: Span) -> Result<PathBuf> {
+    for directory in directories {
+        imports.push(directory.name.to_string());
+        // Throw an error if an intermediate directory doesn't exist.
+        if !imports.is_dir() {
+            return Err(CompilerError::import_directory_not_found(imports.display(), directory.span).into());
+        }
+    }
+    let import_file_name = format!("{}.leo", name.name);
+
+    let package_directory = imports.join(name.name.to_string());
+    let entry_file = ENTRY_FILE_NAMES.iter().find_map(|entry| find_import_file(&package_directory, entry).ok());
+
+    // Throw an error if the import file doesn't exist.
+    // The file name must match exactly, even on case-insensitive file systems.
+    let lookup = match entry_file {
+        Some(path) => Ok(path),
+        None => find_import_file(&imports, &import_file_name),
+    };
+    match lookup {
+        Ok(path) => Ok(path),
+        Err(Some(actual)) => Err(CompilerError::import_case_mismatch(import_file_name, actual, span).into()),
+        Err(None) => Err(CompilerError::import_not_found(imports.join(import_file_name).display(), span).into()),
+    }
+}
+
+/// Where imported programs are looked up, besides the `imports/` directory of the current directory.
+#[derive(Clone, Debug, Default)]
+pub struct ImportPaths {
+    /// The files of programs that are imported from their local path, keyed by program name,
+    /// e.g. the other members of a workspace. These take precedence over any imports directory.
+    pub programs: IndexMap<String, PathBuf>,
+    /// The directories searched, in order, for programs that are not in the `imports/` directory.
+    pub directories: Vec<PathBuf>,
+    /// The sources of programs held in memory, keyed by program name, e.g. `token` or `tokens/token` for a nested
+    /// import. These take precedence over any file.
+    pub sources: IndexMap<String, String>,
+    /// Whether imports are only resolved from `sources`, without reading any file or the current directory.
+    pub sandboxed: bool,
+}
+
+/// The state of import resolution, shared by the parsers of a program and of the programs it imports.
+pub(crate) struct Imports<'a> {
+    /// Where imported programs are looked up.
+    pub(crate) paths: &'a ImportPaths,
+    /// The `imports/` directory of the current directory, looked up on the first import read from a file.
+    imports_directory: RefCell<Option<PathBuf>>,
+    /// The names of the imports currently being parsed, outermost first. Used to detect cyclic imports.
+    stack: RefCell<Vec<Symbol>>,
+}
+
+impl<'a> Imports<'a> {
+    /// Returns the state of a program whose imports are resolved through `paths`, before any import is parsed.
+    pub(crate) fn new(paths: &'a ImportPaths) -> Self {
+        Self { paths, imports_directory: RefCell::new(None), stack: RefCell::new(Vec::new()) }
+    }
+
+    /// Returns the `imports/` directory of the current directory.
+    fn imports_directory(&self, span: Span) -> Result<PathBuf> {
+        if let Some(directory) = self.imports_directory.borrow().as_ref() {
+            return Ok(directory.clone());
+        }
+        let directory =
+            std::env::current_dir().map_err(|err| CompilerError::cannot_open_cwd(err, span))?.join("imports");
+        *self.imports_directory.borrow_mut() = Some(directory.clone());
+        Ok(directory)
+    }
+
+    /// Returns the file of the program `name`, nested in `directories`, found through the import paths.
+    /// Programs imported from their local path take precedence over the imports directories.
+    /// Otherwise, the `imports/` directory is searched first, then the additional import directories in order.
+    /// If the program is found in none of them, the error for the `imports/` directory is reported.
+    fn locate(&self, directories: &[Identifier], name: Identifier, span: Span) -> Result<PathBuf> {
+        if directories.is_empty() {
+            if let Some(path) = self.paths.programs.get(&name.name.to_string()) {
+                return Ok(path.clone());
+            }
+        }
+        let mut lookups = std::iter::once(self.imports_directory(span)?)
+            .chain(self.paths.directories.iter().cloned())
+            .map(|directory| find_import(directory, directories, name, span));
+        // Note that there is always a lookup for the `imports/` directory.
+        match lookups.next().unwrap() {
+            Ok(path) => Ok(path),
+            Err(error) => lookups.find_map(|lookup| lookup.ok()).ok_or(error),
+        }
+    }
+}
+
+/// Returns the names of the programs that can be imported through the given import paths, or from the `imports/`
+/// directory of the current directory unless the import paths are sandboxed. The programs are found by their files,
+/// without being parsed.
+pub fn importable_programs(import_paths: &ImportPaths) -> Vec<String> {
+    let mut programs = import_paths.sources.keys().cloned().collect::<IndexSet<_>>();
+    if import_paths.sandboxed {
+        return programs.into_iter().collect();
+    }
+    programs.extend(import_paths.programs.keys().cloned());
+    let imports_directory = std::env::current_dir().map(|directory| directory.join("imports"));
+    for directory in imports_directory.into_iter().chain(import_paths.directories.iter().cloned()) {
+        let Ok(entries) = fs::read_dir(directory) else { continue };
+        let mut paths = entries.flatten().map(|entry| entry.path()).collect::<Vec<_>>();
+        paths.sort();
+        for path in paths {
+            // A program is either a `name.leo` file or a package directory `name/` with an entry file.
+            let is_program = match path.is_dir() {
+                true => ENTRY_FILE_NAMES.iter().any(|entry| path.join(entry).is_file()),
+                false => path.extension().map_or(false, |extension| extension == "leo"),
+            };
+            match path.file_stem().and_then(|name| name.to_str()) {
+                Some(name) if is_program && crate::is_identifier(name) => programs.insert(name.to_string()),
+                _ => continue,
+            };
+        }
+    }
+    programs.into_iter().collect()
+}
+
+/// Removes the imports of imported programs that are already imported elsewhere, e.g. `token.leo` imported by both
+/// the program and its import `bank.leo`, so that the items of each program are only declared once.
+/// The program's own imports are always kept, since they are the imports declared in the generated program.
+fn deduplicate_imports(imports: &mut IndexMap<Symbol, (Program, Span)>) {
+    fn prune(imports: &mut IndexMap<Symbol, (Program, Span)>, seen: &mut IndexSet<Symbol>) {
+        imports.values_mut().for_each(|(import, _)| import.imports.retain(|name, _| seen.insert(*name)));
+        imports.values_mut().for_each(|(import, _)| prune(&mut import.imports, seen));
+    }
+    let mut seen = imports.keys().copied().collect();
+    prune(imports, &mut seen);
+}
+
+/// The methods defined in the body of a struct, keyed by their lowered names.
+type Methods = Vec<(Symbol, Function)>;
+
+/// A parsed import statement.
+pub(super) enum Import {
+    /// A program imported with `import foo.leo;`.
+    Program(Symbol, (Program, Span)),
+    /// A module of the standard library imported with `import std.math.*;`, whose items are added to the program.
+    Std(Symbol, Program),
+}
+
+/// Adds the items of the imported modules of the standard library to the program, before its own items.
+fn add_std_modules(
+    program_scope: &mut ProgramScope,
+    imports: &mut IndexMap<Symbol, (Program, Span)>,
+    modules: Vec<Program>,
+) {
+    for module in modules.into_iter().rev() {
+        imports.extend(module.imports);
+        for module_scope in module.program_scopes.into_values() {
+            program_scope.consts.splice(0..0, module_scope.consts);
+            program_scope.structs.splice(0..0, module_scope.structs);
+            program_scope.mappings.splice(0..0, module_scope.mappings);
+            program_scope.functions.splice(0..0, module_scope.functions);
+        }
+    }
+}
 
 impl ParserContext<'_> {
     /// Returns a [`Program`] AST if all tokens can be consumed and represent a valid Leo program.
     pub fn parse_program(&mut self) -> Result<Program> {
+        let mut metadata = Vec::new();
         let mut imports = IndexMap::new();
         let mut program_scopes = IndexMap::new();
+        let mut std_modules = IndexMap::new();
 
         // TODO: Remove restrictions on multiple program scopes
         let mut parsed_program_scope = false;
 
         while self.has_next() {
             match &self.token.token {
-                Token::Import => {
-                    let (id, import) = self.parse_import()?;
-                    imports.insert(id, import);
-                }
+                // Metadata must precede the program scope.
+                Token::At if !parsed_program_scope => metadata.push(self.parse_metadata()?),
+                Token::Import => match self.parse_import()? {
+                    Import::Program(id, import) => {
+                        imports.insert(id, import);
+                    }
+                    Import::Std(module, program) => {
+                        // A module imported more than once is only added once.
+                        std_modules.entry(module).or_insert(program);
+                    }
+                },
                 Token::Program => {
                     match parsed_program_scope {
                         // Only one program scope is allowed per file.
@@ -56,7 +249,17 @@ impl ParserContext<'_> {
             return Err(ParserError::missing_program_scope(self.token.span).into());
         }
 
-        Ok(Program { imports, program_scopes })
+        // Note that the program scope exists, since it is required above.
+        if let Some(program_scope) = program_scopes.values_mut().next() {
+            add_std_modules(program_scope, &mut imports, std_modules.into_values().collect());
+        }
+
+        // The imports of imported programs are deduplicated once the whole tree of imports is parsed.
+        if self.imports.stack.borrow().is_empty() {
+            deduplicate_imports(&mut imports);
+        }
+
+        Ok(Program { metadata, imports, program_scopes })
     }
 
     fn unexpected_item(token: &SpannedToken, expected: &[Token]) -> ParserError {
@@ -67,51 +270,124 @@ impl ParserContext<'_> {
         )
     }
 
+    /// Parses a metadata annotation `@key("value")` at the top of a file.
+    fn parse_metadata(&mut self) -> Result<Metadata> {
+        // Parse `@key("value")`, where the key may be `program`.
+        let annotation = self.parse_annotation()?;
+        match annotation.value {
+            Some(value) => Ok(Metadata { key: annotation.identifier, value, span: annotation.span, id: annotation.id }),
+            None => self.unexpected("metadata value"),
+        }
+    }
+
     // TODO: remove import resolution from parser.
     /// Parses an import statement `import foo.leo;`.
-    pub(super) fn parse_import(&mut self) -> Result<(Symbol, (Program, Span))> {
+    /// Imports may be nested in directories, e.g. `import tokens.foo.leo;` imports `imports/tokens/foo.leo`.
+    /// If `imports/tokens/foo/` is a package directory, its entry file `lib.leo` or `main.leo` is imported instead.
+    /// A module of the standard library is imported with `import std.math.*;`.
+    pub(super) fn parse_import(&mut self) -> Result<Import> {
         // Parse `import`.
         let start = self.expect(&Token::Import)?;
 
-        // Parse `foo`.
-        let import_name = self.expect_identifier()?;
+        // Parse `foo`, along with any directories it is nested in.
+        let mut import_name = self.expect_identifier()?;
+        let mut directories = Vec::new();
 
-        // Parse `.leo`.
-        self.expect(&Token::Dot)?;
-        if !self.eat(&Token::Leo) {
-            // Throw error for non-leo files.
-            return Err(ParserError::leo_imports_only(self.token.span).into());
+        // Parse `.leo`, or `.*` for a module of the standard library.
+        let mut is_std_module = false;
+        loop {
+            self.expect(&Token::Dot)?;
+            if self.eat(&Token::Leo) {
+                break;
+            }
+            if self.eat(&Token::Mul) {
+                is_std_module = true;
+                break;
+            }
+            match self.eat_identifier() {
+                Some(name) => directories.push(std::mem::replace(&mut import_name, name)),
+                // Throw error for non-leo files.
+                None => return Err(ParserError::leo_imports_only(self.token.span).into()),
+            }
         }
 
         let end = self.expect(&Token::Semicolon)?;
+        let span = start + end;
 
-        // Tokenize and parse import file.
-        // Todo: move this to a different module.
-        let mut import_file_path =
-            std::env::current_dir().map_err(|err| CompilerError::cannot_open_cwd(err, self.token.span))?;
-        import_file_path.push("imports");
-        import_file_path.push(format!("{}.leo", import_name.name));
+        let source_name =
+            directories.iter().chain([&import_name]).map(|name| name.name.to_string()).collect::<Vec<_>>().join("/");
+        if is_std_module && !matches!(directories.as_slice(), [directory] if directory.name == sym::std) {
+            return Err(ParserError::glob_import_outside_std(source_name.replace('/', "."), span).into());
+        }
+        // The import stack holds `std/math` for a module of the standard library, so that it is told from a program.
+        let stack_name = match is_std_module {
+            true => Symbol::intern(&source_name),
+            false => import_name.name,
+        };
 
-        // Throw an error if the import file doesn't exist.
-        if !import_file_path.exists() {
-            return Err(CompilerError::import_not_found(import_file_path.display(), self.prev_token.span).into());
+        // Throw an error if the import is already being parsed.
+        let cycle = {
+            let stack = self.imports.stack.borrow();
+            stack.iter().position(|name| *name == stack_name).map(|index| {
+                stack[index..].iter().chain([&stack_name]).map(|name| format!("`{name}.leo`")).collect::<Vec<_>>()
+            })
+        };
+        if let Some(cycle) = cycle {
+            return Err(CompilerError::cyclic_import(cycle.join(" --> "), span).into());
         }
 
-        // Read the import file into string.
-        // Todo: protect against cyclic imports.
-        let program_string =
-            fs::read_to_string(&import_file_path).map_err(|e| CompilerError::file_read_error(&import_file_path, e))?;
+        // Programs held in memory take precedence over files. A sandboxed compilation only imports those.
+        // Modules of the standard library held in memory, e.g. `std/math`, replace the embedded ones.
+        let import_paths = self.imports.paths;
+        let (program_string, name) = match import_paths.sources.get(&source_name) {
+            Some(source) => (source.clone(), FileName::Custom(format!("{source_name}.leo"))),
+            None if is_std_module => match stdlib::module_source(&import_name.name.to_string()) {
+                Some(source) => (source.to_string(), FileName::Custom(format!("{source_name}.leo"))),
+                None => {
+                    let modules = stdlib::std_modules().map(|module| format!("`{module}`")).collect::<Vec<_>>();
+                    return Err(ParserError::unknown_std_module(import_name, modules.join(", "), span).into());
+                }
+            },
+            None if import_paths.sandboxed => {
+                return Err(CompilerError::import_not_in_sandbox(source_name, span).into());
+            }
+            None => {
+                let import_file_path = self.imports.locate(&directories, import_name, span)?;
 
-        // Create import file name.
-        let name: FileName = FileName::Real(import_file_path);
+                // Read the import file into string.
+                let program_string = fs::read_to_string(&import_file_path)
+                    .map_err(|e| CompilerError::import_read_error(import_file_path.display(), e, span))?;
+                (program_string, FileName::Real(import_file_path))
+            }
+        };
 
         // Register the source (`program_string`) in the source map.
-        let prg_sf = with_session_globals(|s| s.source_map.new_source(&program_string, name));
+        let prg_sf = with_session_globals(|s| s.source_map.new_imported_source(&program_string, name, span));
 
         // Use the parser to construct the imported abstract syntax tree (ast).
-        let program_ast = parse_ast(self.handler, self.node_builder, &prg_sf.src, prg_sf.start_pos)?;
+        self.imports.stack.borrow_mut().push(stack_name);
+        let program =
+            super::parse_with_imports(self.handler, self.node_builder, &prg_sf.src, prg_sf.start_pos, self.imports);
+        self.imports.stack.borrow_mut().pop();
+        let program = program?;
+
+        // Check that the name of the imported program scope matches the file name.
+        // Note that parsing enforces that there is exactly one program scope in a file.
+        if let Some(program_scope) = program.program_scopes.values().next() {
+            if program_scope.program_id.name.name != import_name.name {
+                return Err(CompilerError::program_scope_name_does_not_match(
+                    program_scope.program_id.name,
+                    import_name.name,
+                    program_scope.program_id.name.span,
+                )
+                .into());
+            }
+        }
 
-        Ok((import_name.name, (program_ast.into_repr(), start + end)))
+        match is_std_module {
+            true => Ok(Import::Std(import_name.name, program)),
+            false => Ok(Import::Program(import_name.name, (program, span))),
+        }
     }
 
     /// Parsers a program scope `program foo.aleo { ... }`.
@@ -149,18 +425,25 @@ impl ParserContext<'_> {
                     let declaration = self.parse_const_declaration_statement()?;
                     consts.push((Symbol::intern(&declaration.place.to_string()), declaration));
                 }
-                Token::Struct | Token::Record => {
-                    let (id, struct_) = self.parse_struct()?;
-                    structs.push((id, struct_));
-                }
                 Token::Mapping => {
                     let (id, mapping) = self.parse_mapping()?;
                     mappings.push((id, mapping));
                 }
-                Token::At | Token::Function | Token::Transition | Token::Inline => {
-                    let (id, function) = self.parse_function()?;
-                    functions.push((id, function));
+                Token::At | Token::Struct | Token::Record | Token::Function | Token::Transition | Token::Inline => {
+                    // Both structs and functions may be annotated.
+                    let annotations = self.parse_annotations()?;
+                    if matches!(self.token.token, Token::Struct | Token::Record) {
+                        let (id, struct_, methods) = self.parse_struct(annotations)?;
+                        structs.push((id, struct_));
+                        // Methods are lowered to free functions of the program scope.
+                        functions.extend(methods);
+                    } else {
+                        let (id, function) = self.parse_function(None, annotations)?;
+                        functions.push((id, function));
+                    }
                 }
+                // Note that `macro` is not a keyword, so it is only recognized at the start of an item.
+                Token::Identifier(sym::Macro) => self.parse_macro()?,
                 Token::RightCurly => break,
                 _ => {
                     return Err(Self::unexpected_item(&self.token, &[
@@ -183,13 +466,21 @@ impl ParserContext<'_> {
         Ok(ProgramScope { program_id, consts, functions, structs, mappings, span: start + end })
     }
 
-    /// Returns a [`Vec<Member>`] AST node if the next tokens represent a struct member.
-    fn parse_struct_members(&mut self) -> Result<(Vec<Member>, Span)> {
+    /// Returns a [`Vec<Member>`] AST node and the struct's methods if the next tokens represent the body of a struct.
+    fn parse_struct_members(&mut self, struct_name: Identifier) -> Result<(Vec<Member>, Methods, Span)> {
         let mut members = Vec::new();
+        let mut methods = Vec::new();
 
         let (mut semi_colons, mut commas) = (false, false);
 
         while !self.check(&Token::RightCurly) {
+            // Parse a method, e.g., `function magnitude(self) -> u32 { ... }`.
+            if matches!(self.token.token, Token::At | Token::Function | Token::Inline) {
+                let annotations = self.parse_annotations()?;
+                methods.push(self.parse_function(Some(struct_name), annotations)?);
+                continue;
+            }
+
             let variable = self.parse_member_variable_declaration()?;
 
             if self.eat(&Token::Semicolon) {
@@ -210,7 +501,7 @@ impl ParserContext<'_> {
         }
         let span = self.expect(&Token::RightCurly)?;
 
-        Ok((members, span))
+        Ok((members, methods, span))
     }
 
     /// Parses `IDENT: TYPE`.
@@ -231,22 +522,25 @@ impl ParserContext<'_> {
         Ok(Member { mode, identifier, type_, span, id: self.node_builder.next_id() })
     }
 
-    /// Parses a struct or record definition, e.g., `struct Foo { ... }` or `record Foo { ... }`.
-    pub(super) fn parse_struct(&mut self) -> Result<(Symbol, Struct)> {
+    /// Parses a struct or record definition, e.g., `struct Foo { ... }` or `record Foo { ... }`,
+    /// along with the methods defined on it.
+    pub(super) fn parse_struct(&mut self, annotations: Vec<Annotation>) -> Result<(Symbol, Struct, Methods)> {
         let is_record = matches!(&self.token.token, Token::Record);
         let start = self.expect_any(&[Token::Struct, Token::Record])?;
         let struct_name = self.expect_identifier()?;
 
         self.expect(&Token::LeftCurly)?;
-        let (members, end) = self.parse_struct_members()?;
+        let (members, methods, end) = self.parse_struct_members(struct_name)?;
 
-        Ok((struct_name.name, Struct {
+        let struct_ = Struct {
+            annotations,
             identifier: struct_name,
             members,
             is_record,
             span: start + end,
             id: self.node_builder.next_id(),
-        }))
+        };
+        Ok((struct_name.name, struct_, methods))
     }
 
     /// Parses a mapping declaration, e.g. `mapping balances: address => u128`.
@@ -386,38 +680,148 @@ impl ParserContext<'_> {
             Token::Program => {
                 Identifier { name: sym::program, span: self.expect(&Token::Program)?, id: self.node_builder.next_id() }
             }
+            Token::Inline => {
+                Identifier { name: sym::inline, span: self.expect(&Token::Inline)?, id: self.node_builder.next_id() }
+            }
             _ => self.expect_identifier()?,
         };
-        let span = start + identifier.span;
+        let mut span = start + identifier.span;
 
         // TODO: Verify that this check is sound.
         // Check that there is no whitespace in between the `@` symbol and identifier.
-        match identifier.span.hi.0 - start.lo.0 > 1 + identifier.name.to_string().len() as u32 {
-            true => Err(ParserError::space_in_annotation(span).into()),
-            false => Ok(Annotation { identifier, span, id: self.node_builder.next_id() }),
+        if identifier.span.hi.0 - start.lo.0 > 1 + identifier.name.to_string().len() as u32 {
+            return Err(ParserError::space_in_annotation(span).into());
+        }
+
+        // Parse the value of the annotation, if it exists, e.g. `("Use `bar` instead.")` or `(50000)`.
+        let value = match self.eat(&Token::LeftParen) {
+            false => None,
+            true => {
+                let value = match &self.token.token {
+                    Token::StaticString(value) | Token::Integer(value) => value.clone(),
+                    _ => return self.unexpected("string or integer"),
+                };
+                self.bump();
+                span = span + self.expect(&Token::RightParen)?;
+                Some(value)
+            }
+        };
+
+        Ok(Annotation { identifier, value, span, id: self.node_builder.next_id() })
+    }
+
+    /// Returns whether the receiver is mutable, along with its span, if the next tokens represent a method receiver,
+    /// i.e. `self` or `mut self`.
+    fn parse_receiver(&mut self) -> Result<Option<(bool, Span)>> {
+        let is_mutable =
+            self.token.token == Token::Identifier(sym::Mut) && self.look_ahead(1, |t| &t.token) == &Token::SelfLower;
+        if !is_mutable && !self.check(&Token::SelfLower) {
+            return Ok(None);
         }
+        let start = self.token.span;
+        if is_mutable {
+            self.bump();
+        }
+        let end = self.expect(&Token::SelfLower)?;
+        Ok(Some((is_mutable, start + end)))
     }
 
-    /// Returns an [`(Identifier, Function)`] AST node if the next tokens represent a function name
-    /// and function definition.
-    fn parse_function(&mut self) -> Result<(Symbol, Function)> {
+    /// Returns the annotations preceding a struct or function definition, if they exist.
+    fn parse_annotations(&mut self) -> Result<Vec<Annotation>> {
         // TODO: Handle dangling annotations.
-        // Parse annotations, if they exist.
         let mut annotations = Vec::new();
         while self.look_ahead(0, |t| &t.token) == &Token::At {
             annotations.push(self.parse_annotation()?)
         }
+        Ok(annotations)
+    }
+
+    /// Returns an [`(Identifier, Function)`] AST node if the next tokens represent a function name
+    /// and function definition.
+    /// If `owner` is the name of a struct, the function is a method of that struct, which is lowered to
+    /// a free function taking the receiver as its first input.
+    fn parse_function(
+        &mut self,
+        owner: Option<Identifier>,
+        annotations: Vec<Annotation>,
+    ) -> Result<(Symbol, Function)> {
         // Parse `<variant> IDENT`, where `<variant>` is `function`, `transition`, or `inline`.
+        // Note that `@inline function` is equivalent to `inline`.
+        let is_inline = annotations.iter().any(|annotation| annotation.identifier.name == sym::inline);
         let (variant, start) = match self.token.token {
             Token::Inline => (Variant::Inline, self.expect(&Token::Inline)?),
+            Token::Function if is_inline => (Variant::Inline, self.expect(&Token::Function)?),
             Token::Function => (Variant::Standard, self.expect(&Token::Function)?),
             Token::Transition => (Variant::Transition, self.expect(&Token::Transition)?),
             _ => self.unexpected("'function', 'transition', or 'inline'")?,
         };
         let name = self.expect_identifier()?;
+        // Names containing `__` are reserved for the functions that methods are lowered to, e.g., `Point__magnitude`.
+        if name.name.to_string().contains("__") {
+            self.emit_err(ParserError::function_name_contains_double_underscore(name, name.span));
+        }
+
+        // Within a method, `Self` refers to the struct the method is defined on.
+        // It is reset whether or not the function parses, so that it does not apply to the items that follow.
+        self.self_type = owner;
+        let function = self.parse_function_after_name(owner, annotations, variant, start, name);
+        self.self_type = None;
+        function
+    }
 
-        // Parse parameters.
-        let (inputs, ..) = self.parse_paren_comma_list(|p| p.parse_input().map(Some))?;
+    /// Returns an [`(Identifier, Function)`] AST node if the next tokens represent the parameters, outputs, body,
+    /// and `finalize` block of the function `name`, whose variant and start have already been parsed.
+    fn parse_function_after_name(
+        &mut self,
+        owner: Option<Identifier>,
+        annotations: Vec<Annotation>,
+        variant: Variant,
+        start: Span,
+        name: Identifier,
+    ) -> Result<(Symbol, Function)> {
+        // Parse parameters, where the first parameter of a method must be its receiver.
+        let mut receiver = None;
+        let mut has_misplaced_receiver = false;
+        let mut position = 0;
+        let (inputs, _, parameters_span) = self.parse_paren_comma_list(|p| {
+            position += 1;
+            match (p.parse_receiver()?, owner) {
+                (None, _) => p.parse_input().map(Some),
+                (Some((is_mutable, span)), Some(struct_name)) if position == 1 => {
+                    receiver = Some(Receiver { struct_name: struct_name.name, method_name: name.name, is_mutable });
+                    Ok(Some(functions::Input::Internal(FunctionInput {
+                        identifier: Identifier { name: sym::SelfLower, span, id: p.node_builder.next_id() },
+                        mode: Mode::None,
+                        type_: Type::Identifier(Identifier {
+                            name: struct_name.name,
+                            span,
+                            id: p.node_builder.next_id(),
+                        }),
+                        span,
+                        id: p.node_builder.next_id(),
+                    })))
+                }
+                (Some((_, span)), _) => {
+                    has_misplaced_receiver = true;
+                    p.emit_err(ParserError::invalid_method_receiver(span));
+                    Ok(None)
+                }
+            }
+        })?;
+
+        // Check that a method takes a receiver and is not named after a built-in operation.
+        let name = match owner {
+            None => name,
+            Some(struct_name) => {
+                if receiver.is_none() && !has_misplaced_receiver {
+                    self.emit_err(ParserError::missing_method_receiver(parameters_span));
+                }
+                if Self::is_builtin_method(name.name) {
+                    self.emit_err(ParserError::method_name_is_reserved(name, name.span));
+                }
+                Identifier { name: Receiver::lowered_name(struct_name.name, name.name), ..name }
+            }
+        };
 
         // Parse return type.
         let output = match self.eat(&Token::Arrow) {
@@ -472,20 +876,19 @@ impl ParserContext<'_> {
         };
 
         let span = start + block.span;
-        Ok((
-            name.name,
-            Function::new(
-                annotations,
-                variant,
-                name,
-                inputs,
-                output,
-                block,
-                finalize,
-                span,
-                self.node_builder.next_id(),
-            ),
-        ))
+        let mut function = Function::new(
+            annotations,
+            variant,
+            name,
+            inputs,
+            output,
+            block,
+            finalize,
+            span,
+            self.node_builder.next_id(),
+        );
+        function.receiver = receiver;
+        Ok((name.name, function))
     }
 }
 
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{tokenizer::SpannedToken, Token};
+
+use leo_ast::DEFAULT_MAX_EXPRESSION_DEPTH;
+use leo_errors::{ParserError, Result};
+
+use indexmap::IndexSet;
+use std::cell::RefCell;
+
+thread_local! {
+    /// The limits set through [`with_parser_limits`].
+    static PARSER_LIMITS: RefCell<ParserLimits> = RefCell::new(ParserLimits::default());
+}
+
+/// Limits on the size of the files being parsed, which bound the resources spent on untrusted programs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParserLimits {
+    /// The depth to which expressions, statements, and types may be nested in one another.
+    pub max_expression_depth: usize,
+    /// The number of distinct identifiers a file may contain, if limited.
+    pub max_identifiers: Option<usize>,
+}
+
+impl Default for ParserLimits {
+    fn default() -> Self {
+        Self { max_expression_depth: DEFAULT_MAX_EXPRESSION_DEPTH, max_identifiers: None }
+    }
+}
+
+/// Runs `parse` with the given limits, which also apply to the imported programs it parses.
+pub fn with_parser_limits<T>(limits: &ParserLimits, parse: impl FnOnce() -> T) -> T {
+    let previous = PARSER_LIMITS.with(|current| current.replace(limits.clone()));
+    let result = parse();
+    PARSER_LIMITS.with(|current| current.replace(previous));
+    result
+}
+
+/// Returns the limits set through [`with_parser_limits`].
+pub(super) fn parser_limits() -> ParserLimits {
+    PARSER_LIMITS.with(|limits| limits.borrow().clone())
+}
+
+/// Checks that the `tokens` of a file contain no more distinct identifiers than allowed.
+/// The error points at the first identifier beyond the limit.
+pub(super) fn check_identifiers(tokens: &[SpannedToken], limits: &ParserLimits) -> Result<()> {
+    let Some(max_identifiers) = limits.max_identifiers else { return Ok(()) };
+    let mut identifiers = IndexSet::new();
+    for token in tokens {
+        if let Token::Identifier(name) = token.token {
+            if identifiers.insert(name) && identifiers.len() > max_identifiers {
+                return Err(ParserError::too_many_identifiers(max_identifiers, token.span).into());
+            }
+        }
+    }
+    Ok(())
+}
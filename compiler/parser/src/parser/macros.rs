@@ -0,0 +1,367 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Macros, which generate repetitive code such as the rounds of a hash permutation.
+//!
+//! A macro is declared in the program scope with `macro name(a, b) { ... }` and invoked after its declaration
+//! with `name!(x, y)`. Each invocation is replaced by the tokens of the macro's body, in which the parameters
+//! are replaced by the tokens of the arguments. An invocation used as a statement expands to a block, and one
+//! used as an expression expands to a parenthesized expression. Since arguments are substituted rather than
+//! evaluated, an argument used twice in the body is computed twice.
+//!
+//! Macros are hygienic: the variables declared in a macro's body are renamed at each expansion,
+//! so that they neither clash with nor capture the variables at the invocation.
+//!
+//! The built-in macro `include_constants!("table.json")` expands to the constants in a JSON file,
+//! e.g. a precomputed table, whose types are checked against the type of the declaration they initialize.
+
+use super::*;
+
+use super::expression::INT_TYPES;
+use leo_errors::{ParserError, Result};
+use leo_span::{source_map::FileName, sym, symbol::with_session_globals, Symbol};
+
+use indexmap::IndexSet;
+use serde_json::Value;
+use std::{fs, path::PathBuf};
+
+/// The number of macro expansions that may be nested in one another, e.g. by a macro that invokes itself.
+const MACRO_RECURSION_LIMIT: usize = 64;
+
+/// A macro declared with `macro name(a, b) { ... }`.
+pub(crate) struct Macro {
+    /// The name of the macro.
+    name: Identifier,
+    /// The parameters of the macro.
+    params: Vec<Identifier>,
+    /// The tokens between the braces of the macro's body.
+    body: Vec<SpannedToken>,
+    /// The variables declared in the body, which are renamed at each expansion.
+    locals: IndexSet<Symbol>,
+}
+
+impl Macro {
+    /// Returns the tokens of the body with the parameters replaced by `args`,
+    /// and the variables declared in the body renamed for the given `expansion`.
+    fn expand(&self, args: &[Vec<SpannedToken>], expansion: usize) -> Vec<SpannedToken> {
+        let mut tokens = Vec::with_capacity(self.body.len());
+        for (i, spanned) in self.body.iter().enumerate() {
+            let previous = i.checked_sub(1).map(|i| &self.body[i].token);
+            let next = self.body.get(i + 1).map(|spanned| &spanned.token);
+            match &spanned.token {
+                Token::Identifier(name) if !is_member_name(previous, next) => {
+                    if let Some(position) = self.params.iter().position(|param| param.name == *name) {
+                        let arg = &args[position];
+                        // Arguments with operators are parenthesized, so that e.g. `square!(a + b)` squares the sum.
+                        if needs_parentheses(arg) {
+                            tokens.push(SpannedToken { token: Token::LeftParen, span: spanned.span });
+                            tokens.extend(arg.iter().cloned());
+                            tokens.push(SpannedToken { token: Token::RightParen, span: spanned.span });
+                        } else {
+                            tokens.extend(arg.iter().cloned());
+                        }
+                    } else if self.locals.contains(name) {
+                        // The renamed variable contains `$`, so it cannot be written in Leo code.
+                        let renamed = Symbol::intern(&format!("{name}${}${expansion}", self.name));
+                        tokens.push(SpannedToken { token: Token::Identifier(renamed), span: spanned.span });
+                    } else {
+                        tokens.push(spanned.clone());
+                    }
+                }
+                _ => tokens.push(spanned.clone()),
+            }
+        }
+        tokens
+    }
+}
+
+/// Returns `true` if an identifier between the `previous` and `next` tokens names a member,
+/// e.g. `x` in `p.x`, `Point { x: 1u8 }`, or `Mapping::x`, rather than a variable.
+fn is_member_name(previous: Option<&Token>, next: Option<&Token>) -> bool {
+    matches!(previous, Some(Token::Dot | Token::DoubleColon))
+        || (matches!(previous, Some(Token::LeftCurly | Token::Comma)) && matches!(next, Some(Token::Colon)))
+}
+
+/// Returns `true` if the tokens of an argument must be parenthesized to be used as an operand.
+/// This is the case unless they are a single literal or variable, possibly followed by accesses and calls.
+fn needs_parentheses(arg: &[SpannedToken]) -> bool {
+    let mut depth = 0usize;
+    arg.len() > 1
+        && arg.iter().any(|spanned| match &spanned.token {
+            Token::LeftParen | Token::LeftSquare | Token::LeftCurly => {
+                depth += 1;
+                false
+            }
+            Token::RightParen | Token::RightSquare | Token::RightCurly => {
+                depth = depth.saturating_sub(1);
+                false
+            }
+            token => {
+                depth == 0
+                    && !matches!(
+                        token,
+                        Token::Identifier(_)
+                            | Token::Integer(_)
+                            | Token::True
+                            | Token::False
+                            | Token::AddressLit(_)
+                            | Token::SelfLower
+                            | Token::Block
+                            | Token::Dot
+                            | Token::DoubleColon
+                    )
+                    && !crate::type_::TYPE_TOKENS.contains(token)
+            }
+        })
+}
+
+/// Returns the variables declared by `let`, `const`, and `for` in `body`.
+fn declared_variables(body: &[SpannedToken]) -> IndexSet<Symbol> {
+    let mut variables = IndexSet::new();
+    for (i, spanned) in body.iter().enumerate() {
+        if !matches!(spanned.token, Token::Let | Token::Const | Token::For) {
+            continue;
+        }
+        match body.get(i + 1).map(|spanned| &spanned.token) {
+            Some(Token::Identifier(name)) => {
+                variables.insert(*name);
+            }
+            // The variables of a tuple definition, e.g. `let (a, b): (u8, u8) = ...`.
+            Some(Token::LeftParen) => {
+                for spanned in body[i + 2..].iter().take_while(|spanned| spanned.token != Token::RightParen) {
+                    if let Token::Identifier(name) = spanned.token {
+                        variables.insert(name);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    variables
+}
+
+/// Splits the tokens of a list of arguments at the commas outside of any delimiters.
+fn split_arguments(tokens: Vec<SpannedToken>) -> Vec<Vec<SpannedToken>> {
+    let mut args = vec![Vec::new()];
+    let mut depth = 0usize;
+    for spanned in tokens {
+        match spanned.token {
+            Token::LeftParen | Token::LeftSquare | Token::LeftCurly => depth += 1,
+            Token::RightParen | Token::RightSquare | Token::RightCurly => depth = depth.saturating_sub(1),
+            Token::Comma if depth == 0 => {
+                args.push(Vec::new());
+                continue;
+            }
+            _ => {}
+        }
+        // Note that this unwrap is safe since `args` is never empty.
+        args.last_mut().unwrap().push(spanned);
+    }
+    // Allow a trailing comma, and no arguments at all.
+    if args.last().map_or(false, Vec::is_empty) {
+        args.pop();
+    }
+    args
+}
+
+/// Appends the tokens of the constants in `value` to `tokens`, all spanning the invocation at `span`.
+/// Returns the first value that is not a constant as an error.
+fn constant_tokens(value: &Value, span: Span, tokens: &mut Vec<SpannedToken>) -> std::result::Result<(), String> {
+    match value {
+        Value::Array(elements) => {
+            tokens.push(SpannedToken { token: Token::LeftSquare, span });
+            for (i, element) in elements.iter().enumerate() {
+                if i > 0 {
+                    tokens.push(SpannedToken { token: Token::Comma, span });
+                }
+                constant_tokens(element, span, tokens)?;
+            }
+            tokens.push(SpannedToken { token: Token::RightSquare, span });
+        }
+        Value::Bool(true) => tokens.push(SpannedToken { token: Token::True, span }),
+        Value::Bool(false) => tokens.push(SpannedToken { token: Token::False, span }),
+        // Note that numbers which do not fit in 64 bits would have lost precision, so they must be strings.
+        Value::Number(number) if number.is_u64() || number.is_i64() => {
+            tokens.extend(literal_tokens(&number.to_string(), span).ok_or_else(|| value.to_string())?)
+        }
+        Value::String(literal) => tokens.extend(literal_tokens(literal, span).ok_or_else(|| value.to_string())?),
+        _ => return Err(value.to_string()),
+    }
+    Ok(())
+}
+
+/// Returns the tokens of `literal` if it is a single literal, e.g. `-5`, `42u8`, or `1field`, spanning `span`.
+fn literal_tokens(literal: &str, span: Span) -> Option<Vec<SpannedToken>> {
+    if literal.contains(char::is_whitespace) {
+        return None;
+    }
+    let tokens: Vec<_> = crate::tokenize(literal, BytePos(0)).ok()?.into_iter().map(|spanned| spanned.token).collect();
+    let is_literal = match tokens.as_slice() {
+        [Token::Integer(_)] | [Token::Sub, Token::Integer(_)] | [Token::AddressLit(_)] => true,
+        [Token::Integer(_), suffix] | [Token::Sub, Token::Integer(_), suffix] => INT_TYPES.contains(suffix),
+        _ => false,
+    };
+    // A type suffix must directly follow the number, so it is given an empty span at the end of `span`.
+    let suffix_span = Span::new(span.hi, span.hi);
+    is_literal.then(|| {
+        tokens
+            .into_iter()
+            .map(|token| {
+                let span = if INT_TYPES.contains(&token) { suffix_span } else { span };
+                SpannedToken { token, span }
+            })
+            .collect()
+    })
+}
+
+impl ParserContext<'_> {
+    /// Parses a macro declaration `macro name(a, b) { ... }`.
+    /// The macro is only recorded, since each of its invocations is replaced by its body.
+    pub(super) fn parse_macro(&mut self) -> Result<()> {
+        // Note that `parse_macro` is called only if the next token is `macro`, which is not a keyword.
+        self.expect_identifier()?;
+        let name = self.expect_identifier()?;
+        let (params, ..) = self.parse_paren_comma_list(|p| p.expect_identifier().map(Some))?;
+        for (i, param) in params.iter().enumerate() {
+            if params[..i].iter().any(|previous| previous.name == param.name) {
+                return Err(ParserError::duplicate_macro_parameter(param, name, param.span).into());
+            }
+        }
+
+        // Parse the body, which is only checked for balanced delimiters.
+        self.expect(&Token::LeftCurly)?;
+        let body = self.eat_token_trees(&Token::RightCurly)?;
+
+        if self.macros.contains_key(&name.name) {
+            return Err(ParserError::macro_already_declared(name, name.span).into());
+        }
+        let locals = declared_variables(&body);
+        self.macros.insert(name.name, Macro { name, params, body, locals });
+        Ok(())
+    }
+
+    /// Returns `true` if the next tokens are a macro invocation `name!(...)`.
+    pub(super) fn peek_is_macro_invocation(&self) -> bool {
+        matches!(self.token.token, Token::Identifier(_))
+            && self.look_ahead(1, |t| t.token == Token::Not)
+            && self.look_ahead(2, |t| t.token == Token::LeftParen)
+    }
+
+    /// Replaces the macro invocation `name!(...)` at the current token by the macro's body enclosed in `delimiter`,
+    /// and parses the expansion with `parse`.
+    pub(super) fn parse_macro_invocation<T>(
+        &mut self,
+        delimiter: Delimiter,
+        parse: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> Result<T> {
+        let name = self.expect_identifier()?;
+        self.expect(&Token::Not)?;
+        self.expect(&Token::LeftParen)?;
+        let args = split_arguments(self.eat_token_trees(&Token::RightParen)?);
+        let span = name.span + self.prev_token.span;
+
+        let macro_ = match self.macros.get(&name.name) {
+            Some(macro_) => macro_,
+            None => return Err(ParserError::undeclared_macro(name, name.span).into()),
+        };
+        if args.len() != macro_.params.len() {
+            return Err(ParserError::macro_argument_count(name, macro_.params.len(), args.len(), span).into());
+        }
+        if self.macro_depth == MACRO_RECURSION_LIMIT {
+            return Err(ParserError::macro_recursion_limit(name, MACRO_RECURSION_LIMIT, span).into());
+        }
+
+        let (open, close) = delimiter.open_close_pair();
+        let mut tokens = vec![SpannedToken { token: open, span }];
+        tokens.extend(macro_.expand(&args, self.macro_expansions));
+        tokens.push(SpannedToken { token: close, span });
+        self.macro_expansions += 1;
+        self.prepend_tokens(tokens);
+
+        self.macro_depth += 1;
+        let result = parse(self);
+        self.macro_depth -= 1;
+        result
+    }
+
+    /// Returns `true` if the next tokens are an invocation of the built-in macro `include_constants!(...)`.
+    pub(super) fn peek_is_include_constants(&self) -> bool {
+        self.token.token == Token::Identifier(sym::include_constants) && self.peek_is_macro_invocation()
+    }
+
+    /// Replaces the invocation `include_constants!("table.json")` at the current token by the constants in the file,
+    /// and parses them as an expression.
+    /// A relative path is resolved against the directory of the file being parsed, or else the current directory.
+    pub(super) fn parse_include_constants(&mut self) -> Result<Expression> {
+        let start = self.expect_identifier()?.span;
+        self.expect(&Token::Not)?;
+        self.expect(&Token::LeftParen)?;
+        let path = match &self.token.token {
+            Token::StaticString(path) => path.clone(),
+            _ => return self.unexpected("string"),
+        };
+        self.bump();
+        let span = start + self.expect(&Token::RightParen)?;
+        if self.imports.paths.sandboxed {
+            return Err(ParserError::constants_file_in_sandbox(path, span).into());
+        }
+
+        let directory = with_session_globals(|s| match s.source_map.span_to_location(span) {
+            Some(location) => match &location.source_file.name {
+                FileName::Real(file) => file.parent().map(|directory| directory.to_path_buf()),
+                FileName::Custom(_) => None,
+            },
+            None => None,
+        });
+        let file = match directory {
+            Some(directory) => directory.join(&path),
+            None => PathBuf::from(&path),
+        };
+        let contents =
+            fs::read_to_string(&file).map_err(|e| ParserError::constants_file_read_error(file.display(), e, span))?;
+        let value: Value = serde_json::from_str(&contents)
+            .map_err(|e| ParserError::invalid_constants_file(file.display(), e, span))?;
+
+        let mut tokens = vec![SpannedToken { token: Token::LeftParen, span }];
+        constant_tokens(&value, span, &mut tokens)
+            .map_err(|value| ParserError::invalid_constant(file.display(), value, span))?;
+        tokens.push(SpannedToken { token: Token::RightParen, span });
+        self.prepend_tokens(tokens);
+        self.parse_tuple_expression()
+    }
+
+    /// Eats the tokens up to and including the `close` token matching an opening delimiter that was already eaten,
+    /// and returns the tokens before it.
+    fn eat_token_trees(&mut self, close: &Token) -> Result<Vec<SpannedToken>> {
+        let mut tokens = Vec::new();
+        let mut depth = 0usize;
+        while depth > 0 || !self.check(close) {
+            match self.token.token {
+                Token::Eof => return self.unexpected(close),
+                Token::LeftParen | Token::LeftSquare | Token::LeftCurly => depth += 1,
+                Token::RightParen | Token::RightSquare | Token::RightCurly => match depth.checked_sub(1) {
+                    Some(outer) => depth = outer,
+                    None => return self.unexpected(close),
+                },
+                _ => {}
+            }
+            tokens.push(self.token.clone());
+            self.bump();
+        }
+        self.bump();
+        Ok(tokens)
+    }
+}
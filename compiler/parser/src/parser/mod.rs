@@ -34,13 +34,45 @@ pub(super) use context::ParserContext;
 
 mod expression;
 mod file;
+pub use file::{importable_programs, ImportPaths};
+pub(crate) use file::Imports;
 mod input;
+mod limits;
+pub use limits::{with_parser_limits, ParserLimits};
+mod macros;
+pub(super) use macros::Macro;
 mod statement;
+mod stdlib;
+pub use stdlib::std_modules;
 pub(super) mod type_;
 
 /// Creates a new program from a given file path and source code text.
 pub fn parse(handler: &Handler, node_builder: &NodeBuilder, source: &str, start_pos: BytePos) -> Result<Program> {
-    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+    parse_with_import_paths(handler, node_builder, source, start_pos, &ImportPaths::default())
+}
+
+/// Creates a new program from a given file path and source code text, resolving its imports through `import_paths`.
+pub fn parse_with_import_paths(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+    import_paths: &ImportPaths,
+) -> Result<Program> {
+    parse_with_imports(handler, node_builder, source, start_pos, &Imports::new(import_paths))
+}
+
+/// Creates a new program from a given source code text, which is the program or one of the imports of `imports`.
+fn parse_with_imports(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+    imports: &Imports,
+) -> Result<Program> {
+    let tokens = crate::tokenize(source, start_pos)?;
+    limits::check_identifiers(&tokens, &limits::parser_limits())?;
+    let mut tokens = ParserContext::new(handler, node_builder, tokens, imports);
 
     tokens.parse_program()
 }
@@ -52,7 +84,9 @@ pub fn parse_input(
     source: &str,
     start_pos: BytePos,
 ) -> Result<InputAst> {
-    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+    let import_paths = ImportPaths::default();
+    let imports = Imports::new(&import_paths);
+    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?, &imports);
 
     tokens.parse_input_file()
 }
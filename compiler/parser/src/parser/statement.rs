@@ -39,6 +39,11 @@ const ASSIGN_TOKENS: &[Token] = &[
 impl ParserContext<'_> {
     /// Returns a [`Statement`] AST node if the next tokens represent a statement.
     pub(crate) fn parse_statement(&mut self) -> Result<Statement> {
+        self.parse_nested(Self::parse_unnested_statement)
+    }
+
+    /// Returns a [`Statement`] AST node if the next tokens represent a statement, without counting its depth.
+    fn parse_unnested_statement(&mut self) -> Result<Statement> {
         match &self.token.token {
             Token::Return => Ok(Statement::Return(self.parse_return_statement()?)),
             Token::If => Ok(Statement::Conditional(self.parse_conditional_statement()?)),
@@ -47,8 +52,24 @@ impl ParserContext<'_> {
             Token::Let => Ok(Statement::Definition(self.parse_definition_statement()?)),
             Token::Const => Ok(Statement::Const(self.parse_const_declaration_statement()?)),
             Token::LeftCurly => Ok(Statement::Block(self.parse_block()?)),
-            Token::Console => Err(ParserError::console_statements_are_not_yet_supported(self.token.span).into()),
+            Token::Console => Ok(Statement::Console(self.parse_console_statement()?)),
             Token::Finalize => Err(ParserError::finalize_statements_are_deprecated(self.token.span).into()),
+            // Note that `fail` and `abort` are not keywords, so they are only recognized in these forms.
+            Token::Identifier(sym::fail)
+                if self.look_ahead(1, |t| t.token == Token::LeftParen)
+                    && self.look_ahead(2, |t| matches!(t.token, Token::StaticString(_))) =>
+            {
+                Ok(self.parse_fail_statement()?)
+            }
+            Token::Identifier(sym::abort) if self.look_ahead(1, |t| t.token == Token::Semicolon) => {
+                Ok(self.parse_fail_statement()?)
+            }
+            // A macro invoked as a statement expands to a block, which is followed by the invocation's semicolon.
+            Token::Identifier(_) if self.peek_is_macro_invocation() => {
+                let block = self.parse_macro_invocation(Delimiter::Brace, Self::parse_block)?;
+                self.expect(&Token::Semicolon)?;
+                Ok(Statement::Block(block))
+            }
             _ => Ok(self.parse_assign_statement()?),
         }
     }
@@ -86,6 +107,35 @@ impl ParserContext<'_> {
         Ok(Statement::Assert(AssertStatement { variant, span, id: self.node_builder.next_id() }))
     }
 
+    /// Returns a [`AssertStatement`] AST node if the next tokens represent a `fail("reason")` or `abort` statement.
+    fn parse_fail_statement(&mut self) -> Result<Statement> {
+        // Note that `parse_fail_statement` is called only if the next token is `fail` or `abort`.
+        let is_fail = self.token.token == Token::Identifier(sym::fail);
+        let span = self.expect_identifier()?.span;
+        // Parse the reason, e.g. `("insufficient balance")`.
+        let reason = match is_fail {
+            true => {
+                self.expect(&Token::LeftParen)?;
+                let reason = match &self.token.token {
+                    Token::StaticString(reason) => reason.clone(),
+                    _ => return self.unexpected("string"),
+                };
+                self.bump();
+                self.expect(&Token::RightParen)?;
+                Some(reason)
+            }
+            false => None,
+        };
+        // Parse the semicolon token.
+        self.expect(&Token::Semicolon)?;
+
+        Ok(Statement::Assert(AssertStatement {
+            variant: AssertVariant::Fail(reason),
+            span,
+            id: self.node_builder.next_id(),
+        }))
+    }
+
     /// Returns a [`AssignStatement`] AST node if the next tokens represent a assign, otherwise expects an expression statement.
     fn parse_assign_statement(&mut self) -> Result<Statement> {
         let place = self.parse_expression()?;
@@ -266,48 +316,52 @@ impl ParserContext<'_> {
     }
 
     /// Returns a [`ConsoleStatement`] AST node if the next tokens represent a console statement.
-    #[allow(dead_code)]
     fn parse_console_statement(&mut self) -> Result<ConsoleStatement> {
         let keyword = self.expect(&Token::Console)?;
         self.expect(&Token::Dot)?;
-        let identifier = self.expect_identifier()?;
-        let (span, function) = match identifier.name {
-            sym::assert => {
+        let (span, function) = match self.token.token {
+            Token::Assert => {
+                self.bump();
                 self.expect(&Token::LeftParen)?;
                 let expr = self.parse_expression()?;
-                self.expect(&Token::RightParen)?;
-                (keyword + expr.span(), ConsoleFunction::Assert(expr))
+                let end = self.expect(&Token::RightParen)?;
+                (end, ConsoleFunction::Assert(expr))
             }
-            sym::assert_eq => {
+            Token::AssertEq | Token::AssertNeq => {
+                let is_eq = self.check(&Token::AssertEq);
+                self.bump();
                 self.expect(&Token::LeftParen)?;
                 let left = self.parse_expression()?;
                 self.expect(&Token::Comma)?;
                 let right = self.parse_expression()?;
-                self.expect(&Token::RightParen)?;
-                (left.span() + right.span(), ConsoleFunction::AssertEq(left, right))
-            }
-            sym::assert_neq => {
-                self.expect(&Token::LeftParen)?;
-                let left = self.parse_expression()?;
-                self.expect(&Token::Comma)?;
-                let right = self.parse_expression()?;
-                self.expect(&Token::RightParen)?;
-                (left.span() + right.span(), ConsoleFunction::AssertNeq(left, right))
+                let end = self.expect(&Token::RightParen)?;
+                let function = if is_eq {
+                    ConsoleFunction::AssertEq(left, right)
+                } else {
+                    ConsoleFunction::AssertNeq(left, right)
+                };
+                (end, function)
             }
-            symbol => {
-                // Not sure what it is, assume it's `log`.
-                self.emit_err(ParserError::unexpected_ident(
-                    symbol,
-                    &["assert", "assert_eq", "assert_neq"],
-                    identifier.span,
-                ));
-                (
-                    Default::default(),
-                    ConsoleFunction::Assert(Expression::Err(ErrExpression {
-                        span: Default::default(),
-                        id: self.node_builder.next_id(),
-                    })),
-                )
+            _ => {
+                let identifier = self.expect_identifier()?;
+                match identifier.name {
+                    sym::error => {
+                        let (format, arguments, end) = self.parse_formatted_arguments()?;
+                        (end, ConsoleFunction::Error(format, arguments))
+                    }
+                    sym::log => {
+                        let (format, arguments, end) = self.parse_formatted_arguments()?;
+                        (end, ConsoleFunction::Log(format, arguments))
+                    }
+                    symbol => {
+                        return Err(ParserError::unexpected_ident(
+                            symbol,
+                            &["assert", "assert_eq", "assert_neq", "error", "log"],
+                            identifier.span,
+                        )
+                        .into());
+                    }
+                }
             }
         };
         self.expect(&Token::Semicolon)?;
@@ -315,6 +369,24 @@ impl ParserContext<'_> {
         Ok(ConsoleStatement { span: keyword + span, function, id: self.node_builder.next_id() })
     }
 
+    /// Returns the format string, the arguments, and the span of the closing parenthesis
+    /// if the next tokens represent the arguments of a formatted console function, e.g., `("x = {}", x)`.
+    fn parse_formatted_arguments(&mut self) -> Result<(String, Vec<Expression>, Span)> {
+        self.expect(&Token::LeftParen)?;
+        // The first argument must be a format string, e.g., `"x = {}"`.
+        let format = match &self.token.token {
+            Token::StaticString(format) => format.clone(),
+            _ => return self.unexpected("formatted string"),
+        };
+        self.bump();
+        let mut arguments = Vec::new();
+        while self.eat(&Token::Comma) {
+            arguments.push(self.parse_expression()?);
+        }
+        let end = self.expect(&Token::RightParen)?;
+        Ok((format, arguments, end))
+    }
+
     /// Returns a [`ConstDeclaration`] AST node if the next tokens represent a const declaration statement.
     pub(super) fn parse_const_declaration_statement(&mut self) -> Result<ConstDeclaration> {
         self.expect(&Token::Const)?;
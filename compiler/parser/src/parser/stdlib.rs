@@ -0,0 +1,38 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The standard library of Leo, whose modules are imported with `import std.<module>.*;`.
+//! The source of each module is embedded in the compiler, so importing it reads no files.
+//! The items of an imported module are added to the program that imports it, so their names, and the names of the
+//! variables they declare, must not be used by the items of the program.
+
+/// The modules of the standard library, with their sources.
+const MODULES: [(&str, &str); 4] = [
+    ("bits", include_str!("stdlib/bits.leo")),
+    ("hash", include_str!("stdlib/hash.leo")),
+    ("math", include_str!("stdlib/math.leo")),
+    ("merkle", include_str!("stdlib/merkle.leo")),
+];
+
+/// Returns the source of the module `name` of the standard library, if there is one.
+pub(super) fn module_source(name: &str) -> Option<&'static str> {
+    MODULES.iter().find(|(module, _)| *module == name).map(|(_, source)| *source)
+}
+
+/// Returns the names of the modules of the standard library.
+pub fn std_modules() -> impl Iterator<Item = &'static str> {
+    MODULES.iter().map(|(module, _)| *module)
+}
@@ -17,6 +17,7 @@
 use super::*;
 
 use leo_errors::{ParserError, Result};
+use leo_span::sym;
 
 pub(super) const TYPE_TOKENS: &[Token] = &[
     Token::Address,
@@ -78,7 +79,27 @@ impl ParserContext<'_> {
     /// Returns a [`(Type, Span)`] tuple of AST nodes if the next token represents a type.
     /// Also returns the span of the parsed token.
     pub fn parse_type(&mut self) -> Result<(Type, Span)> {
+        self.parse_nested(Self::parse_unnested_type)
+    }
+
+    /// Returns a [`(Type, Span)`] tuple of AST nodes if the next token represents a type, without counting its depth.
+    fn parse_unnested_type(&mut self) -> Result<(Type, Span)> {
         if let Some(ident) = self.eat_identifier() {
+            // Parse an option type, e.g. `Option<u8>`.
+            if ident.name == sym::Option && self.eat(&Token::Lt) {
+                let (element_type, _) = self.parse_type()?;
+                let end = self.expect(&Token::Gt)?;
+                return Ok((Type::Option(OptionType::new(element_type)), ident.span + end));
+            }
+            // Parse a result type, e.g. `Result<u8, u16>`.
+            if ident.name == sym::Result && self.eat(&Token::Lt) {
+                let (ok_type, _) = self.parse_type()?;
+                self.expect(&Token::Comma)?;
+                let (err_type, _) = self.parse_type()?;
+                let end = self.expect(&Token::Gt)?;
+                return Ok((Type::Result(ResultType::new(ok_type, err_type)), ident.span + end));
+            }
+            let ident = self.resolve_self_type(ident);
             Ok((Type::Identifier(ident), ident.span))
         } else if self.token.token == Token::LeftSquare {
             // Parse the left bracket.
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{tokenizer, ParserContext, SpannedToken};
+use crate::{tokenizer, ImportPaths, Imports, ParserContext, SpannedToken};
 
 use leo_ast::{NodeBuilder, NodeID, Statement};
 use leo_errors::{emitter::Handler, LeoError};
@@ -68,7 +68,9 @@ fn with_handler<T>(
 ) -> Result<T, String> {
     let (handler, buf) = Handler::new_with_buf();
     let node_builder = NodeBuilder::default();
-    let mut tokens = ParserContext::new(&handler, &node_builder, tokens);
+    let import_paths = ImportPaths::default();
+    let imports = Imports::new(&import_paths);
+    let mut tokens = ParserContext::new(&handler, &node_builder, tokens, &imports);
     let parsed = handler
         .extend_if_error(logic(&mut tokens))
         .map_err(|_| buf.extract_errs().to_string() + &buf.extract_warnings().to_string())?;
@@ -163,17 +163,36 @@ impl Token {
 
         let mut int = String::new();
 
-        // Note that it is still impossible to have a number that starts with an `_` because eat_integer is only called when the first character is a digit.
-        while let Some(c) = input.next_if(|c| c.is_ascii_digit() || *c == '_') {
-            if c == '0' && matches!(input.peek(), Some('x')) {
-                int.push(c);
-                int.push(input.next().unwrap());
-                return Err(ParserError::lexer_hex_number_provided(int).into());
+        // Integers written in hexadecimal, octal, or binary start with a radix prefix, e.g., `0xFF`, `0o77`, or `0b1010`.
+        let mut radix = 10;
+        if input.next_if_eq(&'0').is_some() {
+            int.push('0');
+            if let Some(prefix) = input.next_if(|c| matches!(c, 'x' | 'o' | 'b')) {
+                int.push(prefix);
+                radix = match prefix {
+                    'x' => 16,
+                    'o' => 8,
+                    _ => 2,
+                };
             }
+        }
 
+        // Note that it is still impossible to have a number that starts with an `_` because eat_integer is only called when the first character is a digit.
+        while let Some(c) = input.next_if(|c| c.is_digit(radix) || *c == '_') {
             int.push(c);
         }
 
+        // A radix prefix must be followed by at least one digit.
+        if radix != 10 && !int[2..].chars().any(|c| c.is_digit(radix)) {
+            return Err(ParserError::lexer_radix_prefix_without_digits(int).into());
+        }
+
+        // A decimal digit that is not a digit of the radix cannot start the suffix, e.g., `0b12u8`.
+        if let Some(digit) = input.next_if(|c| c.is_ascii_digit()) {
+            int.push(digit);
+            return Err(ParserError::lexer_invalid_digit_for_radix(int, digit).into());
+        }
+
         Ok((int.len(), Token::Integer(int)))
     }
 
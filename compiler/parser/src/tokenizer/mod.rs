@@ -61,6 +61,32 @@ pub(crate) fn tokenize_iter(mut input: &str, mut lo: BytePos) -> impl '_ + Itera
     })
 }
 
+/// The lexical class of a token, as returned by [`classify_tokens`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenClass {
+    /// A keyword, e.g. `let`.
+    Keyword,
+    /// A keyword naming a primitive type, e.g. `u32`.
+    Type,
+    /// An identifier.
+    Identifier,
+    /// A number, address, or boolean literal.
+    Literal,
+    /// A string literal.
+    String,
+    /// A line or block comment.
+    Comment,
+    /// An operator or a delimiter, e.g. `+` or `{`.
+    Punctuation,
+}
+
+/// Splits source code into the spans of its tokens, including comments, along with their lexical classes.
+///
+/// The `start_pos` byte position determines where spans will start.
+pub fn classify_tokens(input: &str, start_pos: BytePos) -> Result<Vec<(TokenClass, Span)>> {
+    tokenize_iter(input, start_pos).map(|token| token.map(|token| (token.token.class(), token.span))).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use super::TokenClass;
 use leo_span::{sym, Symbol};
 
 use serde::{Deserialize, Serialize};
@@ -196,6 +197,24 @@ pub const KEYWORD_TOKENS: &[Token] = &[
 ];
 
 impl Token {
+    /// Returns the lexical class of the token.
+    pub fn class(&self) -> TokenClass {
+        use Token::*;
+
+        match self {
+            CommentLine(_) | CommentBlock(_) => TokenClass::Comment,
+            StaticString(_) => TokenClass::String,
+            Identifier(_) => TokenClass::Identifier,
+            Integer(_) | True | False | AddressLit(_) => TokenClass::Literal,
+            Address | Bool | Field | Group | Scalar | Signature | String | I8 | I16 | I32 | I64 | I128 | U8 | U16
+            | U32 | U64 | U128 => TokenClass::Type,
+            Record | As | Assert | AssertEq | AssertNeq | Console | Const | Constant | Else | Finalize | For
+            | Function | If | Import | In | Inline | Let | Mapping | Private | Program | Public | Return
+            | SelfLower | Struct | Then | Transition | Block | Leo => TokenClass::Keyword,
+            _ => TokenClass::Punctuation,
+        }
+    }
+
     /// Returns `true` if the `self` token equals a Leo keyword.
     pub fn is_keyword(&self) -> bool {
         KEYWORD_TOKENS.contains(self)
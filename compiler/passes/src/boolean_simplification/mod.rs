@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Boolean Simplification pass traverses the AST and simplifies boolean expressions, so that they are compiled into
+//! fewer instructions. The pass is run after the Loop Unrolling pass, once constants have been substituted.
+//!
+//! The pass applies the following rewrites, from the innermost expression outwards.
+//! - Double negation: `!!a` becomes `a`.
+//! - Negated operations: `!(a == b)` becomes `a != b`, `!(a < b)` becomes `a >= b`, `!(a && b)` becomes `a.nand(b)`,
+//!   and so on.
+//! - De Morgan's laws: `!a && !b` becomes `a.nor(b)`, and `!a || !b` becomes `a.nand(b)`.
+//! - Constant absorption: `a && true` and `a || false` become `a`, while `a && false` becomes `false` and `a || true`
+//!   becomes `true`.
+//! - Comparisons with constants: `a == true` becomes `a`, and `a == false` becomes `!a`.
+//!
+//! Note that `&&` and `||` do not short-circuit in a circuit: both operands are always evaluated, and the program
+//! fails if the evaluation of either operand fails, e.g. due to an overflow or a failing assertion in a called
+//! function. To preserve these semantics, an operand is only removed by constant absorption if its evaluation cannot
+//! fail and has no side effects.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: bool, b: bool, c: u8) -> bool {
+//!     return !(!a && !b) == true && (c > 0u8 || true);
+//! }
+//! ```
+//!
+//! The boolean simplification pass produces the following code.
+//! ```leo
+//! transition main(a: bool, b: bool, c: u8) -> bool {
+//!     return a || b;
+//! }
+//! ```
+
+mod simplify_expression;
+
+mod simplify_program;
+
+mod simplify_statement;
+
+pub mod simplifier;
+pub use simplifier::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for BooleanSimplifier<'a> {
+    type Input = (Ast, &'a NodeBuilder, &'a TypeTable);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder, tt): Self::Input) -> Self::Output {
+        let mut reconstructor = BooleanSimplifier::new(node_builder, tt);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::*;
+use leo_span::Span;
+
+pub struct BooleanSimplifier<'a> {
+    /// A counter to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+}
+
+impl<'a> BooleanSimplifier<'a> {
+    /// Initializes a new `BooleanSimplifier`.
+    pub fn new(node_builder: &'a NodeBuilder, type_table: &'a TypeTable) -> Self {
+        Self { node_builder, type_table }
+    }
+
+    /// Constructs a boolean literal.
+    pub(crate) fn boolean(&self, value: bool, span: Span) -> Expression {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Boolean);
+        Expression::Literal(Literal::Boolean(value, span, id))
+    }
+
+    /// Constructs the simplest expression equivalent to `!input`.
+    pub(crate) fn negate(&self, input: Expression, span: Span) -> Expression {
+        match input {
+            // `!!a` is `a`.
+            Expression::Unary(UnaryExpression { op: UnaryOperation::Not, receiver, .. }) => *receiver,
+            // `!true` is `false`, and `!false` is `true`.
+            Expression::Literal(Literal::Boolean(value, ..)) => self.boolean(!value, span),
+            // `!(a == b)` is `a != b`, `!(a && b)` is `a.nand(b)`, and so on.
+            Expression::Binary(binary) if negated_operation(binary.op).is_some() => {
+                // Note that the type of the expression does not change, so the ID can be reused.
+                Expression::Binary(BinaryExpression { op: negated_operation(binary.op).unwrap(), span, ..binary })
+            }
+            input => {
+                let id = self.node_builder.next_id();
+                if let Some(type_) = self.type_table.get(&input.id()) {
+                    self.type_table.insert(id, type_);
+                }
+                Expression::Unary(UnaryExpression { receiver: Box::new(input), op: UnaryOperation::Not, span, id })
+            }
+        }
+    }
+}
+
+/// Returns the operation whose result is the negation of the result of `op`, if it exists.
+pub(crate) fn negated_operation(op: BinaryOperation) -> Option<BinaryOperation> {
+    Some(match op {
+        BinaryOperation::And => BinaryOperation::Nand,
+        BinaryOperation::Eq => BinaryOperation::Neq,
+        BinaryOperation::Gte => BinaryOperation::Lt,
+        BinaryOperation::Gt => BinaryOperation::Lte,
+        BinaryOperation::Lte => BinaryOperation::Gt,
+        BinaryOperation::Lt => BinaryOperation::Gte,
+        BinaryOperation::Nand => BinaryOperation::And,
+        BinaryOperation::Neq => BinaryOperation::Eq,
+        BinaryOperation::Nor => BinaryOperation::Or,
+        BinaryOperation::Or => BinaryOperation::Nor,
+        _ => return None,
+    })
+}
+
+/// Returns `true` if the evaluation of the expression cannot fail and has no side effects, so that it can be removed.
+pub(crate) fn is_removable(input: &Expression) -> bool {
+    match input {
+        Expression::Identifier(_) | Expression::Literal(_) => true,
+        Expression::Access(AccessExpression::Member(access)) => is_removable(&access.inner),
+        Expression::Access(AccessExpression::Tuple(access)) => is_removable(&access.tuple),
+        Expression::Unary(unary) => unary.op == UnaryOperation::Not && is_removable(&unary.receiver),
+        Expression::Binary(binary) => {
+            // Arithmetic operations can fail, e.g. due to an overflow or a division by zero.
+            let is_total = matches!(
+                binary.op,
+                BinaryOperation::And
+                    | BinaryOperation::BitwiseAnd
+                    | BinaryOperation::Eq
+                    | BinaryOperation::Gte
+                    | BinaryOperation::Gt
+                    | BinaryOperation::Lte
+                    | BinaryOperation::Lt
+                    | BinaryOperation::Nand
+                    | BinaryOperation::Neq
+                    | BinaryOperation::Nor
+                    | BinaryOperation::Or
+                    | BinaryOperation::BitwiseOr
+                    | BinaryOperation::Xor
+            );
+            is_total && is_removable(&binary.left) && is_removable(&binary.right)
+        }
+        Expression::Ternary(ternary) => {
+            is_removable(&ternary.condition) && is_removable(&ternary.if_true) && is_removable(&ternary.if_false)
+        }
+        _ => false,
+    }
+}
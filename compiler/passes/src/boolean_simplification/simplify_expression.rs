@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{is_removable, BooleanSimplifier};
+
+use leo_ast::*;
+
+impl ExpressionReconstructor for BooleanSimplifier<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let left = self.reconstruct_expression(*input.left).0;
+        let right = self.reconstruct_expression(*input.right).0;
+
+        let literal = |expression: &Expression| match expression {
+            Expression::Literal(Literal::Boolean(value, ..)) => Some(*value),
+            _ => None,
+        };
+
+        match (input.op, literal(&left), literal(&right)) {
+            // `a && true` is `a`, and `a || false` is `a`.
+            (BinaryOperation::And, Some(true), _) | (BinaryOperation::Or, Some(false), _) => return (right, ()),
+            (BinaryOperation::And, _, Some(true)) | (BinaryOperation::Or, _, Some(false)) => return (left, ()),
+            // `a && false` is `false`, and `a || true` is `true`, if `a` can be removed.
+            (BinaryOperation::And, Some(false), _) | (BinaryOperation::Or, Some(true), _) if is_removable(&right) => {
+                return (left, ());
+            }
+            (BinaryOperation::And, _, Some(false)) | (BinaryOperation::Or, _, Some(true)) if is_removable(&left) => {
+                return (right, ());
+            }
+            // `a == true` is `a`, and `a == false` is `!a`.
+            (BinaryOperation::Eq, Some(value), _) | (BinaryOperation::Neq, Some(value), _) => {
+                let is_negated = (input.op == BinaryOperation::Eq) != value;
+                return (if is_negated { self.negate(right, input.span) } else { right }, ());
+            }
+            (BinaryOperation::Eq, _, Some(value)) | (BinaryOperation::Neq, _, Some(value)) => {
+                let is_negated = (input.op == BinaryOperation::Eq) != value;
+                return (if is_negated { self.negate(left, input.span) } else { left }, ());
+            }
+            _ => {}
+        }
+
+        match (input.op, left, right) {
+            // `!a && !b` is `a.nor(b)`, and `!a || !b` is `a.nand(b)`.
+            (
+                op @ (BinaryOperation::And | BinaryOperation::Or),
+                Expression::Unary(UnaryExpression { op: UnaryOperation::Not, receiver: left, .. }),
+                Expression::Unary(UnaryExpression { op: UnaryOperation::Not, receiver: right, .. }),
+            ) => (
+                Expression::Binary(BinaryExpression {
+                    left,
+                    right,
+                    op: if op == BinaryOperation::And { BinaryOperation::Nor } else { BinaryOperation::Nand },
+                    span: input.span,
+                    id: input.id,
+                }),
+                (),
+            ),
+            (op, left, right) => (
+                Expression::Binary(BinaryExpression {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    op,
+                    span: input.span,
+                    id: input.id,
+                }),
+                (),
+            ),
+        }
+    }
+
+    fn reconstruct_unary(&mut self, input: UnaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let receiver = self.reconstruct_expression(*input.receiver).0;
+
+        match input.op {
+            UnaryOperation::Not => (self.negate(receiver, input.span), ()),
+            op => (
+                Expression::Unary(UnaryExpression { receiver: Box::new(receiver), op, span: input.span, id: input.id }),
+                (),
+            ),
+        }
+    }
+}
@@ -35,13 +35,13 @@ pub struct CodeGenerator<'a> {
     /// Reference to the current function.
     pub(crate) current_function: Option<&'a Function>,
     /// Mapping of variables to registers.
-    pub(crate) variable_mapping: IndexMap<&'a Symbol, String>,
+    pub(crate) variable_mapping: IndexMap<Symbol, String>,
     /// Mapping of composite names to a tuple containing metadata associated with the name.
     /// The first element of the tuple indicate whether the composite is a record or not.
     /// The second element of the tuple is a string modifier used for code generation.
-    pub(crate) composite_mapping: IndexMap<&'a Symbol, (bool, String)>,
+    pub(crate) composite_mapping: IndexMap<Symbol, (bool, &'static str)>,
     /// Mapping of global identifiers to their associated names.
-    pub(crate) global_mapping: IndexMap<&'a Symbol, String>,
+    pub(crate) global_mapping: IndexMap<Symbol, String>,
     /// Are we traversing a transition function?
     pub(crate) is_transition_function: bool,
     /// Are we traversing a finalize block?
@@ -28,8 +28,10 @@ use leo_ast::{
     ErrExpression,
     Expression,
     Identifier,
+    IntegerType,
     Literal,
     MemberAccess,
+    Node,
     StructExpression,
     TernaryExpression,
     TupleExpression,
@@ -39,6 +41,7 @@ use leo_ast::{
     UnitExpression,
 };
 use leo_span::sym;
+use snarkvm::console::network::{Network, Testnet3};
 use std::borrow::Borrow;
 
 use std::fmt::Write as _;
@@ -78,13 +81,51 @@ impl<'a> CodeGenerator<'a> {
     }
 
     fn visit_value(&mut self, input: &'a Literal) -> (String, String) {
-        (format!("{input}"), String::new())
+        match input {
+            // Aleo instructions only support decimal numbers, so hexadecimal, octal, and binary integers are converted.
+            // Note that this unwrap is safe since type checking guarantees that the integer is valid.
+            Literal::Integer(type_, string, _, _) if Literal::has_radix_prefix(string) => {
+                (format!("{}{type_}", Literal::integer_to_decimal(string).unwrap()), String::new())
+            }
+            _ => (format!("{input}"), String::new()),
+        }
     }
 
     fn visit_binary(&mut self, input: &'a BinaryExpression) -> (String, String) {
         let (left_operand, left_instructions) = self.visit_expression(&input.left);
         let (right_operand, right_instructions) = self.visit_expression(&input.right);
 
+        // Concatenate the instructions.
+        let mut instructions = left_instructions;
+        instructions.push_str(&right_instructions);
+
+        let destination_register = format!("r{}", self.next_register);
+
+        // Lower multiplications by small constants into instructions that introduce fewer constraints.
+        if let Some((is_left_operand, factor)) = Self::constant_factor(input.op, &left_operand, &right_operand) {
+            let operand = if is_left_operand { &left_operand } else { &right_operand };
+            let reduced_instruction = match (input.op, factor) {
+                // `x * 1` is `x`, so no instruction is needed if `x` is stored in a register.
+                (_, 1) if operand.starts_with('r') && operand[1..].chars().all(|c| c.is_ascii_digit()) => {
+                    return (operand.clone(), instructions);
+                }
+                // `x * 2` overflows if and only if `x + x` overflows.
+                (BinaryOperation::Mul, 2) => {
+                    Some(format!("    add {operand} {operand} into {destination_register};\n"))
+                }
+                // Wrapping multiplication by `2^k` is a wrapping left shift by `k`.
+                (BinaryOperation::MulWrapped, factor) if factor.is_power_of_two() => {
+                    Some(format!("    shl.w {operand} {}u8 into {destination_register};\n", factor.trailing_zeros()))
+                }
+                _ => None,
+            };
+            if let Some(reduced_instruction) = reduced_instruction {
+                self.next_register += 1;
+                instructions.push_str(&reduced_instruction);
+                return (destination_register, instructions);
+            }
+        }
+
         let opcode = match input.op {
             BinaryOperation::Add => String::from("add"),
             BinaryOperation::AddWrapped => String::from("add.w"),
@@ -118,20 +159,42 @@ impl<'a> CodeGenerator<'a> {
             BinaryOperation::Xor => String::from("xor"),
         };
 
-        let destination_register = format!("r{}", self.next_register);
         let binary_instruction = format!("    {opcode} {left_operand} {right_operand} into {destination_register};\n",);
 
         // Increment the register counter.
         self.next_register += 1;
 
-        // Concatenate the instructions.
-        let mut instructions = left_instructions;
-        instructions.push_str(&right_instructions);
         instructions.push_str(&binary_instruction);
 
         (destination_register, instructions)
     }
 
+    /// Returns the constant factor of a multiplication by a non-negative integer literal, along with whether the other
+    /// factor is the left operand.
+    fn constant_factor(op: BinaryOperation, left_operand: &str, right_operand: &str) -> Option<(bool, u128)> {
+        if !matches!(op, BinaryOperation::Mul | BinaryOperation::MulWrapped) {
+            return None;
+        }
+        // Literals are emitted with their type suffix, e.g. `2u8`.
+        let factor = |operand: &str| {
+            let suffix = operand.find(|c: char| !c.is_ascii_digit())?;
+            let is_integer_type = matches!(
+                &operand[suffix..],
+                "u8" | "u16" | "u32" | "u64" | "u128" | "i8" | "i16" | "i32" | "i64" | "i128"
+            );
+            if is_integer_type {
+                operand[..suffix].parse::<u128>().ok()
+            } else {
+                None
+            }
+        };
+        match (factor(left_operand), factor(right_operand)) {
+            (_, Some(factor)) => Some((true, factor)),
+            (Some(factor), None) => Some((false, factor)),
+            (None, None) => None,
+        }
+    }
+
     fn visit_cast(&mut self, input: &'a CastExpression) -> (String, String) {
         let (expression_operand, mut instructions) = self.visit_expression(&input.expression);
 
@@ -194,8 +257,12 @@ impl<'a> CodeGenerator<'a> {
             UnaryOperation::Negate => ("neg", ""),
             UnaryOperation::Square => ("square", ""),
             UnaryOperation::SquareRoot => ("sqrt", ""),
+            UnaryOperation::ToBitsLe => {
+                return self.visit_to_bits_le(input, expression_operand, expression_instructions)
+            }
             UnaryOperation::ToXCoordinate => ("cast", " as group.x"),
             UnaryOperation::ToYCoordinate => ("cast", " as group.y"),
+            UnaryOperation::Try => unreachable!("The `?` operator is lowered before code generation."),
         };
 
         let destination_register = format!("r{}", self.next_register);
@@ -211,6 +278,120 @@ impl<'a> CodeGenerator<'a> {
         (destination_register, instructions)
     }
 
+    /// Decomposes an integer into an array of its bits, least significant bit first.
+    /// Each bit is extracted by masking the integer with the weight of the bit.
+    fn visit_to_bits_le(
+        &mut self,
+        input: &'a UnaryExpression,
+        operand: String,
+        mut instructions: String,
+    ) -> (String, String) {
+        let integer_type = match self.type_table.get(&input.receiver.id()) {
+            Some(Type::Integer(integer_type)) => integer_type,
+            _ => unreachable!("Type checking guarantees that only integers are decomposed into bits."),
+        };
+
+        let bits = Self::bit_weights(integer_type)
+            .into_iter()
+            .map(|weight| {
+                let masked_register = format!("r{}", self.next_register);
+                let bit_register = format!("r{}", self.next_register + 1);
+                self.next_register += 2;
+                writeln!(instructions, "    and {operand} {weight} into {masked_register};")
+                    .expect("failed to write to string");
+                writeln!(instructions, "    is.neq {masked_register} 0{integer_type} into {bit_register};")
+                    .expect("failed to write to string");
+                bit_register
+            })
+            .collect::<Vec<_>>();
+
+        let destination_register = format!("r{}", self.next_register);
+        self.next_register += 1;
+        writeln!(
+            instructions,
+            "    cast {} into {destination_register} as [boolean; {}u32];",
+            bits.join(" "),
+            bits.len()
+        )
+        .expect("failed to write to string");
+
+        (destination_register, instructions)
+    }
+
+    /// Recomposes an integer from an array of its bits, least significant bit first.
+    /// Each bit selects either its weight or zero, and the selected weights are combined with `or`.
+    fn recompose_bits_le(&mut self, bits: &str, integer_type: IntegerType) -> (String, String) {
+        let mut instructions = String::new();
+        let mut accumulator: Option<String> = None;
+        for (index, weight) in Self::bit_weights(integer_type).into_iter().enumerate() {
+            let term_register = format!("r{}", self.next_register);
+            self.next_register += 1;
+            writeln!(instructions, "    ternary {bits}[{index}u32] {weight} 0{integer_type} into {term_register};")
+                .expect("failed to write to string");
+            accumulator = Some(match accumulator {
+                None => term_register,
+                Some(accumulator) => {
+                    let sum_register = format!("r{}", self.next_register);
+                    self.next_register += 1;
+                    writeln!(instructions, "    or {accumulator} {term_register} into {sum_register};")
+                        .expect("failed to write to string");
+                    sum_register
+                }
+            });
+        }
+        (accumulator.expect("Integers have at least one bit."), instructions)
+    }
+
+    /// Verifies that `leaf` is at position `index` of a Merkle tree with the given `root`, by hashing the leaf with
+    /// each sibling on the `path`, from the bottom of the tree up. The bits of the index select the order of each pair.
+    fn verify_merkle_path(&mut self, variant: &str, arguments: &[String], depth: usize) -> (String, String) {
+        let (root, leaf, path, index) = (&arguments[0], &arguments[1], &arguments[2], &arguments[3]);
+        let mut get_destination_register = || {
+            let destination_register = format!("r{}", self.next_register);
+            self.next_register += 1;
+            destination_register
+        };
+
+        let mut instructions = Vec::new();
+        let mut node = leaf.clone();
+        for level in 0..depth {
+            let sibling = format!("{path}[{level}u32]");
+            let [masked, is_right, left, right, pair, parent] = std::array::from_fn(|_| get_destination_register());
+            instructions.push(format!("and {index} {}u32 into {masked}", 1u64 << level));
+            instructions.push(format!("is.neq {masked} 0u32 into {is_right}"));
+            instructions.push(format!("ternary {is_right} {sibling} {node} into {left}"));
+            instructions.push(format!("ternary {is_right} {node} {sibling} into {right}"));
+            instructions.push(format!("cast {left} {right} into {pair} as [field; 2u32]"));
+            instructions.push(format!("hash.{variant} {pair} into {parent} as field"));
+            node = parent;
+        }
+
+        let mut destination_register = get_destination_register();
+        instructions.push(format!("is.eq {node} {root} into {destination_register}"));
+
+        // The index must also be in range, so that each leaf has exactly one valid index.
+        if depth < 32 {
+            let [in_range, verified] = std::array::from_fn(|_| get_destination_register());
+            instructions.push(format!("lt {index} {}u32 into {in_range}", 1u64 << depth));
+            instructions.push(format!("and {destination_register} {in_range} into {verified}"));
+            destination_register = verified;
+        }
+
+        (destination_register, instructions.iter().map(|instruction| format!("    {instruction};\n")).collect())
+    }
+
+    /// Returns the weight of each bit of an integer type as a literal, least significant bit first.
+    /// The most significant bit of a signed integer has a negative weight, e.g. `-128i8`.
+    fn bit_weights(integer_type: IntegerType) -> Vec<String> {
+        let width = integer_type.size_in_bits();
+        (0..width)
+            .map(|index| match integer_type.is_signed() && index + 1 == width {
+                true => format!("-{}{integer_type}", 1u128 << index),
+                false => format!("{}{integer_type}", 1u128 << index),
+            })
+            .collect()
+    }
+
     fn visit_ternary(&mut self, input: &'a TernaryExpression) -> (String, String) {
         let (condition_operand, condition_instructions) = self.visit_expression(&input.condition);
         let (if_true_operand, if_true_instructions) = self.visit_expression(&input.if_true);
@@ -287,6 +468,10 @@ impl<'a> CodeGenerator<'a> {
     fn visit_array_access(&mut self, input: &'a ArrayAccess) -> (String, String) {
         let (array_operand, _) = self.visit_expression(&input.array);
         let index_operand = match input.index.as_ref() {
+            // Note that this unwrap is safe since type checking guarantees that the index is valid.
+            Expression::Literal(Literal::Integer(_, string, _, _)) if Literal::has_radix_prefix(string) => {
+                format!("{}u32", Literal::integer_to_decimal(string).unwrap())
+            }
             Expression::Literal(Literal::Integer(_, string, _, _)) => format!("{}u32", string),
             _ => unreachable!("Array indices must be integer literals"),
         };
@@ -296,6 +481,14 @@ impl<'a> CodeGenerator<'a> {
     }
 
     fn visit_member_access(&mut self, input: &'a MemberAccess) -> (String, String) {
+        // `network.id` is not an operand in the current version of snarkVM, so it is lowered to the id of the target
+        // network, unless `network` names a variable.
+        if matches!(&*input.inner, Expression::Identifier(Identifier { name: sym::network, .. }))
+            && !self.variable_mapping.contains_key(&sym::network)
+        {
+            return (format!("{}u16", Testnet3::ID), String::new());
+        }
+
         let (inner_struct, _) = self.visit_expression(&input.inner);
         let member_access = format!("{inner_struct}.{}", input.name);
 
@@ -498,6 +691,42 @@ impl<'a> CodeGenerator<'a> {
                 .expect("failed to write to string");
                 (destination_register, instruction)
             }
+            Type::Identifier(Identifier { name: sym::MerkleTree, .. }) => {
+                let depth = match self.type_table.get(&input.arguments[2].id()) {
+                    Some(Type::Array(array_type)) => array_type.length(),
+                    _ => unreachable!("Type checking guarantees that the path of a Merkle tree is an array."),
+                };
+                let variant = match input.name.name {
+                    sym::verify => "psd2",
+                    sym::verify_bhp256 => "bhp256",
+                    _ => unreachable!("The only associated methods of MerkleTree are `verify` and `verify_bhp256`"),
+                };
+                self.verify_merkle_path(variant, &arguments, depth)
+            }
+            Type::Identifier(_) if input.name.name == sym::from_field => {
+                let integer_type = match self.type_table.get(&input.id) {
+                    Some(Type::Integer(integer_type)) => integer_type,
+                    _ => unreachable!("Type checking guarantees that fields are only converted into integers."),
+                };
+                let destination_register = get_destination_register();
+                let field_register = get_destination_register();
+                // Cast the field element, and prove that it is in range by casting the result back.
+                let mut instruction = String::new();
+                writeln!(instruction, "    cast {} into {destination_register} as {integer_type};", arguments[0])
+                    .expect("failed to write to string");
+                writeln!(instruction, "    cast {destination_register} into {field_register} as field;")
+                    .expect("failed to write to string");
+                writeln!(instruction, "    assert.eq {field_register} {};", arguments[0])
+                    .expect("failed to write to string");
+                (destination_register, instruction)
+            }
+            Type::Identifier(_) if input.name.name == sym::from_bits_le => {
+                let integer_type = match self.type_table.get(&input.id) {
+                    Some(Type::Integer(integer_type)) => integer_type,
+                    _ => unreachable!("Type checking guarantees that bits are only recomposed into integers."),
+                };
+                self.recompose_bits_le(&arguments[0], integer_type)
+            }
             _ => unreachable!("All core functions should be known at this phase of compilation"),
         };
         // Add the instruction to the list of instructions.
@@ -110,9 +110,16 @@ impl<'a> CodeGenerator<'a> {
     }
 
     fn visit_import(&mut self, import_name: &'a Symbol, import_program: &'a Program) -> String {
-        // Load symbols into composite mapping.
-        let _import_program_string = self.visit_program(import_program);
-        // todo: We do not need the import program string because we generate instructions for imports separately during leo build.
+        // Load the imported structs and records into the composite mapping.
+        // Note that instructions for the imported program are generated separately during `leo build`,
+        // so there is no need to generate the whole imported program here.
+        for (_, struct_) in import_program.program_scopes.values().flat_map(|scope| scope.structs.iter()) {
+            let metadata = match struct_.is_record {
+                true => (true, "record"),
+                false => (false, "private"),
+            };
+            self.composite_mapping.insert(struct_.identifier.name, metadata);
+        }
 
         // Generate string for import statement.
         format!("import {import_name}.aleo;")
@@ -124,7 +131,7 @@ impl<'a> CodeGenerator<'a> {
 
     fn visit_struct(&mut self, struct_: &'a Struct) -> String {
         // Add private symbol to composite types.
-        self.composite_mapping.insert(&struct_.identifier.name, (false, String::from("private"))); // todo: private by default here.
+        self.composite_mapping.insert(struct_.identifier.name, (false, "private")); // todo: private by default here.
 
         let mut output_string = format!("struct {}:\n", struct_.identifier); // todo: check if this is safe from name conflicts.
 
@@ -139,9 +146,9 @@ impl<'a> CodeGenerator<'a> {
 
     fn visit_record(&mut self, record: &'a Struct) -> String {
         // Add record symbol to composite types.
-        let mut output_string = String::from("record");
-        self.composite_mapping.insert(&record.identifier.name, (true, output_string.clone()));
-        writeln!(output_string, " {}:", record.identifier).expect("failed to write to string"); // todo: check if this is safe from name conflicts.
+        self.composite_mapping.insert(record.identifier.name, (true, "record"));
+
+        let mut output_string = format!("record {}:\n", record.identifier); // todo: check if this is safe from name conflicts.
 
         // Construct and append the record variables.
         for var in record.members.iter() {
@@ -168,8 +175,8 @@ impl<'a> CodeGenerator<'a> {
         self.variable_mapping = IndexMap::new();
         self.futures.clear();
         // TODO: Figure out a better way to initialize.
-        self.variable_mapping.insert(&sym::SelfLower, "self".to_string());
-        self.variable_mapping.insert(&sym::block, "block".to_string());
+        self.variable_mapping.insert(sym::SelfLower, "self".to_string());
+        self.variable_mapping.insert(sym::block, "block".to_string());
         self.current_function = Some(function);
 
         // Construct the header of the function.
@@ -189,7 +196,7 @@ impl<'a> CodeGenerator<'a> {
 
             let type_string = match input {
                 functions::Input::Internal(input) => {
-                    self.variable_mapping.insert(&input.identifier.name, register_string.clone());
+                    self.variable_mapping.insert(input.identifier.name, register_string.clone());
                     let visibility = match (self.is_transition_function, input.mode) {
                         (true, Mode::None) => Mode::Private,
                         _ => input.mode,
@@ -197,7 +204,7 @@ impl<'a> CodeGenerator<'a> {
                     self.visit_type_with_visibility(&input.type_, visibility)
                 }
                 functions::Input::External(input) => {
-                    self.variable_mapping.insert(&input.identifier.name, register_string.clone());
+                    self.variable_mapping.insert(input.identifier.name, register_string.clone());
                     format!("{}.aleo/{}.record", input.program_name, input.record)
                 }
             };
@@ -219,8 +226,8 @@ impl<'a> CodeGenerator<'a> {
             // Clear the variable mapping.
             // TODO: Figure out a better way to initialize.
             self.variable_mapping = IndexMap::new();
-            self.variable_mapping.insert(&sym::SelfLower, "self".to_string());
-            self.variable_mapping.insert(&sym::block, "block".to_string());
+            self.variable_mapping.insert(sym::SelfLower, "self".to_string());
+            self.variable_mapping.insert(sym::block, "block".to_string());
 
             function_string.push_str(&format!("\nfinalize {}:\n", function.identifier));
 
@@ -244,7 +251,7 @@ impl<'a> CodeGenerator<'a> {
                     // TODO: Dedup code.
                     let type_string = match input {
                         functions::Input::Internal(input) => {
-                            self.variable_mapping.insert(&input.identifier.name, register_string.clone());
+                            self.variable_mapping.insert(input.identifier.name, register_string.clone());
 
                             let visibility = match (self.is_transition_function, input.mode) {
                                 (true, Mode::None) => Mode::Public,
@@ -253,7 +260,7 @@ impl<'a> CodeGenerator<'a> {
                             self.visit_type_with_visibility(&input.type_, visibility)
                         }
                         functions::Input::External(input) => {
-                            self.variable_mapping.insert(&input.program_name.name, register_string.clone());
+                            self.variable_mapping.insert(input.program_name.name, register_string.clone());
                             format!("{}.aleo/{}.record", input.program_name, input.record)
                         }
                     };
@@ -308,7 +315,7 @@ impl<'a> CodeGenerator<'a> {
         mapping_string.push_str(&format!("\tvalue as {};\n", create_type(&mapping.value_type)));
 
         // Add the mapping to the variable mapping.
-        self.global_mapping.insert(&mapping.identifier.name, mapping.identifier.to_string());
+        self.global_mapping.insert(mapping.identifier.name, mapping.identifier.to_string());
 
         mapping_string
     }
@@ -31,6 +31,7 @@ use leo_ast::{
     Output,
     ReturnStatement,
     Statement,
+    Type,
 };
 
 use itertools::Itertools;
@@ -77,6 +78,9 @@ impl<'a> CodeGenerator<'a> {
             }
             AssertVariant::AssertEq(left, right) => generate_assert_instruction("assert.eq", left, right),
             AssertVariant::AssertNeq(left, right) => generate_assert_instruction("assert.neq", left, right),
+            AssertVariant::Fail(_) => {
+                unreachable!("`fail` and `abort` are lowered to assertions in the flattening pass.")
+            }
         }
     }
 
@@ -99,6 +103,23 @@ impl<'a> CodeGenerator<'a> {
                     true => vec![],
                     false => operand.split(' ').collect_vec(),
                 };
+                // A register can only be output once, so a repeated operand is first copied into a new register.
+                let mut outputs = Vec::with_capacity(operand_strings.len());
+                let operand_strings = operand_strings
+                    .iter()
+                    .zip_eq(output.clone())
+                    .map(|(operand, output)| match output {
+                        Output::Internal(output) if outputs.contains(operand) => {
+                            let (register, instructions) = self.copy_to_register(operand, &output.type_);
+                            expression_instructions.push_str(&instructions);
+                            register
+                        }
+                        _ => {
+                            outputs.push(*operand);
+                            operand.to_string()
+                        }
+                    })
+                    .collect_vec();
                 let instructions = operand_strings
                     .iter()
                     .zip_eq(output)
@@ -191,7 +212,7 @@ impl<'a> CodeGenerator<'a> {
     fn visit_definition(&mut self, _input: &'a DefinitionStatement) -> String {
         // TODO: If SSA is made optional, then conditionally enable codegen for DefinitionStatement
         // let (operand, expression_instructions) = self.visit_expression(&input.value);
-        // self.variable_mapping.insert(&input.variable_name.name, operand);
+        // self.variable_mapping.insert(input.variable_name.name, operand);
         // expression_instructions
         unreachable!("DefinitionStatement's should not exist in SSA form.")
     }
@@ -204,7 +225,7 @@ impl<'a> CodeGenerator<'a> {
         match (&input.place, &input.value) {
             (Expression::Identifier(identifier), _) => {
                 let (operand, expression_instructions) = self.visit_expression(&input.value);
-                self.variable_mapping.insert(&identifier.name, operand);
+                self.variable_mapping.insert(identifier.name, operand);
                 expression_instructions
             }
             (Expression::Tuple(tuple), Expression::Call(_)) => {
@@ -215,7 +236,7 @@ impl<'a> CodeGenerator<'a> {
                 tuple.elements.iter().zip_eq(operands).for_each(|(element, operand)| {
                     match element {
                         Expression::Identifier(identifier) => {
-                            self.variable_mapping.insert(&identifier.name, operand.to_string())
+                            self.variable_mapping.insert(identifier.name, operand.to_string())
                         }
                         _ => {
                             unreachable!("Type checking ensures that tuple elements on the lhs are always identifiers.")
@@ -248,4 +269,32 @@ impl<'a> CodeGenerator<'a> {
         // For each statement in the block, visit it and add its instructions to the list.
         input.statements.iter().map(|stmt| self.visit_statement(stmt)).join("")
     }
+
+    /// Copies the value of `operand`, of type `type_`, into a new register.
+    /// Returns the new register and the instructions that produce it.
+    fn copy_to_register(&mut self, operand: &str, type_: &Type) -> (String, String) {
+        // Structs and arrays are cast from their members and elements.
+        let operands = match type_ {
+            Type::Array(array_type) => (0..array_type.length()).map(|i| format!("{operand}[{i}u32]")).join(" "),
+            // Note that this unwrap is safe, since type checking guarantees that the struct is defined.
+            Type::Identifier(identifier) => self
+                .symbol_table
+                .lookup_struct(identifier.name)
+                .unwrap()
+                .members
+                .iter()
+                .map(|member| format!("{operand}.{}", member.identifier))
+                .join(" "),
+            _ => operand.to_string(),
+        };
+
+        // Construct the destination register.
+        let destination_register = format!("r{}", self.next_register);
+        // Increment the register counter.
+        self.next_register += 1;
+
+        let instructions =
+            format!("    cast {operands} into {destination_register} as {};\n", Self::visit_type(type_));
+        (destination_register, instructions)
+    }
 }
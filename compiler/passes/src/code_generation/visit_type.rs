@@ -36,6 +36,8 @@ impl<'a> CodeGenerator<'a> {
             Type::Mapping(_) => {
                 unreachable!("Mapping types are not supported at this phase of compilation")
             }
+            Type::Option(_) => unreachable!("Option types are lowered to structs before code generation"),
+            Type::Result(_) => unreachable!("Result types are lowered to structs before code generation"),
             Type::Tuple(_) => {
                 unreachable!("Tuple types should not be visited at this phase of compilation")
             }
@@ -17,7 +17,10 @@
 use leo_span::Symbol;
 
 use indexmap::{IndexMap, IndexSet};
-use std::{fmt::Debug, hash::Hash};
+use std::{
+    fmt::{Debug, Display, Write},
+    hash::Hash,
+};
 
 /// A struct dependency graph.
 pub type StructGraph = DiGraph<Symbol>;
@@ -56,6 +59,11 @@ impl<N: Node> DiGraph<N> {
         Self { nodes, edges: IndexMap::new() }
     }
 
+    /// Adds a node to the graph, if it is not already in the graph.
+    pub fn add_node(&mut self, node: N) {
+        self.nodes.insert(node);
+    }
+
     /// Adds an edge to the graph.
     pub fn add_edge(&mut self, from: N, to: N) {
         // Add `from` and `to` to the set of nodes if they are not already in the set.
@@ -72,6 +80,38 @@ impl<N: Node> DiGraph<N> {
         self.nodes.contains(&node)
     }
 
+    /// Returns the nodes of the graph, in the order in which they were added.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    /// Returns the edges of the graph, as pairs of source and target nodes.
+    pub fn edges(&self) -> impl Iterator<Item = (N, N)> + '_ {
+        self.edges.iter().flat_map(|(from, targets)| targets.iter().map(move |to| (*from, *to)))
+    }
+
+    /// Returns the nodes that the given node points to.
+    pub fn successors(&self, node: N) -> impl Iterator<Item = &N> {
+        self.edges.get(&node).into_iter().flatten()
+    }
+
+    /// Returns the nodes that point to the given node.
+    pub fn predecessors(&self, node: N) -> impl Iterator<Item = N> + '_ {
+        self.edges.iter().filter(move |(_, targets)| targets.contains(&node)).map(|(from, _)| *from)
+    }
+
+    /// Returns the nodes that are reachable from the given node, excluding the node itself unless it is on a cycle.
+    pub fn reachable_from(&self, node: N) -> IndexSet<N> {
+        let mut reachable = IndexSet::new();
+        let mut worklist: Vec<N> = self.successors(node).copied().collect();
+        while let Some(next) = worklist.pop() {
+            if reachable.insert(next) {
+                worklist.extend(self.successors(next).copied());
+            }
+        }
+        reachable
+    }
+
     /// Returns the post-order ordering of the graph.
     /// Detects if there is a cycle in the graph.
     pub fn post_order(&self) -> Result<IndexSet<N>, DiGraphError<N>> {
@@ -142,6 +182,22 @@ impl<N: Node> DiGraph<N> {
     }
 }
 
+impl<N: Node + Display> DiGraph<N> {
+    /// Returns the graph in the DOT format, with each node labeled by its display form.
+    pub fn to_dot(&self, name: &str) -> String {
+        let mut dot = format!("digraph \"{name}\" {{\n");
+        // Note that writing to a `String` cannot fail.
+        for node in self.nodes.iter() {
+            writeln!(dot, "    \"{node}\";").unwrap();
+        }
+        for (from, to) in self.edges() {
+            writeln!(dot, "    \"{from}\" -> \"{to}\";").unwrap();
+        }
+        dot.push('}');
+        dot
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -208,6 +264,37 @@ mod test {
         assert_eq!(cycle, expected);
     }
 
+    #[test]
+    fn test_queries() {
+        let mut graph = DiGraph::<u32>::new(IndexSet::from([6]));
+
+        graph.add_edge(1, 2);
+        graph.add_edge(1, 3);
+        graph.add_edge(2, 4);
+        graph.add_edge(3, 4);
+        graph.add_edge(4, 5);
+
+        assert_eq!(graph.nodes().copied().collect::<Vec<_>>(), [6, 1, 2, 3, 4, 5]);
+        assert_eq!(graph.edges().collect::<Vec<_>>(), [(1, 2), (1, 3), (2, 4), (3, 4), (4, 5)]);
+        assert_eq!(graph.successors(1).copied().collect::<Vec<_>>(), [2, 3]);
+        assert_eq!(graph.successors(6).count(), 0);
+        assert_eq!(graph.predecessors(4).collect::<Vec<_>>(), [2, 3]);
+        assert_eq!(graph.reachable_from(2).into_iter().collect::<Vec<_>>(), [4, 5]);
+        assert!(graph.reachable_from(5).is_empty());
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let mut graph = DiGraph::<u32>::new(IndexSet::from([3]));
+
+        graph.add_edge(1, 2);
+
+        assert_eq!(
+            graph.to_dot("test"),
+            "digraph \"test\" {\n    \"3\";\n    \"1\";\n    \"2\";\n    \"1\" -> \"2\";\n}"
+        );
+    }
+
     #[test]
     fn test_unconnected_graph() {
         let graph = DiGraph::<u32>::new(IndexSet::from([1, 2, 3, 4, 5]));
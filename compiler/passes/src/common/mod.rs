@@ -26,6 +26,9 @@ pub use rename_table::*;
 pub mod replacer;
 pub use replacer::*;
 
+pub mod resolution_table;
+pub use resolution_table::*;
+
 pub mod constant_propagation_table;
 pub use constant_propagation_table::*;
 
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::NodeID;
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+
+/// The kinds of definitions that a name can refer to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DefinitionKind {
+    /// A constant, declared in a program scope or a function body.
+    Const,
+    /// A function, transition, or inline function.
+    Function,
+    /// An input of a function or finalize block.
+    Input,
+    /// A mapping.
+    Mapping,
+    /// A member of a struct or record.
+    Member,
+    /// A struct or record.
+    Struct,
+    /// A variable declared with `let`, or the variable of a loop.
+    Variable,
+}
+
+/// The definition of a name.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Definition {
+    /// The kind of the definition.
+    pub kind: DefinitionKind,
+    /// The name being defined.
+    pub name: Symbol,
+    /// The ID of the identifier in the definition.
+    pub id: NodeID,
+    /// The span of the identifier in the definition.
+    pub span: Span,
+}
+
+/// An occurrence of a name, along with the definition it resolves to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reference {
+    /// The span of the occurrence.
+    pub span: Span,
+    /// The definition that the occurrence resolves to.
+    pub definition: Definition,
+}
+
+/// A mapping between the node IDs of identifiers and the definitions they resolve to.
+/// The identifier in a definition resolves to the definition itself.
+#[derive(Debug, Default, Clone)]
+pub struct ResolutionTable {
+    /// The inner table.
+    /// `RefCell` is used here to avoid `&mut` all over the compiler.
+    inner: RefCell<IndexMap<NodeID, Reference>>,
+}
+
+impl ResolutionTable {
+    /// Gets an entry from the table.
+    pub fn get(&self, index: &NodeID) -> Option<Reference> {
+        self.inner.borrow().get(index).copied()
+    }
+
+    /// Inserts an entry into the table.
+    pub fn insert(&self, index: NodeID, value: Reference) {
+        self.inner.borrow_mut().insert(index, value);
+    }
+
+    /// Returns all the references in the table, in the order in which they were resolved.
+    pub fn references(&self) -> Vec<Reference> {
+        self.inner.borrow().values().copied().collect()
+    }
+
+    /// Returns all the definitions in the table, in the order in which they were resolved.
+    pub fn definitions(&self) -> Vec<Definition> {
+        self.inner
+            .borrow()
+            .iter()
+            .filter(|(id, reference)| reference.definition.id == **id)
+            .map(|(_, reference)| reference.definition)
+            .collect()
+    }
+
+    /// Returns the references to the definition with the given ID, including the definition itself, in the order
+    /// in which they were resolved.
+    pub fn references_to(&self, definition: NodeID) -> Vec<Reference> {
+        self.inner.borrow().values().filter(|reference| reference.definition.id == definition).copied().collect()
+    }
+}
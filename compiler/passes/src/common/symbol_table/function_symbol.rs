@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_ast::{Function, Input, Type, Variant};
+use leo_ast::{Function, Input, Receiver, Type, Variant};
 use leo_span::Span;
 
 use serde::{Deserialize, Serialize};
@@ -40,11 +40,17 @@ pub struct FunctionSymbol {
     /// Is this function a transition, inlined, or a regular function?.
     pub variant: Variant,
     /// The `Span` associated with the function.
-    pub(crate) _span: Span,
+    pub(crate) span: Span,
     /// The inputs to the function.
     pub(crate) input: Vec<Input>,
     /// Metadata associated with the finalize block.
     pub(crate) finalize: Option<FinalizeData>,
+    /// The receiver, if the function is a method of a struct.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) receiver: Option<Receiver>,
+    /// The deprecation message, if the function is annotated with `@deprecated`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) deprecated: Option<String>,
 }
 
 impl SymbolTable {
@@ -53,12 +59,14 @@ impl SymbolTable {
             id,
             output_type: func.output_type.clone(),
             variant: func.variant,
-            _span: func.span,
+            span: func.span,
             input: func.input.clone(),
             finalize: func.finalize.as_ref().map(|finalize| FinalizeData {
                 input: finalize.input.clone(),
                 output_type: finalize.output_type.clone(),
             }),
+            receiver: func.receiver,
+            deprecated: func.deprecation(),
         }
     }
 }
@@ -55,15 +55,18 @@ pub struct SymbolTable {
 impl SymbolTable {
     /// Recursively checks if the symbol table contains an entry for the given symbol.
     /// Leo does not allow any variable shadowing or overlap between different symbols.
+    /// A variable only conflicts with the declaration at `span` if it is declared before it. The scopes of a program
+    /// that has been type checked also hold the variables declared later in each block, which a nested block may reuse.
     pub fn check_shadowing(&self, symbol: Symbol, span: Span) -> Result<()> {
-        if self.variables.contains_key(&symbol) {
-            Err(AstError::shadowed_variable(symbol, span).into())
-        } else if self.functions.contains_key(&symbol) {
-            Err(AstError::shadowed_function(symbol, span).into())
+        let previous = || format!("previous definition of `{symbol}` here");
+        if let Some(existing) = self.variables.get(&symbol).filter(|existing| existing.span.lo <= span.lo) {
+            Err(AstError::shadowed_variable(symbol, span).with_label(existing.span, previous()).into())
+        } else if let Some(existing) = self.functions.get(&symbol) {
+            Err(AstError::shadowed_function(symbol, span).with_label(existing.span, previous()).into())
         } else if let Some(existing) = self.structs.get(&symbol) {
             match existing.is_record {
-                true => Err(AstError::shadowed_record(symbol, span).into()),
-                false => Err(AstError::shadowed_struct(symbol, span).into()),
+                true => Err(AstError::shadowed_record(symbol, span).with_label(existing.span, previous()).into()),
+                false => Err(AstError::shadowed_struct(symbol, span).with_label(existing.span, previous()).into()),
             }
         } else if let Some(parent) = self.parent.as_ref() {
             parent.check_shadowing(symbol, span)
@@ -147,6 +150,25 @@ impl SymbolTable {
         }
     }
 
+    /// Returns the span of the declaration of a variable in a scope of the current function that has ended,
+    /// i.e., in a nested block that is not an enclosing scope, if there is one.
+    pub fn lookup_variable_in_ended_scope(&self, symbol: Symbol) -> Option<Span> {
+        // The scopes of the root are those of the functions, so the search stops at the scope of the current function.
+        let parent = self.parent.as_ref()?;
+        self.scopes
+            .iter()
+            .find_map(|scope| scope.borrow().lookup_variable_in_nested_scopes(symbol))
+            .or_else(|| parent.lookup_variable_in_ended_scope(symbol))
+    }
+
+    /// Returns the span of the declaration of a variable in this scope or a scope nested in it, if there is one.
+    fn lookup_variable_in_nested_scopes(&self, symbol: Symbol) -> Option<Span> {
+        self.variables
+            .get(&symbol)
+            .map(|var| var.span)
+            .or_else(|| self.scopes.iter().find_map(|scope| scope.borrow().lookup_variable_in_nested_scopes(symbol)))
+    }
+
     /// Returns true if the variable exists in the local scope
     pub fn variable_in_local_scope(&self, symbol: Symbol) -> bool {
         self.variables.contains_key(&symbol)
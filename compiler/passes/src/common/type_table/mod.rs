@@ -17,24 +17,49 @@
 use leo_ast::{NodeID, Type};
 
 use indexmap::IndexMap;
-use std::cell::RefCell;
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 /// A mapping between node IDs and their types.
-#[derive(Debug, Default, Clone)]
+/// It is `Send` and `Sync`, so that passes running on several threads can share it.
+#[derive(Debug, Default)]
 pub struct TypeTable {
     /// The inner table.
-    /// `RefCell` is used here to avoid `&mut` all over the compiler.
-    inner: RefCell<IndexMap<NodeID, Type>>,
+    /// `RwLock` is used here to avoid `&mut` all over the compiler.
+    inner: RwLock<IndexMap<NodeID, Type>>,
+}
+
+impl Clone for TypeTable {
+    fn clone(&self) -> Self {
+        Self { inner: RwLock::new(self.read().clone()) }
+    }
 }
 
 impl TypeTable {
     /// Gets an entry from the table.
     pub fn get(&self, index: &NodeID) -> Option<Type> {
-        self.inner.borrow().get(index).cloned()
+        self.read().get(index).cloned()
     }
 
     /// Inserts an entry into the table.
     pub fn insert(&self, index: NodeID, value: Type) {
-        self.inner.borrow_mut().insert(index, value);
+        self.write().insert(index, value);
+    }
+
+    /// Replaces each type in the table with the result of `f`.
+    /// Note that `f` must not access the table.
+    pub fn map_types(&self, mut f: impl FnMut(&Type) -> Type) {
+        for type_ in self.write().values_mut() {
+            *type_ = f(type_);
+        }
+    }
+
+    /// Locks the table for reading, even if a thread panicked while writing to it.
+    fn read(&self) -> RwLockReadGuard<'_, IndexMap<NodeID, Type>> {
+        self.inner.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Locks the table for writing, even if a thread panicked while writing to it.
+    fn write(&self) -> RwLockWriteGuard<'_, IndexMap<NodeID, Type>> {
+        self.inner.write().unwrap_or_else(|e| e.into_inner())
     }
 }
@@ -56,6 +56,7 @@ impl ProgramReconstructor for DeadCodeEliminator<'_> {
             output_type: input.output_type,
             block,
             finalize,
+            receiver: input.receiver,
             span: input.span,
             id: input.id,
         }
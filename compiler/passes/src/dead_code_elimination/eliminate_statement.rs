@@ -49,6 +49,7 @@ impl StatementReconstructor for DeadCodeEliminator<'_> {
                 AssertVariant::AssertNeq(left, right) => {
                     AssertVariant::AssertNeq(self.reconstruct_expression(left).0, self.reconstruct_expression(right).0)
                 }
+                AssertVariant::Fail(reason) => AssertVariant::Fail(reason),
             },
             span: input.span,
             id: input.id,
@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{is_constant_table, DynamicIndexer};
+
+use leo_ast::*;
+
+impl ExpressionReconstructor for DynamicIndexer<'_> {
+    type AdditionalOutput = ();
+
+    /// Lowers an array access with a non-constant index. Accesses with a constant index are left as is,
+    /// unless they access a constant lookup table.
+    fn reconstruct_array_access(&mut self, input: ArrayAccess) -> (Expression, Self::AdditionalOutput) {
+        let index = self.reconstruct_expression(*input.index).0;
+        let position = match &index {
+            Expression::Literal(Literal::Integer(_, value, ..)) => {
+                Literal::integer_to_decimal(value).and_then(|value| value.parse::<usize>().ok())
+            }
+            _ => None,
+        };
+
+        // If a row of a constant lookup table is accessed with a constant index, the index is applied to every row first,
+        // so that only the accessed elements need to be selected.
+        if let (Some(position), Expression::Access(AccessExpression::Array(row))) = (position, input.array.as_ref()) {
+            if let Some(column) = self.column(&row.array, position, input.id) {
+                return self.reconstruct_array_access(ArrayAccess {
+                    array: Box::new(column),
+                    index: row.index.clone(),
+                    span: input.span,
+                    id: input.id,
+                });
+            }
+        }
+
+        let array = self.reconstruct_expression(*input.array).0;
+
+        match array {
+            // An element of a constant lookup table is accessed directly.
+            // Note that the other elements are literals, so they can be discarded.
+            Expression::Array(mut array)
+                if is_constant_table(&array) && position.map_or(false, |position| position < array.elements.len()) =>
+            {
+                (array.elements.swap_remove(position.unwrap()), Default::default())
+            }
+            array if matches!(index, Expression::Literal(_)) => (
+                Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: Box::new(array),
+                    index: Box::new(index),
+                    span: input.span,
+                    id: input.id,
+                })),
+                Default::default(),
+            ),
+            array => (self.select(array, index, input.span), Default::default()),
+        }
+    }
+}
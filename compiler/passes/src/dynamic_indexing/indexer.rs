@@ -0,0 +1,259 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Assigner, TypeTable};
+
+use leo_ast::*;
+use leo_span::{Span, Symbol};
+
+/// The strategy used to select an element of an array with a non-constant index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelectionStrategy {
+    /// Compares the index against each position, using `n - 1` comparisons and `n - 1` selections.
+    Linear,
+    /// Selects between pairs of elements using the bits of the index, using two instructions to test each bit and `n - 1` selections.
+    Tree,
+}
+
+impl SelectionStrategy {
+    /// Returns the strategy that requires the fewest instructions for an array with `length` elements.
+    /// Note that the linear strategy is preferred if both strategies require the same number of instructions.
+    pub fn for_length(length: usize) -> Self {
+        let selections = length.saturating_sub(1);
+        let linear_cost = 2 * selections;
+        let tree_cost = selections + 2 * index_bits(length);
+        match tree_cost < linear_cost {
+            true => Self::Tree,
+            false => Self::Linear,
+        }
+    }
+}
+
+/// Returns the number of bits needed to represent every index of an array with `length` elements.
+fn index_bits(length: usize) -> usize {
+    (usize::BITS - length.saturating_sub(1).leading_zeros()) as usize
+}
+
+/// Returns `true` if the array consists of literals, or of other constant lookup tables.
+pub(crate) fn is_constant_table(array: &ArrayExpression) -> bool {
+    array.elements.iter().all(|element| match element {
+        Expression::Literal(_) => true,
+        Expression::Array(array) => is_constant_table(array),
+        _ => false,
+    })
+}
+
+pub struct DynamicIndexer<'a> {
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// A struct used to construct (unique) variable names.
+    pub(crate) assigner: &'a Assigner,
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// The statements produced while lowering the current statement, which are inserted before it.
+    pub(crate) statements: Vec<Statement>,
+}
+
+impl<'a> DynamicIndexer<'a> {
+    /// Initializes a new `DynamicIndexer`.
+    pub(crate) fn new(node_builder: &'a NodeBuilder, assigner: &'a Assigner, type_table: &'a TypeTable) -> Self {
+        Self { node_builder, assigner, type_table, statements: Vec::new() }
+    }
+
+    /// Returns a new node ID for an expression of the given type.
+    fn typed_id(&self, type_: Type) -> NodeID {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_);
+        id
+    }
+
+    /// Constructs an identifier expression for the variable `name`.
+    fn identifier(&self, name: Symbol, type_: Type) -> Expression {
+        Expression::Identifier(Identifier { name, span: Default::default(), id: self.typed_id(type_) })
+    }
+
+    /// Constructs an integer literal.
+    fn integer(&self, type_: IntegerType, value: usize) -> Expression {
+        Expression::Literal(Literal::Integer(
+            type_,
+            value.to_string(),
+            Default::default(),
+            self.typed_id(Type::Integer(type_)),
+        ))
+    }
+
+    /// Constructs a binary expression.
+    fn binary(&self, op: BinaryOperation, left: Expression, right: Expression, type_: Type) -> Expression {
+        Expression::Binary(BinaryExpression {
+            left: Box::new(left),
+            right: Box::new(right),
+            op,
+            span: Default::default(),
+            id: self.typed_id(type_),
+        })
+    }
+
+    /// Constructs a ternary expression.
+    fn ternary(&self, condition: Expression, if_true: Expression, if_false: Expression, type_: &Type) -> Expression {
+        Expression::Ternary(TernaryExpression {
+            condition: Box::new(condition),
+            if_true: Box::new(if_true),
+            if_false: Box::new(if_false),
+            span: Default::default(),
+            id: self.typed_id(type_.clone()),
+        })
+    }
+
+    /// Binds the expression to a new variable, unless it is already a variable, and returns the name of the variable.
+    fn bind(&mut self, expression: Expression, type_: Type) -> Symbol {
+        if let Expression::Identifier(identifier) = expression {
+            return identifier.name;
+        }
+        let name = self.assigner.unique_symbol("$dyn", "$");
+        let place = Identifier { name, span: Default::default(), id: self.typed_id(type_.clone()) };
+        self.statements.push(Statement::Definition(DefinitionStatement {
+            declaration_type: DeclarationType::Let,
+            place: Expression::Identifier(place),
+            type_,
+            value: expression,
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        }));
+        name
+    }
+
+    /// Returns the elements at `position` in each row of a constant lookup table, as an array with elements of the same type as `id`.
+    pub(crate) fn column(&self, table: &Expression, position: usize, id: NodeID) -> Option<Expression> {
+        let table = match table {
+            Expression::Array(table) if is_constant_table(table) => table,
+            _ => return None,
+        };
+        let elements = table
+            .elements
+            .iter()
+            .map(|row| match row {
+                Expression::Array(row) => row.elements.get(position).cloned(),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let type_ = Type::Array(ArrayType::new(self.type_table.get(&id)?, NonNegativeNumber::from(elements.len())));
+
+        Some(Expression::Array(ArrayExpression { elements, span: table.span, id: self.typed_id(type_) }))
+    }
+
+    /// Lowers the access `array[index]`, where `index` is not a constant, into a selection between the elements of `array`.
+    /// The statements that check the bounds of the index and bind intermediate values are added to `self.statements`.
+    pub(crate) fn select(&mut self, array: Expression, index: Expression, span: Span) -> Expression {
+        let array_type = match self.type_table.get(&array.id()) {
+            Some(Type::Array(array_type)) => array_type,
+            _ => unreachable!("Type checking guarantees that the accessed expression is an array."),
+        };
+        let index_type = match self.type_table.get(&index.id()) {
+            Some(Type::Integer(index_type)) => index_type,
+            _ => unreachable!("Type checking guarantees that the index is an integer."),
+        };
+        let element_type = array_type.element_type().clone();
+        let length = array_type.length();
+
+        // The index is used multiple times, so it is bound to a variable.
+        let index = self.bind(index, Type::Integer(index_type));
+
+        // Assert that the index is in bounds.
+        let mut in_bounds = self.binary(
+            BinaryOperation::Lt,
+            self.identifier(index, Type::Integer(index_type)),
+            self.integer(index_type, length),
+            Type::Boolean,
+        );
+        if index_type.is_signed() {
+            let non_negative = self.binary(
+                BinaryOperation::Gte,
+                self.identifier(index, Type::Integer(index_type)),
+                self.integer(index_type, 0),
+                Type::Boolean,
+            );
+            in_bounds = self.binary(BinaryOperation::And, non_negative, in_bounds, Type::Boolean);
+        }
+        self.statements.push(Statement::Assert(AssertStatement {
+            variant: AssertVariant::Assert(in_bounds),
+            span,
+            id: self.node_builder.next_id(),
+        }));
+
+        let mut elements = match array {
+            // The elements of an array expression, e.g. a constant lookup table, are selected directly.
+            Expression::Array(array) => array.elements,
+            // Otherwise, the array is bound to a variable and each element is accessed with a constant index.
+            array => {
+                let array = self.bind(array, Type::Array(array_type.clone()));
+                (0..length)
+                    .map(|position| {
+                        Expression::Access(AccessExpression::Array(ArrayAccess {
+                            array: Box::new(self.identifier(array, Type::Array(array_type.clone()))),
+                            index: Box::new(self.integer(IntegerType::U32, position)),
+                            span: Default::default(),
+                            id: self.typed_id(element_type.clone()),
+                        }))
+                    })
+                    .collect()
+            }
+        };
+
+        match SelectionStrategy::for_length(length) {
+            // Construct `index == 0 ? array[0] : index == 1 ? array[1] : ... : array[n - 1]`.
+            // Note that the last element does not need a comparison, since the index is in bounds.
+            SelectionStrategy::Linear => {
+                let last = elements.pop().expect("Type checking guarantees that arrays are not empty.");
+                elements.into_iter().enumerate().rev().fold(last, |otherwise, (position, element)| {
+                    let condition = self.binary(
+                        BinaryOperation::Eq,
+                        self.identifier(index, Type::Integer(index_type)),
+                        self.integer(index_type, position),
+                        Type::Boolean,
+                    );
+                    self.ternary(condition, element, otherwise, &element_type)
+                })
+            }
+            // Select between adjacent pairs of elements using the least significant bit of the index, and repeat with the next bit.
+            // Note that an unpaired element is kept as is, since the index is in bounds.
+            SelectionStrategy::Tree => {
+                for bit in 0..index_bits(length) {
+                    let mask = self.binary(
+                        BinaryOperation::BitwiseAnd,
+                        self.identifier(index, Type::Integer(index_type)),
+                        self.integer(index_type, 1 << bit),
+                        Type::Integer(index_type),
+                    );
+                    let condition = self.binary(BinaryOperation::Neq, mask, self.integer(index_type, 0), Type::Boolean);
+                    let condition = self.bind(condition, Type::Boolean);
+
+                    let mut pairs = elements.into_iter();
+                    let mut selected = Vec::new();
+                    while let Some(even) = pairs.next() {
+                        selected.push(match pairs.next() {
+                            Some(odd) => {
+                                self.ternary(self.identifier(condition, Type::Boolean), odd, even, &element_type)
+                            }
+                            None => even,
+                        });
+                    }
+                    elements = selected;
+                }
+                elements.pop().expect("Type checking guarantees that arrays are not empty.")
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Dynamic Indexing pass traverses the AST after the Loop Unrolling pass and lowers array accesses with non-constant indices.
+//! Aleo instructions only support array accesses with constant indices, so an access `a[i]` is lowered into a selection between the elements of `a`.
+//! The pass first asserts that the index is in bounds, and then chooses the strategy that requires the fewest instructions for the length of the array.
+//! - The linear strategy compares the index against each position of the array.
+//! - The tree strategy selects between pairs of elements using each bit of the index, starting from the least significant bit.
+//!
+//! Constant lookup tables, i.e. `const` declarations of arrays of literals, are substituted by the Loop Unrolling pass.
+//! The literals in a lookup table are selected directly, so the table itself is never constructed.
+//! An element accessed with a constant index is replaced by its value, and an access such as `TABLE[i][1u32]` only selects between the elements in the second column.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function main(a: [u8; 3], i: u32) -> u8 {
+//!     return a[i];
+//! }
+//! ```
+//!
+//! The dynamic indexing pass produces the following code.
+//! ```leo
+//! function main(a: [u8; 3], i: u32) -> u8 {
+//!     assert(i < 3u32);
+//!     return i == 0u32 ? a[0u32] : i == 1u32 ? a[1u32] : a[2u32];
+//! }
+//! ```
+//!
+//! For an array with eight elements, the tree strategy produces the following code.
+//! ```leo
+//! function main(a: [u8; 8], i: u32) -> u8 {
+//!     assert(i < 8u32);
+//!     let $dyn$0: bool = i & 1u32 != 0u32;
+//!     let $dyn$1: bool = i & 2u32 != 0u32;
+//!     let $dyn$2: bool = i & 4u32 != 0u32;
+//!     return $dyn$2
+//!         ? ($dyn$1 ? ($dyn$0 ? a[7u32] : a[6u32]) : ($dyn$0 ? a[5u32] : a[4u32]))
+//!         : ($dyn$1 ? ($dyn$0 ? a[3u32] : a[2u32]) : ($dyn$0 ? a[1u32] : a[0u32]));
+//! }
+//! ```
+
+mod index_expression;
+
+mod index_program;
+
+mod index_statement;
+
+pub mod indexer;
+pub use indexer::*;
+
+use crate::{Assigner, Pass, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for DynamicIndexer<'a> {
+    type Input = (Ast, &'a NodeBuilder, &'a Assigner, &'a TypeTable);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder, assigner, tt): Self::Input) -> Self::Output {
+        let mut reconstructor = DynamicIndexer::new(node_builder, assigner, tt);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
@@ -68,7 +68,16 @@ impl ExpressionReconstructor for Flattener<'_> {
     /// let var$2 = Foo { bar: var$0, baz: var$1 };
     /// var$2
     /// ```
+    ///
+    /// If both expressions have the same value, e.g. when both branches of a conditional statement assign the same value to a variable,
+    /// then no selection is necessary and the ternary expression is replaced by the first expression.
     fn reconstruct_ternary(&mut self, input: TernaryExpression) -> (Expression, Self::AdditionalOutput) {
+        if let (Some(first), Some(second)) = (self.value_of(&input.if_true), self.value_of(&input.if_false)) {
+            if first.to_string() == second.to_string() {
+                return (*input.if_true, Default::default());
+            }
+        }
+
         let mut statements = Vec::new();
         match (*input.if_true, *input.if_false) {
             // If both expressions are identifiers which are arrays, construct ternary expressions for each of the members and an array expression for the result.
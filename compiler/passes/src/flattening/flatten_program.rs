@@ -62,6 +62,7 @@ impl ProgramReconstructor for Flattener<'_> {
             output_type: function.output_type,
             block,
             finalize,
+            receiver: function.receiver,
             span: function.span,
             id: function.id,
         }
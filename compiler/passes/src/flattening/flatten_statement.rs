@@ -29,6 +29,7 @@ use leo_ast::{
     Expression,
     ExpressionReconstructor,
     IterationStatement,
+    Literal,
     Node,
     ReturnStatement,
     Statement,
@@ -58,6 +59,8 @@ impl StatementReconstructor for Flattener<'_> {
     /// assert(!(condition1 && condition2) || foo);
     /// ```
     /// which is equivalent to the logical formula `(condition1 /\ condition2) ==> foo`.
+    /// Similarly, an assert statement that follows an early return is only enforced if the return was not taken.
+    /// A `fail` or `abort` statement is flattened to an assertion of `false` with the same guard, i.e. `assert(!(condition1 && condition2))`.
     fn reconstruct_assert(&mut self, input: AssertStatement) -> (Statement, Self::AdditionalOutput) {
         let mut statements = Vec::new();
 
@@ -85,17 +88,42 @@ impl StatementReconstructor for Flattener<'_> {
                     statements.extend(additional_statements);
                     AssertVariant::AssertNeq(left, right)
                 }
+                AssertVariant::Fail(reason) => AssertVariant::Fail(reason),
             },
         };
 
         // Add the appropriate guards.
-        match self.construct_guard() {
-            // If the condition stack is empty, we can return the flattened assert statement.
+        let (exemption, exemption_statements) = self.construct_assert_exemption();
+        statements.extend(exemption_statements);
+
+        // A `fail` or `abort` statement asserts `!guard || false`, i.e. the exemption, so that its execution path is unsatisfiable.
+        if let AssertVariant::Fail(_) = assert.variant {
+            let expression = exemption.unwrap_or_else(|| {
+                Expression::Literal(Literal::Boolean(false, input.span, {
+                    // Create a new node ID for the literal.
+                    let id = self.node_builder.next_id();
+                    // Update the type table with the type of the literal.
+                    self.type_table.insert(id, Type::Boolean);
+                    id
+                }))
+            });
+            return (
+                Statement::Assert(AssertStatement {
+                    span: input.span,
+                    id: input.id,
+                    variant: AssertVariant::Assert(expression),
+                }),
+                statements,
+            );
+        }
+
+        match exemption {
+            // If the condition stack is empty and no early return was taken, we can return the flattened assert statement.
             None => (Statement::Assert(assert), statements),
             // Otherwise, we need to join the guard with the expression in the flattened assert statement.
             // Note given the guard and the expression, we construct the logical formula `guard => expression`,
-            // which is equivalent to `!guard || expression`.
-            Some(guard) => (
+            // which is equivalent to `!guard || expression`, where `!guard` is the exemption.
+            Some(exemption) => (
                 Statement::Assert(AssertStatement {
                     span: input.span,
                     id: input.id,
@@ -109,19 +137,7 @@ impl StatementReconstructor for Flattener<'_> {
                             self.type_table.insert(id, Type::Boolean);
                             id
                         },
-                        // Take the logical negation of the guard.
-                        left: Box::new(Expression::Unary(UnaryExpression {
-                            op: UnaryOperation::Not,
-                            receiver: Box::new(guard),
-                            span: Default::default(),
-                            id: {
-                                // Create a new node ID for the unary expression.
-                                let id = self.node_builder.next_id();
-                                // Update the type table with the type of the unary expression.
-                                self.type_table.insert(id, Type::Boolean);
-                                id
-                            },
-                        })),
+                        left: Box::new(exemption),
                         right: Box::new(match assert.variant {
                             // If the assert statement is an `assert`, use the expression as is.
                             AssertVariant::Assert(expression) => expression,
@@ -153,6 +169,7 @@ impl StatementReconstructor for Flattener<'_> {
                                     id
                                 },
                             }),
+                            AssertVariant::Fail(_) => unreachable!("`fail` and `abort` are flattened above."),
                         }),
                     })),
                 }),
@@ -44,8 +44,13 @@ use leo_ast::{
     TupleExpression,
     TupleType,
     Type,
+    UnaryExpression,
+    UnaryOperation,
     UnitExpression,
 };
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
 
 pub struct Flattener<'a> {
     /// The symbol table associated with the program.
@@ -63,6 +68,13 @@ pub struct Flattener<'a> {
     /// Note that returns are inserted in the order they are encountered during a pre-order traversal of the AST.
     /// Note that type checking guarantees that there is at most one return in a basic block.
     pub(crate) returns: Vec<(Option<Expression>, ReturnStatement)>,
+    /// A failure flag that is true if one of the early returns in `returns` was taken, e.g. by the `?` operator,
+    /// together with the number of returns that it accumulates.
+    /// Statements that follow an early return, such as assertions, are only enforced if the flag is false.
+    pub(crate) has_returned: Option<(usize, Identifier)>,
+    /// A mapping from variables to their values, for variables assigned literals, other variables, member accesses, or struct expressions.
+    /// Note that SSA guarantees that each variable is assigned exactly once.
+    pub(crate) values: IndexMap<Symbol, Expression>,
 }
 
 impl<'a> Flattener<'a> {
@@ -72,11 +84,21 @@ impl<'a> Flattener<'a> {
         node_builder: &'a NodeBuilder,
         assigner: &'a Assigner,
     ) -> Self {
-        Self { symbol_table, type_table, node_builder, assigner, condition_stack: Vec::new(), returns: Vec::new() }
+        Self {
+            symbol_table,
+            type_table,
+            node_builder,
+            assigner,
+            condition_stack: Vec::new(),
+            returns: Vec::new(),
+            has_returned: None,
+            values: IndexMap::new(),
+        }
     }
 
     /// Clears the state associated with `ReturnStatements`, returning the ones that were previously stored.
     pub(crate) fn clear_early_returns(&mut self) -> Vec<(Option<Expression>, ReturnStatement)> {
+        self.has_returned = None;
         core::mem::take(&mut self.returns)
     }
 
@@ -106,6 +128,63 @@ impl<'a> Flattener<'a> {
         }
     }
 
+    /// Constructs the condition under which an assertion is not enforced, i.e. the negation of its guard.
+    /// An assertion is exempt if its execution path is not taken, or if an early return was taken before it.
+    /// The guards of the early returns are accumulated into a failure flag, which is assigned to a new variable when it changes.
+    pub(crate) fn construct_assert_exemption(&mut self) -> (Option<Expression>, Vec<Statement>) {
+        let mut statements = Vec::new();
+
+        // Update the failure flag if an early return was encountered since it was last constructed.
+        let number_of_returns = self.returns.len();
+        if self.has_returned.map_or(0, |(count, _)| count) < number_of_returns {
+            let guards = self.returns.iter().filter_map(|(guard, _)| guard.clone()).collect::<Vec<_>>();
+            if let Some(flag) = self.disjunction(guards) {
+                let (flag, statement) = self.unique_simple_assign_statement(flag);
+                statements.push(statement);
+                self.has_returned = Some((number_of_returns, flag));
+            }
+        }
+
+        // Take the logical negation of the guard.
+        let not_guard = self.construct_guard().map(|guard| {
+            Expression::Unary(UnaryExpression {
+                op: UnaryOperation::Not,
+                receiver: Box::new(guard),
+                span: Default::default(),
+                id: {
+                    // Create a new node ID for the unary expression.
+                    let id = self.node_builder.next_id();
+                    // Update the type table with the type of the unary expression.
+                    self.type_table.insert(id, Type::Boolean);
+                    id
+                },
+            })
+        });
+        let has_returned = self.has_returned.map(|(_, flag)| Expression::Identifier(flag));
+
+        (self.disjunction(not_guard.into_iter().chain(has_returned).collect()), statements)
+    }
+
+    /// Constructs the disjunction of the given boolean expressions, if there are any.
+    fn disjunction(&mut self, expressions: Vec<Expression>) -> Option<Expression> {
+        let (first, rest) = expressions.split_first()?;
+        Some(rest.iter().cloned().fold(first.clone(), |acc, expression| {
+            Expression::Binary(BinaryExpression {
+                op: BinaryOperation::Or,
+                left: Box::new(acc),
+                right: Box::new(expression),
+                span: Default::default(),
+                id: {
+                    // Create a new node ID for the binary expression.
+                    let id = self.node_builder.next_id();
+                    // Set the type of the node ID.
+                    self.type_table.insert(id, Type::Boolean);
+                    id
+                },
+            })
+        }))
+    }
+
     /// Fold guards and expressions into a single expression.
     /// Note that this function assumes that at least one guard is present.
     pub(crate) fn fold_guards(
@@ -195,10 +274,61 @@ impl<'a> Flattener<'a> {
             None => unreachable!("Type checking guarantees that all expressions have a type."),
         };
         self.type_table.insert(lhs.id(), type_);
+        // Record the value of the lhs, if it is known.
+        if let Some(value) = self.value_of(&rhs) {
+            self.values.insert(lhs.name, value);
+        }
         // Construct the statement.
         self.assigner.simple_assign_statement(lhs, rhs, self.node_builder.next_id())
     }
 
+    /// Returns the value of an expression in terms of literals, variables, and member accesses, if it is known.
+    /// Expressions with the same value always evaluate to the same result, so a selection between them is unnecessary.
+    pub(crate) fn value_of(&self, expression: &Expression) -> Option<Expression> {
+        match expression {
+            Expression::Literal(_) => Some(expression.clone()),
+            // A variable whose value is unknown is its own value.
+            Expression::Identifier(identifier) => {
+                Some(self.values.get(&identifier.name).cloned().unwrap_or_else(|| expression.clone()))
+            }
+            Expression::Access(AccessExpression::Member(access)) => match self.value_of(&access.inner)? {
+                // If the inner expression is a struct expression, then the value is that of the accessed member.
+                Expression::Struct(struct_) => struct_
+                    .members
+                    .into_iter()
+                    .find(|member| member.identifier.name == access.name.name)
+                    .and_then(|member| member.expression),
+                inner => Some(Expression::Access(AccessExpression::Member(MemberAccess {
+                    inner: Box::new(inner),
+                    name: access.name,
+                    span: access.span,
+                    id: access.id,
+                }))),
+            },
+            Expression::Struct(struct_) => {
+                let members = struct_
+                    .members
+                    .iter()
+                    .map(|member| {
+                        Some(StructVariableInitializer {
+                            identifier: member.identifier,
+                            expression: Some(self.value_of(member.expression.as_ref()?)?),
+                            span: member.span,
+                            id: member.id,
+                        })
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Some(Expression::Struct(StructExpression {
+                    name: struct_.name,
+                    members,
+                    span: struct_.span,
+                    id: struct_.id,
+                }))
+            }
+            _ => None,
+        }
+    }
+
     /// Folds a list of return statements into a single return statement and adds the produced statements to the block.
     pub(crate) fn fold_returns(&mut self, block: &mut Block, returns: Vec<(Option<Expression>, ReturnStatement)>) {
         // If the list of returns is not empty, then fold them into a single return statement.
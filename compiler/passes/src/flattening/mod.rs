@@ -18,6 +18,8 @@
 //! The pass flattens `ConditionalStatement`s into a sequence of `AssignStatement`s.
 //! The pass rewrites `ReturnStatement`s into `AssignStatement`s and consolidates the returned values as a single `ReturnStatement` at the end of the function.
 //! The pass rewrites ternary expressions over composite data types, into ternary expressions over the individual fields of the composite data type, followed by an expression constructing the composite data type.
+//! The pass removes ternary expressions whose branches have the same value, so that a variable assigned the same value in both branches of a conditional statement does not require a selection.
+//! The pass rewrites `fail` and `abort` statements into assertions of `false`, which are only enforced if their execution path is taken.
 //!
 //! Consider the following Leo code, output by the SSA pass.
 //! ```leo
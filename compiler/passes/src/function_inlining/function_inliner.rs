@@ -18,6 +18,7 @@ use crate::{Assigner, AssignmentRenamer, CallGraph, TypeTable};
 
 use leo_ast::{Function, NodeBuilder};
 use leo_span::Symbol;
+use std::time::Instant;
 
 pub struct FunctionInliner<'a> {
     /// A counter used to create unique NodeIDs.
@@ -30,6 +31,10 @@ pub struct FunctionInliner<'a> {
     pub(crate) type_table: &'a TypeTable,
     /// A map of reconstructed functions in the current program scope.
     pub(crate) reconstructed_functions: Vec<(Symbol, Function)>,
+    /// The time by which inlining must end, if any.
+    pub(crate) deadline: Option<Instant>,
+    /// Whether the deadline has passed, after which no further calls are inlined.
+    pub(crate) deadline_passed: bool,
 }
 
 impl<'a> FunctionInliner<'a> {
@@ -39,6 +44,7 @@ impl<'a> FunctionInliner<'a> {
         call_graph: &'a CallGraph,
         assigner: &'a Assigner,
         type_table: &'a TypeTable,
+        deadline: Option<Instant>,
     ) -> Self {
         Self {
             node_builder,
@@ -46,6 +52,8 @@ impl<'a> FunctionInliner<'a> {
             assignment_renamer: AssignmentRenamer::new(assigner),
             reconstructed_functions: Default::default(),
             type_table,
+            deadline,
+            deadline_passed: false,
         }
     }
 }
@@ -31,6 +31,7 @@ use leo_ast::{
 
 use indexmap::IndexMap;
 use itertools::Itertools;
+use std::time::Instant;
 
 impl ExpressionReconstructor for FunctionInliner<'_> {
     type AdditionalOutput = Vec<Statement>;
@@ -41,6 +42,12 @@ impl ExpressionReconstructor for FunctionInliner<'_> {
             return (Expression::Call(input), Default::default());
         }
 
+        // Once the deadline has passed, the calls are left as they are, since the pass fails.
+        if self.deadline_passed || self.deadline.map_or(false, |deadline| Instant::now() > deadline) {
+            self.deadline_passed = true;
+            return (Expression::Call(input), Default::default());
+        }
+
         // Get the name of the callee function.
         let function_name = match *input.function {
             Expression::Identifier(identifier) => identifier.name,
@@ -67,15 +67,19 @@ pub use function_inliner::*;
 use crate::{Assigner, CallGraph, Pass, TypeTable};
 
 use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
-use leo_errors::Result;
+use leo_errors::{CompilerError, Result};
+use std::time::Instant;
 
 impl<'a> Pass for FunctionInliner<'a> {
-    type Input = (Ast, &'a NodeBuilder, &'a CallGraph, &'a Assigner, &'a TypeTable);
+    type Input = (Ast, &'a NodeBuilder, &'a CallGraph, &'a Assigner, &'a TypeTable, Option<Instant>);
     type Output = Result<Ast>;
 
-    fn do_pass((ast, node_builder, call_graph, assigner, tt): Self::Input) -> Self::Output {
-        let mut reconstructor = FunctionInliner::new(node_builder, call_graph, assigner, tt);
+    fn do_pass((ast, node_builder, call_graph, assigner, tt, deadline): Self::Input) -> Self::Output {
+        let mut reconstructor = FunctionInliner::new(node_builder, call_graph, assigner, tt, deadline);
         let program = reconstructor.reconstruct_program(ast.into_repr());
+        if reconstructor.deadline_passed {
+            return Err(CompilerError::time_limit_exceeded("function inlining").into());
+        }
 
         Ok(Ast::new(program))
     }
@@ -0,0 +1,135 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{merge, Flow, InformationFlowAnalyzer, Taint};
+
+use leo_ast::*;
+
+impl<'a> ExpressionVisitor<'a> for InformationFlowAnalyzer<'a> {
+    type AdditionalInput = ();
+    type Output = Flow;
+
+    fn visit_access(&mut self, input: &'a AccessExpression, _: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(access) => {
+                let array = self.visit_expression(&access.array, &());
+                let index = self.visit_expression(&access.index, &());
+                array.join(index.through("index"))
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                let operation = format!("{}::{}", function.ty, function.name);
+                function
+                    .arguments
+                    .iter()
+                    .fold(Flow::default(), |flow, argument| flow.join(self.visit_expression(argument, &())))
+                    .through(&operation)
+            }
+            AccessExpression::Member(access) => match self.visit_expression(&access.inner, &()) {
+                Flow::Struct(mut members) => members.shift_remove(&access.name.name).unwrap_or_default(),
+                flow => flow,
+            },
+            AccessExpression::Tuple(access) => match self.visit_expression(&access.tuple, &()) {
+                Flow::Tuple(mut elements) if access.index.value() < elements.len() => {
+                    elements.swap_remove(access.index.value())
+                }
+                flow => flow,
+            },
+            AccessExpression::AssociatedConstant(_) => Flow::default(),
+        }
+    }
+
+    fn visit_array(&mut self, input: &'a ArrayExpression, _: &Self::AdditionalInput) -> Self::Output {
+        // The elements of an array are not tracked separately.
+        Flow::Value(
+            input
+                .elements
+                .iter()
+                .fold(Taint::new(), |taint, element| merge(taint, self.visit_expression(element, &()).taint())),
+        )
+    }
+
+    fn visit_binary(&mut self, input: &'a BinaryExpression, _: &Self::AdditionalInput) -> Self::Output {
+        let left = self.visit_expression(&input.left, &());
+        let right = self.visit_expression(&input.right, &());
+        left.join(right).through(&input.op.to_string())
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, _: &Self::AdditionalInput) -> Self::Output {
+        let operation = match &input.external {
+            Some(external) => format!("call {external}/{}", input.function),
+            None => format!("call {}", input.function),
+        };
+        // The receiver of a method call is an argument as well.
+        let receiver = match &*input.function {
+            Expression::Access(AccessExpression::Member(access)) => self.visit_expression(&access.inner, &()),
+            _ => Flow::default(),
+        };
+        input
+            .arguments
+            .iter()
+            .fold(receiver, |flow, argument| flow.join(self.visit_expression(argument, &())))
+            .through(&operation)
+    }
+
+    fn visit_cast(&mut self, input: &'a CastExpression, _: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.expression, &()).through("cast")
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, _: &Self::AdditionalInput) -> Self::Output {
+        Flow::Struct(
+            input
+                .members
+                .iter()
+                .map(|member| {
+                    let flow = match &member.expression {
+                        Some(expression) => self.visit_expression(expression, &()),
+                        None => self.visit_identifier(&member.identifier, &()),
+                    };
+                    (member.identifier.name, flow)
+                })
+                .collect(),
+        )
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _: &Self::AdditionalInput) -> Self::Output {
+        self.variables.get(&input.name).cloned().unwrap_or_default()
+    }
+
+    fn visit_ternary(&mut self, input: &'a TernaryExpression, _: &Self::AdditionalInput) -> Self::Output {
+        let condition = self.visit_expression(&input.condition, &()).through("condition");
+        let if_true = self.visit_expression(&input.if_true, &());
+        let if_false = self.visit_expression(&input.if_false, &());
+        // The condition is joined with each member of a struct, so that the members are still tracked separately.
+        let join_condition = |flow: Flow| match flow {
+            Flow::Struct(members) => {
+                Flow::Struct(members.into_iter().map(|(name, member)| (name, member.join(condition.clone()))).collect())
+            }
+            Flow::Tuple(elements) => {
+                Flow::Tuple(elements.into_iter().map(|element| element.join(condition.clone())).collect())
+            }
+            flow => flow.join(condition.clone()),
+        };
+        join_condition(if_true.join(if_false))
+    }
+
+    fn visit_tuple(&mut self, input: &'a TupleExpression, _: &Self::AdditionalInput) -> Self::Output {
+        Flow::Tuple(input.elements.iter().map(|element| self.visit_expression(element, &())).collect())
+    }
+
+    fn visit_unary(&mut self, input: &'a UnaryExpression, _: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.receiver, &()).through(input.op.as_str())
+    }
+}
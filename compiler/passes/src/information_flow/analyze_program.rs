@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{is_public_mode, Flow, InformationFlowAnalyzer, TransitionFlows};
+
+use leo_ast::*;
+
+impl<'a> ProgramVisitor<'a> for InformationFlowAnalyzer<'a> {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        self.program = input.program_id.to_string();
+        self.structs = input.structs.iter().map(|(name, struct_)| (*name, struct_)).collect();
+
+        // Only transitions have inputs and outputs that are visible on chain.
+        input
+            .functions
+            .iter()
+            .filter(|(_, function)| function.variant == Variant::Transition)
+            .for_each(|(_, function)| self.visit_function(function));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        // The private inputs of the transition are the sources.
+        self.variables.clear();
+        for input in input.input.iter() {
+            if let Input::Internal(input) = input {
+                let flow =
+                    self.input_flow(input.identifier.name.to_string(), &input.type_, !is_public_mode(input.mode));
+                self.variables.insert(input.identifier.name, flow);
+            }
+        }
+
+        self.returned = None;
+        self.visit_block(&input.block);
+        let (output, finalize_arguments) = self.returned.take().unwrap_or_default();
+
+        // The public outputs and the finalize arguments of the transition are the sinks.
+        let mut flows = Vec::new();
+        let outputs = match output {
+            Flow::Tuple(elements) if elements.len() == input.output.len() => elements,
+            output => vec![output; input.output.len()],
+        };
+        for (index, (output, flow)) in input.output.iter().zip(outputs.iter()).enumerate() {
+            if let Output::Internal(output) = output {
+                self.output_flows(
+                    format!("output {index}"),
+                    &output.type_,
+                    is_public_mode(output.mode),
+                    flow,
+                    &mut flows,
+                );
+            }
+        }
+        if let Some(finalize) = &input.finalize {
+            for (input, flow) in finalize.input.iter().zip(finalize_arguments.iter()) {
+                self.output_flows(format!("finalize {}", input.identifier()), &input.type_(), true, flow, &mut flows);
+            }
+        }
+
+        self.report.transitions.push(TransitionFlows {
+            program: self.program.clone(),
+            name: input.identifier.to_string(),
+            flows,
+        });
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Flow, InformationFlowAnalyzer};
+
+use leo_ast::*;
+
+impl InformationFlowAnalyzer<'_> {
+    /// Records the flow into the variables defined or assigned by `place`.
+    fn bind(&mut self, place: &Expression, flow: Flow) {
+        match place {
+            Expression::Identifier(identifier) => {
+                self.variables.insert(identifier.name, flow);
+            }
+            Expression::Tuple(tuple) => {
+                let flows = match flow {
+                    Flow::Tuple(elements) if elements.len() == tuple.elements.len() => elements,
+                    flow => vec![Flow::Value(flow.taint()); tuple.elements.len()],
+                };
+                tuple.elements.iter().zip(flows).for_each(|(element, flow)| self.bind(element, flow));
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for InformationFlowAnalyzer<'a> {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        let flow = self.visit_expression(&input.value, &());
+        self.bind(&input.place, flow);
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        let flow = self.visit_expression(&input.value, &());
+        self.bind(&input.place, flow);
+    }
+
+    fn visit_return(&mut self, input: &'a ReturnStatement) {
+        let output = self.visit_expression(&input.expression, &());
+        let finalize_arguments = match &input.finalize_arguments {
+            Some(arguments) => arguments.iter().map(|argument| self.visit_expression(argument, &())).collect(),
+            None => Vec::new(),
+        };
+        self.returned = Some((output, finalize_arguments));
+    }
+}
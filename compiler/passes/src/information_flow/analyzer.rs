@@ -0,0 +1,181 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{InformationFlowReport, PrivateFlow};
+
+use leo_ast::{Mode, Struct, Type};
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+
+/// The private inputs that a value depends on, along with the operations through which each of them flows into it.
+pub type Taint = IndexMap<String, IndexSet<String>>;
+
+/// The information flow into a value.
+#[derive(Clone, Debug)]
+pub enum Flow {
+    /// A value whose parts are not tracked separately.
+    Value(Taint),
+    /// A struct or record, with the flow into each of its members.
+    Struct(IndexMap<Symbol, Flow>),
+    /// A tuple, with the flow into each of its elements.
+    Tuple(Vec<Flow>),
+}
+
+impl Default for Flow {
+    fn default() -> Self {
+        Flow::Value(Taint::new())
+    }
+}
+
+impl Flow {
+    /// Returns the private inputs that any part of the value depends on.
+    pub fn taint(&self) -> Taint {
+        match self {
+            Flow::Value(taint) => taint.clone(),
+            Flow::Struct(members) => members.values().fold(Taint::new(), |taint, member| merge(taint, member.taint())),
+            Flow::Tuple(elements) => elements.iter().fold(Taint::new(), |taint, element| merge(taint, element.taint())),
+        }
+    }
+
+    /// Returns the flow into a value computed from this one by `operation`.
+    pub fn through(&self, operation: &str) -> Flow {
+        let mut taint = self.taint();
+        taint.values_mut().for_each(|operations| {
+            operations.insert(operation.to_string());
+        });
+        Flow::Value(taint)
+    }
+
+    /// Returns the flow into a value that depends on both this value and `other`.
+    /// The members of structs and the elements of tuples are combined pairwise.
+    pub fn join(self, other: Flow) -> Flow {
+        match (self, other) {
+            (Flow::Struct(mut left), Flow::Struct(right)) if left.len() == right.len() => {
+                for (name, flow) in right {
+                    let member = left.shift_remove(&name).unwrap_or_default();
+                    left.insert(name, member.join(flow));
+                }
+                Flow::Struct(left)
+            }
+            (Flow::Tuple(left), Flow::Tuple(right)) if left.len() == right.len() => {
+                Flow::Tuple(left.into_iter().zip(right).map(|(left, right)| left.join(right)).collect())
+            }
+            (left, right) => Flow::Value(merge(left.taint(), right.taint())),
+        }
+    }
+}
+
+/// Merges the dependencies in `other` into `taint`.
+pub fn merge(mut taint: Taint, other: Taint) -> Taint {
+    for (source, operations) in other {
+        taint.entry(source).or_default().extend(operations);
+    }
+    taint
+}
+
+pub struct InformationFlowAnalyzer<'a> {
+    /// The report, to which the dependencies of each transition are added.
+    pub(crate) report: InformationFlowReport,
+    /// The name of the current program, e.g. `token.aleo`.
+    pub(crate) program: String,
+    /// The structs and records of the current program.
+    pub(crate) structs: IndexMap<Symbol, &'a Struct>,
+    /// The flow into each variable of the current transition.
+    pub(crate) variables: IndexMap<Symbol, Flow>,
+    /// The flows into the output and the finalize arguments of the return statement of the current transition.
+    pub(crate) returned: Option<(Flow, Vec<Flow>)>,
+}
+
+impl InformationFlowAnalyzer<'_> {
+    /// Initializes a new `InformationFlowAnalyzer`.
+    pub fn new() -> Self {
+        Self {
+            report: Default::default(),
+            program: Default::default(),
+            structs: Default::default(),
+            variables: Default::default(),
+            returned: None,
+        }
+    }
+
+    /// Returns the flow into an input named `name`, whose private parts are sources.
+    /// The members of a record are private unless they are declared `public`.
+    pub(crate) fn input_flow(&self, name: String, type_: &Type, is_private: bool) -> Flow {
+        match type_ {
+            Type::Identifier(identifier) if self.structs.contains_key(&identifier.name) => Flow::Struct(
+                self.structs[&identifier.name]
+                    .members
+                    .iter()
+                    .map(|member| {
+                        let is_private = is_private && !is_public_mode(member.mode);
+                        let flow = self.input_flow(format!("{name}.{}", member.name()), &member.type_, is_private);
+                        (member.name(), flow)
+                    })
+                    .collect(),
+            ),
+            _ if is_private => Flow::Value(Taint::from([(name, IndexSet::new())])),
+            _ => Flow::default(),
+        }
+    }
+
+    /// Adds the dependencies of an output named `sink` on the private inputs to `flows`.
+    /// The members of a record are public if they are declared `public`.
+    pub(crate) fn output_flows(
+        &self,
+        sink: String,
+        type_: &Type,
+        is_public: bool,
+        flow: &Flow,
+        flows: &mut Vec<PrivateFlow>,
+    ) {
+        match type_ {
+            Type::Identifier(identifier) if self.structs.contains_key(&identifier.name) => {
+                for member in self.structs[&identifier.name].members.iter() {
+                    let member_flow = match flow {
+                        Flow::Struct(members) => members.get(&member.name()).cloned().unwrap_or_default(),
+                        flow => flow.clone(),
+                    };
+                    let is_public = is_public || is_public_mode(member.mode);
+                    self.output_flows(
+                        format!("{sink}.{}", member.name()),
+                        &member.type_,
+                        is_public,
+                        &member_flow,
+                        flows,
+                    );
+                }
+            }
+            _ if is_public => flows.extend(flow.taint().into_iter().map(|(source, operations)| PrivateFlow {
+                source,
+                sink: sink.clone(),
+                operations: operations.into_iter().collect(),
+            })),
+            _ => {}
+        }
+    }
+}
+
+impl Default for InformationFlowAnalyzer<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns `true` if a value with the given mode is visible on chain.
+pub(crate) fn is_public_mode(mode: Mode) -> bool {
+    matches!(mode, Mode::Public | Mode::Constant)
+}
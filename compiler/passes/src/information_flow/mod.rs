@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Information Flow pass reports, for each transition, which of its public outputs depend on which of its private
+//! inputs, and through which operations. It is run on the AST produced by the Function Inlining pass, in which each
+//! transition is straight-line code ending in a single return statement, so that every dependency is explicit.
+//!
+//! The sources are the private inputs of a transition, and the private members of its input records.
+//! The sinks are its public outputs, the public members of its output records, and the arguments to its finalize
+//! block, which are published on chain.
+//! A dependency through the condition of a ternary expression is reported as well, since the outcome of the
+//! condition can be observed in the output.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(secret: field, public salt: field) -> public field {
+//!     return BHP256::hash_to_field(secret + salt);
+//! }
+//! ```
+//!
+//! The pass reports that `output 0` depends on `secret`, through `+` and `BHP256::hash_to_field`.
+//! It does not modify the AST.
+
+mod analyze_expressions;
+
+mod analyze_program;
+
+mod analyze_statements;
+
+pub mod analyzer;
+pub use analyzer::*;
+
+pub mod report;
+pub use report::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::Result;
+
+impl<'a> Pass for InformationFlowAnalyzer<'a> {
+    type Input = &'a Ast;
+    type Output = Result<InformationFlowReport>;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        let mut visitor = InformationFlowAnalyzer::new();
+        visitor.visit_program(ast.as_repr());
+
+        Ok(visitor.report)
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use serde::Serialize;
+use std::fmt;
+
+/// A dependency of a public output on a private input.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PrivateFlow {
+    /// The private input, e.g. `amount` or `token.amount`.
+    pub source: String,
+    /// The public output, e.g. `output 0`, `output 1.amount`, or `finalize amount`.
+    pub sink: String,
+    /// The operations through which the input flows into the output, in the order in which they were found.
+    pub operations: Vec<String>,
+}
+
+/// The dependencies of the public outputs of a transition on its private inputs.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct TransitionFlows {
+    /// The program that defines the transition, e.g. `token.aleo`.
+    pub program: String,
+    /// The name of the transition.
+    pub name: String,
+    /// The dependencies, ordered by output.
+    pub flows: Vec<PrivateFlow>,
+}
+
+/// The information flow report of a program and its imports.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct InformationFlowReport {
+    pub transitions: Vec<TransitionFlows>,
+}
+
+impl InformationFlowReport {
+    /// Serializes the report into a JSON string.
+    pub fn to_json_string(&self) -> String {
+        // Note that this unwrap is safe since the report only contains strings.
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+impl fmt::Display for InformationFlowReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for transition in self.transitions.iter() {
+            writeln!(f, "{}/{}:", transition.program, transition.name)?;
+            if transition.flows.is_empty() {
+                writeln!(f, "    no private input flows into a public output")?;
+            }
+            for flow in transition.flows.iter() {
+                match flow.operations.is_empty() {
+                    true => writeln!(f, "    {} <- {}", flow.sink, flow.source)?,
+                    false => writeln!(f, "    {} <- {} (via {})", flow.sink, flow.source, flow.operations.join(", "))?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -17,6 +17,9 @@
 #![forbid(unsafe_code)]
 #![doc = include_str!("../README.md")]
 
+pub mod boolean_simplification;
+pub use boolean_simplification::*;
+
 pub mod code_generation;
 pub use code_generation::*;
 
@@ -29,18 +32,45 @@ pub use dead_code_elimination::*;
 pub mod destructuring;
 pub use destructuring::*;
 
+pub mod dynamic_indexing;
+pub use dynamic_indexing::*;
+
 pub mod flattening;
 pub use flattening::*;
 
 pub mod function_inlining;
 pub use function_inlining::*;
 
+pub mod information_flow;
+pub use information_flow::*;
+
+pub mod loop_invariant_code_motion;
+pub use loop_invariant_code_motion::*;
+
 pub mod loop_unrolling;
 pub use self::loop_unrolling::*;
 
+pub mod move_checking;
+pub use move_checking::*;
+
+pub mod name_resolution;
+pub use name_resolution::*;
+
+pub mod option_lowering;
+pub use option_lowering::*;
+
 pub mod pass;
 pub use self::pass::*;
 
+pub mod pretty_printing;
+pub use pretty_printing::*;
+
+pub mod semantic_highlighting;
+pub use semantic_highlighting::*;
+
+pub mod static_analysis;
+pub use static_analysis::*;
+
 pub mod static_single_assignment;
 pub use static_single_assignment::*;
 
@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::LoopInvariantHoister;
+
+use leo_ast::*;
+
+impl ExpressionReconstructor for LoopInvariantHoister<'_> {
+    type AdditionalOutput = Vec<Statement>;
+
+    fn reconstruct_expression(&mut self, input: Expression) -> (Expression, Self::AdditionalOutput) {
+        // Only expressions that add constraints to the circuit are worth hoisting.
+        let is_computation = matches!(
+            input,
+            Expression::Access(AccessExpression::AssociatedFunction(_))
+                | Expression::Binary(_)
+                | Expression::Cast(_)
+                | Expression::Ternary(_)
+                | Expression::Unary(_)
+        );
+        if self.is_hoisting && is_computation && self.is_invariant(&input) {
+            if let Some(type_) = self.type_table.get(&input.id()) {
+                return (self.hoist(input, type_), Default::default());
+            }
+        }
+
+        match input {
+            Expression::Access(access) => self.reconstruct_access(access),
+            Expression::Array(array) => self.reconstruct_array(array),
+            Expression::Binary(binary) => self.reconstruct_binary(binary),
+            Expression::Call(call) => self.reconstruct_call(call),
+            Expression::Cast(cast) => self.reconstruct_cast(cast),
+            Expression::Struct(struct_) => self.reconstruct_struct_init(struct_),
+            Expression::Err(err) => self.reconstruct_err(err),
+            Expression::Identifier(identifier) => self.reconstruct_identifier(identifier),
+            Expression::Literal(value) => self.reconstruct_literal(value),
+            Expression::Ternary(ternary) => self.reconstruct_ternary(ternary),
+            Expression::Tuple(tuple) => self.reconstruct_tuple(tuple),
+            Expression::Unary(unary) => self.reconstruct_unary(unary),
+            Expression::Unit(unit) => self.reconstruct_unit(unit),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{assigned_variables, LoopInvariantHoister};
+
+use leo_ast::*;
+
+impl StatementReconstructor for LoopInvariantHoister<'_> {
+    /// Inserts the definitions of the expressions hoisted out of a loop before the loop.
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        let mut statements = Vec::with_capacity(input.statements.len());
+        for statement in input.statements {
+            let (statement, hoisted) = self.reconstruct_statement(statement);
+            statements.extend(hoisted);
+            statements.push(statement);
+        }
+
+        (Block { statements, span: input.span, id: input.id }, Default::default())
+    }
+
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
+        // Record the constant, since it may be used as a loop bound.
+        if let Expression::Literal(literal) = &input.value {
+            self.constants.insert(input.place.name, literal.clone());
+        }
+        (Statement::Const(input), Default::default())
+    }
+
+    /// Returns the loop and the definitions of the expressions hoisted out of it.
+    fn reconstruct_iteration(&mut self, input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
+        // Expressions are hoisted out of one loop at a time.
+        // Nested loops have already been processed, so their bodies do not contain expressions that can be hoisted.
+        if self.is_hoisting {
+            return (Statement::Iteration(Box::new(input)), Default::default());
+        }
+
+        let executes_at_least_once = self.executes_at_least_once(&input);
+
+        // First, hoist expressions out of the nested loops.
+        let mut block = self.reconstruct_block(input.block).0;
+
+        let mut hoisted = Vec::new();
+        if executes_at_least_once {
+            self.variant = assigned_variables(&block);
+            self.variant.insert(input.variable.name);
+            self.hoisted_variables.clear();
+
+            self.is_hoisting = true;
+            block = self.reconstruct_block(block).0;
+            self.is_hoisting = false;
+
+            hoisted = std::mem::take(&mut self.hoisted);
+        }
+
+        (
+            Statement::Iteration(Box::new(IterationStatement {
+                variable: input.variable,
+                type_: input.type_,
+                start: input.start,
+                start_value: input.start_value,
+                stop: input.stop,
+                stop_value: input.stop_value,
+                block,
+                inclusive: input.inclusive,
+                span: input.span,
+                id: input.id,
+            })),
+            hoisted,
+        )
+    }
+}
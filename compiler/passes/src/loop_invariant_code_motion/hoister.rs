@@ -0,0 +1,193 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Assigner, TypeTable};
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+
+pub struct LoopInvariantHoister<'a> {
+    /// A counter to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// A struct used to construct (unique) variable names.
+    pub(crate) assigner: &'a Assigner,
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// The values of the constants declared so far.
+    pub(crate) constants: IndexMap<Symbol, Literal>,
+    /// The variables that are defined or assigned in the loop whose body is being hoisted from.
+    pub(crate) variant: IndexSet<Symbol>,
+    /// Whether or not invariant expressions are currently hoisted.
+    pub(crate) is_hoisting: bool,
+    /// The definitions of the hoisted expressions, in the order in which they were hoisted.
+    pub(crate) hoisted: Vec<Statement>,
+    /// A mapping from hoisted expressions and their types to the variables that hold them.
+    /// This ensures that an expression that occurs more than once in a loop body is only hoisted once.
+    pub(crate) hoisted_variables: IndexMap<String, Symbol>,
+}
+
+impl<'a> LoopInvariantHoister<'a> {
+    /// Initializes a new `LoopInvariantHoister`.
+    pub fn new(node_builder: &'a NodeBuilder, assigner: &'a Assigner, type_table: &'a TypeTable) -> Self {
+        Self {
+            node_builder,
+            assigner,
+            type_table,
+            constants: Default::default(),
+            variant: Default::default(),
+            is_hoisting: false,
+            hoisted: Default::default(),
+            hoisted_variables: Default::default(),
+        }
+    }
+
+    /// Returns `true` if the value of the expression is the same in every iteration of the current loop.
+    pub(crate) fn is_invariant(&self, input: &Expression) -> bool {
+        match input {
+            Expression::Access(AccessExpression::Array(access)) => {
+                self.is_invariant(&access.array) && self.is_invariant(&access.index)
+            }
+            Expression::Access(AccessExpression::AssociatedConstant(_)) => true,
+            Expression::Access(AccessExpression::AssociatedFunction(function)) => {
+                let is_pure = match &function.ty {
+                    Type::Identifier(module) => CoreFunction::from_symbols(module.name, function.name.name)
+                        .map_or(false, |core_function| !core_function.is_finalize_command()),
+                    _ => false,
+                };
+                is_pure && function.arguments.iter().all(|argument| self.is_invariant(argument))
+            }
+            Expression::Access(AccessExpression::Member(access)) => self.is_invariant(&access.inner),
+            Expression::Access(AccessExpression::Tuple(access)) => self.is_invariant(&access.tuple),
+            Expression::Array(array) => array.elements.iter().all(|element| self.is_invariant(element)),
+            Expression::Binary(binary) => self.is_invariant(&binary.left) && self.is_invariant(&binary.right),
+            Expression::Cast(cast) => self.is_invariant(&cast.expression),
+            Expression::Identifier(identifier) => !self.variant.contains(&identifier.name),
+            Expression::Literal(_) | Expression::Unit(_) => true,
+            Expression::Struct(struct_) => struct_.members.iter().all(|member| match &member.expression {
+                Some(expression) => self.is_invariant(expression),
+                None => !self.variant.contains(&member.identifier.name),
+            }),
+            Expression::Ternary(ternary) => {
+                self.is_invariant(&ternary.condition)
+                    && self.is_invariant(&ternary.if_true)
+                    && self.is_invariant(&ternary.if_false)
+            }
+            Expression::Tuple(tuple) => tuple.elements.iter().all(|element| self.is_invariant(element)),
+            Expression::Unary(unary) => self.is_invariant(&unary.receiver),
+            // Function calls may have side effects, e.g. assertions.
+            Expression::Call(_) | Expression::Err(_) => false,
+        }
+    }
+
+    /// Returns `true` if the loop is known to execute at least once.
+    pub(crate) fn executes_at_least_once(&self, input: &IterationStatement) -> bool {
+        let value = |expression: &Expression| {
+            let literal = match expression {
+                Expression::Literal(literal) => literal.clone(),
+                Expression::Identifier(identifier) => self.constants.get(&identifier.name)?.clone(),
+                _ => return None,
+            };
+            let literal = match literal {
+                Literal::Unsuffixed(..) => literal.with_inferred_suffix(&input.type_)?,
+                literal => literal,
+            };
+            Value::try_from(&literal).ok()
+        };
+
+        let (Some(start), Some(stop)) = (value(&input.start), value(&input.stop)) else {
+            return false;
+        };
+        let executes = match input.inclusive {
+            true => start.le(stop, input.span),
+            false => start.lt(stop, input.span),
+        };
+        matches!(executes, Ok(Value::Boolean(true, _)))
+    }
+
+    /// Hoists the expression into a new variable and returns the variable.
+    pub(crate) fn hoist(&mut self, input: Expression, type_: Type) -> Expression {
+        let key = format!("{type_}: {input}");
+        let name = match self.hoisted_variables.get(&key) {
+            Some(name) => *name,
+            None => {
+                let name = self.assigner.unique_symbol("$licm", "$");
+                let place = Identifier { name, span: Default::default(), id: self.node_builder.next_id() };
+                self.type_table.insert(place.id, type_.clone());
+                self.hoisted.push(Statement::Definition(DefinitionStatement {
+                    declaration_type: DeclarationType::Let,
+                    place: Expression::Identifier(place),
+                    type_: type_.clone(),
+                    value: input,
+                    span: Default::default(),
+                    id: self.node_builder.next_id(),
+                }));
+                self.hoisted_variables.insert(key, name);
+                name
+            }
+        };
+
+        let identifier = Identifier { name, span: Default::default(), id: self.node_builder.next_id() };
+        self.type_table.insert(identifier.id, type_);
+        Expression::Identifier(identifier)
+    }
+}
+
+/// Collects the variables that are defined or assigned in a block, including the variables of nested loops.
+pub(crate) fn assigned_variables(input: &Block) -> IndexSet<Symbol> {
+    /// Collects the variables that are (partially) written by assigning to `place`.
+    fn collect_place(place: &Expression, variables: &mut IndexSet<Symbol>) {
+        match place {
+            Expression::Identifier(identifier) => {
+                variables.insert(identifier.name);
+            }
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| collect_place(element, variables)),
+            Expression::Access(AccessExpression::Array(access)) => collect_place(&access.array, variables),
+            Expression::Access(AccessExpression::Member(access)) => collect_place(&access.inner, variables),
+            Expression::Access(AccessExpression::Tuple(access)) => collect_place(&access.tuple, variables),
+            _ => {}
+        }
+    }
+
+    fn collect_statement(statement: &Statement, variables: &mut IndexSet<Symbol>) {
+        match statement {
+            Statement::Assign(assign) => collect_place(&assign.place, variables),
+            Statement::Block(block) => {
+                block.statements.iter().for_each(|statement| collect_statement(statement, variables))
+            }
+            Statement::Conditional(conditional) => {
+                conditional.then.statements.iter().for_each(|statement| collect_statement(statement, variables));
+                if let Some(otherwise) = &conditional.otherwise {
+                    collect_statement(otherwise, variables);
+                }
+            }
+            Statement::Const(declaration) => {
+                variables.insert(declaration.place.name);
+            }
+            Statement::Definition(definition) => collect_place(&definition.place, variables),
+            Statement::Iteration(iteration) => {
+                variables.insert(iteration.variable.name);
+                iteration.block.statements.iter().for_each(|statement| collect_statement(statement, variables));
+            }
+            Statement::Assert(_) | Statement::Console(_) | Statement::Expression(_) | Statement::Return(_) => {}
+        }
+    }
+
+    let mut variables = IndexSet::new();
+    input.statements.iter().for_each(|statement| collect_statement(statement, &mut variables));
+    variables
+}
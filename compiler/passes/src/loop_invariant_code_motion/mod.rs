@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Loop-Invariant Code Motion pass traverses the AST and hoists expressions whose value is the same in every
+//! iteration of a loop out of the loop body. The pass is run before the Loop Unrolling pass, which otherwise
+//! duplicates the constraints of these expressions once per iteration.
+//!
+//! See https://en.wikipedia.org/wiki/Loop-invariant_code_motion for more information.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8, b: u8) -> u8 {
+//!     let c: u8 = 0u8;
+//!     for i: u8 in 0u8..4u8 {
+//!         c = c + a * b + i;
+//!     }
+//!     return c;
+//! }
+//! ```
+//!
+//! The loop-invariant code motion pass produces the following code.
+//! ```leo
+//! transition main(a: u8, b: u8) -> u8 {
+//!     let c: u8 = 0u8;
+//!     let $licm$0: u8 = a * b;
+//!     for i: u8 in 0u8..4u8 {
+//!         c = c + $licm$0 + i;
+//!     }
+//!     return c;
+//! }
+//! ```
+//!
+//! An expression is only hoisted if
+//! - it does not read a variable that is defined or assigned in the loop, including the loop variable,
+//! - it does not call a function or a finalize command, e.g. `Mapping::get` or `ChaCha::rand_u8`, and
+//! - the loop executes at least once, so that hoisting does not introduce a computation that can fail.
+//!
+//! Note that the expressions of both branches of a conditional are always evaluated in the circuit, so expressions are
+//! also hoisted out of conditionals in the loop body.
+
+mod hoist_expression;
+
+mod hoist_program;
+
+mod hoist_statement;
+
+pub mod hoister;
+pub use hoister::*;
+
+use crate::{Assigner, Pass, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for LoopInvariantHoister<'a> {
+    type Input = (Ast, &'a NodeBuilder, &'a Assigner, &'a TypeTable);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder, assigner, tt): Self::Input) -> Self::Output {
+        let mut reconstructor = LoopInvariantHoister::new(node_builder, assigner, tt);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
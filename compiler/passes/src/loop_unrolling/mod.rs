@@ -32,15 +32,22 @@ pub use unroll_statement::*;
 use crate::{Pass, SymbolTable, TypeTable};
 
 use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
-use leo_errors::{emitter::Handler, Result};
+use leo_errors::{emitter::Handler, CompilerError, Result};
+use std::time::Instant;
 
 impl<'a> Pass for Unroller<'a> {
-    type Input = (Ast, &'a Handler, &'a NodeBuilder, SymbolTable, &'a TypeTable);
+    type Input =
+        (Ast, &'a Handler, &'a NodeBuilder, SymbolTable, &'a TypeTable, Option<usize>, Option<usize>, Option<Instant>);
     type Output = Result<(Ast, SymbolTable)>;
 
-    fn do_pass((ast, handler, node_builder, st, tt): Self::Input) -> Self::Output {
-        let mut reconstructor = Self::new(st, tt, handler, node_builder);
+    fn do_pass(
+        (ast, handler, node_builder, st, tt, unroll_limit, iteration_limit, deadline): Self::Input,
+    ) -> Self::Output {
+        let mut reconstructor = Self::new(st, tt, handler, node_builder, unroll_limit, iteration_limit, deadline);
         let program = reconstructor.reconstruct_program(ast.into_repr());
+        if reconstructor.deadline_passed {
+            return Err(CompilerError::time_limit_exceeded("loop unrolling").into());
+        }
         handler.last_err().map_err(|e| *e)?;
 
         Ok((Ast::new(program), reconstructor.symbol_table.take()))
@@ -15,32 +15,12 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use leo_ast::*;
-use leo_errors::LoopUnrollerError;
 
 use crate::Unroller;
 
 impl ExpressionReconstructor for Unroller<'_> {
     type AdditionalOutput = bool;
 
-    fn reconstruct_array_access(&mut self, input: ArrayAccess) -> (Expression, Self::AdditionalOutput) {
-        // Reconstruct the index.
-        let index = self.reconstruct_expression(*input.index).0;
-        // If the index is not a literal, then emit an error.
-        if !matches!(index, Expression::Literal(_)) {
-            self.emit_err(LoopUnrollerError::variable_array_access(input.span));
-        }
-
-        (
-            Expression::Access(AccessExpression::Array(ArrayAccess {
-                array: Box::new(self.reconstruct_expression(*input.array).0),
-                index: Box::new(index),
-                span: input.span,
-                id: input.id,
-            })),
-            Default::default(),
-        )
-    }
-
     fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
         // Substitute the identifier with the constant value if it is a constant.
         if let Some(expr) = self.constant_propagation_table.borrow().lookup_constant(input.name) {
@@ -48,4 +28,16 @@ impl ExpressionReconstructor for Unroller<'_> {
         }
         (Expression::Identifier(input), Default::default())
     }
+
+    fn reconstruct_literal(&mut self, input: Literal) -> (Expression, Self::AdditionalOutput) {
+        // Resolve the suffix of an unsuffixed literal from the type inferred during type checking.
+        if let Literal::Unsuffixed(..) = input {
+            // Note that this unwrap is safe since type checking assigns a type to every literal.
+            let type_ = self.type_table.get(&input.id()).unwrap();
+            if let Some(literal) = input.clone().with_inferred_suffix(&type_) {
+                return (Expression::Literal(literal), Default::default());
+            }
+        }
+        (Expression::Literal(input), Default::default())
+    }
 }
@@ -39,6 +39,12 @@ impl ProgramReconstructor for Unroller<'_> {
         // Note that this unwrap is safe since function metadata is stored in a prior pass.
         let function_index = self.symbol_table.borrow().lookup_fn_symbol(function.identifier.name).unwrap().id;
 
+        // Reset the unroll budget for the function.
+        self.function = Some(function.identifier.name);
+        self.unrolled_statements = 0;
+        self.unrolled_iterations = 0;
+        self.limit_exceeded = false;
+
         // Enter the function's scope.
         let previous_function_index = self.enter_scope(function_index);
 
@@ -76,6 +82,7 @@ impl ProgramReconstructor for Unroller<'_> {
             output_type: function.output_type,
             block,
             finalize,
+            receiver: function.receiver,
             span: function.span,
             id: function.id,
         };
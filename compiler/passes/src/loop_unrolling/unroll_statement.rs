@@ -55,7 +55,11 @@ impl StatementReconstructor for Unroller<'_> {
         let reconstructed_value_expression = self.reconstruct_expression(input.value.clone()).0;
 
         // Add to constant propagation table. Since TC completed we know that the RHS is a literal or tuple of literals.
-        if let Err(err) = self.constant_propagation_table.borrow_mut().insert_constant(input.place.name, input.value) {
+        if let Err(err) = self
+            .constant_propagation_table
+            .borrow_mut()
+            .insert_constant(input.place.name, reconstructed_value_expression.clone())
+        {
             self.handler.emit_err(err);
         }
 
@@ -26,7 +26,8 @@ use leo_ast::{
     Type,
     Value,
 };
-use std::cell::RefCell;
+use leo_span::{Span, Symbol};
+use std::{cell::RefCell, time::Instant};
 
 use leo_errors::{emitter::Handler, loop_unroller::LoopUnrollerError};
 
@@ -54,6 +55,24 @@ pub struct Unroller<'a> {
     pub(crate) node_builder: &'a NodeBuilder,
     /// Are we in the midst of unrolling a loop?
     pub(crate) is_unrolling: bool,
+    /// The maximum number of statements a single function may unroll to, if any.
+    pub(crate) unroll_limit: Option<usize>,
+    /// The number of statements unrolled so far in the current function.
+    pub(crate) unrolled_statements: usize,
+    /// The maximum number of iterations a single function may unroll, if any.
+    pub(crate) iteration_limit: Option<usize>,
+    /// The number of iterations unrolled so far in the current function.
+    pub(crate) unrolled_iterations: usize,
+    /// The name of the function being processed.
+    pub(crate) function: Option<Symbol>,
+    /// The span of the outermost loop being unrolled.
+    pub(crate) outermost_loop: Span,
+    /// Whether an unroll limit has been exceeded in the current function.
+    pub(crate) limit_exceeded: bool,
+    /// The time by which unrolling must end, if any.
+    pub(crate) deadline: Option<Instant>,
+    /// Whether the deadline has passed, after which no further iterations are unrolled.
+    pub(crate) deadline_passed: bool,
 }
 
 impl<'a> Unroller<'a> {
@@ -62,6 +81,9 @@ impl<'a> Unroller<'a> {
         type_table: &'a TypeTable,
         handler: &'a Handler,
         node_builder: &'a NodeBuilder,
+        unroll_limit: Option<usize>,
+        iteration_limit: Option<usize>,
+        deadline: Option<Instant>,
     ) -> Self {
         Self {
             constant_propagation_table: RefCell::new(ConstantPropagationTable::default()),
@@ -71,13 +93,26 @@ impl<'a> Unroller<'a> {
             handler,
             node_builder,
             is_unrolling: false,
+            unroll_limit,
+            unrolled_statements: 0,
+            iteration_limit,
+            unrolled_iterations: 0,
+            function: None,
+            outermost_loop: Span::default(),
+            limit_exceeded: false,
+            deadline,
+            deadline_passed: false,
         }
     }
 
     /// Returns the index of the current scope.
     /// Note that if we are in the midst of unrolling an IterationStatement, a new scope is created.
     pub(crate) fn current_scope_index(&mut self) -> usize {
-        if self.is_unrolling { self.symbol_table.borrow_mut().insert_block() } else { self.scope_index }
+        if self.is_unrolling {
+            self.symbol_table.borrow_mut().insert_block()
+        } else {
+            self.scope_index
+        }
     }
 
     /// Enters a child scope.
@@ -142,6 +177,11 @@ impl<'a> Unroller<'a> {
             Err(s) => return s,
         };
 
+        // Remember the outermost loop, so that an exceeded unroll limit can be attributed to it.
+        if !self.is_unrolling {
+            self.outermost_loop = input.span;
+        }
+
         // Get the index of the current scope.
         let scope_index = self.current_scope_index();
 
@@ -161,11 +201,13 @@ impl<'a> Unroller<'a> {
             statements: match input.inclusive {
                 true => {
                     let iter = RangeIterator::new(start, stop, Clusivity::Inclusive);
-                    iter.map(|iteration_count| self.unroll_single_iteration(&input, iteration_count)).collect()
+                    iter.map_while(|iteration_count| self.try_unroll_single_iteration(&input, iteration_count))
+                        .collect()
                 }
                 false => {
                     let iter = RangeIterator::new(start, stop, Clusivity::Exclusive);
-                    iter.map(|iteration_count| self.unroll_single_iteration(&input, iteration_count)).collect()
+                    iter.map_while(|iteration_count| self.try_unroll_single_iteration(&input, iteration_count))
+                        .collect()
                 }
             },
             id: input.id,
@@ -177,6 +219,42 @@ impl<'a> Unroller<'a> {
         iter_blocks
     }
 
+    /// Unrolls a single iteration of an IterationStatement, unless doing so would exceed an unroll limit.
+    /// Once a limit is exceeded, an error is emitted and no further iterations are unrolled in the current function.
+    /// Once the deadline has passed, no further iterations are unrolled at all, and the pass fails.
+    fn try_unroll_single_iteration<I: LoopBound>(
+        &mut self,
+        input: &IterationStatement,
+        iteration_count: I,
+    ) -> Option<Statement> {
+        if self.limit_exceeded || self.deadline_passed {
+            return None;
+        }
+        if self.deadline.map_or(false, |deadline| Instant::now() > deadline) {
+            self.deadline_passed = true;
+            return None;
+        }
+        if let Some(limit) = self.unroll_limit {
+            self.unrolled_statements += count_statements(&input.block);
+            if self.unrolled_statements > limit {
+                self.limit_exceeded = true;
+                let function = self.function.map(|name| name.to_string()).unwrap_or_default();
+                self.emit_err(LoopUnrollerError::unroll_limit_exceeded(function, limit, self.outermost_loop));
+                return None;
+            }
+        }
+        if let Some(limit) = self.iteration_limit {
+            self.unrolled_iterations += 1;
+            if self.unrolled_iterations > limit {
+                self.limit_exceeded = true;
+                let function = self.function.map(|name| name.to_string()).unwrap_or_default();
+                self.emit_err(LoopUnrollerError::iteration_limit_exceeded(function, limit, self.outermost_loop));
+                return None;
+            }
+        }
+        Some(self.unroll_single_iteration(input, iteration_count))
+    }
+
     /// A helper function to unroll a single iteration an IterationStatement.
     fn unroll_single_iteration<I: LoopBound>(&mut self, input: &IterationStatement, iteration_count: I) -> Statement {
         // Create a scope for a single unrolling of the `IterationStatement`.
@@ -260,3 +338,19 @@ impl<'a> Unroller<'a> {
         block
     }
 }
+
+/// Counts the statements in a block, including those in nested blocks and conditionals.
+/// The bodies of nested loops are not counted, since they are accounted for as they are unrolled.
+fn count_statements(block: &Block) -> usize {
+    block.statements.iter().map(count_statement).sum()
+}
+
+fn count_statement(statement: &Statement) -> usize {
+    match statement {
+        Statement::Block(block) => 1 + count_statements(block),
+        Statement::Conditional(conditional) => {
+            1 + count_statements(&conditional.then) + conditional.otherwise.as_deref().map_or(0, count_statement)
+        }
+        _ => 1,
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::MoveChecker;
+
+use leo_ast::*;
+
+impl<'a> ExpressionVisitor<'a> for MoveChecker<'_> {
+    /// Whether the value of the expression is moved, e.g. because it is passed to a function.
+    type AdditionalInput = bool;
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        // Accessing a member or an element copies it, so the accessed value is not moved.
+        match input {
+            AccessExpression::Array(array) => {
+                self.visit_expression(&array.array, &false);
+                self.visit_expression(&array.index, &false);
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                function.arguments.iter().for_each(|argument| self.visit_expression(argument, &false));
+            }
+            AccessExpression::Member(member) => self.visit_expression(&member.inner, &false),
+            AccessExpression::Tuple(tuple) => self.visit_expression(&tuple.tuple, &false),
+            AccessExpression::AssociatedConstant(_) => {}
+        }
+    }
+
+    fn visit_array(&mut self, input: &'a ArrayExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        input.elements.iter().for_each(|element| self.visit_expression(element, &false));
+    }
+
+    fn visit_binary(&mut self, input: &'a BinaryExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.left, &false);
+        self.visit_expression(&input.right, &false);
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        // The receiver of a method call and the arguments are moved into the callee, from left to right.
+        if let Expression::Access(AccessExpression::Member(access)) = &*input.function {
+            self.visit_expression(&access.inner, &true);
+        }
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, &true));
+    }
+
+    fn visit_cast(&mut self, input: &'a CastExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.expression, &false);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        // Note that a member initialized without an expression, e.g. `Foo { x }`, reads the variable `x`.
+        input.members.iter().for_each(|member| match &member.expression {
+            Some(expression) => self.visit_expression(expression, &false),
+            None => self.check_use(&member.identifier),
+        });
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, additional: &Self::AdditionalInput) -> Self::Output {
+        self.check_use(input);
+        if *additional && self.is_record(input.id) {
+            self.move_record(input);
+        }
+    }
+
+    fn visit_ternary(&mut self, input: &'a TernaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // Both branches are moved, since either may be selected.
+        self.visit_expression(&input.condition, &false);
+        self.visit_expression(&input.if_true, additional);
+        self.visit_expression(&input.if_false, additional);
+    }
+
+    fn visit_unary(&mut self, input: &'a UnaryExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.receiver, &false);
+    }
+}
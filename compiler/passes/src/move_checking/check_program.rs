@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::MoveChecker;
+
+use leo_ast::*;
+
+impl MoveChecker<'_> {
+    /// Collects the records defined in a program and its imports.
+    fn collect_records(&mut self, input: &Program) {
+        input.imports.values().for_each(|import| self.collect_records(&import.0));
+        for scope in input.program_scopes.values() {
+            self.records.extend(scope.structs.iter().filter(|(_, struct_)| struct_.is_record).map(|(name, _)| *name));
+        }
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for MoveChecker<'_> {
+    fn visit_program(&mut self, input: &'a Program) {
+        // Records may be defined in imported programs, so they are collected before any function is checked.
+        // Note that the functions of imported programs are checked when those programs are compiled.
+        self.collect_records(input);
+        input.program_scopes.values().for_each(|scope| self.visit_program_scope(scope));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.moved.clear();
+        self.has_returned = false;
+        self.visit_block(&input.block);
+
+        if let Some(finalize) = &input.finalize {
+            self.moved.clear();
+            self.has_returned = false;
+            self.visit_block(&finalize.block);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::MoveChecker;
+
+use leo_ast::*;
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+
+impl MoveChecker<'_> {
+    /// Marks the variables in `place` as holding new values, which have not been moved.
+    fn initialize(&mut self, place: &Expression) {
+        match place {
+            Expression::Identifier(identifier) => {
+                self.moved.shift_remove(&identifier.name);
+            }
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| self.initialize(element)),
+            // Assigning to a member or an element only updates part of the variable, so it is treated as a use.
+            place => self.visit_expression(place, &false),
+        }
+    }
+
+    /// Joins the states at the end of two paths, where a record is moved if it is moved on either path.
+    fn join(&mut self, (moved, has_returned): (IndexMap<Symbol, Span>, bool)) {
+        match (self.has_returned, has_returned) {
+            // A path that has returned does not reach the statement following the join.
+            (true, _) => self.moved = moved,
+            (false, true) => {}
+            (false, false) => moved.into_iter().for_each(|(name, span)| {
+                self.moved.entry(name).or_insert(span);
+            }),
+        }
+        self.has_returned &= has_returned;
+    }
+}
+
+impl<'a> StatementVisitor<'a> for MoveChecker<'_> {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.value, &true);
+        self.initialize(&input.place);
+    }
+
+    fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
+        self.visit_expression(&input.condition, &false);
+
+        let before = (self.moved.clone(), self.has_returned);
+        self.visit_block(&input.then);
+        let then = std::mem::replace(&mut self.moved, before.0);
+        let then_has_returned = std::mem::replace(&mut self.has_returned, before.1);
+
+        if let Some(otherwise) = &input.otherwise {
+            self.visit_statement(otherwise);
+        }
+
+        self.join((then, then_has_returned));
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.visit_expression(&input.value, &true);
+        self.initialize(&input.place);
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.visit_expression(&input.start, &false);
+        self.visit_expression(&input.stop, &false);
+
+        // The body is checked a second time, starting from the records that may have been moved in the first iteration.
+        // Since the loop may also run zero times, the records moved before the loop are kept.
+        let before = (self.moved.clone(), self.has_returned);
+        self.visit_block(&input.block);
+        self.join(before.clone());
+        self.visit_block(&input.block);
+        self.join(before);
+    }
+
+    fn visit_return(&mut self, input: &'a ReturnStatement) {
+        self.visit_expression(&input.expression, &true);
+        if let Some(arguments) = &input.finalize_arguments {
+            arguments.iter().for_each(|argument| self.visit_expression(argument, &true));
+        }
+        self.has_returned = true;
+    }
+}
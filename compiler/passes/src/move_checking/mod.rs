@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Move Checking pass ensures that a record is not used after it has been moved.
+//! A record represents an asset owned by its `owner`, so each record value may be consumed at most once.
+//! The pass is run after the Type Checking pass, since it relies on the types of expressions.
+//!
+//! A record held in a variable is moved when the variable is
+//! - passed as an argument to a function, including as the receiver of a method call,
+//! - returned, or
+//! - assigned to another variable.
+//!
+//! Any later use of the variable, including an access to one of its members, is an error, until the variable is assigned a new record.
+//! Reading a member of a record, e.g. `token.amount`, copies the member and does not move the record.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition split(token: Token) -> (Token, Token) {
+//!     let first: Token = burn(token);
+//!     let second: Token = Token { owner: token.owner, amount: token.amount };
+//!     return (first, second);
+//! }
+//! ```
+//!
+//! The pass reports that `token` is used on the third line, after it was moved into `burn` on the second line.
+//! A record that is moved on only one branch of a conditional is treated as moved after the conditional,
+//! and a record that is moved in the body of a loop cannot be used again in the next iteration.
+//! The pass does not modify the AST.
+
+mod check_expressions;
+
+mod check_program;
+
+mod check_statements;
+
+pub mod move_checker;
+pub use move_checker::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for MoveChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a TypeTable);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, tt): Self::Input) -> Self::Output {
+        let mut visitor = MoveChecker::new(handler, tt);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::{Identifier, NodeID, Type};
+use leo_errors::{emitter::Handler, MoveCheckerError};
+use leo_span::{symbol::with_session_globals, Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+
+pub struct MoveChecker<'a> {
+    /// The handler to which errors are emitted.
+    pub(crate) handler: &'a Handler,
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// The names of the records defined in the program and its imports.
+    pub(crate) records: IndexSet<Symbol>,
+    /// The variables whose records may have been moved, on some path to the current statement, along with where they were moved.
+    pub(crate) moved: IndexMap<Symbol, Span>,
+    /// Whether every path to the current statement has returned.
+    pub(crate) has_returned: bool,
+    /// The spans of the uses that have already been reported.
+    /// The body of a loop is checked twice, so that moves in one iteration are checked against uses in the next.
+    pub(crate) reported: IndexSet<Span>,
+}
+
+impl<'a> MoveChecker<'a> {
+    /// Initializes a new `MoveChecker`.
+    pub fn new(handler: &'a Handler, type_table: &'a TypeTable) -> Self {
+        Self {
+            handler,
+            type_table,
+            records: Default::default(),
+            moved: Default::default(),
+            has_returned: false,
+            reported: Default::default(),
+        }
+    }
+
+    /// Returns `true` if the expression with the given ID is a record.
+    pub(crate) fn is_record(&self, id: NodeID) -> bool {
+        matches!(self.type_table.get(&id), Some(Type::Identifier(identifier)) if self.records.contains(&identifier.name))
+    }
+
+    /// Reports a use of `identifier` if its record has been moved.
+    pub(crate) fn check_use(&mut self, identifier: &Identifier) {
+        if let Some(moved_at) = self.moved.get(&identifier.name) {
+            if self.reported.insert(identifier.span) {
+                let line = with_session_globals(|s| s.source_map.span_to_location(*moved_at))
+                    .map_or_else(|| "<unknown>".to_string(), |location| location.line_start.to_string());
+                self.handler.emit_err(MoveCheckerError::record_used_after_move(identifier, line, identifier.span));
+            }
+        }
+    }
+
+    /// Marks the record held by `identifier` as moved, unless it has already been moved.
+    pub(crate) fn move_record(&mut self, identifier: &Identifier) {
+        self.moved.entry(identifier.name).or_insert(identifier.span);
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Name Resolution pass traverses the AST and resolves every name to its definition, recording the results in a
+//! [`ResolutionTable`]. The pass is run after the Symbol Table Creation pass and before the Type Checking pass.
+//!
+//! The pass resolves:
+//! - uses of variables, inputs, constants, and mappings,
+//! - the names of called functions, including functions of imported programs,
+//! - the names of structs and records in types and struct expressions, and
+//! - the members initialized in struct expressions.
+//!
+//! Names that do not resolve are left out of the table; the Type Checking pass reports them.
+//! Member accesses, e.g. `p.x`, depend on the type of the accessed expression, so they are not resolved here.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! struct Point {
+//!     x: u32,
+//! }
+//!
+//! transition main(a: u32) -> Point {
+//!     let b: u32 = a + 1u32;
+//!     return Point { x: b };
+//! }
+//! ```
+//!
+//! The pass records that `a` refers to the input of `main`, that `b` refers to the variable defined by the `let`
+//! statement, and that `Point` and `x` refer to the struct and its member. It does not modify the AST.
+
+mod resolve_expressions;
+
+mod resolve_program;
+
+mod resolve_statements;
+
+pub mod resolver;
+pub use resolver::*;
+
+use crate::{Pass, ResolutionTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::Result;
+
+impl<'a> Pass for NameResolver<'a> {
+    type Input = (&'a Ast, &'a ResolutionTable);
+    type Output = Result<()>;
+
+    fn do_pass((ast, resolution_table): Self::Input) -> Self::Output {
+        let mut visitor = NameResolver::new(resolution_table);
+        visitor.visit_program(ast.as_repr());
+
+        Ok(())
+    }
+}
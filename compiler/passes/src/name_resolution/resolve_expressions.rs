@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::NameResolver;
+
+use leo_ast::*;
+
+impl<'a> ExpressionVisitor<'a> for NameResolver<'_> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match &*input.function {
+            // Note that calls to functions of imported programs resolve through the imported definitions.
+            Expression::Identifier(identifier) => self.resolve_function(identifier),
+            // Visit the receiver of a method call.
+            Expression::Access(AccessExpression::Member(access)) => self.visit_expression(&access.inner, additional),
+            _ => {}
+        }
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.resolve_struct(&input.name);
+        for member in input.members.iter() {
+            match &member.expression {
+                Some(expression) => {
+                    self.resolve_member(input.name.name, &member.identifier);
+                    self.visit_expression(expression, additional);
+                }
+                // In the shorthand `Foo { a }`, the identifier is a use of the variable `a`.
+                None => self.resolve_value(&member.identifier),
+            }
+        }
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.resolve_value(input);
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{DefinitionKind, NameResolver};
+
+use leo_ast::*;
+
+impl NameResolver<'_> {
+    /// Defines the structs, mappings, constants, and functions of a program and its imports, since they can be used
+    /// before they are declared.
+    fn define_items(&mut self, input: &Program) {
+        input.imports.values().for_each(|(import, _)| self.define_items(import));
+
+        for scope in input.program_scopes.values() {
+            for (_, struct_) in scope.structs.iter() {
+                let definition = self.define(&struct_.identifier, DefinitionKind::Struct);
+                let members = struct_
+                    .members
+                    .iter()
+                    .map(|member| (member.identifier.name, self.define(&member.identifier, DefinitionKind::Member)))
+                    .collect();
+                self.structs.insert(struct_.name(), (definition, members));
+            }
+            for (_, mapping) in scope.mappings.iter() {
+                self.define_value(&mapping.identifier, DefinitionKind::Mapping);
+            }
+            for (_, declaration) in scope.consts.iter() {
+                self.define_value(&declaration.place, DefinitionKind::Const);
+            }
+            for (_, function) in scope.functions.iter() {
+                let definition = self.define(&function.identifier, DefinitionKind::Function);
+                self.functions.insert(function.name(), definition);
+            }
+        }
+    }
+
+    /// Defines the inputs of a function or finalize block, and resolves the types of its inputs and outputs.
+    fn define_signature(&mut self, input: &[Input], output: &[Output]) {
+        for input in input.iter() {
+            match input {
+                Input::Internal(function_input) => self.resolve_type(&function_input.type_),
+                Input::External(external) => self.resolve_struct(&external.record),
+            }
+            self.define_value(&input.identifier(), DefinitionKind::Input);
+        }
+        for output in output.iter() {
+            match output {
+                Output::Internal(function_output) => self.resolve_type(&function_output.type_),
+                Output::External(external) => self.resolve_struct(&external.record),
+            }
+        }
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for NameResolver<'_> {
+    fn visit_program(&mut self, input: &'a Program) {
+        self.define_items(input);
+
+        input.imports.values().for_each(|(import, _)| self.visit_import(import));
+        input.program_scopes.values().for_each(|scope| self.visit_program_scope(scope));
+    }
+
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        for (_, struct_) in input.structs.iter() {
+            struct_.members.iter().for_each(|member| self.resolve_type(&member.type_));
+        }
+        for (_, mapping) in input.mappings.iter() {
+            self.resolve_type(&mapping.key_type);
+            self.resolve_type(&mapping.value_type);
+        }
+        for (_, declaration) in input.consts.iter() {
+            self.visit_expression(&declaration.value, &Default::default());
+            self.resolve_type(&declaration.type_);
+        }
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+    }
+
+    fn visit_import(&mut self, input: &'a Program) {
+        // Note that the items of imported programs are defined along with the items of the importing program.
+        input.imports.values().for_each(|(import, _)| self.visit_import(import));
+        input.program_scopes.values().for_each(|scope| self.visit_program_scope(scope));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.in_scope(|resolver| {
+            resolver.define_signature(&input.input, &input.output);
+            resolver.visit_block(&input.block);
+        });
+        if let Some(finalize) = &input.finalize {
+            self.in_scope(|resolver| {
+                resolver.define_signature(&finalize.input, &finalize.output);
+                resolver.visit_block(&finalize.block);
+            });
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{DefinitionKind, NameResolver};
+
+use leo_ast::*;
+
+impl<'a> StatementVisitor<'a> for NameResolver<'_> {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.value, &Default::default());
+        self.visit_expression(&input.place, &Default::default());
+    }
+
+    fn visit_block(&mut self, input: &'a Block) {
+        self.in_scope(|resolver| input.statements.iter().for_each(|statement| resolver.visit_statement(statement)));
+    }
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        // Note that the value is resolved before the constant is defined.
+        self.visit_expression(&input.value, &Default::default());
+        self.resolve_type(&input.type_);
+        self.define_value(&input.place, DefinitionKind::Const);
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        // Note that the value is resolved before the variables are defined.
+        self.visit_expression(&input.value, &Default::default());
+        self.resolve_type(&input.type_);
+        match &input.place {
+            Expression::Identifier(identifier) => self.define_value(identifier, DefinitionKind::Variable),
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| {
+                if let Expression::Identifier(identifier) = element {
+                    self.define_value(identifier, DefinitionKind::Variable);
+                }
+            }),
+            _ => {}
+        }
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.visit_expression(&input.start, &Default::default());
+        self.visit_expression(&input.stop, &Default::default());
+        self.in_scope(|resolver| {
+            resolver.define_value(&input.variable, DefinitionKind::Variable);
+            resolver.visit_block(&input.block);
+        });
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Definition, DefinitionKind, Reference, ResolutionTable};
+
+use leo_ast::{Identifier, Type};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+pub struct NameResolver<'a> {
+    /// The table in which the resolved names are recorded.
+    pub(crate) resolution_table: &'a ResolutionTable,
+    /// The functions of the program and its imports.
+    pub(crate) functions: IndexMap<Symbol, Definition>,
+    /// The structs and records of the program and its imports, along with their members.
+    pub(crate) structs: IndexMap<Symbol, (Definition, IndexMap<Symbol, Definition>)>,
+    /// The scopes of values, from the outermost to the innermost.
+    /// The outermost scope contains the constants and mappings of the program and its imports.
+    pub(crate) scopes: Vec<IndexMap<Symbol, Definition>>,
+}
+
+impl<'a> NameResolver<'a> {
+    /// Initializes a new `NameResolver`.
+    pub fn new(resolution_table: &'a ResolutionTable) -> Self {
+        Self {
+            resolution_table,
+            functions: Default::default(),
+            structs: Default::default(),
+            scopes: vec![Default::default()],
+        }
+    }
+
+    /// Records that `identifier` resolves to `definition`.
+    pub(crate) fn record(&self, identifier: &Identifier, definition: Definition) {
+        self.resolution_table.insert(identifier.id, Reference { span: identifier.span, definition });
+    }
+
+    /// Returns the definition introduced by `identifier`, and records that the identifier resolves to it.
+    pub(crate) fn define(&self, identifier: &Identifier, kind: DefinitionKind) -> Definition {
+        let definition = Definition { kind, name: identifier.name, id: identifier.id, span: identifier.span };
+        self.record(identifier, definition);
+        definition
+    }
+
+    /// Defines a value in the innermost scope.
+    pub(crate) fn define_value(&mut self, identifier: &Identifier, kind: DefinitionKind) {
+        let definition = self.define(identifier, kind);
+        // Note that there is always at least one scope.
+        self.scopes.last_mut().unwrap().insert(identifier.name, definition);
+    }
+
+    /// Resolves a use of a value, searching the scopes from the innermost to the outermost.
+    pub(crate) fn resolve_value(&self, identifier: &Identifier) {
+        if let Some(definition) = self.scopes.iter().rev().find_map(|scope| scope.get(&identifier.name)) {
+            self.record(identifier, *definition);
+        }
+    }
+
+    /// Resolves a use of a function.
+    pub(crate) fn resolve_function(&self, identifier: &Identifier) {
+        if let Some(definition) = self.functions.get(&identifier.name) {
+            self.record(identifier, *definition);
+        }
+    }
+
+    /// Resolves a use of a struct or record.
+    pub(crate) fn resolve_struct(&self, identifier: &Identifier) {
+        if let Some((definition, _)) = self.structs.get(&identifier.name) {
+            self.record(identifier, *definition);
+        }
+    }
+
+    /// Resolves a use of the member `member` of the struct or record `struct_name`.
+    pub(crate) fn resolve_member(&self, struct_name: Symbol, member: &Identifier) {
+        if let Some(definition) = self.structs.get(&struct_name).and_then(|(_, members)| members.get(&member.name)) {
+            self.record(member, *definition);
+        }
+    }
+
+    /// Resolves the names of the structs and records in a type.
+    pub(crate) fn resolve_type(&self, type_: &Type) {
+        match type_ {
+            Type::Identifier(identifier) => self.resolve_struct(identifier),
+            Type::Array(array_type) => self.resolve_type(array_type.element_type()),
+            Type::Mapping(mapping_type) => {
+                self.resolve_type(&mapping_type.key);
+                self.resolve_type(&mapping_type.value);
+            }
+            Type::Option(option_type) => self.resolve_type(option_type.element_type()),
+            Type::Result(result_type) => {
+                self.resolve_type(result_type.ok_type());
+                self.resolve_type(result_type.err_type());
+            }
+            Type::Tuple(tuple_type) => tuple_type.elements().iter().for_each(|type_| self.resolve_type(type_)),
+            _ => {}
+        }
+    }
+
+    /// Runs `f` in a new innermost scope.
+    pub(crate) fn in_scope<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        self.scopes.push(Default::default());
+        let result = f(self);
+        self.scopes.pop();
+        result
+    }
+}
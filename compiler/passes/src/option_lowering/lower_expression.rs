@@ -0,0 +1,217 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::OptionLowerer;
+
+use leo_ast::*;
+use leo_span::sym;
+
+impl ExpressionReconstructor for OptionLowerer<'_> {
+    type AdditionalOutput = ();
+
+    /// Lowers the functions of `Option` and `Result` into operations on the struct representing the option or result.
+    /// Other associated functions are reconstructed as is.
+    fn reconstruct_associated_function(&mut self, input: AssociatedFunction) -> (Expression, Self::AdditionalOutput) {
+        let core_function = match &input.ty {
+            Type::Identifier(identifier) => CoreFunction::from_symbols(identifier.name, input.name.name),
+            _ => None,
+        };
+        let core_function = match core_function {
+            Some(core_function) if core_function.is_option_function() || core_function.is_result_function() => {
+                core_function
+            }
+            _ => {
+                return (
+                    Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+                        ty: input.ty,
+                        name: input.name,
+                        arguments: input.arguments.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect(),
+                        span: input.span,
+                        id: input.id,
+                    })),
+                    Default::default(),
+                );
+            }
+        };
+
+        // Returns the type of the value held by the option with the given node ID.
+        let element_type = |lowerer: &mut Self, id: NodeID| match lowerer.type_table.get(&id) {
+            Some(Type::Option(option_type)) => lowerer.lower_type(option_type.element_type()),
+            _ => unreachable!("Type checking guarantees that the expression is an option."),
+        };
+        // Returns the types of the value and the error held by the result with the given node ID.
+        let result_types = |lowerer: &mut Self, id: NodeID| match lowerer.type_table.get(&id) {
+            Some(Type::Result(result_type)) => {
+                (lowerer.lower_type(result_type.ok_type()), lowerer.lower_type(result_type.err_type()))
+            }
+            _ => unreachable!("Type checking guarantees that the expression is a result."),
+        };
+
+        let span = input.span;
+        let mut arguments = input.arguments.into_iter();
+        let expression = match core_function {
+            // `Option::some(x)` is lowered to `Option__T { is_some: true, payload: x }`.
+            CoreFunction::OptionSome => {
+                let element = element_type(self, input.id);
+                let value = self.reconstruct_expression(arguments.next().unwrap()).0;
+                self.option(true, value, element, span)
+            }
+            // `Option::none()` is lowered to `Option__T { is_some: false, payload: <default> }`.
+            CoreFunction::OptionNone => {
+                let element = element_type(self, input.id);
+                let value = self.default_value(&element, span);
+                self.option(false, value, element, span)
+            }
+            // `o.is_some()` is lowered to `o.is_some`.
+            CoreFunction::OptionIsSome => {
+                let option = self.reconstruct_expression(arguments.next().unwrap()).0;
+                self.member(option, sym::is_some, Type::Boolean, span)
+            }
+            // `o.is_none()` is lowered to `!o.is_some`.
+            CoreFunction::OptionIsNone => {
+                let option = self.reconstruct_expression(arguments.next().unwrap()).0;
+                Expression::Unary(UnaryExpression {
+                    receiver: Box::new(self.member(option, sym::is_some, Type::Boolean, span)),
+                    op: UnaryOperation::Not,
+                    span,
+                    id: self.typed_id(Type::Boolean),
+                })
+            }
+            // `o.unwrap_or(d)` is lowered to `o.is_some ? o.payload : d`, and `r.unwrap_or(d)` to `r.is_ok ? r.payload : d`.
+            // Since the option is used twice, it is first bound to a variable.
+            CoreFunction::OptionUnwrapOr => {
+                let option = arguments.next().unwrap();
+                let (element, flag) = match self.type_table.get(&option.id()) {
+                    Some(Type::Result(_)) => (result_types(self, option.id()).0, sym::is_ok),
+                    _ => (element_type(self, option.id()), sym::is_some),
+                };
+                let option_type = self.type_table.get(&option.id()).map(|type_| self.lower_type(&type_)).unwrap();
+                let option = self.reconstruct_expression(option).0;
+                let default = self.reconstruct_expression(arguments.next().unwrap()).0;
+                let option = self.bind(option, option_type.clone());
+                let is_some = self.member(self.identifier(option, option_type.clone()), flag, Type::Boolean, span);
+                let value = self.member(self.identifier(option, option_type), sym::payload, element.clone(), span);
+                Expression::Ternary(TernaryExpression {
+                    condition: Box::new(is_some),
+                    if_true: Box::new(value),
+                    if_false: Box::new(default),
+                    span,
+                    id: self.typed_id(element),
+                })
+            }
+            // `Result::ok(x)` is lowered to `Result__T__E { is_ok: true, payload: x, error: <default> }`.
+            CoreFunction::ResultOk => {
+                let types = result_types(self, input.id);
+                let value = self.reconstruct_expression(arguments.next().unwrap()).0;
+                let error = self.default_value(&types.1, span);
+                self.result(true, value, error, types, span)
+            }
+            // `Result::err(e)` is lowered to `Result__T__E { is_ok: false, payload: <default>, error: e }`.
+            CoreFunction::ResultErr => {
+                let types = result_types(self, input.id);
+                let value = self.default_value(&types.0, span);
+                let error = self.reconstruct_expression(arguments.next().unwrap()).0;
+                self.result(false, value, error, types, span)
+            }
+            // `r.is_ok()` is lowered to `r.is_ok`.
+            CoreFunction::ResultIsOk => {
+                let result = self.reconstruct_expression(arguments.next().unwrap()).0;
+                self.member(result, sym::is_ok, Type::Boolean, span)
+            }
+            // `r.is_err()` is lowered to `!r.is_ok`.
+            CoreFunction::ResultIsErr => {
+                let result = self.reconstruct_expression(arguments.next().unwrap()).0;
+                Expression::Unary(UnaryExpression {
+                    receiver: Box::new(self.member(result, sym::is_ok, Type::Boolean, span)),
+                    op: UnaryOperation::Not,
+                    span,
+                    id: self.typed_id(Type::Boolean),
+                })
+            }
+            _ => unreachable!("The function is a function of `Option` or `Result`."),
+        };
+
+        (expression, Default::default())
+    }
+
+    /// Lowers `r?` into an early return of the error held by `r`, followed by an access to the value held by `r`.
+    /// That is, the statement `if !r.is_ok { return Result__U__E { is_ok: false, payload: <default>, error: r.error }; }` is inserted before the current statement.
+    fn reconstruct_unary(&mut self, input: UnaryExpression) -> (Expression, Self::AdditionalOutput) {
+        if input.op != UnaryOperation::Try {
+            return (
+                Expression::Unary(UnaryExpression {
+                    receiver: Box::new(self.reconstruct_expression(*input.receiver).0),
+                    op: input.op,
+                    span: input.span,
+                    id: input.id,
+                }),
+                Default::default(),
+            );
+        }
+
+        let span = input.span;
+        let (ok_type, err_type) = match self.type_table.get(&input.receiver.id()) {
+            Some(Type::Result(result_type)) => {
+                (self.lower_type(result_type.ok_type()), self.lower_type(result_type.err_type()))
+            }
+            _ => unreachable!("Type checking guarantees that the receiver is a result."),
+        };
+        let result_type = self.lower_type(&Type::Result(ResultType::new(ok_type.clone(), err_type.clone())));
+        let result = self.reconstruct_expression(*input.receiver).0;
+        let result = self.bind(result, result_type.clone());
+
+        // Construct the result returned by the function, which holds the same error.
+        let output_types = match self.output_type.clone() {
+            Type::Result(output_type) => {
+                (self.lower_type(output_type.ok_type()), self.lower_type(output_type.err_type()))
+            }
+            _ => unreachable!("Type checking guarantees that the function returns a result."),
+        };
+        let value = self.default_value(&output_types.0, span);
+        let error = self.member(self.identifier(result, result_type.clone()), sym::error, err_type, span);
+        let output = self.result(false, value, error, output_types, span);
+
+        let is_err = Expression::Unary(UnaryExpression {
+            receiver: Box::new(self.member(
+                self.identifier(result, result_type.clone()),
+                sym::is_ok,
+                Type::Boolean,
+                span,
+            )),
+            op: UnaryOperation::Not,
+            span,
+            id: self.typed_id(Type::Boolean),
+        });
+        self.statements.push(Statement::Conditional(ConditionalStatement {
+            condition: is_err,
+            then: Block {
+                statements: vec![Statement::Return(ReturnStatement {
+                    expression: output,
+                    finalize_arguments: None,
+                    span,
+                    id: self.node_builder.next_id(),
+                })],
+                span,
+                id: self.node_builder.next_id(),
+            },
+            otherwise: None,
+            span,
+            id: self.node_builder.next_id(),
+        }));
+
+        (self.member(self.identifier(result, result_type), sym::payload, ok_type, span), Default::default())
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::OptionLowerer;
+
+use leo_ast::*;
+
+impl OptionLowerer<'_> {
+    /// Replaces the options and results in the type of a function input.
+    fn lower_input(&mut self, input: Input) -> Input {
+        match input {
+            Input::Internal(input) => Input::Internal(FunctionInput { type_: self.lower_type(&input.type_), ..input }),
+            input => input,
+        }
+    }
+
+    /// Replaces the options and results in the type of a function output.
+    fn lower_output(&mut self, output: Output) -> Output {
+        match output {
+            Output::Internal(output) => {
+                Output::Internal(FunctionOutput { type_: self.lower_type(&output.type_), ..output })
+            }
+            output => output,
+        }
+    }
+}
+
+impl ProgramReconstructor for OptionLowerer<'_> {
+    /// Reconstructs a program scope, and adds the structs representing the options and results that it uses.
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        // Save the structs used by the enclosing program, since imported programs are reconstructed first.
+        let enclosing_structs = core::mem::take(&mut self.used_structs);
+
+        let mut structs: Vec<_> = input.structs.into_iter().map(|(i, s)| (i, self.reconstruct_struct(s))).collect();
+        let mappings = input.mappings.into_iter().map(|(i, m)| (i, self.reconstruct_mapping(m))).collect();
+        let functions = input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect();
+        let consts = input
+            .consts
+            .into_iter()
+            .map(|(i, c)| (i, ConstDeclaration { type_: self.lower_type(&c.type_), ..c }))
+            .collect();
+
+        // Note that the structs are ordered by the struct dependency graph during code generation.
+        structs.extend(
+            self.generated_structs
+                .values()
+                .filter(|struct_| self.used_structs.contains(&struct_.identifier.name))
+                .map(|struct_| (struct_.identifier.name, struct_.clone())),
+        );
+
+        self.used_structs = enclosing_structs;
+
+        ProgramScope { program_id: input.program_id, structs, mappings, functions, consts, span: input.span }
+    }
+
+    /// Replaces the options and results in the inputs and outputs of a function.
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        // The output type is tracked, since the `?` operator constructs the output of the function.
+        self.output_type = input.output_type.clone();
+        let block = self.reconstruct_block(input.block).0;
+
+        Function {
+            annotations: input.annotations,
+            variant: input.variant,
+            identifier: input.identifier,
+            input: input.input.into_iter().map(|input| self.lower_input(input)).collect(),
+            output: input.output.into_iter().map(|output| self.lower_output(output)).collect(),
+            output_type: self.lower_type(&input.output_type),
+            block,
+            finalize: input.finalize.map(|finalize| {
+                self.output_type = finalize.output_type.clone();
+                Finalize {
+                    identifier: finalize.identifier,
+                    input: finalize.input.into_iter().map(|input| self.lower_input(input)).collect(),
+                    output: finalize.output.into_iter().map(|output| self.lower_output(output)).collect(),
+                    output_type: self.lower_type(&finalize.output_type),
+                    block: self.reconstruct_block(finalize.block).0,
+                    span: finalize.span,
+                    id: finalize.id,
+                }
+            }),
+            receiver: input.receiver,
+            span: input.span,
+            id: input.id,
+        }
+    }
+
+    /// Replaces the options and results in the members of a struct, and adds the structs representing them to the struct dependency graph.
+    fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        let members: Vec<Member> = input
+            .members
+            .into_iter()
+            .map(|member| Member { type_: self.lower_type(&member.type_), ..member })
+            .collect();
+        for member in members.iter() {
+            let mut base_element = &member.type_;
+            while let Type::Array(array_type) = base_element {
+                base_element = array_type.element_type();
+            }
+            if let Type::Identifier(member_type) = base_element {
+                if self.generated_structs.values().any(|struct_| struct_.identifier.name == member_type.name) {
+                    self.struct_graph.add_edge(input.identifier.name, member_type.name);
+                }
+            }
+        }
+        let struct_ = Struct { members, ..input };
+
+        // The lowered members are also used by later passes, e.g. to reconstruct the struct in a ternary expression.
+        self.symbol_table.structs.insert(struct_.identifier.name, struct_.clone());
+
+        struct_
+    }
+
+    /// Replaces the options and results in the key and value types of a mapping.
+    fn reconstruct_mapping(&mut self, input: Mapping) -> Mapping {
+        Mapping { key_type: self.lower_type(&input.key_type), value_type: self.lower_type(&input.value_type), ..input }
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::OptionLowerer;
+
+use leo_ast::{
+    Block,
+    ConditionalStatement,
+    DefinitionStatement,
+    ExpressionReconstructor,
+    Node,
+    Statement,
+    StatementReconstructor,
+};
+
+impl StatementReconstructor for OptionLowerer<'_> {
+    /// Reconstructs the statements in a block, inserting the statements produced while lowering each statement before it.
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        // Save the statements produced by the enclosing statement, e.g. by the condition of a conditional statement.
+        let enclosing_statements = core::mem::take(&mut self.statements);
+
+        let mut statements = Vec::with_capacity(input.statements.len());
+        for statement in input.statements {
+            let statement = self.reconstruct_statement(statement).0;
+            statements.append(&mut self.statements);
+            statements.push(statement);
+        }
+
+        self.statements = enclosing_statements;
+
+        (Block { statements, span: input.span, id: input.id }, Default::default())
+    }
+
+    /// Reconstructs a conditional statement.
+    /// The statements produced by the condition of an `else if` must only be executed if the preceding conditions are false,
+    /// so they are wrapped in a block together with the nested conditional statement.
+    fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Conditional(ConditionalStatement {
+                condition: self.reconstruct_expression(input.condition).0,
+                then: self.reconstruct_block(input.then).0,
+                otherwise: input.otherwise.map(|otherwise| {
+                    Box::new(match *otherwise {
+                        Statement::Block(block) => Statement::Block(self.reconstruct_block(block).0),
+                        statement => {
+                            let block =
+                                Block { span: statement.span(), id: Default::default(), statements: vec![statement] };
+                            let mut block = self.reconstruct_block(block).0;
+                            // If no statements were produced, the block is unnecessary.
+                            match block.statements.len() {
+                                1 => block.statements.pop().unwrap(),
+                                _ => Statement::Block(Block { id: self.node_builder.next_id(), ..block }),
+                            }
+                        }
+                    })
+                }),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Reconstructs a definition statement, replacing the options and results in its type.
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Definition(DefinitionStatement {
+                declaration_type: input.declaration_type,
+                place: input.place,
+                type_: self.lower_type(&input.type_),
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+}
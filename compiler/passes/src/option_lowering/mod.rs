@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Option Lowering pass traverses the AST after the Boolean Simplification pass and lowers options and results into structs.
+//! Aleo instructions do not have an optional type, so each `Option<T>` is represented by a struct with an `is_some` flag and a `payload`.
+//! An empty option holds the default value of `T`, e.g. `0u8`, `false`, or a struct whose members hold their default values.
+//! The struct for each type `T` is generated once, and is named after `T`, e.g. `Option__u8`.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function main(o: Option<u8>, flag: bool) -> u8 {
+//!     let p: Option<u8> = flag ? Option::some(1u8) : Option::none();
+//!     assert(p.is_some() || o.is_none());
+//!     return o.unwrap_or(2u8);
+//! }
+//! ```
+//!
+//! The option lowering pass produces the following code.
+//! ```leo
+//! struct Option__u8 {
+//!     is_some: bool,
+//!     payload: u8,
+//! }
+//!
+//! function main(o: Option__u8, flag: bool) -> u8 {
+//!     let p: Option__u8 = flag ? Option__u8 { is_some: true, payload: 1u8 } : Option__u8 { is_some: false, payload: 0u8 };
+//!     assert(p.is_some || !o.is_some);
+//!     return o.is_some ? o.payload : 2u8;
+//! }
+//! ```
+//! An option that is not a variable is first bound to one, since `unwrap_or` uses it twice.
+//!
+//! Similarly, each `Result<T, E>` is represented by a struct with an `is_ok` flag, a `payload`, and an `error`, e.g. `Result__u8__u16`.
+//! The `?` operator is lowered into an early return of the error, which the Flattening pass folds into conditional selects.
+//! Consider the following Leo code.
+//! ```leo
+//! function main(r: Result<u8, u16>) -> Result<u32, u16> {
+//!     let x: u8 = r?;
+//!     return Result::ok(x as u32);
+//! }
+//! ```
+//!
+//! The option lowering pass produces the following code.
+//! ```leo
+//! struct Result__u8__u16 {
+//!     is_ok: bool,
+//!     payload: u8,
+//!     error: u16,
+//! }
+//!
+//! struct Result__u32__u16 {
+//!     is_ok: bool,
+//!     payload: u32,
+//!     error: u16,
+//! }
+//!
+//! function main(r: Result__u8__u16) -> Result__u32__u16 {
+//!     if !r.is_ok {
+//!         return Result__u32__u16 { is_ok: false, payload: 0u32, error: r.error };
+//!     }
+//!     let x: u8 = r.payload;
+//!     return Result__u32__u16 { is_ok: true, payload: x as u32, error: 0u16 };
+//! }
+//! ```
+//! Note that, as with function calls, an early return in a branch of a ternary expression is taken even if the branch is not selected.
+
+mod lower_expression;
+
+mod lower_program;
+
+mod lower_statement;
+
+pub mod option_lowerer;
+pub use option_lowerer::*;
+
+use crate::{Assigner, Pass, StructGraph, SymbolTable, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for OptionLowerer<'a> {
+    type Input = (Ast, &'a NodeBuilder, &'a Assigner, &'a TypeTable, SymbolTable, StructGraph);
+    type Output = Result<(Ast, SymbolTable, StructGraph)>;
+
+    fn do_pass((ast, node_builder, assigner, tt, st, struct_graph): Self::Input) -> Self::Output {
+        let mut reconstructor = OptionLowerer::new(node_builder, assigner, tt, st, struct_graph);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        // Replace the options and results in the types of all expressions, including those that were not reconstructed, e.g. variables.
+        // Note that every option and result type occurs in the program, so no new structs are generated here.
+        tt.map_types(|type_| reconstructor.lower_type(type_));
+
+        Ok((Ast::new(program), reconstructor.symbol_table, reconstructor.struct_graph))
+    }
+}
@@ -0,0 +1,325 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Assigner, StructGraph, SymbolTable, TypeTable};
+
+use leo_ast::*;
+use leo_span::{sym, Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// The maximum length of an identifier in Aleo instructions.
+const MAX_IDENTIFIER_LENGTH: usize = 31;
+
+/// The address used as the value of an empty `Option<address>`, or of a `Result` holding an `address`.
+const ZERO_ADDRESS: &str = "aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc";
+
+pub struct OptionLowerer<'a> {
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// A struct used to construct (unique) variable names.
+    pub(crate) assigner: &'a Assigner,
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// The symbol table, into which the structs representing options and results are inserted.
+    pub(crate) symbol_table: SymbolTable,
+    /// The struct dependency graph, into which the structs representing options and results are inserted.
+    pub(crate) struct_graph: StructGraph,
+    /// A mapping from each (lowered) option or result type to the struct representing it.
+    /// Note that types are keyed by their string representation, since identifiers are compared by their spans.
+    pub(crate) generated_structs: IndexMap<String, Struct>,
+    /// The generated structs that are used by the current program scope.
+    pub(crate) used_structs: IndexSet<Symbol>,
+    /// The statements produced while lowering the current statement, which are inserted before it.
+    pub(crate) statements: Vec<Statement>,
+    /// The output type of the current function, to which the `?` operator returns.
+    pub(crate) output_type: Type,
+}
+
+impl<'a> OptionLowerer<'a> {
+    /// Initializes a new `OptionLowerer`.
+    pub(crate) fn new(
+        node_builder: &'a NodeBuilder,
+        assigner: &'a Assigner,
+        type_table: &'a TypeTable,
+        symbol_table: SymbolTable,
+        struct_graph: StructGraph,
+    ) -> Self {
+        Self {
+            node_builder,
+            assigner,
+            type_table,
+            symbol_table,
+            struct_graph,
+            generated_structs: IndexMap::new(),
+            used_structs: IndexSet::new(),
+            statements: Vec::new(),
+            output_type: Type::Unit,
+        }
+    }
+
+    /// Replaces each option and result in the type with the struct representing it.
+    pub(crate) fn lower_type(&mut self, type_: &Type) -> Type {
+        match type_ {
+            Type::Option(option_type) => {
+                let element = self.lower_type(option_type.element_type());
+                Type::Identifier(self.option_struct(element))
+            }
+            Type::Result(result_type) => {
+                let ok_type = self.lower_type(result_type.ok_type());
+                let err_type = self.lower_type(result_type.err_type());
+                Type::Identifier(self.result_struct(ok_type, err_type))
+            }
+            Type::Array(array_type) => Type::Array(ArrayType::new(
+                self.lower_type(array_type.element_type()),
+                NonNegativeNumber::from(array_type.length()),
+            )),
+            Type::Tuple(tuple_type) => Type::Tuple(TupleType::new(
+                tuple_type.elements().iter().map(|element| self.lower_type(element)).collect(),
+            )),
+            Type::Mapping(mapping_type) => Type::Mapping(MappingType {
+                key: Box::new(self.lower_type(&mapping_type.key)),
+                value: Box::new(self.lower_type(&mapping_type.value)),
+            }),
+            type_ => type_.clone(),
+        }
+    }
+
+    /// Returns the name of the struct representing an option that holds a value of the (lowered) type.
+    fn option_struct(&mut self, element: Type) -> Identifier {
+        let key = Type::Option(OptionType::new(element.clone())).to_string();
+        let name = format!("__{}", mangle(&element));
+        self.generated_struct(key, "Option", name, vec![(sym::is_some, Type::Boolean), (sym::payload, element)])
+    }
+
+    /// Returns the name of the struct representing a result that holds a value or an error of the (lowered) types.
+    fn result_struct(&mut self, ok_type: Type, err_type: Type) -> Identifier {
+        let key = Type::Result(ResultType::new(ok_type.clone(), err_type.clone())).to_string();
+        let name = format!("__{}__{}", mangle(&ok_type), mangle(&err_type));
+        self.generated_struct(key, "Result", name, vec![
+            (sym::is_ok, Type::Boolean),
+            (sym::payload, ok_type),
+            (sym::error, err_type),
+        ])
+    }
+
+    /// Returns the name of the struct with the given members, which represents the type with the given key.
+    /// The struct is created on first use, and is added to the symbol table and the struct dependency graph.
+    fn generated_struct(
+        &mut self,
+        key: String,
+        prefix: &str,
+        suffix: String,
+        members: Vec<(Symbol, Type)>,
+    ) -> Identifier {
+        if let Some(struct_) = self.generated_structs.get(&key) {
+            let name = struct_.identifier;
+            self.used_structs.insert(name.name);
+            return name;
+        }
+
+        // The struct is named after the types it holds, e.g. `Option__u8`, unless that name is taken or too long.
+        let mut name = format!("{prefix}{suffix}");
+        if name.len() > MAX_IDENTIFIER_LENGTH || self.symbol_table.lookup_struct(Symbol::intern(&name)).is_some() {
+            let mut index = self.generated_structs.len();
+            loop {
+                name = format!("{prefix}__{index}");
+                if self.symbol_table.lookup_struct(Symbol::intern(&name)).is_none() {
+                    break;
+                }
+                index += 1;
+            }
+        }
+        let name = Identifier::new(Symbol::intern(&name), self.node_builder.next_id());
+
+        let struct_ = Struct {
+            annotations: Vec::new(),
+            identifier: name,
+            members: members
+                .into_iter()
+                .map(|(identifier, type_)| Member {
+                    mode: Mode::None,
+                    identifier: Identifier::new(identifier, self.node_builder.next_id()),
+                    type_,
+                    span: Default::default(),
+                    id: self.node_builder.next_id(),
+                })
+                .collect(),
+            is_record: false,
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        };
+
+        // The struct depends on the structs that it holds, if any.
+        self.struct_graph.add_node(name.name);
+        for member in struct_.members.iter() {
+            let mut base_element = &member.type_;
+            while let Type::Array(array_type) = base_element {
+                base_element = array_type.element_type();
+            }
+            if let Type::Identifier(base_element) = base_element {
+                self.struct_graph.add_edge(name.name, base_element.name);
+            }
+        }
+
+        self.symbol_table.structs.insert(name.name, struct_.clone());
+        self.generated_structs.insert(key, struct_);
+        self.used_structs.insert(name.name);
+
+        name
+    }
+
+    /// Returns a new node ID for an expression of the given (lowered) type.
+    pub(crate) fn typed_id(&self, type_: Type) -> NodeID {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_);
+        id
+    }
+
+    /// Constructs a boolean literal.
+    pub(crate) fn boolean(&self, value: bool, span: Span) -> Expression {
+        Expression::Literal(Literal::Boolean(value, span, self.typed_id(Type::Boolean)))
+    }
+
+    /// Constructs an access to a member of the struct representing an option or a result.
+    pub(crate) fn member(&self, inner: Expression, name: Symbol, type_: Type, span: Span) -> Expression {
+        Expression::Access(AccessExpression::Member(MemberAccess {
+            inner: Box::new(inner),
+            name: Identifier::new(name, self.node_builder.next_id()),
+            span,
+            id: self.typed_id(type_),
+        }))
+    }
+
+    /// Constructs the struct representing an option, with the given flag and value.
+    pub(crate) fn option(&mut self, is_some: bool, value: Expression, element: Type, span: Span) -> Expression {
+        let name = self.option_struct(element);
+        let is_some = self.boolean(is_some, span);
+        self.struct_expression(name, vec![(sym::is_some, is_some), (sym::payload, value)], span)
+    }
+
+    /// Constructs the struct representing a result, with the given flag, value, and error.
+    pub(crate) fn result(
+        &mut self,
+        is_ok: bool,
+        value: Expression,
+        error: Expression,
+        (ok_type, err_type): (Type, Type),
+        span: Span,
+    ) -> Expression {
+        let name = self.result_struct(ok_type, err_type);
+        let is_ok = self.boolean(is_ok, span);
+        self.struct_expression(name, vec![(sym::is_ok, is_ok), (sym::payload, value), (sym::error, error)], span)
+    }
+
+    /// Constructs a struct expression with the given members.
+    fn struct_expression(&self, name: Identifier, members: Vec<(Symbol, Expression)>, span: Span) -> Expression {
+        Expression::Struct(StructExpression {
+            name,
+            members: members
+                .into_iter()
+                .map(|(identifier, expression)| StructVariableInitializer {
+                    identifier: Identifier::new(identifier, self.node_builder.next_id()),
+                    expression: Some(expression),
+                    span,
+                    id: self.node_builder.next_id(),
+                })
+                .collect(),
+            span,
+            id: self.typed_id(Type::Identifier(name)),
+        })
+    }
+
+    /// Constructs the value held by an empty option of the given (lowered) type, or by a result in place of its value or error.
+    /// Type checking guarantees that options and results only hold types that have such a value.
+    pub(crate) fn default_value(&mut self, type_: &Type, span: Span) -> Expression {
+        let id = self.typed_id(type_.clone());
+        match type_ {
+            Type::Address => Expression::Literal(Literal::Address(ZERO_ADDRESS.to_string(), span, id)),
+            Type::Boolean => Expression::Literal(Literal::Boolean(false, span, id)),
+            Type::Field => Expression::Literal(Literal::Field("0".to_string(), span, id)),
+            Type::Group => {
+                Expression::Literal(Literal::Group(Box::new(GroupLiteral::Single("0".to_string(), span, id))))
+            }
+            Type::Integer(integer_type) => {
+                Expression::Literal(Literal::Integer(*integer_type, "0".to_string(), span, id))
+            }
+            Type::Scalar => Expression::Literal(Literal::Scalar("0".to_string(), span, id)),
+            Type::Array(array_type) => Expression::Array(ArrayExpression {
+                elements: (0..array_type.length())
+                    .map(|_| self.default_value(array_type.element_type(), span))
+                    .collect(),
+                span,
+                id,
+            }),
+            Type::Identifier(identifier) => {
+                let members = match self.symbol_table.lookup_struct(identifier.name) {
+                    Some(struct_) => struct_.members.clone(),
+                    None => unreachable!("Type checking guarantees that the struct is defined."),
+                };
+                Expression::Struct(StructExpression {
+                    name: *identifier,
+                    members: members
+                        .into_iter()
+                        .map(|member| StructVariableInitializer {
+                            identifier: Identifier::new(member.identifier.name, self.node_builder.next_id()),
+                            expression: Some(self.default_value(&member.type_, span)),
+                            span,
+                            id: self.node_builder.next_id(),
+                        })
+                        .collect(),
+                    span,
+                    id,
+                })
+            }
+            _ => {
+                unreachable!("Type checking guarantees that options and results only hold types with a default value.")
+            }
+        }
+    }
+
+    /// Constructs an identifier expression for the variable.
+    pub(crate) fn identifier(&self, variable: Identifier, type_: Type) -> Expression {
+        Expression::Identifier(Identifier { id: self.typed_id(type_), ..variable })
+    }
+
+    /// Binds the expression to a new variable, unless it is already a variable, and returns the variable.
+    pub(crate) fn bind(&mut self, expression: Expression, type_: Type) -> Identifier {
+        if let Expression::Identifier(identifier) = expression {
+            return identifier;
+        }
+        let name = self.assigner.unique_symbol("$opt", "$");
+        let place = Identifier { name, span: Default::default(), id: self.typed_id(type_.clone()) };
+        self.statements.push(Statement::Definition(DefinitionStatement {
+            declaration_type: DeclarationType::Let,
+            place: Expression::Identifier(place),
+            type_,
+            value: expression,
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        }));
+        place
+    }
+}
+
+/// Returns a name for the (lowered) type that can be used in an identifier, e.g. `array3_u8` for `[u8; 3]`.
+fn mangle(type_: &Type) -> String {
+    match type_ {
+        Type::Array(array_type) => format!("array{}_{}", array_type.length(), mangle(array_type.element_type())),
+        Type::Identifier(identifier) => identifier.name.to_string(),
+        type_ => type_.to_string(),
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Pretty Printer converts an AST back into Leo source, so that the program can be inspected after the
+//! optimization passes, e.g. with `leo build --emit expanded`. It does not modify the AST.
+//!
+//! The output is valid Leo, even though the AST no longer is after static single assignment.
+//! The variables introduced by the compiler, e.g. `$var$0` or `a$1`, are renamed to valid identifiers that do not
+//! collide with the names in the program, and the first assignment to each variable is printed as its definition.
+//!
+//! Consider the following Leo code, after static single assignment, flattening, and inlining.
+//! ```leo
+//! $var$0 = a * 2u8;
+//! a$1 = $var$0 + b;
+//! return a$1;
+//! ```
+//!
+//! The pass prints it as follows.
+//! ```leo
+//! let var_0: u8 = a * 2u8;
+//! let a_1: u8 = var_0 + b;
+//! return a_1;
+//! ```
+
+pub mod printer;
+pub use printer::*;
+
+mod visit_expressions;
+
+mod visit_program;
+
+mod visit_statements;
+
+mod visit_type;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::Ast;
+
+impl<'a> Pass for PrettyPrinter<'a> {
+    type Input = (&'a Ast, &'a TypeTable);
+    type Output = String;
+
+    fn do_pass((ast, type_table): Self::Input) -> Self::Output {
+        // Collect the names used in the program, so that the names of the variables introduced by the compiler
+        // can be chosen not to collide with them.
+        let mut collector = Self::new(type_table, Default::default());
+        collector.visit_program(ast.as_repr());
+
+        let mut printer = Self::new(type_table, collector.names);
+        printer.visit_program(ast.as_repr());
+
+        printer.output
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_span::{sym, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+use std::fmt::Display;
+
+/// The number of spaces each level of nesting is indented by.
+const INDENT: &str = "    ";
+
+pub struct PrettyPrinter<'a> {
+    /// A mapping between expressions and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// The source printed so far.
+    pub(crate) output: String,
+    /// The current level of nesting.
+    pub(crate) indent: usize,
+    /// The valid identifiers used in the program.
+    pub(crate) names: IndexSet<String>,
+    /// The identifiers that names cannot be renamed to, as they are used in the program or already given to another name.
+    pub(crate) taken: IndexSet<String>,
+    /// A mapping from the names that are not valid identifiers, e.g. `$var$0`, to the identifiers they are printed as.
+    pub(crate) renamed: IndexMap<Symbol, String>,
+    /// The variables defined so far in the current function or finalize block.
+    pub(crate) defined: IndexSet<Symbol>,
+    /// Are we traversing a method, whose receiver is named `self`?
+    pub(crate) is_method: bool,
+}
+
+impl<'a> PrettyPrinter<'a> {
+    /// Initializes a new `PrettyPrinter`, which renames invalid identifiers so that they do not collide with `names`.
+    pub fn new(type_table: &'a TypeTable, names: IndexSet<String>) -> Self {
+        Self {
+            type_table,
+            output: String::new(),
+            indent: 0,
+            taken: names.clone(),
+            names,
+            renamed: IndexMap::new(),
+            defined: IndexSet::new(),
+            is_method: false,
+        }
+    }
+
+    /// Writes a line at the current level of nesting.
+    pub(crate) fn line(&mut self, line: impl Display) {
+        let line = line.to_string();
+        if !line.is_empty() {
+            for _ in 0..self.indent {
+                self.output.push_str(INDENT);
+            }
+        }
+        self.output.push_str(&line);
+        self.output.push('\n');
+    }
+
+    /// Returns the identifier `name` is printed as.
+    /// Names introduced by the compiler, e.g. `$var$0`, are not valid identifiers, and are renamed to one that is not
+    /// used in the program, e.g. `var_0`. The receiver of a method is renamed as well, since `self` is a keyword.
+    pub(crate) fn name(&mut self, name: Symbol) -> String {
+        if name == sym::SelfLower && !self.is_method {
+            return name.to_string();
+        }
+        if let Some(renamed) = self.renamed.get(&name) {
+            return renamed.clone();
+        }
+
+        let string = name.to_string();
+        // Note that `_nonce` is a valid member name.
+        let is_valid = string.trim_start_matches('_').starts_with(|c: char| c.is_ascii_alphabetic())
+            && string.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if is_valid && name != sym::SelfLower {
+            self.names.insert(string.clone());
+            self.taken.insert(string.clone());
+            return string;
+        }
+
+        // Join the parts of the name, e.g. `var` and `0` in `$var$0`, and append underscores until it is unused.
+        let parts = string.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).filter(|part| !part.is_empty());
+        let mut renamed = parts.collect::<Vec<_>>().join("_");
+        if !renamed.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            renamed.insert(0, 'v');
+        }
+        if name == sym::SelfLower {
+            renamed.push('_');
+        }
+        while self.taken.contains(&renamed) {
+            renamed.push('_');
+        }
+
+        self.taken.insert(renamed.clone());
+        self.renamed.insert(name, renamed.clone());
+        renamed
+    }
+}
@@ -0,0 +1,272 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::PrettyPrinter;
+
+use leo_ast::{
+    AccessExpression,
+    BinaryExpression,
+    BinaryOperation,
+    CallExpression,
+    Expression,
+    GroupLiteral,
+    Literal,
+    StructExpression,
+    UnaryExpression,
+    UnaryOperation,
+};
+
+/// The precedence of an expression, from the loosest binding to the tightest, as defined in the parser.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Precedence {
+    /// `a ? b : c`.
+    Ternary,
+    /// `a || b`.
+    Or,
+    /// `a && b`.
+    And,
+    /// `a == b` and `a != b`.
+    Equality,
+    /// `a < b`, `a <= b`, `a > b`, and `a >= b`.
+    Ordering,
+    /// `a ^ b`.
+    BitwiseXor,
+    /// `a | b`.
+    BitwiseOr,
+    /// `a & b`.
+    BitwiseAnd,
+    /// `a << b` and `a >> b`.
+    Shift,
+    /// `a + b` and `a - b`.
+    Additive,
+    /// `a * b`, `a / b`, and `a % b`.
+    Multiplicative,
+    /// `a ** b`.
+    Power,
+    /// `a as u8`.
+    Cast,
+    /// `!a` and `-a`.
+    Prefix,
+    /// Accesses, calls, and method calls, e.g. `a.b`, `f(a)`, and `a.add_wrapped(b)`, and the primary expressions.
+    Postfix,
+}
+
+impl Precedence {
+    /// Returns the next tighter precedence.
+    fn tighter(self) -> Self {
+        use Precedence::*;
+        match self {
+            Ternary => Or,
+            Or => And,
+            And => Equality,
+            Equality => Ordering,
+            Ordering => BitwiseXor,
+            BitwiseXor => BitwiseOr,
+            BitwiseOr => BitwiseAnd,
+            BitwiseAnd => Shift,
+            Shift => Additive,
+            Additive => Multiplicative,
+            Multiplicative => Power,
+            Power => Cast,
+            Cast => Prefix,
+            Prefix | Postfix => Postfix,
+        }
+    }
+
+    /// Returns the precedence of `input`.
+    fn of(input: &Expression) -> Self {
+        match input {
+            Expression::Binary(binary) => Self::binary_operator(binary.op).0,
+            Expression::Cast(_) => Precedence::Cast,
+            Expression::Ternary(_) => Precedence::Ternary,
+            Expression::Unary(unary) if matches!(unary.op, UnaryOperation::Not | UnaryOperation::Negate) => {
+                Precedence::Prefix
+            }
+            // Negative literals are parsed as negated literals, e.g. `-1i8`.
+            Expression::Literal(literal) if literal.to_string().starts_with('-') => Precedence::Prefix,
+            _ => Precedence::Postfix,
+        }
+    }
+
+    /// Returns the precedence and the symbol of a binary operator.
+    /// The operators that can only be written as a method call, e.g. `a.add_wrapped(b)`, have postfix precedence.
+    fn binary_operator(op: BinaryOperation) -> (Self, &'static str) {
+        match op {
+            BinaryOperation::Or => (Precedence::Or, "||"),
+            BinaryOperation::And => (Precedence::And, "&&"),
+            BinaryOperation::Eq => (Precedence::Equality, "=="),
+            BinaryOperation::Neq => (Precedence::Equality, "!="),
+            BinaryOperation::Lt => (Precedence::Ordering, "<"),
+            BinaryOperation::Lte => (Precedence::Ordering, "<="),
+            BinaryOperation::Gt => (Precedence::Ordering, ">"),
+            BinaryOperation::Gte => (Precedence::Ordering, ">="),
+            BinaryOperation::Xor => (Precedence::BitwiseXor, "^"),
+            BinaryOperation::BitwiseOr => (Precedence::BitwiseOr, "|"),
+            BinaryOperation::BitwiseAnd => (Precedence::BitwiseAnd, "&"),
+            BinaryOperation::Shl => (Precedence::Shift, "<<"),
+            BinaryOperation::Shr => (Precedence::Shift, ">>"),
+            BinaryOperation::Add => (Precedence::Additive, "+"),
+            BinaryOperation::Sub => (Precedence::Additive, "-"),
+            BinaryOperation::Mul => (Precedence::Multiplicative, "*"),
+            BinaryOperation::Div => (Precedence::Multiplicative, "/"),
+            BinaryOperation::Rem => (Precedence::Multiplicative, "%"),
+            BinaryOperation::Pow => (Precedence::Power, "**"),
+            BinaryOperation::AddWrapped => (Precedence::Postfix, "add_wrapped"),
+            BinaryOperation::DivWrapped => (Precedence::Postfix, "div_wrapped"),
+            BinaryOperation::Mod => (Precedence::Postfix, "mod"),
+            BinaryOperation::MulWrapped => (Precedence::Postfix, "mul_wrapped"),
+            BinaryOperation::Nand => (Precedence::Postfix, "nand"),
+            BinaryOperation::Nor => (Precedence::Postfix, "nor"),
+            BinaryOperation::PowWrapped => (Precedence::Postfix, "pow_wrapped"),
+            BinaryOperation::RemWrapped => (Precedence::Postfix, "rem_wrapped"),
+            BinaryOperation::ShlWrapped => (Precedence::Postfix, "shl_wrapped"),
+            BinaryOperation::ShrWrapped => (Precedence::Postfix, "shr_wrapped"),
+            BinaryOperation::SubWrapped => (Precedence::Postfix, "sub_wrapped"),
+        }
+    }
+}
+
+impl<'a> PrettyPrinter<'a> {
+    pub(crate) fn visit_expression(&mut self, input: &Expression) -> String {
+        match input {
+            Expression::Access(expr) => self.visit_access(expr),
+            Expression::Array(expr) => format!("[{}]", self.visit_expressions(&expr.elements)),
+            Expression::Binary(expr) => self.visit_binary(expr),
+            Expression::Call(expr) => self.visit_call(expr),
+            Expression::Cast(expr) => {
+                let expression = self.visit_operand(&expr.expression, Precedence::Prefix);
+                format!("{expression} as {}", self.visit_type(&expr.type_))
+            }
+            Expression::Struct(expr) => self.visit_struct_init(expr),
+            Expression::Err(_) => "error".to_string(),
+            Expression::Identifier(identifier) => self.name(identifier.name),
+            Expression::Literal(literal) => Self::visit_literal(literal),
+            Expression::Ternary(expr) => {
+                let condition = self.visit_operand(&expr.condition, Precedence::Or);
+                let if_true = self.visit_expression(&expr.if_true);
+                let if_false = self.visit_expression(&expr.if_false);
+                format!("{condition} ? {if_true} : {if_false}")
+            }
+            Expression::Tuple(expr) => format!("({})", self.visit_expressions(&expr.elements)),
+            Expression::Unary(expr) => self.visit_unary(expr),
+            Expression::Unit(_) => "()".to_string(),
+        }
+    }
+
+    /// Returns a comma separated list of `input`, e.g. the arguments of a call.
+    pub(crate) fn visit_expressions(&mut self, input: &[Expression]) -> String {
+        input.iter().map(|expression| self.visit_expression(expression)).collect::<Vec<_>>().join(", ")
+    }
+
+    /// Returns `input`, parenthesized if it binds looser than `precedence`.
+    fn visit_operand(&mut self, input: &Expression, precedence: Precedence) -> String {
+        let operand = self.visit_expression(input);
+        match Precedence::of(input) < precedence {
+            true => format!("({operand})"),
+            false => operand,
+        }
+    }
+
+    fn visit_access(&mut self, input: &AccessExpression) -> String {
+        match input {
+            AccessExpression::Array(access) => {
+                let array = self.visit_operand(&access.array, Precedence::Postfix);
+                format!("{array}[{}]", self.visit_expression(&access.index))
+            }
+            AccessExpression::AssociatedConstant(constant) => {
+                format!("{}::{}", self.visit_type(&constant.ty), self.name(constant.name.name))
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                let ty = self.visit_type(&function.ty);
+                let name = self.name(function.name.name);
+                format!("{ty}::{name}({})", self.visit_expressions(&function.arguments))
+            }
+            AccessExpression::Member(access) => {
+                let inner = self.visit_operand(&access.inner, Precedence::Postfix);
+                format!("{inner}.{}", self.name(access.name.name))
+            }
+            AccessExpression::Tuple(access) => {
+                format!("{}.{}", self.visit_operand(&access.tuple, Precedence::Postfix), access.index)
+            }
+        }
+    }
+
+    fn visit_binary(&mut self, input: &BinaryExpression) -> String {
+        match Precedence::binary_operator(input.op) {
+            (Precedence::Postfix, method) => {
+                let left = self.visit_operand(&input.left, Precedence::Postfix);
+                format!("{left}.{method}({})", self.visit_expression(&input.right))
+            }
+            (precedence, operator) => {
+                // Equality and ordering operators are not associative, and the others are left-associative.
+                let left_precedence = match precedence {
+                    Precedence::Equality | Precedence::Ordering => precedence.tighter(),
+                    _ => precedence,
+                };
+                let left = self.visit_operand(&input.left, left_precedence);
+                let right = self.visit_operand(&input.right, precedence.tighter());
+                format!("{left} {operator} {right}")
+            }
+        }
+    }
+
+    fn visit_call(&mut self, input: &CallExpression) -> String {
+        let function = self.visit_expression(&input.function);
+        let arguments = self.visit_expressions(&input.arguments);
+        match &input.external {
+            Some(external) => format!("{}.leo/{function}({arguments})", self.visit_expression(external)),
+            None => format!("{function}({arguments})"),
+        }
+    }
+
+    fn visit_literal(input: &Literal) -> String {
+        match input {
+            Literal::Group(group) => match &**group {
+                GroupLiteral::Single(value, ..) => format!("{value}group"),
+                GroupLiteral::Tuple(tuple) => format!("({}, {})group", tuple.x, tuple.y),
+            },
+            _ => input.to_string(),
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &StructExpression) -> String {
+        let name = self.name(input.name.name);
+        let members = input
+            .members
+            .iter()
+            .map(|member| {
+                let identifier = self.name(member.identifier.name);
+                match &member.expression {
+                    Some(expression) => format!("{identifier}: {}", self.visit_expression(expression)),
+                    None => identifier,
+                }
+            })
+            .collect::<Vec<_>>();
+        match members.is_empty() {
+            true => format!("{name} {{}}"),
+            false => format!("{name} {{ {} }}", members.join(", ")),
+        }
+    }
+
+    fn visit_unary(&mut self, input: &UnaryExpression) -> String {
+        match input.op {
+            UnaryOperation::Not => format!("!{}", self.visit_operand(&input.receiver, Precedence::Postfix)),
+            UnaryOperation::Negate => format!("-{}", self.visit_operand(&input.receiver, Precedence::Postfix)),
+            UnaryOperation::Try => format!("{}?", self.visit_operand(&input.receiver, Precedence::Postfix)),
+            op => format!("{}.{}()", self.visit_operand(&input.receiver, Precedence::Postfix), op.as_str()),
+        }
+    }
+}
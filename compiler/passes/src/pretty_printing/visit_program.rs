@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::PrettyPrinter;
+
+use leo_ast::{Annotation, Finalize, Function, Input, Mapping, Output, Program, ProgramScope, Struct, Variant};
+
+impl<'a> PrettyPrinter<'a> {
+    pub(crate) fn visit_program(&mut self, input: &Program) {
+        for metadata in input.metadata.iter() {
+            self.line(metadata);
+        }
+        for name in input.imports.keys() {
+            self.line(format!("import {name}.leo;"));
+        }
+        if !input.metadata.is_empty() || !input.imports.is_empty() {
+            self.line("");
+        }
+
+        input.program_scopes.values().for_each(|scope| self.visit_program_scope(scope));
+    }
+
+    fn visit_program_scope(&mut self, input: &ProgramScope) {
+        self.line(format!("program {} {{", input.program_id));
+        self.indent += 1;
+
+        input.consts.iter().for_each(|(_, const_)| self.visit_const(const_));
+        for (_, struct_) in input.structs.iter() {
+            self.separate();
+            self.visit_struct(struct_);
+        }
+        for (_, mapping) in input.mappings.iter() {
+            self.separate();
+            self.visit_mapping(mapping);
+        }
+        for (_, function) in input.functions.iter() {
+            self.separate();
+            self.visit_function(function);
+        }
+
+        self.indent -= 1;
+        self.line("}");
+    }
+
+    /// Writes an empty line, unless the program scope was just opened.
+    fn separate(&mut self) {
+        if !self.output.ends_with("{\n") {
+            self.line("");
+        }
+    }
+
+    fn visit_annotations(&mut self, input: &[Annotation]) {
+        input.iter().for_each(|annotation| self.line(annotation));
+    }
+
+    fn visit_struct(&mut self, input: &Struct) {
+        self.visit_annotations(&input.annotations);
+        let keyword = if input.is_record { "record" } else { "struct" };
+        let name = self.name(input.identifier.name);
+        self.line(format!("{keyword} {name} {{"));
+        self.indent += 1;
+        for member in input.members.iter() {
+            let name = self.name(member.identifier.name);
+            let type_ = self.visit_type(&member.type_);
+            self.line(format!("{}{name}: {type_},", Self::mode_prefix(member.mode)));
+        }
+        self.indent -= 1;
+        self.line("}");
+    }
+
+    fn visit_mapping(&mut self, input: &Mapping) {
+        let name = self.name(input.identifier.name);
+        let key_type = self.visit_type(&input.key_type);
+        let value_type = self.visit_type(&input.value_type);
+        self.line(format!("mapping {name}: {key_type} => {value_type};"));
+    }
+
+    fn visit_function(&mut self, input: &Function) {
+        self.is_method = input.receiver.is_some();
+        self.defined.clear();
+
+        self.visit_annotations(&input.annotations);
+        let variant = match input.variant {
+            Variant::Inline => "inline",
+            Variant::Standard => "function",
+            Variant::Transition => "transition",
+        };
+        let name = self.name(input.identifier.name);
+        let signature = self.visit_signature(&input.input, &input.output);
+        self.line(format!("{variant} {name}{signature} {{"));
+        self.visit_block(&input.block);
+        self.line("}");
+
+        if let Some(finalize) = &input.finalize {
+            self.visit_finalize(finalize);
+        }
+        self.is_method = false;
+    }
+
+    fn visit_finalize(&mut self, input: &Finalize) {
+        self.defined.clear();
+
+        let name = self.name(input.identifier.name);
+        let signature = self.visit_signature(&input.input, &input.output);
+        self.line(format!("finalize {name}{signature} {{"));
+        self.visit_block(&input.block);
+        self.line("}");
+    }
+
+    /// Returns the inputs and outputs of a function or finalize block, e.g. `(a: u8, public b: u8) -> u8`.
+    /// The inputs are recorded as defined.
+    fn visit_signature(&mut self, inputs: &[Input], outputs: &[Output]) -> String {
+        let inputs = inputs
+            .iter()
+            .map(|input| {
+                self.defined.insert(input.identifier().name);
+                let name = self.name(input.identifier().name);
+                match input {
+                    Input::Internal(input) => {
+                        format!("{}{name}: {}", Self::mode_prefix(input.mode), self.visit_type(&input.type_))
+                    }
+                    Input::External(input) => format!("{name}: {}", self.visit_external_type(input)),
+                }
+            })
+            .collect::<Vec<_>>();
+        let mut outputs = outputs
+            .iter()
+            .map(|output| match output {
+                Output::Internal(output) => {
+                    format!("{}{}", Self::mode_prefix(output.mode), self.visit_type(&output.type_))
+                }
+                Output::External(output) => self.visit_external_type(output),
+            })
+            .collect::<Vec<_>>();
+
+        let outputs = match outputs.len() {
+            0 => String::new(),
+            1 => format!(" -> {}", outputs.remove(0)),
+            _ => format!(" -> ({})", outputs.join(", ")),
+        };
+        format!("({}){outputs}", inputs.join(", "))
+    }
+}
@@ -0,0 +1,197 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::PrettyPrinter;
+
+use leo_ast::{
+    AssertStatement,
+    AssertVariant,
+    AssignStatement,
+    Block,
+    ConditionalStatement,
+    ConsoleFunction,
+    ConsoleStatement,
+    ConstDeclaration,
+    Expression,
+    IterationStatement,
+    Node,
+    ReturnStatement,
+    Statement,
+};
+
+impl<'a> PrettyPrinter<'a> {
+    pub(crate) fn visit_statement(&mut self, input: &Statement) {
+        match input {
+            Statement::Assert(stmt) => self.visit_assert(stmt),
+            Statement::Assign(stmt) => self.visit_assign(stmt),
+            // Empty blocks are left in place of the statements removed by the compiler, so they are not printed.
+            Statement::Block(block) if block.statements.is_empty() => {}
+            Statement::Block(block) => {
+                self.line("{");
+                self.visit_block(block);
+                self.line("}");
+            }
+            Statement::Conditional(stmt) => self.visit_conditional(stmt),
+            Statement::Console(stmt) => self.visit_console(stmt),
+            Statement::Const(stmt) => self.visit_const(stmt),
+            Statement::Definition(stmt) => {
+                self.define(&stmt.place);
+                let place = self.visit_expression(&stmt.place);
+                let type_ = self.visit_type(&stmt.type_);
+                let value = self.visit_expression(&stmt.value);
+                self.line(format!("{} {place}: {type_} = {value};", stmt.declaration_type));
+            }
+            Statement::Expression(stmt) => {
+                let expression = self.visit_expression(&stmt.expression);
+                self.line(format!("{expression};"));
+            }
+            Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Return(stmt) => self.visit_return(stmt),
+        }
+    }
+
+    /// Writes the statements of `input`, one level of nesting deeper.
+    pub(crate) fn visit_block(&mut self, input: &Block) {
+        self.indent += 1;
+        input.statements.iter().for_each(|statement| self.visit_statement(statement));
+        self.indent -= 1;
+    }
+
+    /// Records the variables in `place` as defined, returning `true` if any of them was not defined before.
+    fn define(&mut self, place: &Expression) -> bool {
+        match place {
+            Expression::Identifier(identifier) => self.defined.insert(identifier.name),
+            // `count` rather than `any`, so that every element is recorded.
+            Expression::Tuple(tuple) => tuple.elements.iter().filter(|element| self.define(element)).count() > 0,
+            _ => false,
+        }
+    }
+
+    fn visit_assert(&mut self, input: &AssertStatement) {
+        let statement = match &input.variant {
+            AssertVariant::Assert(expr) => format!("assert({});", self.visit_expression(expr)),
+            AssertVariant::AssertEq(left, right) => {
+                format!("assert_eq({}, {});", self.visit_expression(left), self.visit_expression(right))
+            }
+            AssertVariant::AssertNeq(left, right) => {
+                format!("assert_neq({}, {});", self.visit_expression(left), self.visit_expression(right))
+            }
+            AssertVariant::Fail(Some(reason)) => format!("fail(\"{reason}\");"),
+            AssertVariant::Fail(None) => "abort;".to_string(),
+        };
+        self.line(statement);
+    }
+
+    fn visit_assign(&mut self, input: &AssignStatement) {
+        let is_definition = self.define(&input.place);
+        let place = self.visit_expression(&input.place);
+        let value = self.visit_expression(&input.value);
+
+        // After static single assignment, variables are defined by their first assignment, so it is printed as a definition.
+        let type_ = match is_definition {
+            true => self.type_table.get(&input.place.id()).or_else(|| self.type_table.get(&input.value.id())),
+            false => None,
+        };
+        match type_ {
+            Some(type_) => {
+                let type_ = self.visit_type(&type_);
+                self.line(format!("let {place}: {type_} = {value};"));
+            }
+            None => self.line(format!("{place} = {value};")),
+        }
+    }
+
+    fn visit_conditional(&mut self, input: &ConditionalStatement) {
+        let condition = self.visit_expression(&input.condition);
+        self.line(format!("if {condition} {{"));
+        self.visit_block(&input.then);
+
+        // Print an `else if` chain without nesting.
+        let mut otherwise = input.otherwise.as_deref();
+        while let Some(statement) = otherwise {
+            match statement {
+                Statement::Conditional(conditional) => {
+                    let condition = self.visit_expression(&conditional.condition);
+                    self.line(format!("}} else if {condition} {{"));
+                    self.visit_block(&conditional.then);
+                    otherwise = conditional.otherwise.as_deref();
+                }
+                Statement::Block(block) => {
+                    self.line("} else {");
+                    self.visit_block(block);
+                    otherwise = None;
+                }
+                _ => {
+                    unreachable!("The parser guarantees that the `otherwise` is a `Block` or a `ConditionalStatement`.")
+                }
+            }
+        }
+        self.line("}");
+    }
+
+    fn visit_console(&mut self, input: &ConsoleStatement) {
+        let function = match &input.function {
+            ConsoleFunction::Assert(expr) => format!("assert({})", self.visit_expression(expr)),
+            ConsoleFunction::AssertEq(left, right) => {
+                format!("assert_eq({}, {})", self.visit_expression(left), self.visit_expression(right))
+            }
+            ConsoleFunction::AssertNeq(left, right) => {
+                format!("assert_neq({}, {})", self.visit_expression(left), self.visit_expression(right))
+            }
+            ConsoleFunction::Error(format, arguments) | ConsoleFunction::Log(format, arguments) => {
+                let name = if matches!(input.function, ConsoleFunction::Error(..)) { "error" } else { "log" };
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| format!(", {}", self.visit_expression(argument)))
+                    .collect::<String>();
+                format!("{name}(\"{format}\"{arguments})")
+            }
+        };
+        self.line(format!("console.{function};"));
+    }
+
+    pub(crate) fn visit_const(&mut self, input: &ConstDeclaration) {
+        let place = self.name(input.place.name);
+        let type_ = self.visit_type(&input.type_);
+        let value = self.visit_expression(&input.value);
+        self.line(format!("const {place}: {type_} = {value};"));
+    }
+
+    fn visit_iteration(&mut self, input: &IterationStatement) {
+        self.defined.insert(input.variable.name);
+        let variable = self.name(input.variable.name);
+        let type_ = self.visit_type(&input.type_);
+        let start = self.visit_expression(&input.start);
+        let stop = self.visit_expression(&input.stop);
+        let range = if input.inclusive { "..=" } else { ".." };
+        self.line(format!("for {variable}: {type_} in {start}{range}{stop} {{"));
+        self.visit_block(&input.block);
+        self.line("}");
+    }
+
+    fn visit_return(&mut self, input: &ReturnStatement) {
+        let expression = match &input.expression {
+            Expression::Unit(_) => String::new(),
+            expression => format!(" {}", self.visit_expression(expression)),
+        };
+        let finalize = match &input.finalize_arguments {
+            Some(arguments) if arguments.is_empty() => " then finalize".to_string(),
+            Some(arguments) => format!(" then finalize({})", self.visit_expressions(arguments)),
+            None => String::new(),
+        };
+        self.line(format!("return{expression}{finalize};"));
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::PrettyPrinter;
+
+use leo_ast::{External, Mode, Type};
+
+impl<'a> PrettyPrinter<'a> {
+    pub(crate) fn visit_type(&mut self, input: &Type) -> String {
+        match input {
+            Type::Boolean => "bool".to_string(),
+            Type::Address
+            | Type::Field
+            | Type::Group
+            | Type::Scalar
+            | Type::Signature
+            | Type::String
+            | Type::Integer(..)
+            | Type::Unit
+            | Type::Err => format!("{input}"),
+            Type::Identifier(identifier) => self.name(identifier.name),
+            Type::Array(array_type) => {
+                format!("[{}; {}]", self.visit_type(array_type.element_type()), array_type.length())
+            }
+            Type::Mapping(mapping_type) => {
+                format!("({} => {})", self.visit_type(&mapping_type.key), self.visit_type(&mapping_type.value))
+            }
+            Type::Option(option_type) => format!("Option<{}>", self.visit_type(option_type.element_type())),
+            Type::Result(result_type) => {
+                format!(
+                    "Result<{}, {}>",
+                    self.visit_type(result_type.ok_type()),
+                    self.visit_type(result_type.err_type())
+                )
+            }
+            Type::Tuple(tuple_type) => {
+                let elements = tuple_type.elements().iter().map(|type_| self.visit_type(type_)).collect::<Vec<_>>();
+                format!("({})", elements.join(", "))
+            }
+        }
+    }
+
+    /// Returns the keyword of `mode` followed by a space, e.g. `public `, or nothing if there is no mode.
+    pub(crate) fn mode_prefix(mode: Mode) -> String {
+        match mode {
+            Mode::None => String::new(),
+            _ => format!("{mode} "),
+        }
+    }
+
+    /// Returns the type of a record defined in another program, e.g. `token.leo/Token.record`.
+    pub(crate) fn visit_external_type(&mut self, input: &External) -> String {
+        format!("{}.leo/{}.record", self.name(input.program_name.name), self.name(input.record.name))
+    }
+}
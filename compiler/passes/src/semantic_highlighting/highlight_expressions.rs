@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::{SemanticHighlighter, SemanticTokenKind};
+
+use leo_ast::*;
+use leo_span::sym;
+
+impl<'a> ExpressionVisitor<'a> for SemanticHighlighter<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(array) => {
+                self.visit_expression(&array.array, additional);
+                self.visit_expression(&array.index, additional);
+            }
+            AccessExpression::AssociatedConstant(constant) => {
+                if let Type::Identifier(module) = &constant.ty {
+                    self.name(module, SemanticTokenKind::Builtin);
+                }
+                self.name(&constant.name, SemanticTokenKind::Builtin);
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                if let Type::Identifier(module) = &function.ty {
+                    self.name(module, SemanticTokenKind::Builtin);
+                }
+                self.name(&function.name, SemanticTokenKind::Builtin);
+                function.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+            }
+            AccessExpression::Member(member) => {
+                // Members depend on the type of the accessed value, so they are not resolved.
+                let builtin = match &*member.inner {
+                    Expression::Identifier(inner) if inner.name == sym::SelfLower || inner.name == sym::block => true,
+                    // `network` is the program context only when it does not name a variable.
+                    Expression::Identifier(inner)
+                        if inner.name == sym::network && self.resolution_table.get(&inner.id).is_none() =>
+                    {
+                        self.name(inner, SemanticTokenKind::Builtin);
+                        true
+                    }
+                    _ => false,
+                };
+                let kind = if builtin { SemanticTokenKind::Builtin } else { SemanticTokenKind::Member };
+                self.name(&member.name, kind);
+                self.visit_expression(&member.inner, additional);
+            }
+            AccessExpression::Tuple(tuple) => self.visit_expression(&tuple.tuple, additional),
+        }
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // The name of a method is classified as a function rather than as a member.
+        if let Expression::Access(AccessExpression::Member(access)) = &*input.function {
+            self.name(&access.name, SemanticTokenKind::Function);
+            self.visit_expression(&access.inner, additional);
+        }
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input
+            .members
+            .iter()
+            .filter_map(|member| member.expression.as_ref())
+            .for_each(|expression| self.visit_expression(expression, additional));
+    }
+}
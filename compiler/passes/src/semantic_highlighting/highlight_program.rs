@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::{SemanticHighlighter, SemanticTokenKind};
+
+use leo_ast::*;
+
+impl<'a> ProgramVisitor<'a> for SemanticHighlighter<'a> {
+    fn visit_struct(&mut self, input: &'a Struct) {
+        if input.is_record {
+            self.records.insert(input.identifier.span);
+        }
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            // The name of a finalize block is that of its function, and is not resolved separately.
+            self.name(&finalize.identifier, SemanticTokenKind::Function);
+            self.visit_block(&finalize.block);
+        }
+    }
+}
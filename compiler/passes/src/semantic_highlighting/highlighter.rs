@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::{DefinitionKind, ResolutionTable};
+
+use leo_ast::Identifier;
+use leo_errors::Result;
+use leo_parser::TokenClass;
+use leo_span::{source_map::SourceFile, span::BytePos, Span};
+
+use indexmap::{IndexMap, IndexSet};
+use serde::Serialize;
+
+/// The classification of a token for semantic highlighting.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SemanticTokenKind {
+    /// A keyword, e.g. `let`.
+    Keyword,
+    /// A primitive type, e.g. `u32`.
+    Type,
+    /// A number, address, or boolean literal.
+    Literal,
+    /// A string literal.
+    String,
+    /// A comment.
+    Comment,
+    /// An operator or a delimiter.
+    Punctuation,
+    /// The name of a function, transition, finalize block, or method.
+    Function,
+    /// The name of a struct.
+    Struct,
+    /// The name of a record.
+    Record,
+    /// The name of a member of a struct or record.
+    Member,
+    /// The name of a constant, or of a loop variable.
+    Constant,
+    /// The name of an input of a function or finalize block.
+    Parameter,
+    /// The name of a variable declared with `let`.
+    Variable,
+    /// The name of a mapping.
+    Mapping,
+    /// The name of a core module, one of its functions or constants, or a member of `self` or `block`.
+    Builtin,
+    /// Any other name, e.g. the name of a program or an annotation.
+    Identifier,
+}
+
+/// A classified token in a source file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct SemanticToken {
+    /// The classification of the token.
+    pub kind: SemanticTokenKind,
+    /// The span of the token.
+    pub span: Span,
+}
+
+/// Classifies the names in a program for semantic highlighting.
+pub struct SemanticHighlighter<'a> {
+    /// The names resolved by the Name Resolution pass.
+    pub(crate) resolution_table: &'a ResolutionTable,
+    /// The spans of the names of records, which are resolved as structs.
+    pub(crate) records: IndexSet<Span>,
+    /// The loop variables, which are resolved as variables.
+    pub(crate) loop_variables: IndexSet<Span>,
+    /// The classifications of names that are not resolved, by their start positions.
+    pub(crate) names: IndexMap<BytePos, SemanticTokenKind>,
+}
+
+impl<'a> SemanticHighlighter<'a> {
+    /// Returns a new semantic highlighter.
+    pub fn new(resolution_table: &'a ResolutionTable) -> Self {
+        Self { resolution_table, records: IndexSet::new(), loop_variables: IndexSet::new(), names: IndexMap::new() }
+    }
+
+    /// Classifies a name that is not resolved.
+    pub(crate) fn name(&mut self, identifier: &Identifier, kind: SemanticTokenKind) {
+        self.names.insert(identifier.span.lo, kind);
+    }
+
+    /// Classifies the tokens of a source file.
+    pub(crate) fn classify(&self, source_file: &SourceFile) -> Result<Vec<SemanticToken>> {
+        // The resolved names, by their start positions.
+        let resolved = self
+            .resolution_table
+            .references()
+            .into_iter()
+            .map(|reference| {
+                (reference.span.lo, self.resolved_kind(reference.definition.kind, reference.definition.span))
+            })
+            .collect::<IndexMap<_, _>>();
+
+        let tokens = leo_parser::classify_tokens(&source_file.src, source_file.start_pos)?;
+        Ok(tokens
+            .into_iter()
+            .map(|(class, span)| {
+                let kind = match class {
+                    TokenClass::Keyword => SemanticTokenKind::Keyword,
+                    TokenClass::Type => SemanticTokenKind::Type,
+                    TokenClass::Literal => SemanticTokenKind::Literal,
+                    TokenClass::String => SemanticTokenKind::String,
+                    TokenClass::Comment => SemanticTokenKind::Comment,
+                    TokenClass::Punctuation => SemanticTokenKind::Punctuation,
+                    TokenClass::Identifier => resolved
+                        .get(&span.lo)
+                        .or_else(|| self.names.get(&span.lo))
+                        .copied()
+                        .unwrap_or(SemanticTokenKind::Identifier),
+                };
+                SemanticToken { kind, span }
+            })
+            .collect())
+    }
+
+    /// Returns the classification of a name resolved to a definition of the given kind and span.
+    fn resolved_kind(&self, kind: DefinitionKind, definition: Span) -> SemanticTokenKind {
+        match kind {
+            DefinitionKind::Const => SemanticTokenKind::Constant,
+            DefinitionKind::Function => SemanticTokenKind::Function,
+            DefinitionKind::Input => SemanticTokenKind::Parameter,
+            DefinitionKind::Mapping => SemanticTokenKind::Mapping,
+            DefinitionKind::Member => SemanticTokenKind::Member,
+            DefinitionKind::Struct if self.records.contains(&definition) => SemanticTokenKind::Record,
+            DefinitionKind::Struct => SemanticTokenKind::Struct,
+            DefinitionKind::Variable if self.loop_variables.contains(&definition) => SemanticTokenKind::Constant,
+            DefinitionKind::Variable => SemanticTokenKind::Variable,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+//! The Semantic Highlighting pass classifies every token of a source file, so that editors can highlight names by
+//! what they refer to rather than by how they are spelled. It is run after the Name Resolution pass, whose results
+//! classify most names; the names that are not resolved, such as members in member accesses and the functions of
+//! core modules, are classified by where they appear in the AST.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u32) -> u32 {
+//!     let b: u32 = a + 1u32;
+//!     return BHP256::hash_to_u32(b);
+//! }
+//! ```
+//!
+//! The pass classifies `main` as a function, `a` as a parameter, `b` as a variable, `BHP256` and `hash_to_u32` as
+//! builtins, and the other tokens by their lexical class, e.g. `u32` as a type and `1u32` as a literal.
+//! It does not modify the AST.
+
+mod highlight_expressions;
+
+mod highlight_program;
+
+mod highlight_statements;
+
+pub mod highlighter;
+pub use highlighter::*;
+
+use crate::{Pass, ResolutionTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::Result;
+use leo_span::source_map::SourceFile;
+
+impl<'a> Pass for SemanticHighlighter<'a> {
+    type Input = (&'a Ast, &'a ResolutionTable, &'a SourceFile);
+    type Output = Result<Vec<SemanticToken>>;
+
+    fn do_pass((ast, resolution_table, source_file): Self::Input) -> Self::Output {
+        let mut visitor = SemanticHighlighter::new(resolution_table);
+        visitor.visit_program(ast.as_repr());
+
+        visitor.classify(source_file)
+    }
+}
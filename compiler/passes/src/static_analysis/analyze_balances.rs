@@ -0,0 +1,341 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::StaticAnalyzer;
+
+use leo_ast::*;
+use leo_errors::StaticAnalyzerWarning;
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+use std::fmt;
+
+/// A linear combination of opaque terms, such as inputs and members of input records, plus a constant.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct LinearSum {
+    /// The coefficient of each term, none of which are zero.
+    terms: IndexMap<String, i128>,
+    /// The constant part of the sum.
+    constant: i128,
+}
+
+impl LinearSum {
+    fn term(name: String) -> Self {
+        Self { terms: IndexMap::from([(name, 1)]), constant: 0 }
+    }
+
+    fn constant(constant: i128) -> Self {
+        Self { terms: IndexMap::new(), constant }
+    }
+
+    /// Returns `self + sign * other`, or `None` on overflow.
+    fn add(mut self, other: &Self, sign: i128) -> Option<Self> {
+        for (term, coefficient) in other.terms.iter() {
+            let entry = self.terms.entry(term.clone()).or_default();
+            *entry = entry.checked_add(sign.checked_mul(*coefficient)?)?;
+        }
+        self.terms.retain(|_, coefficient| *coefficient != 0);
+        self.constant = self.constant.checked_add(sign.checked_mul(other.constant)?)?;
+        Some(self)
+    }
+
+    /// Returns `factor * self`, or `None` on overflow.
+    fn scale(mut self, factor: i128) -> Option<Self> {
+        for coefficient in self.terms.values_mut() {
+            *coefficient = coefficient.checked_mul(factor)?;
+        }
+        self.terms.retain(|_, coefficient| *coefficient != 0);
+        self.constant = self.constant.checked_mul(factor)?;
+        Some(self)
+    }
+}
+
+impl fmt::Display for LinearSum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts: Vec<(bool, String)> = self
+            .terms
+            .iter()
+            .map(|(term, coefficient)| match coefficient.unsigned_abs() {
+                1 => (*coefficient < 0, term.clone()),
+                magnitude => (*coefficient < 0, format!("{magnitude} * {term}")),
+            })
+            .collect();
+        if self.constant != 0 || parts.is_empty() {
+            parts.push((self.constant < 0, self.constant.unsigned_abs().to_string()));
+        }
+        for (index, (is_negative, part)) in parts.iter().enumerate() {
+            match (index, is_negative) {
+                (0, false) => write!(f, "{part}")?,
+                (0, true) => write!(f, "-{part}")?,
+                (_, false) => write!(f, " + {part}")?,
+                (_, true) => write!(f, " - {part}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The value of a variable, as far as the balance analysis is concerned.
+#[derive(Clone, Debug)]
+enum Binding {
+    /// An integer, which is a linear combination of the inputs.
+    Sum(LinearSum),
+    /// A record, with the value of each of its balance members, if it is known.
+    Record(IndexMap<Symbol, Option<LinearSum>>),
+}
+
+/// The state of the balance analysis of a transition.
+struct Balances<'b, 'a> {
+    analyzer: &'b StaticAnalyzer<'a>,
+    /// The values of the variables that are defined once and never assigned.
+    bindings: IndexMap<Symbol, Binding>,
+}
+
+impl Balances<'_, '_> {
+    /// Evaluates an expression to a record or an integer, returning `None` if its value is unknown.
+    fn binding(&self, input: &Expression) -> Option<Binding> {
+        match input {
+            Expression::Identifier(identifier) if self.bindings.contains_key(&identifier.name) => {
+                self.bindings.get(&identifier.name).cloned()
+            }
+            Expression::Struct(struct_) => {
+                let members = self.analyzer.records.get(&struct_.name.name)?;
+                let values = members
+                    .iter()
+                    .map(|member| {
+                        let value =
+                            struct_.members.iter().find(|init| init.identifier.name == *member).and_then(|init| {
+                                match &init.expression {
+                                    Some(expression) => self.sum(expression),
+                                    // A member initialized with the shorthand `{ amount }` takes the value of the variable.
+                                    None => self.sum(&Expression::Identifier(init.identifier)),
+                                }
+                            });
+                        (*member, value)
+                    })
+                    .collect();
+                Some(Binding::Record(values))
+            }
+            _ => self.sum(input).map(Binding::Sum),
+        }
+    }
+
+    /// Evaluates an integer expression as a linear combination of the inputs, returning `None` if it is not one.
+    fn sum(&self, input: &Expression) -> Option<LinearSum> {
+        if let Some(value) = self.analyzer.evaluate(input) {
+            return integer_value(&value).map(LinearSum::constant);
+        }
+        match input {
+            Expression::Identifier(identifier) => match self.bindings.get(&identifier.name)? {
+                Binding::Sum(sum) => Some(sum.clone()),
+                Binding::Record(_) => None,
+            },
+            Expression::Access(AccessExpression::Member(access)) => match self.binding(&access.inner)? {
+                Binding::Record(members) => members.get(&access.name.name).cloned().flatten(),
+                Binding::Sum(_) => None,
+            },
+            Expression::Binary(binary) => {
+                let (left, right) = (self.sum(&binary.left)?, self.sum(&binary.right)?);
+                match binary.op {
+                    BinaryOperation::Add => left.add(&right, 1),
+                    BinaryOperation::Sub => left.add(&right, -1),
+                    BinaryOperation::Mul if left.terms.is_empty() => right.scale(left.constant),
+                    BinaryOperation::Mul if right.terms.is_empty() => left.scale(right.constant),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl StaticAnalyzer<'_> {
+    /// Warns if a transition that consumes records produces records of the same type with a larger balance, unless
+    /// the difference is made up of public inputs, which are treated as declared amounts.
+    /// The balances are only compared when the outputs are linear combinations of the inputs, so that the warning is
+    /// only emitted for obviously non-conserving arithmetic.
+    pub(crate) fn check_balances(&mut self, function: &Function) {
+        let mut bindings = IndexMap::new();
+        let mut public = IndexSet::new();
+        // The input records of each record type, by name.
+        let mut consumed: IndexMap<Symbol, Vec<Symbol>> = IndexMap::new();
+        for input in function.input.iter() {
+            let Input::Internal(input) = input else { continue };
+            let name = input.identifier.name;
+            match &input.type_ {
+                Type::Identifier(record) if self.records.contains_key(&record.name) => {
+                    let members = self.records[&record.name]
+                        .iter()
+                        .map(|member| (*member, Some(LinearSum::term(format!("{name}.{member}")))))
+                        .collect();
+                    bindings.insert(name, Binding::Record(members));
+                    consumed.entry(record.name).or_default().push(name);
+                }
+                Type::Integer(_) => {
+                    bindings.insert(name, Binding::Sum(LinearSum::term(name.to_string())));
+                    if matches!(input.mode, Mode::Public | Mode::Constant) {
+                        public.insert(name.to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+        if consumed.is_empty() {
+            return;
+        }
+
+        // Only a single return, at the end of the transition, is analyzed.
+        let mut assigned = IndexSet::new();
+        let mut num_returns = 0;
+        function
+            .block
+            .statements
+            .iter()
+            .for_each(|statement| collect_writes(statement, &mut assigned, &mut num_returns));
+        let Some(Statement::Return(output)) = function.block.statements.last() else { return };
+        if num_returns != 1 {
+            return;
+        }
+
+        // Evaluate the variables that are defined once and never assigned.
+        let mut balances = Balances { analyzer: self, bindings };
+        for statement in function.block.statements.iter() {
+            if let Statement::Definition(DefinitionStatement {
+                place: Expression::Identifier(identifier), value, ..
+            }) = statement
+            {
+                if !assigned.contains(&identifier.name) {
+                    if let Some(binding) = balances.binding(value) {
+                        balances.bindings.insert(identifier.name, binding);
+                    }
+                }
+            }
+        }
+
+        // Sum the balances of the output records of each consumed record type.
+        let elements = match &output.expression {
+            Expression::Tuple(tuple) => tuple.elements.iter().collect(),
+            expression => vec![expression],
+        };
+        let mut produced: IndexMap<Symbol, IndexMap<Symbol, Option<LinearSum>>> = IndexMap::new();
+        for element in elements {
+            let Some(Type::Identifier(record)) = balances.analyzer.type_table.get(&element.id()) else { continue };
+            let Some(members) = balances.analyzer.records.get(&record.name) else { continue };
+            let totals = produced
+                .entry(record.name)
+                .or_insert_with(|| members.iter().map(|member| (*member, Some(LinearSum::default()))).collect());
+            let values = match balances.binding(element) {
+                Some(Binding::Record(values)) => values,
+                _ => IndexMap::new(),
+            };
+            for (member, total) in totals.iter_mut() {
+                *total = match (total.take(), values.get(member).cloned().flatten()) {
+                    (Some(total), Some(value)) => total.add(&value, 1),
+                    _ => None,
+                };
+            }
+        }
+
+        // Compare the balances of the output records with the balances of the input records.
+        let mut warnings = Vec::new();
+        for (record, inputs) in consumed.iter() {
+            let members = &balances.analyzer.records[record];
+            for member in members.iter() {
+                let total = match produced.get(record) {
+                    Some(totals) => totals.get(member).cloned().flatten(),
+                    None => Some(LinearSum::default()),
+                };
+                let excess = total.and_then(|total| {
+                    inputs
+                        .iter()
+                        .try_fold(total, |excess, input| excess.add(&LinearSum::term(format!("{input}.{member}")), -1))
+                });
+                let Some(mut excess) = excess else { continue };
+                excess.terms.retain(|term, coefficient| !(*coefficient > 0 && public.contains(term)));
+                // A negative term may cancel out the excess, so the balance may be conserved.
+                if excess.terms.values().any(|coefficient| *coefficient < 0) {
+                    continue;
+                }
+                if !excess.terms.is_empty() || excess.constant > 0 {
+                    warnings.push(StaticAnalyzerWarning::non_conserving_balance(record, member, excess, output.span));
+                }
+            }
+        }
+        for warning in warnings {
+            self.warn(output.span, warning);
+        }
+    }
+}
+
+/// Collects the names of the variables assigned in a statement, and counts its return statements.
+fn collect_writes(input: &Statement, assigned: &mut IndexSet<Symbol>, num_returns: &mut usize) {
+    match input {
+        Statement::Assign(assign) => match &assign.place {
+            Expression::Identifier(identifier) => {
+                assigned.insert(identifier.name);
+            }
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| {
+                if let Expression::Identifier(identifier) = element {
+                    assigned.insert(identifier.name);
+                }
+            }),
+            place => {
+                // Assigning to a member or an element changes the variable that contains it.
+                let mut place = place;
+                while let Expression::Access(AccessExpression::Array(ArrayAccess { array: inner, .. }))
+                | Expression::Access(AccessExpression::Member(MemberAccess { inner, .. }))
+                | Expression::Access(AccessExpression::Tuple(TupleAccess { tuple: inner, .. })) = place
+                {
+                    place = inner;
+                }
+                if let Expression::Identifier(identifier) = place {
+                    assigned.insert(identifier.name);
+                }
+            }
+        },
+        Statement::Block(block) => {
+            block.statements.iter().for_each(|statement| collect_writes(statement, assigned, num_returns))
+        }
+        Statement::Conditional(conditional) => {
+            conditional.then.statements.iter().for_each(|statement| collect_writes(statement, assigned, num_returns));
+            if let Some(otherwise) = &conditional.otherwise {
+                collect_writes(otherwise, assigned, num_returns);
+            }
+        }
+        Statement::Iteration(iteration) => {
+            iteration.block.statements.iter().for_each(|statement| collect_writes(statement, assigned, num_returns))
+        }
+        Statement::Return(_) => *num_returns += 1,
+        _ => {}
+    }
+}
+
+/// Returns the value of an integer as an `i128`, if it fits.
+fn integer_value(value: &Value) -> Option<i128> {
+    match value {
+        Value::I8(value, _) => Some(*value as i128),
+        Value::I16(value, _) => Some(*value as i128),
+        Value::I32(value, _) => Some(*value as i128),
+        Value::I64(value, _) => Some(*value as i128),
+        Value::I128(value, _) => Some(*value),
+        Value::U8(value, _) => Some(*value as i128),
+        Value::U16(value, _) => Some(*value as i128),
+        Value::U32(value, _) => Some(*value as i128),
+        Value::U64(value, _) => Some(*value as i128),
+        Value::U128(value, _) => i128::try_from(*value).ok(),
+        _ => None,
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::StaticAnalyzer;
+
+use leo_ast::*;
+
+impl StaticAnalyzer<'_> {
+    /// Evaluates an expression that only depends on literals and constants, returning `None` for any other expression.
+    pub(crate) fn evaluate(&self, input: &Expression) -> Option<Value> {
+        match input {
+            Expression::Literal(literal) => {
+                let literal = match literal {
+                    Literal::Unsuffixed(..) => {
+                        literal.clone().with_inferred_suffix(&self.type_table.get(&literal.id())?)?
+                    }
+                    _ => literal.clone(),
+                };
+                Value::try_from(&literal).ok()
+            }
+            Expression::Identifier(identifier) => self.constants.get(&identifier.name).cloned().flatten(),
+            Expression::Unary(unary) => {
+                let receiver = self.evaluate(&unary.receiver)?;
+                match unary.op {
+                    UnaryOperation::Abs => receiver.abs(unary.span),
+                    UnaryOperation::AbsWrapped => receiver.abs_wrapped(unary.span),
+                    UnaryOperation::Negate => receiver.neg(unary.span),
+                    UnaryOperation::Not => receiver.not(unary.span),
+                    _ => return None,
+                }
+                .ok()
+            }
+            Expression::Binary(binary) => {
+                let (left, right) = (self.evaluate(&binary.left), self.evaluate(&binary.right));
+                // `false && x` and `true || x` are constant, even if `x` is not.
+                let absorbing = match binary.op {
+                    BinaryOperation::And => Some(false),
+                    BinaryOperation::Or => Some(true),
+                    _ => None,
+                };
+                if let Some(absorbing) = absorbing {
+                    for value in [&left, &right] {
+                        if matches!(value, Some(Value::Boolean(value, _)) if *value == absorbing) {
+                            return Some(Value::Boolean(absorbing, binary.span));
+                        }
+                    }
+                }
+                let (left, right, span) = (left?, right?, binary.span);
+                match binary.op {
+                    BinaryOperation::Add => left.add(right, span),
+                    BinaryOperation::AddWrapped => left.add_wrapped(right, span),
+                    BinaryOperation::And | BinaryOperation::BitwiseAnd => left.bitand(right, span),
+                    BinaryOperation::Div => left.div(right, span),
+                    BinaryOperation::DivWrapped => left.div_wrapped(right, span),
+                    BinaryOperation::Eq => left.eq(right, span),
+                    BinaryOperation::Gte => left.ge(right, span),
+                    BinaryOperation::Gt => left.gt(right, span),
+                    BinaryOperation::Lte => left.le(right, span),
+                    BinaryOperation::Lt => left.lt(right, span),
+                    BinaryOperation::Mul => left.mul(right, span),
+                    BinaryOperation::MulWrapped => left.mul_wrapped(right, span),
+                    BinaryOperation::Nand => left.bitand(right, span).and_then(|value| value.not(span)),
+                    BinaryOperation::Neq => left.eq(right, span).and_then(|value| value.not(span)),
+                    BinaryOperation::Nor => left.bitor(right, span).and_then(|value| value.not(span)),
+                    BinaryOperation::Or | BinaryOperation::BitwiseOr => left.bitor(right, span),
+                    BinaryOperation::Pow => left.pow(right, span),
+                    BinaryOperation::PowWrapped => left.pow_wrapped(right, span),
+                    BinaryOperation::Shl => left.shl(right, span),
+                    BinaryOperation::ShlWrapped => left.shl_wrapped(right, span),
+                    BinaryOperation::Shr => left.shr(right, span),
+                    BinaryOperation::ShrWrapped => left.shr_wrapped(right, span),
+                    BinaryOperation::Sub => left.sub(right, span),
+                    BinaryOperation::SubWrapped => left.sub_wrapped(right, span),
+                    BinaryOperation::Xor => left.xor(right, span),
+                    _ => return None,
+                }
+                .ok()
+            }
+            Expression::Ternary(ternary) => match self.evaluate(&ternary.condition)? {
+                Value::Boolean(true, _) => self.evaluate(&ternary.if_true),
+                Value::Boolean(false, _) => self.evaluate(&ternary.if_false),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for StaticAnalyzer<'_> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().for_each(|member| match &member.expression {
+            Some(expression) => self.visit_expression(expression, &()),
+            // The shorthand `Foo { x }` reads the variable `x`.
+            None => self.visit_identifier(&member.identifier, &()),
+        });
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        // The variable is read, so the value it holds is not dead.
+        self.overwritten.shift_remove(&input.name);
+    }
+}
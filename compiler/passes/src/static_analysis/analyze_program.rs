@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::StaticAnalyzer;
+
+use leo_ast::{Function, ProgramScope, ProgramVisitor, Statement, StatementVisitor, Type, Variant};
+
+impl StaticAnalyzer<'_> {
+    /// Evaluates the constants declared in a statement, in the order in which they are declared.
+    fn evaluate_constants(&mut self, input: &Statement) {
+        match input {
+            Statement::Const(declaration) => {
+                let value = self.evaluate(&declaration.value);
+                // Constants with the same name can be declared in sibling blocks, so their value is ambiguous.
+                let value = if self.constants.contains_key(&declaration.place.name) { None } else { value };
+                self.constants.insert(declaration.place.name, value);
+            }
+            Statement::Block(block) => block.statements.iter().for_each(|statement| self.evaluate_constants(statement)),
+            Statement::Conditional(conditional) => {
+                conditional.then.statements.iter().for_each(|statement| self.evaluate_constants(statement));
+                if let Some(otherwise) = &conditional.otherwise {
+                    self.evaluate_constants(otherwise);
+                }
+            }
+            Statement::Iteration(iteration) => {
+                iteration.block.statements.iter().for_each(|statement| self.evaluate_constants(statement))
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for StaticAnalyzer<'_> {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        // Evaluate the constants of the program scope before they are used in its functions.
+        self.constants.clear();
+        for (name, declaration) in input.consts.iter() {
+            let value = self.evaluate(&declaration.value);
+            self.constants.insert(*name, value);
+        }
+
+        // Collect the balance members of the records of the program scope.
+        self.records = input
+            .structs
+            .iter()
+            .filter(|(_, struct_)| struct_.is_record)
+            .map(|(name, record)| {
+                let members = record
+                    .members
+                    .iter()
+                    .filter(|member| matches!(member.type_, Type::Integer(_)))
+                    .map(|member| member.identifier.name)
+                    .collect();
+                (*name, members)
+            })
+            .collect();
+
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        let constants = self.constants.clone();
+        input.block.statements.iter().for_each(|statement| self.evaluate_constants(statement));
+        if let Some(finalize) = &input.finalize {
+            finalize.block.statements.iter().for_each(|statement| self.evaluate_constants(statement));
+        }
+
+        // Nothing is read after the function returns.
+        self.overwritten.clear();
+        self.visit_block(&input.block);
+
+        if let Some(finalize) = &input.finalize {
+            self.overwritten.clear();
+            self.visit_block(&finalize.block);
+        }
+
+        if input.variant == Variant::Transition {
+            self.check_balances(input);
+        }
+
+        self.emit_warnings();
+        self.constants = constants;
+    }
+}
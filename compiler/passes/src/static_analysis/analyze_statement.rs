@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::StaticAnalyzer;
+
+use leo_ast::*;
+use leo_errors::StaticAnalyzerWarning;
+
+use indexmap::IndexSet;
+
+impl<'a> StatementVisitor<'a> for StaticAnalyzer<'_> {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        // An assignment of a place to itself does not overwrite anything, but reads the place.
+        if matches!(input.place, Expression::Identifier(_) | Expression::Access(_))
+            && input.place.to_string() == input.value.to_string()
+        {
+            self.warn(input.span, StaticAnalyzerWarning::self_assignment(&input.place, input.span));
+            self.visit_expression(&input.value, &());
+            return;
+        }
+
+        match &input.place {
+            Expression::Identifier(identifier) => {
+                if self.overwritten.contains(&identifier.name) {
+                    self.warn(input.span, StaticAnalyzerWarning::dead_store(identifier, input.span));
+                }
+                self.overwritten.insert(identifier.name);
+            }
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| {
+                if let Expression::Identifier(identifier) = element {
+                    self.overwritten.insert(identifier.name);
+                }
+            }),
+            // Assigning to a member or an element only overwrites part of the variable, so it is treated as a read.
+            place => self.visit_expression(place, &()),
+        }
+
+        self.visit_expression(&input.value, &());
+    }
+
+    fn visit_block(&mut self, input: &'a Block) {
+        // The analysis flows backwards, from the last statement to the first.
+        input.statements.iter().rev().for_each(|statement| self.visit_statement(statement));
+    }
+
+    fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
+        let after = self.overwritten.clone();
+
+        self.visit_block(&input.then);
+        let then = std::mem::replace(&mut self.overwritten, after);
+
+        if let Some(otherwise) = &input.otherwise {
+            self.visit_statement(otherwise);
+        }
+
+        // A variable is only overwritten if it is overwritten on both branches.
+        self.overwritten.retain(|name| then.contains(name));
+
+        if let Some(value) = self.evaluate(&input.condition) {
+            let reason = match input.condition {
+                Expression::Literal(_) => "it is a literal",
+                _ => "its value is determined by literals and constants",
+            };
+            self.warn(
+                input.condition.span(),
+                StaticAnalyzerWarning::constant_condition(value, reason, input.condition.span()),
+            );
+        }
+
+        self.visit_expression(&input.condition, &());
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        match &input.place {
+            Expression::Identifier(identifier) => {
+                if self.overwritten.contains(&identifier.name) {
+                    self.warn(input.span, StaticAnalyzerWarning::dead_store(identifier, input.span));
+                }
+                // The variable does not exist before its definition.
+                self.overwritten.shift_remove(&identifier.name);
+            }
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| {
+                if let Expression::Identifier(identifier) = element {
+                    self.overwritten.shift_remove(&identifier.name);
+                }
+            }),
+            _ => {}
+        }
+
+        self.visit_expression(&input.value, &());
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        let after = self.overwritten.clone();
+
+        // At the end of the body, control flows either to the next iteration or out of the loop.
+        // Iterate over the body, without recording warnings, until the variables overwritten at the end of it no longer change.
+        let is_recording = std::mem::replace(&mut self.is_recording, false);
+        let mut end = after.clone();
+        loop {
+            self.overwritten = end.clone();
+            self.visit_block(&input.block);
+            self.overwritten.shift_remove(&input.variable.name);
+            let next: IndexSet<_> = end.iter().filter(|name| self.overwritten.contains(*name)).copied().collect();
+            if next.len() == end.len() {
+                break;
+            }
+            end = next;
+        }
+        self.is_recording = is_recording;
+
+        self.overwritten = end;
+        self.visit_block(&input.block);
+        self.overwritten.shift_remove(&input.variable.name);
+
+        // The loop may not execute at all.
+        self.overwritten.retain(|name| after.contains(name));
+
+        self.visit_expression(&input.start, &());
+        self.visit_expression(&input.stop, &());
+    }
+
+    fn visit_return(&mut self, input: &'a ReturnStatement) {
+        // Nothing is read after a return statement.
+        self.overwritten.clear();
+
+        self.visit_expression(&input.expression, &());
+        if let Some(arguments) = &input.finalize_arguments {
+            arguments.iter().for_each(|argument| self.visit_expression(argument, &()));
+        }
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Static Analysis pass traverses the type-checked AST and warns about code that is valid but likely unintended.
+//! The pass is run after the Type Checking pass, so that warnings point at the code as the user wrote it.
+//!
+//! The pass currently detects:
+//! - assignments whose value is overwritten before it is read,
+//! - assignments of a variable to itself, and
+//! - conditions of `if` statements that always evaluate to the same value.
+//!
+//! Each of these translates directly into wasted constraints in the compiled circuit.
+//!
+//! The pass also warns about transitions that produce records whose integer members, e.g. `amount`, add up to more
+//! than those of the records they consume, beyond the public inputs of the transition.
+//! Such arithmetic mints value out of nothing, which is a common bug in token programs.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8, b: u8) -> u8 {
+//!     let c: u8 = a + b;
+//!     c = a * b;
+//!     b = b;
+//!     return c;
+//! }
+//! ```
+//!
+//! The pass warns that the value of `a + b` assigned to `c` is never read, and that `b` is assigned to itself.
+//! It does not modify the AST.
+
+mod analyze_balances;
+
+mod analyze_expression;
+
+mod analyze_program;
+
+mod analyze_statement;
+
+pub mod static_analyzer;
+pub use static_analyzer::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for StaticAnalyzer<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a TypeTable);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, tt): Self::Input) -> Self::Output {
+        let mut visitor = StaticAnalyzer::new(handler, tt);
+        visitor.visit_program(ast.as_repr());
+
+        Ok(())
+    }
+}
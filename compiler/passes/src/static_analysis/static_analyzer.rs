@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::Value;
+use leo_errors::{emitter::Handler, StaticAnalyzerWarning};
+use leo_span::{Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+
+pub struct StaticAnalyzer<'a> {
+    /// The handler to which warnings are emitted.
+    pub(crate) handler: &'a Handler,
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// The integer members of each record in the current program scope, which are treated as balances.
+    pub(crate) records: IndexMap<Symbol, Vec<Symbol>>,
+    /// The values of the constants in the current program scope and function.
+    /// A constant maps to `None` if its value cannot be evaluated at compile time, e.g. if it depends on a loop variable.
+    pub(crate) constants: IndexMap<Symbol, Option<Value>>,
+    /// The variables that are assigned again, on every path, before they are read.
+    /// The analysis traverses each block backwards, so this set describes the code following the current statement.
+    pub(crate) overwritten: IndexSet<Symbol>,
+    /// The warnings found in the current function body, along with the spans they point at.
+    pub(crate) warnings: Vec<(Span, StaticAnalyzerWarning)>,
+    /// Whether or not warnings are currently recorded.
+    /// This is `false` while the analysis iterates over the body of a loop until it reaches a fixed point.
+    pub(crate) is_recording: bool,
+}
+
+impl<'a> StaticAnalyzer<'a> {
+    /// Initializes a new `StaticAnalyzer`.
+    pub fn new(handler: &'a Handler, type_table: &'a TypeTable) -> Self {
+        Self {
+            handler,
+            type_table,
+            records: Default::default(),
+            constants: Default::default(),
+            overwritten: Default::default(),
+            warnings: Default::default(),
+            is_recording: true,
+        }
+    }
+
+    /// Records a warning, unless recording is disabled.
+    pub(crate) fn warn(&mut self, span: Span, warning: StaticAnalyzerWarning) {
+        if self.is_recording {
+            self.warnings.push((span, warning));
+        }
+    }
+
+    /// Emits the recorded warnings in the order in which they appear in the source.
+    pub(crate) fn emit_warnings(&mut self) {
+        self.warnings.sort_by_key(|(span, _)| span.lo);
+        for (_, warning) in self.warnings.drain(..) {
+            self.handler.emit_warning(warning.into());
+        }
+    }
+}
@@ -29,6 +29,8 @@ use leo_ast::{
     Identifier,
     Literal,
     MemberAccess,
+    Node,
+    Receiver,
     Statement,
     Struct,
     StructExpression,
@@ -36,6 +38,7 @@ use leo_ast::{
     TernaryExpression,
     TupleAccess,
     TupleExpression,
+    Type,
     UnaryExpression,
     UnitExpression,
 };
@@ -73,8 +76,9 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
             AccessExpression::Member(member) => {
                 // TODO: Create AST node for native access expressions?
                 // If the access expression is of the form `self.<name>`, then don't rename it.
+                // Note that inside a struct method, `self` is the receiver, which is renamed like any other variable.
                 if let Expression::Identifier(Identifier { name, .. }) = *member.inner {
-                    if name == sym::SelfLower {
+                    if name == sym::SelfLower && self.rename_table.lookup(name).is_none() {
                         return (Expression::Access(AccessExpression::Member(member)), Vec::new());
                     }
                 }
@@ -175,9 +179,25 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
     fn consume_call(&mut self, input: CallExpression) -> Self::Output {
         let mut statements = Vec::new();
 
+        // Lower a method call to a call to the corresponding free function, passing the receiver as the first argument.
+        // For example, `p.magnitude()` is lowered to `Point__magnitude(p)`, where `p` is a `Point`.
+        let (function, arguments) = match *input.function {
+            Expression::Access(AccessExpression::Member(access)) => {
+                let struct_name = match self.type_table.get(&access.inner.id()) {
+                    Some(Type::Identifier(struct_name)) => struct_name.name,
+                    _ => unreachable!("Type checking guarantees that the receiver of a method call is a struct."),
+                };
+                let function =
+                    Identifier { name: Receiver::lowered_name(struct_name, access.name.name), ..access.name };
+                let mut arguments = vec![*access.inner];
+                arguments.extend(input.arguments);
+                (Box::new(Expression::Identifier(function)), arguments)
+            }
+            function => (Box::new(function), input.arguments),
+        };
+
         // Process the arguments, accumulating any statements produced.
-        let arguments = input
-            .arguments
+        let arguments = arguments
             .into_iter()
             .map(|argument| {
                 let (argument, mut stmts) = self.consume_expression(argument);
@@ -189,7 +209,7 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
         // Construct and accumulate a new assignment statement for the call expression.
         let (place, statement) = self.unique_simple_assign_statement(Expression::Call(CallExpression {
             // Note that we do not rename the function name.
-            function: input.function,
+            function,
             // Consume the arguments.
             arguments,
             external: input.external,
@@ -120,6 +120,7 @@ impl FunctionConsumer for StaticSingleAssigner<'_> {
             output_type: function.output_type,
             block,
             finalize,
+            receiver: function.receiver,
             span: function.span,
             id: function.id,
         }
@@ -146,6 +147,7 @@ impl ProgramConsumer for StaticSingleAssigner<'_> {
 
     fn consume_program(&mut self, input: Program) -> Self::Output {
         Program {
+            metadata: input.metadata,
             imports: input
                 .imports
                 .into_iter()
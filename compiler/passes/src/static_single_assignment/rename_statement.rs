@@ -25,6 +25,7 @@ use leo_ast::{
     Block,
     CallExpression,
     ConditionalStatement,
+    ConsoleFunction,
     ConsoleStatement,
     ConstDeclaration,
     DefinitionStatement,
@@ -76,6 +77,7 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
 
                 (AssertVariant::AssertNeq(left, right), statements)
             }
+            AssertVariant::Fail(reason) => (AssertVariant::Fail(reason), Vec::new()),
         };
 
         // Add the assert statement to the list of produced statements.
@@ -86,13 +88,16 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
 
     /// Consume all `AssignStatement`s, renaming as necessary.
     fn consume_assign(&mut self, assign: AssignStatement) -> Self::Output {
+        // Assignments to struct members are lowered into assignments to the whole variable.
+        let (place, value) = self.lower_member_assignment(assign.place, assign.value);
+
         // First consume the right-hand-side of the assignment.
-        let (value, mut statements) = self.consume_expression(assign.value);
+        let (value, mut statements) = self.consume_expression(value);
 
         // Then assign a new unique name to the left-hand-side of the assignment.
         // Note that this order is necessary to ensure that the right-hand-side uses the correct name when consuming a complex assignment.
         self.is_lhs = true;
-        let place = match self.consume_expression(assign.place).0 {
+        let place = match self.consume_expression(place).0 {
             Expression::Identifier(identifier) => identifier,
             _ => panic!("Type checking guarantees that the left-hand-side of an assignment is an identifier."),
         };
@@ -231,9 +236,19 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
         statements
     }
 
-    /// Parsing guarantees that console statements are not present in the program.
-    fn consume_console(&mut self, _: ConsoleStatement) -> Self::Output {
-        unreachable!("Parsing guarantees that console statements are not present in the program.")
+    /// Lowers console assertions to assertions, and `console.error` to `fail`, so that they are checked when the
+    /// function is executed.
+    fn consume_console(&mut self, input: ConsoleStatement) -> Self::Output {
+        let variant = match input.function {
+            ConsoleFunction::Assert(expr) => AssertVariant::Assert(expr),
+            ConsoleFunction::AssertEq(left, right) => AssertVariant::AssertEq(left, right),
+            ConsoleFunction::AssertNeq(left, right) => AssertVariant::AssertNeq(left, right),
+            ConsoleFunction::Error(format, _) => AssertVariant::Fail(Some(format)),
+            ConsoleFunction::Log(..) => {
+                unreachable!("Type checking guarantees that `console.log` is not present in the program.")
+            }
+        };
+        self.consume_assert(AssertStatement { variant, span: input.span, id: input.id })
     }
 
     fn consume_const(&mut self, _: ConstDeclaration) -> Self::Output {
@@ -16,7 +16,18 @@
 
 use crate::{Assigner, RenameTable, SymbolTable, TypeTable};
 
-use leo_ast::{Expression, Identifier, Node, NodeBuilder, Statement};
+use leo_ast::{
+    AccessExpression,
+    Expression,
+    Identifier,
+    MemberAccess,
+    Node,
+    NodeBuilder,
+    Statement,
+    StructExpression,
+    StructVariableInitializer,
+    Type,
+};
 
 pub struct StaticSingleAssigner<'a> {
     /// A counter used to generate unique node IDs.
@@ -84,4 +95,54 @@ impl<'a> StaticSingleAssigner<'a> {
 
         (place, statement)
     }
+
+    /// Lowers an assignment to a member of a struct into an assignment to the whole variable, reconstructing each struct on the path.
+    /// For example, `a.b.c = v` becomes `a = A { b: B { c: v, d: a.b.d }, e: a.e }`.
+    /// Returns the variable and the value assigned to it.
+    pub(crate) fn lower_member_assignment(&mut self, place: Expression, value: Expression) -> (Expression, Expression) {
+        let access = match place {
+            Expression::Access(AccessExpression::Member(access)) => access,
+            place => return (place, value),
+        };
+
+        // Lookup the definition of the struct being accessed.
+        // Note that type checking guarantees that the inner expression is a struct.
+        let struct_name = match self.type_table.get(&access.inner.id()) {
+            Some(Type::Identifier(struct_name)) => struct_name,
+            _ => unreachable!("Type checking guarantees that the inner expression of a member access is a struct."),
+        };
+        let members = self.symbol_table.lookup_struct(struct_name.name).unwrap().members.clone();
+
+        // Reconstruct the struct, replacing the assigned member with `value` and copying the other members.
+        let mut value = Some(value);
+        let members = members
+            .into_iter()
+            .map(|member| {
+                let expression = match member.name() == access.name.name {
+                    true => value.take().unwrap(),
+                    false => {
+                        let id = self.node_builder.next_id();
+                        self.type_table.insert(id, member.type_.clone());
+                        Expression::Access(AccessExpression::Member(MemberAccess {
+                            inner: access.inner.clone(),
+                            name: member.identifier,
+                            span: Default::default(),
+                            id,
+                        }))
+                    }
+                };
+                StructVariableInitializer {
+                    identifier: member.identifier,
+                    expression: Some(expression),
+                    span: Default::default(),
+                    id: self.node_builder.next_id(),
+                }
+            })
+            .collect();
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Identifier(struct_name));
+        let value = Expression::Struct(StructExpression { name: struct_name, members, span: access.span, id });
+
+        self.lower_member_assignment(*access.inner, value)
+    }
 }
@@ -14,12 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::TypeChecker;
+use crate::{TypeChecker, TypeTable};
 
 use leo_ast::*;
-use leo_errors::{emitter::Handler, TypeCheckerError};
+use leo_errors::{emitter::Handler, Result, TypeCheckerError};
 use leo_span::{sym, Span};
 
+use indexmap::IndexMap;
 use itertools::Itertools;
 use snarkvm::console::network::{Network, Testnet3};
 use std::str::FromStr;
@@ -38,11 +39,82 @@ fn return_incorrect_type(t1: Option<Type>, t2: Option<Type>, expected: &Option<T
     }
 }
 
+/// Returns the type an unsuffixed magnitude operand, e.g. an exponent or a shift amount, is inferred to have.
+/// Field exponents of field bases are fields, all other magnitudes default to `u32`.
+fn magnitude_for_unsuffixed(expression: &Expression, base: &Option<Type>) -> Option<Type> {
+    match (expression, base) {
+        (Expression::Literal(Literal::Unsuffixed(..)), Some(Type::Field)) => Some(Type::Field),
+        (Expression::Literal(Literal::Unsuffixed(..)), _) => Some(Type::Integer(IntegerType::U32)),
+        _ => None,
+    }
+}
+
+/// Returns the value of `expression` if it is an integer expression whose operands are all literals.
+/// The types of unsuffixed literals are looked up in `type_table`, and the values of the operations that have
+/// already been type checked in `constant_values`, so that nested operations are evaluated only once.
+/// Returns `None` if the expression is not a compile-time constant or if its evaluation fails.
+fn evaluate_constant_integer(
+    expression: &Expression,
+    type_table: &TypeTable,
+    constant_values: &IndexMap<NodeID, Value>,
+) -> Option<Value> {
+    match expression {
+        Expression::Literal(literal @ Literal::Integer(..)) => Value::try_from(literal).ok(),
+        Expression::Literal(literal @ Literal::Unsuffixed(..)) => {
+            match literal.clone().with_inferred_suffix(&type_table.get(&literal.id())?)? {
+                literal @ Literal::Integer(..) => Value::try_from(&literal).ok(),
+                _ => None,
+            }
+        }
+        Expression::Binary(..) | Expression::Unary(..) => constant_values.get(&expression.id()).cloned(),
+        _ => None,
+    }
+}
+
+/// Evaluates the unary operation `op` on the constant `receiver`, if it is a signed integer.
+fn evaluate_constant_unary(op: UnaryOperation, receiver: Value, span: Span) -> Option<Value> {
+    let is_signed =
+        matches!(receiver, Value::I8(..) | Value::I16(..) | Value::I32(..) | Value::I64(..) | Value::I128(..));
+    match op {
+        UnaryOperation::Abs if is_signed => receiver.abs(span).ok(),
+        UnaryOperation::Negate if is_signed => receiver.neg(span).ok(),
+        _ => None,
+    }
+}
+
+/// Evaluates the checked integer operation `op` over two constant operands.
+/// Returns `None` if `op` is not a checked arithmetic operation or if the operand types are not valid for it.
+fn evaluate_constant_binary(op: BinaryOperation, left: Value, right: Value, span: Span) -> Option<Result<Value>> {
+    let is_same_type = Type::from(&left) == Type::from(&right);
+    let is_magnitude = matches!(right, Value::U8(..) | Value::U16(..) | Value::U32(..));
+    Some(match op {
+        BinaryOperation::Add if is_same_type => left.add(right, span),
+        BinaryOperation::Sub if is_same_type => left.sub(right, span),
+        BinaryOperation::Mul if is_same_type => left.mul(right, span),
+        BinaryOperation::Div if is_same_type => left.div(right, span),
+        BinaryOperation::Pow if is_magnitude => left.pow(right, span),
+        BinaryOperation::Shl if is_magnitude => left.shl(right, span),
+        BinaryOperation::Shr if is_magnitude => left.shr(right, span),
+        _ => return None,
+    })
+}
+
 impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
     type AdditionalInput = Option<Type>;
     type Output = Option<Type>;
 
     fn visit_expression(&mut self, input: &'a Expression, additional: &Self::AdditionalInput) -> Self::Output {
+        // Reject expressions nested too deeply for this and later passes to visit them without overflowing the stack.
+        // The parser bounds the nesting of parenthesized expressions, but not of chains of operators such as `a + b + c`.
+        if self.expression_depth == self.max_expression_depth {
+            // Report the error once for the outermost expression, and assume the expected type to avoid further errors.
+            if !self.is_too_deep {
+                self.emit_err(TypeCheckerError::expression_too_deeply_nested(self.max_expression_depth, input.span()));
+                self.is_too_deep = true;
+            }
+            return additional.clone();
+        }
+        self.expression_depth += 1;
         let output = match input {
             Expression::Access(access) => self.visit_access(access, additional),
             Expression::Array(array) => self.visit_array(array, additional),
@@ -58,6 +130,10 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             Expression::Unary(unary) => self.visit_unary(unary, additional),
             Expression::Unit(unit) => self.visit_unit(unit, additional),
         };
+        self.expression_depth -= 1;
+        if self.expression_depth == 0 {
+            self.is_too_deep = false;
+        }
         // If the output type is known, add the expression and its associated type to the symbol table.
         if let Some(type_) = &output {
             self.type_table.insert(input.id(), type_.clone());
@@ -74,7 +150,8 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 self.assert_array_type(&array_type, access.array.span());
 
                 // Check that the index is an integer type.
-                let index_type = self.visit_expression(&access.index, &None);
+                // An unsuffixed index is inferred to be a `u32`.
+                let index_type = self.visit_expression(&access.index, &magnitude_for_unsuffixed(&access.index, &None));
                 self.assert_int_type(&index_type, access.index.span());
 
                 // Get the element type of the array.
@@ -99,6 +176,14 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                         self.emit_err(TypeCheckerError::operation_must_be_in_finalize_block(input.span()));
                     }
 
+                    // The functions of `Option` are generic, so they are checked separately.
+                    if core_instruction.is_option_function() {
+                        return self.check_option_function_call(core_instruction, access, expected);
+                    }
+                    if core_instruction.is_result_function() {
+                        return self.check_result_function_call(core_instruction, access, expected);
+                    }
+
                     // Get the types of the arguments.
                     let argument_types = access
                         .arguments
@@ -157,9 +242,12 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 }
             }
             AccessExpression::Member(access) => {
+                // Inside a struct method, `self` is the receiver instead of the program context.
+                let in_method = self.symbol_table.borrow().lookup_variable(sym::SelfLower).is_some();
+                let network_is_variable = self.symbol_table.borrow().lookup_variable(sym::network).is_some();
                 match *access.inner {
                     // If the access expression is of the form `self.<name>`, then check the <name> is valid.
-                    Expression::Identifier(identifier) if identifier.name == sym::SelfLower => match access.name.name {
+                    Expression::Identifier(id) if id.name == sym::SelfLower && !in_method => match access.name.name {
                         sym::caller => {
                             // Check that the operation is not invoked in a `finalize` block.
                             if self.is_finalize {
@@ -200,6 +288,25 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                             self.emit_err(TypeCheckerError::invalid_block_access(access.name.span()));
                         }
                     },
+                    // If the access expression is of the form `network.<name>`, then check the <name> is valid.
+                    // `network` is not a keyword, so a variable named `network` takes precedence.
+                    Expression::Identifier(identifier) if identifier.name == sym::network && !network_is_variable => {
+                        match access.name.name {
+                            sym::id => {
+                                // Check that the operation is invoked in a `finalize` block.
+                                if !self.is_finalize {
+                                    self.handler.emit_err(TypeCheckerError::invalid_operation_outside_finalize(
+                                        "network.id",
+                                        access.name.span(),
+                                    ))
+                                }
+                                return Some(Type::Integer(IntegerType::U16));
+                            }
+                            _ => {
+                                self.emit_err(TypeCheckerError::invalid_network_access(access.name.span()));
+                            }
+                        }
+                    }
                     _ => {
                         // Check that the type of `inner` in `inner.name` is a struct.
                         match self.visit_expression(&access.inner, &None) {
@@ -207,6 +314,15 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                                 // Retrieve the struct definition associated with `identifier`.
                                 let struct_ = self.symbol_table.borrow().lookup_struct(identifier.name).cloned();
                                 if let Some(struct_) = struct_ {
+                                    // Check that `access.name` is not the nonce of a record, which is not accessible in a program.
+                                    if struct_.is_record && access.name.name == sym::_nonce {
+                                        self.emit_err(TypeCheckerError::record_nonce_not_accessible(
+                                            struct_.identifier,
+                                            access.name.span(),
+                                        ));
+                                        return Some(self.assert_and_return_type(Type::Group, expected, access.span()));
+                                    }
+
                                     // Check that `access.name` is a member of the struct.
                                     match struct_.members.iter().find(|member| member.name() == access.name.name) {
                                         // Case where `access.name` is a member of the struct.
@@ -263,8 +379,17 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
 
     fn visit_array(&mut self, input: &'a ArrayExpression, additional: &Self::AdditionalInput) -> Self::Output {
         // Get the types of each element expression.
-        let element_types =
-            input.elements.iter().map(|element| self.visit_expression(element, &None)).collect::<Vec<_>>();
+        // Unsuffixed literals, also those in nested arrays, are inferred to have the expected element type.
+        let element_types = input
+            .elements
+            .iter()
+            .map(|element| match (element, additional) {
+                (Expression::Literal(Literal::Unsuffixed(..)) | Expression::Array(_), Some(Type::Array(expected))) => {
+                    self.visit_expression(element, &Some(expected.element_type().clone()))
+                }
+                _ => self.visit_expression(element, &None),
+            })
+            .collect::<Vec<_>>();
 
         // Construct the array type.
         let return_type = match element_types.len() {
@@ -307,12 +432,11 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_binary(&mut self, input: &'a BinaryExpression, destination: &Self::AdditionalInput) -> Self::Output {
-        match input.op {
+        let output = match input.op {
             BinaryOperation::And | BinaryOperation::Or | BinaryOperation::Nand | BinaryOperation::Nor => {
                 // Only boolean types.
                 self.assert_bool_type(destination, input.span());
-                let t1 = self.visit_expression(&input.left, destination);
-                let t2 = self.visit_expression(&input.right, destination);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, destination);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -322,8 +446,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             BinaryOperation::BitwiseAnd | BinaryOperation::BitwiseOr | BinaryOperation::Xor => {
                 //  Only boolean or integer types.
                 self.assert_bool_int_type(destination, input.span());
-                let t1 = self.visit_expression(&input.left, destination);
-                let t2 = self.visit_expression(&input.right, destination);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, destination);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -333,8 +456,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             BinaryOperation::Add => {
                 // Only field, group, scalar, or integer types.
                 self.assert_field_group_scalar_int_type(destination, input.span());
-                let t1 = self.visit_expression(&input.left, destination);
-                let t2 = self.visit_expression(&input.right, destination);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, destination);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -344,8 +466,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             BinaryOperation::Sub => {
                 // Only field, group, or integer types.
                 self.assert_field_group_int_type(destination, input.span());
-                let t1 = self.visit_expression(&input.left, destination);
-                let t2 = self.visit_expression(&input.right, destination);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, destination);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -356,14 +477,25 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Operation returns field, group or integer types.
                 self.assert_field_group_int_type(destination, input.span());
 
-                let t1 = self.visit_expression(&input.left, &None);
-                let t2 = self.visit_expression(&input.right, &None);
+                // If both operands are unsuffixed literals, they share the type of the result if it is a field or an integer.
+                let operand_type = match (&*input.left, &*input.right, destination) {
+                    (
+                        Expression::Literal(Literal::Unsuffixed(..)),
+                        Expression::Literal(Literal::Unsuffixed(..)),
+                        Some(Type::Field | Type::Integer(_)),
+                    ) => destination.clone(),
+                    _ => None,
+                };
+                let (t1, t2) = self.visit_factors(&input.left, &input.right, &operand_type);
 
                 // Allow group * scalar multiplication.
                 match (t1, input.left.span(), t2, input.right.span()) {
                     (Some(Type::Group), _, other, other_span) | (other, other_span, Some(Type::Group), _) => {
                         // Other type must be scalar.
-                        self.assert_scalar_type(&other, other_span);
+                        match other {
+                            Some(Type::Field) => self.emit_err(TypeCheckerError::field_used_as_scalar(other_span)),
+                            _ => self.assert_scalar_type(&other, other_span),
+                        }
 
                         // Operation returns group.
                         self.assert_group_type(destination, input.span());
@@ -412,8 +544,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Only field or integer types.
                 self.assert_field_int_type(destination, input.span());
 
-                let t1 = self.visit_expression(&input.left, destination);
-                let t2 = self.visit_expression(&input.right, destination);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, destination);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -424,8 +555,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Only integer types.
                 self.assert_int_type(destination, input.span());
 
-                let t1 = self.visit_expression(&input.left, destination);
-                let t2 = self.visit_expression(&input.right, destination);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, destination);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -436,8 +566,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Only unsigned integer types.
                 self.assert_unsigned_int_type(destination, input.span());
 
-                let t1 = self.visit_expression(&input.left, destination);
-                let t2 = self.visit_expression(&input.right, destination);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, destination);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -449,7 +578,8 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 self.assert_field_int_type(destination, input.span());
 
                 let t1 = self.visit_expression(&input.left, &None);
-                let t2 = self.visit_expression(&input.right, &None);
+                // An unsuffixed exponent is a field if the base is a field, and a `u32` otherwise.
+                let t2 = self.visit_expression(&input.right, &magnitude_for_unsuffixed(&input.right, &t1));
 
                 // Allow field ^ field.
                 match (t1, t2) {
@@ -491,8 +621,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             }
             BinaryOperation::Eq | BinaryOperation::Neq => {
                 // Assert first and second address, boolean, field, group, scalar, or integer types.
-                let t1 = self.visit_expression(&input.left, &None);
-                let t2 = self.visit_expression(&input.right, &None);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, &None);
 
                 // Check that the types of the operands are equal.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -504,8 +633,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             }
             BinaryOperation::Lt | BinaryOperation::Gt | BinaryOperation::Lte | BinaryOperation::Gte => {
                 // Assert left and right are equal field, scalar, or integer types.
-                let t1 = self.visit_expression(&input.left, &None);
-                let t2 = self.visit_expression(&input.right, &None);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, &None);
 
                 match (&t1, &t2) {
                     (Some(Type::Address), _) | (_, Some(Type::Address)) => {
@@ -532,8 +660,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             | BinaryOperation::MulWrapped => {
                 // Only integer types.
                 self.assert_int_type(destination, input.span);
-                let t1 = self.visit_expression(&input.left, destination);
-                let t2 = self.visit_expression(&input.right, destination);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right, destination);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -546,7 +673,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             | BinaryOperation::ShrWrapped
             | BinaryOperation::PowWrapped => {
                 let t1 = self.visit_expression(&input.left, destination);
-                let t2 = self.visit_expression(&input.right, &None);
+                let t2 = self.visit_expression(&input.right, &magnitude_for_unsuffixed(&input.right, &None));
 
                 // Assert left and destination are equal integer types.
                 self.assert_int_type(&t1, input.left.span());
@@ -557,72 +684,121 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
 
                 t1
             }
+        };
+
+        // If both operands are compile-time constants, check that the operation can be evaluated, and record its value.
+        let constants = evaluate_constant_integer(&input.left, self.type_table, &self.constant_values)
+            .zip(evaluate_constant_integer(&input.right, self.type_table, &self.constant_values));
+        if let Some((left, right)) = constants {
+            let type_ = Type::from(&left);
+            let is_division_by_zero = input.op == BinaryOperation::Div && matches!(i128::try_from(&right), Ok(0));
+            match evaluate_constant_binary(input.op, left, right, input.span()) {
+                Some(Ok(value)) => {
+                    self.constant_values.insert(input.id, value);
+                }
+                Some(Err(_)) if is_division_by_zero => {
+                    self.emit_err(TypeCheckerError::constant_division_by_zero(input, input.span()))
+                }
+                Some(Err(_)) => {
+                    self.emit_err(TypeCheckerError::constant_operation_overflows(input, type_, input.span()))
+                }
+                None => {}
+            }
         }
+
+        output
     }
 
     fn visit_call(&mut self, input: &'a CallExpression, expected: &Self::AdditionalInput) -> Self::Output {
-        match &*input.function {
-            // Note that the parser guarantees that `input.function` is always an identifier.
-            Expression::Identifier(ident) => {
-                // Note: The function symbol lookup is performed outside of the `if let Some(func) ...` block to avoid a RefCell lifetime bug in Rust.
-                // Do not move it into the `if let Some(func) ...` block or it will keep `self.symbol_table_creation` alive for the entire block and will be very memory inefficient!
-                let func = self.symbol_table.borrow().lookup_fn_symbol(ident.name).cloned();
-
-                if let Some(func) = func {
-                    // Check that the call is valid.
-                    // Note that this unwrap is safe since we always set the variant before traversing the body of the function.
-                    match self.variant.unwrap() {
-                        // If the function is not a transition function, it can only call "inline" functions.
-                        Variant::Inline | Variant::Standard => {
-                            if !matches!(func.variant, Variant::Inline) {
-                                self.emit_err(TypeCheckerError::can_only_call_inline_function(input.span));
-                            }
-                        }
-                        // If the function is a transition function, then check that the call is not to another local transition function.
-                        Variant::Transition => {
-                            if matches!(func.variant, Variant::Transition) && input.external.is_none() {
-                                self.emit_err(TypeCheckerError::cannot_invoke_call_to_local_transition_function(
-                                    input.span,
-                                ));
-                            }
-                        }
-                    }
+        // Resolve the name of the function being called.
+        // Note that the parser guarantees that `input.function` is either an identifier or a method access.
+        let (ident, method) = match &*input.function {
+            Expression::Identifier(ident) => (*ident, None),
+            // A method is resolved from the type of its receiver, e.g., `p.magnitude()` calls `Point__magnitude` if `p` is a `Point`.
+            Expression::Access(AccessExpression::Member(access)) => match self.visit_expression(&access.inner, &None) {
+                Some(Type::Identifier(struct_)) => (
+                    Identifier { name: Receiver::lowered_name(struct_.name, access.name.name), ..access.name },
+                    Some((struct_, access.name)),
+                ),
+                Some(type_) => {
+                    self.emit_err(TypeCheckerError::unknown_method(type_, access.name, access.name.span()));
+                    return None;
+                }
+                None => return None,
+            },
+            _ => unreachable!("Parsing guarantees that a function name is always an identifier or a method access."),
+        };
 
-                    // Check that the call is not to an external `inline` function.
-                    if func.variant == Variant::Inline && input.external.is_some() {
-                        self.emit_err(TypeCheckerError::cannot_call_external_inline_function(input.span));
+        // Note: The function symbol lookup is performed outside of the `if let Some(func) ...` block to avoid a RefCell lifetime bug in Rust.
+        // Do not move it into the `if let Some(func) ...` block or it will keep `self.symbol_table_creation` alive for the entire block and will be very memory inefficient!
+        // Note that methods can only be called with method syntax.
+        let is_method_call = method.is_some();
+        let func = self
+            .symbol_table
+            .borrow()
+            .lookup_fn_symbol(ident.name)
+            .filter(|func| func.receiver.is_some() == is_method_call)
+            .cloned();
+
+        if let Some(func) = func {
+            // Check that the call is valid.
+            // Note that this unwrap is safe since we always set the variant before traversing the body of the function.
+            match self.variant.unwrap() {
+                // If the function is not a transition function, it can only call "inline" functions.
+                Variant::Inline | Variant::Standard => {
+                    if !matches!(func.variant, Variant::Inline) {
+                        self.emit_err(TypeCheckerError::can_only_call_inline_function(input.span));
                     }
+                }
+                // If the function is a transition function, then check that the call is not to another local transition function.
+                Variant::Transition => {
+                    if matches!(func.variant, Variant::Transition) && input.external.is_none() {
+                        self.emit_err(TypeCheckerError::cannot_invoke_call_to_local_transition_function(input.span));
+                    }
+                }
+            }
 
-                    let ret = self.assert_and_return_type(func.output_type, expected, input.span());
+            // Warn if the function is deprecated.
+            self.check_deprecated(ident.name, func.deprecated.as_ref(), false, func.span, input.span);
 
-                    // Check number of function arguments.
-                    if func.input.len() != input.arguments.len() {
-                        self.emit_err(TypeCheckerError::incorrect_num_args_to_call(
-                            func.input.len(),
-                            input.arguments.len(),
-                            input.span(),
-                        ));
-                    }
+            // Check that the call is not to an external `inline` function.
+            if func.variant == Variant::Inline && input.external.is_some() {
+                self.emit_err(TypeCheckerError::cannot_call_external_inline_function(input.span));
+            }
 
-                    // Check function argument types.
-                    func.input.iter().zip(input.arguments.iter()).for_each(|(expected, argument)| {
-                        self.visit_expression(argument, &Some(expected.type_()));
-                    });
+            let ret = self.assert_and_return_type(func.output_type, expected, input.span());
 
-                    // Add the call to the call graph.
-                    let caller_name = match self.function {
-                        None => unreachable!("`self.function` is set every time a function is visited."),
-                        Some(func) => func,
-                    };
-                    self.call_graph.add_edge(caller_name, ident.name);
+            // The receiver of a method call has already been checked, so only the remaining inputs are matched with the arguments.
+            let inputs = if is_method_call { &func.input[1..] } else { &func.input[..] };
 
-                    Some(ret)
-                } else {
-                    self.emit_err(TypeCheckerError::unknown_sym("function", ident.name, ident.span()));
-                    None
-                }
+            // Check number of function arguments.
+            if inputs.len() != input.arguments.len() {
+                self.emit_err(TypeCheckerError::incorrect_num_args_to_call(
+                    inputs.len(),
+                    input.arguments.len(),
+                    input.span(),
+                ));
             }
-            _ => unreachable!("Parsing guarantees that a function name is always an identifier."),
+
+            // Check function argument types.
+            inputs.iter().zip(input.arguments.iter()).for_each(|(expected, argument)| {
+                self.visit_expression(argument, &Some(expected.type_()));
+            });
+
+            // Add the call to the call graph.
+            let caller_name = match self.function {
+                None => unreachable!("`self.function` is set every time a function is visited."),
+                Some(func) => func,
+            };
+            self.call_graph.add_edge(caller_name, ident.name);
+
+            Some(ret)
+        } else if let Some((struct_, method)) = method {
+            self.emit_err(TypeCheckerError::unknown_method(struct_, method, method.span()));
+            None
+        } else {
+            self.emit_err(TypeCheckerError::unknown_sym("function", ident.name, ident.span()));
+            None
         }
     }
 
@@ -644,11 +820,26 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             // Check struct type name.
             let ret = self.check_expected_struct(struct_.identifier, additional, input.name.span());
 
+            // Warn if the struct is deprecated.
+            self.check_deprecated(struct_.name(), struct_.deprecation().as_ref(), true, struct_.span, input.span());
+
+            // Check that the nonce of a record is not initialized, since it is assigned by the VM.
+            let mut num_members = input.members.len();
+            if struct_.is_record {
+                if let Some(nonce) = input.members.iter().find(|member| member.identifier.name == sym::_nonce) {
+                    self.emit_err(TypeCheckerError::record_nonce_cannot_be_initialized(
+                        struct_.identifier,
+                        nonce.span(),
+                    ));
+                    num_members -= 1;
+                }
+            }
+
             // Check number of struct members.
-            if struct_.members.len() != input.members.len() {
+            if struct_.members.len() != num_members {
                 self.emit_err(TypeCheckerError::incorrect_num_struct_members(
                     struct_.members.len(),
-                    input.members.len(),
+                    num_members,
                     input.span(),
                 ));
             }
@@ -673,6 +864,9 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             });
 
             Some(ret)
+        } else if input.name.name == sym::SelfUpper {
+            self.emit_err(TypeCheckerError::self_type_outside_method(input.name.span()));
+            None
         } else {
             self.emit_err(TypeCheckerError::unknown_sym("struct", input.name.name, input.name.span()));
             None
@@ -688,7 +882,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         if let Some(var) = self.symbol_table.borrow().lookup_variable(input.name) {
             Some(self.assert_and_return_type(var.type_.clone(), expected, input.span()))
         } else {
-            self.emit_err(TypeCheckerError::unknown_sym("variable", input.name, input.span()));
+            self.emit_unknown_variable(*input);
             None
         }
     }
@@ -696,63 +890,55 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
     fn visit_literal(&mut self, input: &'a Literal, expected: &Self::AdditionalInput) -> Self::Output {
         fn parse_integer_literal<I: FromStr>(handler: &Handler, raw_string: &str, span: Span, type_string: &str) {
             let string = raw_string.replace('_', "");
-            if string.parse::<I>().is_err() {
+            if Literal::integer_to_decimal(&string).and_then(|decimal| decimal.parse::<I>().ok()).is_none() {
                 handler.emit_err(TypeCheckerError::invalid_int_value(string, type_string, span));
             }
         }
 
+        fn check_integer_literal(handler: &Handler, integer_type: &IntegerType, string: &str, span: Span) {
+            match integer_type {
+                IntegerType::U8 => parse_integer_literal::<u8>(handler, string, span, "u8"),
+                IntegerType::U16 => parse_integer_literal::<u16>(handler, string, span, "u16"),
+                IntegerType::U32 => parse_integer_literal::<u32>(handler, string, span, "u32"),
+                IntegerType::U64 => parse_integer_literal::<u64>(handler, string, span, "u64"),
+                IntegerType::U128 => parse_integer_literal::<u128>(handler, string, span, "u128"),
+                IntegerType::I8 => parse_integer_literal::<i8>(handler, string, span, "i8"),
+                IntegerType::I16 => parse_integer_literal::<i16>(handler, string, span, "i16"),
+                IntegerType::I32 => parse_integer_literal::<i32>(handler, string, span, "i32"),
+                IntegerType::I64 => parse_integer_literal::<i64>(handler, string, span, "i64"),
+                IntegerType::I128 => parse_integer_literal::<i128>(handler, string, span, "i128"),
+            }
+        }
+
         Some(match input {
             Literal::Address(_, _, _) => self.assert_and_return_type(Type::Address, expected, input.span()),
             Literal::Boolean(_, _, _) => self.assert_and_return_type(Type::Boolean, expected, input.span()),
             Literal::Field(_, _, _) => self.assert_and_return_type(Type::Field, expected, input.span()),
-            Literal::Integer(integer_type, string, _, _) => match integer_type {
-                IntegerType::U8 => {
-                    parse_integer_literal::<u8>(self.handler, string, input.span(), "u8");
-                    self.assert_and_return_type(Type::Integer(IntegerType::U8), expected, input.span())
-                }
-                IntegerType::U16 => {
-                    parse_integer_literal::<u16>(self.handler, string, input.span(), "u16");
-                    self.assert_and_return_type(Type::Integer(IntegerType::U16), expected, input.span())
-                }
-                IntegerType::U32 => {
-                    parse_integer_literal::<u32>(self.handler, string, input.span(), "u32");
-                    self.assert_and_return_type(Type::Integer(IntegerType::U32), expected, input.span())
-                }
-                IntegerType::U64 => {
-                    parse_integer_literal::<u64>(self.handler, string, input.span(), "u64");
-                    self.assert_and_return_type(Type::Integer(IntegerType::U64), expected, input.span())
-                }
-                IntegerType::U128 => {
-                    parse_integer_literal::<u128>(self.handler, string, input.span(), "u128");
-                    self.assert_and_return_type(Type::Integer(IntegerType::U128), expected, input.span())
-                }
-                IntegerType::I8 => {
-                    parse_integer_literal::<i8>(self.handler, string, input.span(), "i8");
-                    self.assert_and_return_type(Type::Integer(IntegerType::I8), expected, input.span())
-                }
-                IntegerType::I16 => {
-                    parse_integer_literal::<i16>(self.handler, string, input.span(), "i16");
-                    self.assert_and_return_type(Type::Integer(IntegerType::I16), expected, input.span())
-                }
-                IntegerType::I32 => {
-                    parse_integer_literal::<i32>(self.handler, string, input.span(), "i32");
-                    self.assert_and_return_type(Type::Integer(IntegerType::I32), expected, input.span())
-                }
-                IntegerType::I64 => {
-                    parse_integer_literal::<i64>(self.handler, string, input.span(), "i64");
-                    self.assert_and_return_type(Type::Integer(IntegerType::I64), expected, input.span())
-                }
-                IntegerType::I128 => {
-                    parse_integer_literal::<i128>(self.handler, string, input.span(), "i128");
-                    self.assert_and_return_type(Type::Integer(IntegerType::I128), expected, input.span())
-                }
-            },
+            Literal::Integer(integer_type, string, _, _) => {
+                check_integer_literal(self.handler, integer_type, string, input.span());
+                self.assert_and_return_type(Type::Integer(*integer_type), expected, input.span())
+            }
             Literal::Group(_) => self.assert_and_return_type(Type::Group, expected, input.span()),
             Literal::Scalar(_, _, _) => self.assert_and_return_type(Type::Scalar, expected, input.span()),
             Literal::String(_, _, _) => {
                 self.emit_err(TypeCheckerError::strings_are_not_supported(input.span()));
                 self.assert_and_return_type(Type::String, expected, input.span())
             }
+            // The type of an unsuffixed literal is inferred from the expected type.
+            Literal::Unsuffixed(string, _, _) => match expected {
+                Some(Type::Integer(integer_type)) => {
+                    check_integer_literal(self.handler, integer_type, string, input.span());
+                    Type::Integer(*integer_type)
+                }
+                // Note that hexadecimal, octal, and binary numbers must be integers.
+                Some(type_ @ (Type::Field | Type::Group | Type::Scalar)) if !Literal::has_radix_prefix(string) => {
+                    type_.clone()
+                }
+                _ => {
+                    self.emit_err(TypeCheckerError::could_not_infer_literal_type(input, input.span()));
+                    return None;
+                }
+            },
         })
     }
 
@@ -800,7 +986,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_unary(&mut self, input: &'a UnaryExpression, destination: &Self::AdditionalInput) -> Self::Output {
-        match input.op {
+        let output = match input.op {
             UnaryOperation::Abs => {
                 // Only signed integer types.
                 self.assert_signed_int_type(destination, input.span());
@@ -843,12 +1029,66 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 self.assert_field_type(destination, input.span());
                 self.visit_expression(&input.receiver, destination)
             }
+            UnaryOperation::ToBitsLe => {
+                // Only integer types, whose bits fit in an array.
+                let type_ = self.visit_expression(&input.receiver, &None);
+                self.assert_int_type(&type_, input.receiver.span());
+                match type_ {
+                    Some(Type::Integer(integer_type)) => self
+                        .bits_type(integer_type, input.span())
+                        .map(|bits_type| self.assert_and_return_type(bits_type, destination, input.span())),
+                    _ => None,
+                }
+            }
+            UnaryOperation::Try => {
+                // Only results, in a function that returns a result with the same error type.
+                let result = self.visit_expression(&input.receiver, &None);
+                let result_type = self.assert_result_type(&result, input.receiver.span());
+                let output_type = self.function.and_then(|function| {
+                    self.symbol_table.borrow().lookup_fn_symbol(function).map(|f| match self.is_finalize {
+                        // Note that this `unwrap()` is safe since we are in the finalize block of the function.
+                        true => (f.finalize.as_ref().unwrap().output_type.clone(), false),
+                        false => (f.output_type.clone(), f.finalize.is_some()),
+                    })
+                });
+                // The error is returned early, which would not provide the arguments of the finalize block.
+                if let Some((_, true)) = output_type {
+                    self.emit_err(TypeCheckerError::try_in_function_with_finalize(input.span()));
+                }
+                match output_type.map(|(output_type, _)| output_type) {
+                    Some(Type::Result(output_type)) => {
+                        if let Some(result_type) = &result_type {
+                            self.assert_type(
+                                &Some(result_type.err_type().clone()),
+                                output_type.err_type(),
+                                input.span(),
+                            );
+                        }
+                    }
+                    Some(output_type) => {
+                        self.emit_err(TypeCheckerError::try_outside_result_function(output_type, input.span()))
+                    }
+                    None => {}
+                }
+                result_type.map(|result_type| {
+                    self.assert_and_return_type(result_type.ok_type().clone(), destination, input.span())
+                })
+            }
             UnaryOperation::ToXCoordinate | UnaryOperation::ToYCoordinate => {
                 // Only field type.
                 self.assert_field_type(destination, input.span());
                 self.visit_expression(&input.receiver, &Some(Type::Group))
             }
+        };
+
+        // If the receiver is a compile-time constant, record the value of the operation.
+        if let Some(value) = evaluate_constant_integer(&input.receiver, self.type_table, &self.constant_values)
+            .and_then(|receiver| evaluate_constant_unary(input.op, receiver, input.span()))
+        {
+            self.constant_values.insert(input.id, value);
         }
+
+        output
     }
 
     fn visit_unit(&mut self, input: &'a UnitExpression, _additional: &Self::AdditionalInput) -> Self::Output {
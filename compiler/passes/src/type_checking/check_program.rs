@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{DiGraphError, TypeChecker, VariableSymbol, VariableType};
+use crate::{DiGraphError, RecordSchemaViolation, TypeChecker, VariableSymbol, VariableType};
 
 use leo_ast::*;
 use leo_errors::TypeCheckerError;
@@ -48,6 +48,38 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             }
         }
 
+        // Check the metadata at the top of the file.
+        let mut keys = HashSet::new();
+        for metadata in input.metadata.iter() {
+            if !keys.insert(metadata.key.name) {
+                self.emit_err(TypeCheckerError::duplicate_metadata(metadata.key, metadata.span));
+            }
+            match metadata.key.name {
+                // The program name must match the name of the program scope.
+                sym::program => {
+                    for scope in input.program_scopes.values() {
+                        if metadata.value != scope.program_id.to_string() {
+                            self.emit_err(TypeCheckerError::program_metadata_mismatch(
+                                &metadata.value,
+                                scope.program_id,
+                                metadata.span,
+                            ));
+                        }
+                    }
+                }
+                // The version must be of the form `MAJOR.MINOR.PATCH`.
+                sym::version => {
+                    let parts = metadata.value.split('.').collect::<Vec<_>>();
+                    let is_number = |part: &&str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+                    if parts.len() != 3 || !parts.iter().all(is_number) {
+                        self.emit_err(TypeCheckerError::invalid_version_metadata(&metadata.value, metadata.span));
+                    }
+                }
+                // Other metadata is not interpreted by the compiler.
+                _ => {}
+            }
+        }
+
         // Typecheck the program scopes.
         input.program_scopes.values().for_each(|scope| self.visit_program_scope(scope));
     }
@@ -93,6 +125,14 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             self.emit_err(TypeCheckerError::cyclic_function_dependency(path));
         }
 
+        // Check that the program has at least one entry point.
+        if transition_count == 0 {
+            self.emit_err(TypeCheckerError::program_has_no_transitions(
+                input.program_id,
+                input.program_id.name.span + input.program_id.network.span,
+            ));
+        }
+
         // TODO: Need similar checks for structs (all in separate PR)
         // Check that the number of transitions does not exceed the maximum.
         if transition_count > Testnet3::MAX_FUNCTIONS {
@@ -104,6 +144,26 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_struct(&mut self, input: &'a Struct) {
+        // Check that the struct's annotations are valid.
+        let mut annotation_names = HashSet::new();
+        for annotation in input.annotations.iter() {
+            if annotation.identifier.name != sym::deprecated {
+                self.emit_err(TypeCheckerError::invalid_struct_annotation(annotation, annotation.span));
+            }
+            if !annotation_names.insert(annotation.identifier.name) {
+                self.emit_err(TypeCheckerError::duplicate_annotation(annotation.identifier, annotation.span));
+            }
+        }
+
+        // Check that the struct and its members are not named after a reserved name.
+        // Note that the `owner` of a record is declared with the `owner` keyword in Aleo instructions.
+        self.assert_name_is_not_reserved(input.identifier, false);
+        for Member { identifier, .. } in input.members.iter() {
+            if !(input.is_record && identifier.name == sym::owner) {
+                self.assert_name_is_not_reserved(*identifier, false);
+            }
+        }
+
         // Check for conflicting struct/record member names.
         let mut used = HashSet::new();
         // TODO: Better span to target duplicate member.
@@ -119,21 +179,28 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             });
         }
 
-        // For records, enforce presence of the `owner: Address` member.
+        // For records, enforce the record schema, e.g. the presence of the `owner: address` member.
         if input.is_record {
-            let check_has_field =
-                |need, expected_ty: Type| match input.members.iter().find_map(|Member { identifier, type_, .. }| {
-                    (identifier.name == need).then_some((identifier, type_))
-                }) {
-                    Some((_, actual_ty)) if expected_ty.eq_flat(actual_ty) => {} // All good, found + right type!
-                    Some((field, _)) => {
-                        self.emit_err(TypeCheckerError::record_var_wrong_type(field, expected_ty, input.span()));
-                    }
-                    None => {
-                        self.emit_err(TypeCheckerError::required_record_variable(need, expected_ty, input.span()));
-                    }
-                };
-            check_has_field(sym::owner, Type::Address);
+            match self.record_schema.check(input).as_slice() {
+                [] => {}
+                // A single missing or mistyped member is reported with the error specific to it.
+                [RecordSchemaViolation::Missing { name, type_ }] => {
+                    self.emit_err(TypeCheckerError::required_record_variable(name, type_, input.span()))
+                }
+                [RecordSchemaViolation::WrongType { member, type_ }] => {
+                    self.emit_err(TypeCheckerError::record_var_wrong_type(member, type_, input.span()))
+                }
+                // Otherwise, all the violations are listed in one error.
+                violations => {
+                    let list = violations.iter().map(|violation| format!("- {violation}")).collect::<Vec<_>>();
+                    self.emit_err(TypeCheckerError::record_schema_violations(
+                        input.identifier,
+                        &self.record_schema.profile,
+                        list.join("\n"),
+                        input.span(),
+                    ));
+                }
+            }
         }
 
         for Member { mode, identifier, type_, span, .. } in input.members.iter() {
@@ -151,12 +218,22 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             // Note that we have already checked that each member is defined and valid.
             if let Type::Identifier(member_type) = type_ {
                 self.struct_graph.add_edge(input.identifier.name, member_type.name);
-            } else if let Type::Array(array_type) = type_ {
-                // Get the base element type.
-                let base_element_type = array_type.base_element_type();
-                // If the base element type is a struct, then add it to the struct dependency graph.
-                if let Type::Identifier(member_type) = base_element_type {
-                    self.struct_graph.add_edge(input.identifier.name, member_type.name);
+            } else if let Type::Array(_) | Type::Option(_) | Type::Result(_) = type_ {
+                // Get the base element types, e.g. `Foo` in `[Option<Foo>; 2]`, or `Foo` and `Bar` in `Result<Foo, [Bar; 2]>`.
+                let mut element_types = vec![type_];
+                while let Some(element_type) = element_types.pop() {
+                    match element_type {
+                        Type::Array(array_type) => element_types.push(array_type.element_type()),
+                        Type::Option(option_type) => element_types.push(option_type.element_type()),
+                        Type::Result(result_type) => {
+                            element_types.extend([result_type.ok_type(), result_type.err_type()])
+                        }
+                        // If the base element type is a struct, then add it to the struct dependency graph.
+                        Type::Identifier(member_type) => {
+                            self.struct_graph.add_edge(input.identifier.name, member_type.name)
+                        }
+                        _ => {}
+                    }
                 }
             }
 
@@ -168,6 +245,9 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_mapping(&mut self, input: &'a Mapping) {
+        // Check that the mapping is not named after a reserved name.
+        self.assert_name_is_not_reserved(input.identifier, false);
+
         // Check that a mapping's key type is valid.
         self.assert_type_is_valid(&input.key_type, input.span);
         // Check that a mapping's key type is not a tuple, record, or mapping.
@@ -205,10 +285,55 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
     fn visit_function(&mut self, function: &'a Function) {
         // Check that the function's annotations are valid.
-        // Note that Leo does not natively support any specific annotations.
+        let mut annotation_names = HashSet::new();
         for annotation in function.annotations.iter() {
-            // TODO: Change to compiler warning.
-            self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span))
+            match Annotation::accepts_value(annotation.identifier.name) {
+                // TODO: Change to compiler warning.
+                None => self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span)),
+                Some(false) if annotation.value.is_some() => {
+                    self.emit_err(TypeCheckerError::annotation_takes_no_value(annotation.identifier, annotation.span))
+                }
+                Some(_) => {}
+            }
+            if !annotation_names.insert(annotation.identifier.name) {
+                self.emit_err(TypeCheckerError::duplicate_annotation(annotation.identifier, annotation.span));
+            }
+        }
+        if let Some(annotation) = function.annotation(sym::should_fail) {
+            if function.annotation(sym::test).is_none() {
+                self.emit_err(TypeCheckerError::should_fail_without_test(annotation.span));
+            }
+        }
+        if let Some(annotation) = function.annotation(sym::inline) {
+            if function.variant == Variant::Transition {
+                self.emit_err(TypeCheckerError::inline_transition(annotation.span));
+            }
+        }
+        if let Some(annotation) = function.annotation(sym::max_constraints) {
+            // Only transitions are synthesized into circuits of their own.
+            if function.variant != Variant::Transition {
+                self.emit_err(TypeCheckerError::constraint_budget_on_non_transition(annotation.span));
+            } else if function.max_constraints().is_none() {
+                self.emit_err(TypeCheckerError::invalid_constraint_budget(annotation.span));
+            }
+        }
+
+        // Check that the function is not named after a reserved name, unless it is inlined and so absent from the bytecode.
+        if function.variant != Variant::Inline {
+            self.assert_name_is_not_reserved(function.identifier, true);
+        }
+
+        // A method works on a copy of its receiver, so changes to `mut self` are only visible if it is returned.
+        if let Some(receiver) = function.receiver.filter(|receiver| receiver.is_mutable) {
+            let returns_receiver = function.output.iter().any(|output| {
+                matches!(output.type_(), Type::Identifier(identifier) if identifier.name == receiver.struct_name)
+            });
+            if !returns_receiver {
+                self.emit_err(TypeCheckerError::mutable_receiver_not_returned(
+                    receiver.method_name,
+                    function.identifier.span,
+                ));
+            }
         }
 
         self.variant = Some(function.variant);
@@ -229,6 +354,9 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
         // Store the name of the function.
         self.function = Some(function.name());
 
+        // Store the visibility modes of the function's outputs.
+        self.output_modes = function.output.iter().map(|output| output.mode()).collect();
+
         // Create a new child scope for the function's parameters and body.
         let scope_index = self.create_child_scope();
 
@@ -240,6 +368,10 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             if matches!(input_var.type_(), Type::Tuple(_)) {
                 self.emit_err(TypeCheckerError::function_cannot_take_tuple_as_input(input_var.span()))
             }
+            // Check that the values of the input type can be given to a transition.
+            if function.variant == Variant::Transition {
+                self.assert_type_is_serializable_input(&input_var.type_(), input_var.span());
+            }
 
             // Note that this unwrap is safe since we assign to `self.variant` above.
             match self.variant.unwrap() {
@@ -296,9 +428,16 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                         self.emit_err(TypeCheckerError::nested_tuple_type(function_output.span))
                     }
                     // Check that the mode of the output is valid.
-                    // For functions, only public and private outputs are allowed
-                    if function_output.mode == Mode::Constant {
-                        self.emit_err(TypeCheckerError::cannot_have_constant_output_mode(function_output.span));
+                    // For transitions, only public and private outputs are allowed.
+                    // Other functions are not executed as circuits, so their outputs cannot have modes.
+                    match function.variant {
+                        Variant::Transition if function_output.mode == Mode::Constant => {
+                            self.emit_err(TypeCheckerError::cannot_have_constant_output_mode(function_output.span))
+                        }
+                        Variant::Standard | Variant::Inline if function_output.mode != Mode::None => self.emit_err(
+                            TypeCheckerError::regular_function_outputs_cannot_have_modes(function_output.span),
+                        ),
+                        _ => {} // Do nothing.
                     }
                 }
             }
@@ -19,7 +19,7 @@ use itertools::Itertools;
 
 use leo_ast::*;
 use leo_errors::TypeCheckerError;
-use leo_span::{Span, Symbol};
+use leo_span::{sym, Span, Symbol};
 
 impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
     fn visit_statement(&mut self, input: &'a Statement) {
@@ -50,42 +50,57 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
                 self.assert_bool_type(&type_, expr.span());
             }
             AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
-                let t1 = self.visit_expression(left, &None);
-                let t2 = self.visit_expression(right, &None);
+                let (t1, t2) = self.visit_operands(left, right, &None);
 
                 // Check that the types are equal.
                 self.check_eq_types(&t1, &t2, input.span());
             }
+            // The execution path never completes, so it needs neither a return nor a finalize statement.
+            AssertVariant::Fail(_) => {
+                self.has_return = true;
+                self.has_finalize = true;
+            }
         }
     }
 
     fn visit_assign(&mut self, input: &'a AssignStatement) {
-        let var_name = match input.place {
-            Expression::Identifier(id) => id,
-            _ => {
+        // The place must be a variable or a path to a member of a variable, e.g. `a.b.c`.
+        let var_name = match input.place.root_identifier() {
+            Some(id) => id,
+            None => {
                 self.emit_err(TypeCheckerError::invalid_assignment_target(input.place.span()));
                 return;
             }
         };
 
+        let has_mutable_receiver = self.has_mutable_receiver();
         let var_type = if let Some(var) = self.symbol_table.borrow_mut().lookup_variable(var_name.name) {
             match &var.declaration {
                 VariableType::Const => self.emit_err(TypeCheckerError::cannot_assign_to_const_var(var_name, var.span)),
                 VariableType::Input(Mode::Constant) => {
                     self.emit_err(TypeCheckerError::cannot_assign_to_const_input(var_name, var.span))
                 }
+                // The receiver of a method can only be reassigned if it is declared as `mut self`.
+                VariableType::Input(_) if var_name.name == sym::SelfLower && !has_mutable_receiver => {
+                    self.emit_err(TypeCheckerError::cannot_assign_to_immutable_self(var_name.span))
+                }
                 _ => {}
             }
 
             Some(var.type_.clone())
         } else {
-            self.emit_err(TypeCheckerError::unknown_sym("variable", var_name.name, var_name.span));
+            self.emit_unknown_variable(var_name);
 
             None
         };
 
         if var_type.is_some() {
-            self.visit_expression(&input.value, &var_type);
+            // If the place is a member of the variable, then the value must have the type of that member.
+            let place_type = match input.place {
+                Expression::Identifier(_) => var_type,
+                _ => self.visit_expression(&input.place, &None),
+            };
+            self.visit_expression(&input.value, &place_type);
         }
     }
 
@@ -147,8 +162,55 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         self.has_finalize = previous_has_finalize || (then_block_has_finalize && otherwise_block_has_finalize);
     }
 
-    fn visit_console(&mut self, _: &'a ConsoleStatement) {
-        unreachable!("Parsing guarantees that console statements are not present in the AST.");
+    fn visit_console(&mut self, input: &'a ConsoleStatement) {
+        match &input.function {
+            ConsoleFunction::Assert(expr) => {
+                let type_ = self.visit_expression(expr, &Some(Type::Boolean));
+                self.assert_bool_type(&type_, expr.span());
+            }
+            ConsoleFunction::AssertEq(left, right) | ConsoleFunction::AssertNeq(left, right) => {
+                let (t1, t2) = self.visit_operands(left, right, &None);
+
+                // Check that the types are equal.
+                self.check_eq_types(&t1, &t2, input.span());
+            }
+            ConsoleFunction::Error(format, arguments) | ConsoleFunction::Log(format, arguments) => {
+                // Check that there is an argument for each placeholder in the format string.
+                let num_placeholders = format.matches("{}").count();
+                if num_placeholders != arguments.len() {
+                    self.emit_err(TypeCheckerError::incorrect_num_log_arguments(
+                        num_placeholders,
+                        arguments.len(),
+                        input.span(),
+                    ));
+                }
+
+                // Check that each argument is a plaintext value.
+                for argument in arguments {
+                    let is_plaintext = match self.visit_expression(argument, &None) {
+                        Some(Type::Identifier(identifier)) => !self
+                            .symbol_table
+                            .borrow()
+                            .lookup_struct(identifier.name)
+                            .map_or(false, |struct_| struct_.is_record),
+                        Some(Type::Unit) => false,
+                        _ => true,
+                    };
+                    if !is_plaintext {
+                        self.emit_err(TypeCheckerError::invalid_log_argument_type(argument, argument.span()));
+                    }
+                }
+
+                match &input.function {
+                    // `console.error` is lowered to `fail`, so its execution path never completes.
+                    ConsoleFunction::Error(..) => {
+                        self.has_return = true;
+                        self.has_finalize = true;
+                    }
+                    _ => self.emit_err(TypeCheckerError::console_log_not_supported(input.span())),
+                }
+            }
+        }
     }
 
     fn visit_const(&mut self, input: &'a ConstDeclaration) {
@@ -172,9 +234,19 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             _ => (), // Do nothing
         }
 
+        // Returns `true` if the expression is a literal or an array of literals, which can be used as a lookup table.
+        fn is_literal_or_table(expression: &Expression) -> bool {
+            match expression {
+                Expression::Literal(_) => true,
+                Expression::Array(array) => array.elements.iter().all(is_literal_or_table),
+                _ => false,
+            }
+        }
+
         // Enforce that Constant variables have literal expressions on right-hand side
         match &input.value {
             Expression::Literal(_) => (),
+            Expression::Array(_) if is_literal_or_table(&input.value) => (),
             Expression::Tuple(tuple_expression) => match tuple_expression.elements.len() {
                 0 | 1 => unreachable!("Parsing guarantees that tuple types have at least two elements."),
                 _ => {
@@ -333,7 +405,9 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         match &input.start {
             Expression::Literal(literal) => {
                 // Note that this check is needed because the pass attempts to make progress, even though the literal may be invalid.
-                if let Ok(value) = Value::try_from(literal) {
+                if let Some(Ok(value)) =
+                    literal.clone().with_inferred_suffix(&input.type_).map(|literal| Value::try_from(&literal))
+                {
                     input.start_value.replace(Some(value));
                 }
             }
@@ -353,7 +427,9 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         match &input.stop {
             Expression::Literal(literal) => {
                 // Note that this check is needed because the pass attempts to make progress, even though the literal may be invalid.
-                if let Ok(value) = Value::try_from(literal) {
+                if let Some(Ok(value)) =
+                    literal.clone().with_inferred_suffix(&input.type_).map(|literal| Value::try_from(&literal))
+                {
                     input.stop_value.replace(Some(value));
                 }
             }
@@ -391,6 +467,28 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             }
         }
 
+        // Check that a transition does not reveal a private input by returning it as a public output.
+        if !self.is_finalize && matches!(self.variant, Some(Variant::Transition)) {
+            let returned = match &input.expression {
+                Expression::Tuple(tuple) => tuple.elements.iter().collect(),
+                expression => vec![expression],
+            };
+            for (expression, mode) in returned.into_iter().zip(self.output_modes.iter()) {
+                if let Expression::Identifier(identifier) = expression {
+                    let is_private_input = matches!(
+                        self.symbol_table.borrow().lookup_variable(identifier.name),
+                        Some(VariableSymbol { declaration: VariableType::Input(Mode::Private), .. })
+                    );
+                    if is_private_input && *mode == Mode::Public {
+                        self.emit_err(TypeCheckerError::private_input_returned_as_public_output(
+                            identifier.name,
+                            identifier.span,
+                        ));
+                    }
+                }
+            }
+        }
+
         // Set the `is_return` flag. This is necessary to allow unit expressions in the return statement.
         self.is_return = true;
         // Type check the associated expression.
@@ -14,16 +14,51 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CallGraph, StructGraph, SymbolTable, TypeTable};
-
-use leo_ast::{CoreConstant, CoreFunction, Identifier, IntegerType, MappingType, Node, Type, Variant};
-use leo_errors::{emitter::Handler, TypeCheckerError};
-use leo_span::{Span, Symbol};
+use crate::{CallGraph, RecordSchema, StructGraph, SymbolTable, TypeTable};
+
+use leo_ast::{
+    ArrayType,
+    AssociatedFunction,
+    CoreConstant,
+    CoreFunction,
+    Expression,
+    ExpressionVisitor,
+    Identifier,
+    IntegerType,
+    Literal,
+    MappingType,
+    Mode,
+    Node,
+    NodeID,
+    NonNegativeNumber,
+    OptionType,
+    ResultType,
+    Type,
+    Value,
+    Variant,
+};
+use leo_errors::{emitter::Handler, TypeCheckerError, TypeCheckerWarning};
+use leo_span::{sym, symbol::with_session_globals, Span, Symbol};
 
 use snarkvm::console::network::{Network, Testnet3};
 
+use indexmap::IndexMap;
 use itertools::Itertools;
-use std::cell::RefCell;
+use std::{cell::RefCell, rc::Rc};
+
+/// The keywords of Aleo instructions that are not keywords in Leo, and so can be written as Leo identifiers.
+/// The Aleo VM rejects programs that use them to name a struct, record, member, mapping, or function.
+const ALEO_KEYWORDS: &[&str] = &[
+    "aleo", "async", "boolean", "break", "case", "closure", "continue", "default", "enum", "future", "global", "impl",
+    "input", "into", "key", "match", "output", "owner", "storage", "switch", "trait", "type", "union", "value",
+    "while",
+];
+
+/// The opcodes of Aleo instructions that can be written as Leo identifiers, which the Aleo VM rejects as names.
+const ALEO_OPCODES: &[&str] = &[
+    "abs", "add", "and", "call", "cast", "div", "double", "gt", "gte", "inv", "lt", "lte", "mod", "mul", "nand", "neg",
+    "nor", "not", "or", "pow", "rem", "shl", "shr", "sqrt", "square", "sub", "ternary", "xor",
+];
 
 pub struct TypeChecker<'a> {
     /// The symbol table for the program.
@@ -36,10 +71,14 @@ pub struct TypeChecker<'a> {
     pub(crate) call_graph: CallGraph,
     /// The error handler.
     pub(crate) handler: &'a Handler,
+    /// The requirements on the members of records.
+    pub(crate) record_schema: &'a RecordSchema,
     /// The name of the function that we are currently traversing.
     pub(crate) function: Option<Symbol>,
     /// The variant of the function that we are currently traversing.
     pub(crate) variant: Option<Variant>,
+    /// The visibility modes of the outputs of the function that we are currently traversing.
+    pub(crate) output_modes: Vec<Mode>,
     /// Whether or not the function that we are currently traversing has a return statement.
     pub(crate) has_return: bool,
     /// Whether or not the function that we are currently traversing invokes the finalize block.
@@ -51,6 +90,16 @@ pub struct TypeChecker<'a> {
     pub(crate) is_imported: bool,
     /// Whether or not we are currently traversing a return statement.
     pub(crate) is_return: bool,
+    /// The number of expressions that we are currently traversing, each nested in the previous one.
+    pub(crate) expression_depth: usize,
+    /// The number of expressions that may be nested in one another.
+    pub(crate) max_expression_depth: usize,
+    /// Whether or not the outermost expression that we are currently traversing is nested too deeply.
+    pub(crate) is_too_deep: bool,
+    /// The maximum number of elements of an array type, if any.
+    pub(crate) max_array_size: Option<usize>,
+    /// The values of the integer operations whose operands are all literals, folded once as they are type checked.
+    pub(crate) constant_values: IndexMap<NodeID, Value>,
 }
 
 const ADDRESS_TYPE: Type = Type::Address;
@@ -98,8 +147,15 @@ const MAGNITUDE_TYPES: [Type; 3] =
     [Type::Integer(IntegerType::U8), Type::Integer(IntegerType::U16), Type::Integer(IntegerType::U32)];
 
 impl<'a> TypeChecker<'a> {
-    /// Returns a new type checker given a symbol table and error handler.
-    pub fn new(symbol_table: SymbolTable, type_table: &'a TypeTable, handler: &'a Handler) -> Self {
+    /// Returns a new type checker given a symbol table, error handler, record schema, and limits on the program.
+    pub fn new(
+        symbol_table: SymbolTable,
+        type_table: &'a TypeTable,
+        handler: &'a Handler,
+        record_schema: &'a RecordSchema,
+        max_expression_depth: usize,
+        max_array_size: Option<usize>,
+    ) -> Self {
         let struct_names = symbol_table.structs.keys().cloned().collect();
 
         let function_names = symbol_table.functions.keys().cloned().collect();
@@ -111,13 +167,20 @@ impl<'a> TypeChecker<'a> {
             struct_graph: StructGraph::new(struct_names),
             call_graph: CallGraph::new(function_names),
             handler,
+            record_schema,
             function: None,
             variant: None,
+            output_modes: Vec::new(),
             has_return: false,
             has_finalize: false,
             is_finalize: false,
             is_imported: false,
             is_return: false,
+            expression_depth: 0,
+            max_expression_depth,
+            is_too_deep: false,
+            max_array_size,
+            constant_values: IndexMap::new(),
         }
     }
 
@@ -145,11 +208,119 @@ impl<'a> TypeChecker<'a> {
         self.symbol_table = RefCell::new(previous_symbol_table);
     }
 
+    /// Type checks the operands of an operation that expects both operands to have the same type.
+    /// If no type is expected and exactly one operand is an unsuffixed literal, its type is inferred from the other operand.
+    pub(crate) fn visit_operands(
+        &mut self,
+        left: &'a Expression,
+        right: &'a Expression,
+        expected: &Option<Type>,
+    ) -> (Option<Type>, Option<Type>) {
+        let is_unsuffixed =
+            |expression: &Expression| matches!(expression, Expression::Literal(Literal::Unsuffixed(..)));
+        match (expected, is_unsuffixed(left), is_unsuffixed(right)) {
+            (None, true, false) => {
+                let t2 = self.visit_expression(right, &None);
+                let t1 = self.visit_expression(left, &t2);
+                (t1, t2)
+            }
+            (None, false, true) => {
+                let t1 = self.visit_expression(left, &None);
+                let t2 = self.visit_expression(right, &t1);
+                (t1, t2)
+            }
+            _ => (self.visit_expression(left, expected), self.visit_expression(right, expected)),
+        }
+    }
+
+    /// Visits the operands of a multiplication, inferring the types of unsuffixed literals like `visit_operands`.
+    /// An unsuffixed literal multiplied with a group element is a scalar, e.g. `2` in `g * 2`.
+    pub(crate) fn visit_factors(
+        &mut self,
+        left: &'a Expression,
+        right: &'a Expression,
+        expected: &Option<Type>,
+    ) -> (Option<Type>, Option<Type>) {
+        let is_unsuffixed =
+            |expression: &Expression| matches!(expression, Expression::Literal(Literal::Unsuffixed(..)));
+        let factor_type = |type_: &Option<Type>| match type_ {
+            Some(Type::Group) => Some(Type::Scalar),
+            type_ => type_.clone(),
+        };
+        match (expected, is_unsuffixed(left), is_unsuffixed(right)) {
+            (None, true, false) => {
+                let t2 = self.visit_expression(right, &None);
+                let t1 = self.visit_expression(left, &factor_type(&t2));
+                (t1, t2)
+            }
+            (None, false, true) => {
+                let t1 = self.visit_expression(left, &None);
+                let t2 = self.visit_expression(right, &factor_type(&t1));
+                (t1, t2)
+            }
+            _ => self.visit_operands(left, right, expected),
+        }
+    }
+
+    /// Returns `true` if the function being checked is a struct method that takes `mut self`.
+    pub(crate) fn has_mutable_receiver(&self) -> bool {
+        self.function
+            .and_then(|name| self.symbol_table.borrow().lookup_fn_symbol(name).and_then(|func| func.receiver))
+            .map_or(false, |receiver| receiver.is_mutable)
+    }
+
+    /// Emits an error for the use of an undeclared variable, which points to its declaration if its scope has ended.
+    pub(crate) fn emit_unknown_variable(&self, name: Identifier) {
+        let declaration = self.symbol_table.borrow().lookup_variable_in_ended_scope(name.name);
+        match declaration {
+            Some(declaration) => self.emit_err(
+                TypeCheckerError::variable_out_of_scope(name, name.span)
+                    .with_label(declaration, format!("`{name}` is declared here, in a scope that has ended")),
+            ),
+            None => self.emit_err(TypeCheckerError::unknown_sym("variable", name.name, name.span)),
+        }
+    }
+
     /// Emits a type checker error.
     pub(crate) fn emit_err(&self, err: TypeCheckerError) {
         self.handler.emit_err(err);
     }
 
+    /// Emits a type checker warning.
+    pub(crate) fn emit_warning(&self, warning: TypeCheckerWarning) {
+        self.handler.emit_warning(warning.into());
+    }
+
+    /// Emits a warning if the struct or function `name`, defined at `definition`, is deprecated.
+    /// If the definition is in another file, then the warning names that file.
+    pub(crate) fn check_deprecated(
+        &self,
+        name: Symbol,
+        message: Option<&String>,
+        is_struct: bool,
+        definition: Span,
+        span: Span,
+    ) {
+        // Uses of a symbol within an imported program are not reported.
+        let Some(message) = message else { return };
+        if self.is_imported {
+            return;
+        }
+        let (definition, use_) =
+            with_session_globals(|s| (s.source_map.span_to_location(definition), s.source_map.span_to_location(span)));
+        match (definition, use_) {
+            (Some(definition), Some(use_)) if !Rc::ptr_eq(&definition.source_file, &use_.source_file) => self
+                .emit_warning(TypeCheckerWarning::deprecated_imported_symbol(
+                    name,
+                    &definition.source_file.name,
+                    message,
+                    span,
+                )),
+            _ if is_struct => self.emit_warning(TypeCheckerWarning::deprecated_struct(name, message, span)),
+            _ => self.emit_warning(TypeCheckerWarning::deprecated_function(name, message, span)),
+        }
+    }
+
     /// Emits an error to the handler if the given type is invalid.
     fn check_type(&self, is_valid: impl Fn(&Type) -> bool, error_string: String, type_: &Option<Type>, span: Span) {
         if let Some(type_) = type_ {
@@ -162,6 +333,11 @@ impl<'a> TypeChecker<'a> {
     /// Emits an error if the two given types are not equal.
     pub(crate) fn check_eq_types(&self, t1: &Option<Type>, t2: &Option<Type>, span: Span) {
         match (t1, t2) {
+            // Fields and integers are never implicitly converted into each other.
+            (Some(Type::Field), Some(Type::Integer(integer_type)))
+            | (Some(Type::Integer(integer_type)), Some(Type::Field)) => {
+                self.emit_err(TypeCheckerError::implicit_field_integer_conversion(integer_type, span))
+            }
             (Some(t1), Some(t2)) if !Type::eq_flat(t1, t2) => {
                 self.emit_err(TypeCheckerError::type_should_be(t1, t2, span))
             }
@@ -1002,6 +1178,26 @@ impl<'a> TypeChecker<'a> {
                 self.assert_group_type(&arguments[0].0, arguments[0].1);
                 Some(Type::Field)
             }
+            CoreFunction::I8FromBitsLe => self.check_from_bits_le(IntegerType::I8, &arguments[0], function_span),
+            CoreFunction::I16FromBitsLe => self.check_from_bits_le(IntegerType::I16, &arguments[0], function_span),
+            CoreFunction::I32FromBitsLe => self.check_from_bits_le(IntegerType::I32, &arguments[0], function_span),
+            CoreFunction::I64FromBitsLe => self.check_from_bits_le(IntegerType::I64, &arguments[0], function_span),
+            CoreFunction::I128FromBitsLe => self.check_from_bits_le(IntegerType::I128, &arguments[0], function_span),
+            CoreFunction::U8FromBitsLe => self.check_from_bits_le(IntegerType::U8, &arguments[0], function_span),
+            CoreFunction::U16FromBitsLe => self.check_from_bits_le(IntegerType::U16, &arguments[0], function_span),
+            CoreFunction::U32FromBitsLe => self.check_from_bits_le(IntegerType::U32, &arguments[0], function_span),
+            CoreFunction::U64FromBitsLe => self.check_from_bits_le(IntegerType::U64, &arguments[0], function_span),
+            CoreFunction::U128FromBitsLe => self.check_from_bits_le(IntegerType::U128, &arguments[0], function_span),
+            CoreFunction::I8FromField => self.check_from_field(IntegerType::I8, &arguments[0], function_span),
+            CoreFunction::I16FromField => self.check_from_field(IntegerType::I16, &arguments[0], function_span),
+            CoreFunction::I32FromField => self.check_from_field(IntegerType::I32, &arguments[0], function_span),
+            CoreFunction::I64FromField => self.check_from_field(IntegerType::I64, &arguments[0], function_span),
+            CoreFunction::I128FromField => self.check_from_field(IntegerType::I128, &arguments[0], function_span),
+            CoreFunction::U8FromField => self.check_from_field(IntegerType::U8, &arguments[0], function_span),
+            CoreFunction::U16FromField => self.check_from_field(IntegerType::U16, &arguments[0], function_span),
+            CoreFunction::U32FromField => self.check_from_field(IntegerType::U32, &arguments[0], function_span),
+            CoreFunction::U64FromField => self.check_from_field(IntegerType::U64, &arguments[0], function_span),
+            CoreFunction::U128FromField => self.check_from_field(IntegerType::U128, &arguments[0], function_span),
             CoreFunction::ChaChaRandAddress => Some(Type::Address),
             CoreFunction::ChaChaRandBool => Some(Type::Boolean),
             CoreFunction::ChaChaRandField => Some(Type::Field),
@@ -1025,6 +1221,150 @@ impl<'a> TypeChecker<'a> {
                 // Return a boolean.
                 Some(Type::Boolean)
             }
+            CoreFunction::MerkleTreeVerify | CoreFunction::MerkleTreeVerifyBHP256 => {
+                // Check that the root and the leaf are fields.
+                self.assert_field_type(&arguments[0].0, arguments[0].1);
+                self.assert_field_type(&arguments[1].0, arguments[1].1);
+                // Check that the path is an array of fields, whose length is the depth of the tree.
+                self.check_type(
+                    |type_| matches!(type_, Type::Array(array_type) if array_type.element_type() == &Type::Field),
+                    "[field; N]".to_string(),
+                    &arguments[2].0,
+                    arguments[2].1,
+                );
+                // Check that the index of the leaf is a `u32`.
+                self.assert_type(&arguments[3].0, &Type::Integer(IntegerType::U32), arguments[3].1);
+                // Return a boolean.
+                Some(Type::Boolean)
+            }
+            CoreFunction::OptionSome
+            | CoreFunction::OptionNone
+            | CoreFunction::OptionIsSome
+            | CoreFunction::OptionIsNone
+            | CoreFunction::OptionUnwrapOr => {
+                unreachable!("The functions of `Option` are checked by `check_option_function_call`.")
+            }
+            CoreFunction::ResultOk | CoreFunction::ResultErr | CoreFunction::ResultIsOk | CoreFunction::ResultIsErr => {
+                unreachable!("The functions of `Result` are checked by `check_result_function_call`.")
+            }
+        }
+    }
+
+    /// Type checks a call to a function of `Option`, and returns its output type.
+    /// The functions are generic in the type of the value held by the option, which is inferred from the arguments,
+    /// or from the expected type in the case of `Option::none()`.
+    pub(crate) fn check_option_function_call(
+        &mut self,
+        core_function: CoreFunction,
+        input: &'a AssociatedFunction,
+        expected: &Option<Type>,
+    ) -> Option<Type> {
+        // Check that the number of arguments is correct.
+        if input.arguments.len() != core_function.num_args() {
+            self.emit_err(TypeCheckerError::incorrect_num_args_to_call(
+                core_function.num_args(),
+                input.arguments.len(),
+                input.span,
+            ));
+            return None;
+        }
+
+        let expected_element = match expected {
+            Some(Type::Option(option_type)) => Some(option_type.element_type().clone()),
+            _ => None,
+        };
+
+        let output = match core_function {
+            CoreFunction::OptionSome => {
+                let element = self.visit_expression(&input.arguments[0], &expected_element);
+                match element {
+                    Some(element) if self.assert_option_element_is_valid(&element, input.arguments[0].span()) => {
+                        Some(Type::Option(OptionType::new(element)))
+                    }
+                    _ => None,
+                }
+            }
+            CoreFunction::OptionNone => {
+                if expected_element.is_none() {
+                    self.emit_err(TypeCheckerError::cannot_infer_option_type(input.span));
+                }
+                expected_element.map(|element| Type::Option(OptionType::new(element)))
+            }
+            CoreFunction::OptionIsSome | CoreFunction::OptionIsNone => {
+                let option = self.visit_expression(&input.arguments[0], &None);
+                self.assert_option_type(&option, input.arguments[0].span());
+                Some(Type::Boolean)
+            }
+            CoreFunction::OptionUnwrapOr => {
+                let option = self.visit_expression(&input.arguments[0], &None);
+                // Note that `unwrap_or` also applies to results, in which case the default replaces an error.
+                let element = match option {
+                    Some(Type::Result(result_type)) => Some(result_type.ok_type().clone()),
+                    option => self.assert_option_type(&option, input.arguments[0].span()),
+                };
+                // The default value must have the type of the value held by the option.
+                let default = self.visit_expression(&input.arguments[1], &element);
+                element.or(default)
+            }
+            _ => unreachable!("`check_option_function_call` is only called on functions of `Option`."),
+        };
+
+        // Check the output type if the expected type is known.
+        if let Some(expected) = expected {
+            self.assert_type(&output, expected, input.span);
+        }
+
+        output
+    }
+
+    /// Type checks a call to a function of `Result`, and returns its output type.
+    /// The type of the value or error that is not given to `Result::ok` or `Result::err` is taken from the expected type.
+    pub(crate) fn check_result_function_call(
+        &mut self,
+        core_function: CoreFunction,
+        input: &'a AssociatedFunction,
+        expected: &Option<Type>,
+    ) -> Option<Type> {
+        // Check that the number of arguments is correct.
+        if input.arguments.len() != core_function.num_args() {
+            self.emit_err(TypeCheckerError::incorrect_num_args_to_call(
+                core_function.num_args(),
+                input.arguments.len(),
+                input.span,
+            ));
+            return None;
+        }
+
+        let expected = match expected {
+            Some(Type::Result(result_type)) => Some(result_type),
+            _ => None,
+        };
+
+        match core_function {
+            CoreFunction::ResultOk | CoreFunction::ResultErr => {
+                let is_ok = core_function == CoreFunction::ResultOk;
+                let argument = &input.arguments[0];
+                let (ok_type, err_type) = match expected {
+                    Some(expected) => (expected.ok_type().clone(), expected.err_type().clone()),
+                    None => {
+                        self.visit_expression(argument, &None);
+                        self.emit_err(TypeCheckerError::cannot_infer_result_type(input.name, input.span));
+                        return None;
+                    }
+                };
+                // The argument must have the type of the value or the error.
+                let argument_type = match is_ok {
+                    true => self.visit_expression(argument, &Some(ok_type.clone())),
+                    false => self.visit_expression(argument, &Some(err_type.clone())),
+                };
+                argument_type.map(|_| Type::Result(ResultType::new(ok_type, err_type)))
+            }
+            CoreFunction::ResultIsOk | CoreFunction::ResultIsErr => {
+                let result = self.visit_expression(&input.arguments[0], &None);
+                self.assert_result_type(&result, input.arguments[0].span());
+                Some(Type::Boolean)
+            }
+            _ => unreachable!("`check_result_function_call` is only called on functions of `Result`."),
         }
     }
 
@@ -1059,6 +1399,27 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Emits an error if `name` is reserved in Aleo instructions, or is `main` and does not name a function.
+    pub(crate) fn assert_name_is_not_reserved(&self, name: Identifier, is_function: bool) {
+        let name_str = name.name.to_string();
+        if ALEO_KEYWORDS.contains(&name_str.as_str()) || ALEO_OPCODES.contains(&name_str.as_str()) {
+            self.emit_err(TypeCheckerError::name_is_reserved(name, name.span));
+        } else if name.name == sym::main && !is_function {
+            self.emit_err(TypeCheckerError::main_is_reserved_for_functions(name.span));
+        }
+    }
+
+    /// Emits an error if values of the type cannot be passed to a transition, e.g. `()`.
+    pub(crate) fn assert_type_is_serializable_input(&self, type_: &Type, span: Span) {
+        let mut element_type = type_;
+        while let Type::Array(array_type) = element_type {
+            element_type = array_type.element_type();
+        }
+        if matches!(element_type, Type::Unit | Type::Mapping(_)) {
+            self.emit_err(TypeCheckerError::transition_input_not_serializable(type_, span));
+        }
+    }
+
     /// Emits an error if the type or its constituent types is not valid.
     pub(crate) fn assert_type_is_valid(&self, type_: &Type, span: Span) -> bool {
         let mut is_valid = true;
@@ -1068,11 +1429,20 @@ impl<'a> TypeChecker<'a> {
                 is_valid = false;
                 self.emit_err(TypeCheckerError::strings_are_not_supported(span));
             }
-            // Check that the named composite type has been defined.
-            Type::Identifier(identifier) if self.symbol_table.borrow().lookup_struct(identifier.name).is_none() => {
-                is_valid = false;
-                self.emit_err(TypeCheckerError::undefined_type(identifier.name, span));
-            }
+            // Check that the named composite type has been defined, and warn if it is deprecated.
+            Type::Identifier(identifier) => match self.symbol_table.borrow().lookup_struct(identifier.name) {
+                None => {
+                    is_valid = false;
+                    // Within a method, the parser has already resolved `Self` to the enclosing struct.
+                    match identifier.name == sym::SelfUpper {
+                        true => self.emit_err(TypeCheckerError::self_type_outside_method(span)),
+                        false => self.emit_err(TypeCheckerError::undefined_type(identifier.name, span)),
+                    }
+                }
+                Some(struct_) => {
+                    self.check_deprecated(identifier.name, struct_.deprecation().as_ref(), true, struct_.span, span)
+                }
+            },
             // Check that the constituent types of the tuple are valid.
             Type::Tuple(tuple_type) => {
                 for type_ in tuple_type.elements().iter() {
@@ -1084,6 +1454,21 @@ impl<'a> TypeChecker<'a> {
                 is_valid &= self.assert_type_is_valid(&mapping_type.key, span);
                 is_valid &= self.assert_type_is_valid(&mapping_type.value, span);
             }
+            // Check that the option holds a valid type.
+            Type::Option(option_type) => {
+                is_valid &= self.assert_type_is_valid(option_type.element_type(), span)
+                    && self.assert_option_element_is_valid(option_type.element_type(), span);
+            }
+            // Check that the result holds a valid value and error.
+            Type::Result(result_type) => {
+                for type_ in [result_type.ok_type(), result_type.err_type()] {
+                    is_valid &= self.assert_type_is_valid(type_, span);
+                    if is_valid && !self.has_default_value(type_) {
+                        self.emit_err(TypeCheckerError::invalid_result_element(type_, span));
+                        is_valid = false;
+                    }
+                }
+            }
             // Check that the array element types are valid.
             Type::Array(array_type) => {
                 // Check that the array length is valid.
@@ -1094,6 +1479,18 @@ impl<'a> TypeChecker<'a> {
                         self.emit_err(TypeCheckerError::array_too_large(length, Testnet3::MAX_ARRAY_ELEMENTS, span))
                     }
                 }
+                // Check that the array, including the arrays nested in it, does not have more elements than allowed.
+                if let Some(max_array_size) = self.max_array_size {
+                    let mut size = array_type.length();
+                    let mut element_type = array_type.element_type();
+                    while let Type::Array(inner) = element_type {
+                        size = size.saturating_mul(inner.length());
+                        element_type = inner.element_type();
+                    }
+                    if size > max_array_size {
+                        self.emit_err(TypeCheckerError::array_size_limit_exceeded(type_, size, max_array_size, span));
+                    }
+                }
                 // Check that the array element type is valid.
                 match array_type.element_type() {
                     // Array elements cannot be tuples.
@@ -1130,6 +1527,103 @@ impl<'a> TypeChecker<'a> {
     pub(crate) fn assert_array_type(&self, type_: &Option<Type>, span: Span) {
         self.check_type(|type_| matches!(type_, Type::Array(_)), "array".to_string(), type_, span);
     }
+
+    /// Emits an error if the type is not an option, and returns the type of the value it holds.
+    pub(crate) fn assert_option_type(&self, type_: &Option<Type>, span: Span) -> Option<Type> {
+        self.check_type(|type_| matches!(type_, Type::Option(_)), "Option<T>".to_string(), type_, span);
+        match type_ {
+            Some(Type::Option(option_type)) => Some(option_type.element_type().clone()),
+            _ => None,
+        }
+    }
+
+    /// Emits an error if the type is not a result, and returns the types of its value and error.
+    pub(crate) fn assert_result_type(&self, type_: &Option<Type>, span: Span) -> Option<ResultType> {
+        self.check_type(|type_| matches!(type_, Type::Result(_)), "Result<T, E>".to_string(), type_, span);
+        match type_ {
+            Some(Type::Result(result_type)) => Some(result_type.clone()),
+            _ => None,
+        }
+    }
+
+    /// Emits an error if an option cannot hold a value of the given type.
+    /// Since an option is lowered to a flag and a value, the value must have a default for when the option is empty.
+    pub(crate) fn assert_option_element_is_valid(&self, type_: &Type, span: Span) -> bool {
+        let is_valid = self.has_default_value(type_);
+        if !is_valid {
+            self.emit_err(TypeCheckerError::invalid_option_element(type_, span));
+        }
+        is_valid
+    }
+
+    /// Returns `true` if the type has a default value, i.e. if it is not a record, a signature, or an option,
+    /// and only contains types with default values.
+    fn has_default_value(&self, type_: &Type) -> bool {
+        match type_ {
+            Type::Address | Type::Boolean | Type::Field | Type::Group | Type::Integer(_) | Type::Scalar => true,
+            Type::Array(array_type) => self.has_default_value(array_type.element_type()),
+            Type::Identifier(identifier) => {
+                let struct_ = self.symbol_table.borrow().lookup_struct(identifier.name).cloned();
+                struct_.map_or(false, |struct_| {
+                    !struct_.is_record && struct_.members.iter().all(|member| self.has_default_value(&member.type_))
+                })
+            }
+            Type::Mapping(_)
+            | Type::Option(_)
+            | Type::Result(_)
+            | Type::Signature
+            | Type::String
+            | Type::Tuple(_)
+            | Type::Unit
+            | Type::Err => false,
+        }
+    }
+
+    /// Returns the type of the bits of an integer type, i.e. `[bool; N]` for an `N`-bit integer.
+    /// Emits an error if the bits do not fit in an array.
+    pub(crate) fn bits_type(&self, integer_type: IntegerType, span: Span) -> Option<Type> {
+        match integer_type.size_in_bits() {
+            width if width <= Testnet3::MAX_ARRAY_ELEMENTS => {
+                Some(Type::Array(ArrayType::new(Type::Boolean, NonNegativeNumber::from(width))))
+            }
+            _ => {
+                self.emit_err(TypeCheckerError::bit_decomposition_too_wide(
+                    integer_type,
+                    Testnet3::MAX_ARRAY_ELEMENTS,
+                    span,
+                ));
+                None
+            }
+        }
+    }
+
+    /// Type checks the recomposition of an integer from its bits, e.g. `u32::from_bits_le(bits)`.
+    fn check_from_bits_le(
+        &self,
+        integer_type: IntegerType,
+        argument: &(Option<Type>, Span),
+        function_span: Span,
+    ) -> Option<Type> {
+        if let Some(bits_type) = self.bits_type(integer_type, function_span) {
+            self.assert_type(&argument.0, &bits_type, argument.1);
+        }
+        Some(Type::Integer(integer_type))
+    }
+
+    /// Type checks the checked conversion of a field element into an integer, e.g. `u32::from_field(f)`.
+    /// Conversions into signed integers are rejected, since a negative integer has no canonical field representation.
+    fn check_from_field(
+        &self,
+        integer_type: IntegerType,
+        argument: &(Option<Type>, Span),
+        function_span: Span,
+    ) -> Option<Type> {
+        if integer_type.is_signed() {
+            self.emit_err(TypeCheckerError::ambiguous_signed_field_conversion(integer_type, function_span));
+        }
+        self.assert_field_type(&argument.0, argument.1);
+        Some(Type::Integer(integer_type))
+    }
 }
 
 fn types_to_string(types: &[Type]) -> String {
@@ -27,17 +27,22 @@ pub use check_statements::*;
 pub mod checker;
 pub use checker::*;
 
+pub mod record_schema;
+pub use record_schema::*;
+
 use crate::{CallGraph, Pass, StructGraph, SymbolTable, TypeTable};
 
 use leo_ast::{Ast, ProgramVisitor};
 use leo_errors::{emitter::Handler, Result};
 
 impl<'a> Pass for TypeChecker<'a> {
-    type Input = (&'a Ast, &'a Handler, SymbolTable, &'a TypeTable);
+    type Input = (&'a Ast, &'a Handler, SymbolTable, &'a TypeTable, &'a RecordSchema, usize, Option<usize>);
     type Output = Result<(SymbolTable, StructGraph, CallGraph)>;
 
-    fn do_pass((ast, handler, st, tt): Self::Input) -> Self::Output {
-        let mut visitor = TypeChecker::new(st, tt, handler);
+    fn do_pass(
+        (ast, handler, st, tt, record_schema, max_expression_depth, max_array_size): Self::Input,
+    ) -> Self::Output {
+        let mut visitor = TypeChecker::new(st, tt, handler, record_schema, max_expression_depth, max_array_size);
         visitor.visit_program(ast.as_repr());
         handler.last_err().map_err(|e| *e)?;
 
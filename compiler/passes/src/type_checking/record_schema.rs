@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Identifier, Member, Struct, Type};
+use leo_span::{sym, Symbol};
+
+use std::fmt;
+
+/// The requirements on the members of records, which depend on the target a program is compiled for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordSchema {
+    /// The name of the profile the schema belongs to, e.g. `aleo`.
+    pub profile: String,
+    /// The members every record must declare, along with their types.
+    pub required: Vec<(Symbol, Type)>,
+    /// Whether the required members must be declared first, in the order in which they are listed.
+    pub ordered: bool,
+    /// The prefixes of the member names that are reserved for the target.
+    pub reserved_prefixes: Vec<String>,
+}
+
+impl Default for RecordSchema {
+    fn default() -> Self {
+        Self::aleo()
+    }
+}
+
+impl RecordSchema {
+    /// The names of the profiles accepted by [`RecordSchema::profile`].
+    pub const PROFILES: [&'static str; 2] = ["aleo", "strict"];
+
+    /// The schema of records on Aleo. Records must have an `owner: address` member, and may not declare a `_nonce`,
+    /// which the VM adds to every record.
+    pub fn aleo() -> Self {
+        Self {
+            profile: "aleo".to_string(),
+            required: vec![(sym::owner, Type::Address)],
+            ordered: false,
+            reserved_prefixes: vec!["_nonce".to_string()],
+        }
+    }
+
+    /// The schema of records on Aleo, with the `owner` declared first, as in the records of Aleo instructions.
+    pub fn strict() -> Self {
+        Self { profile: "strict".to_string(), ordered: true, ..Self::aleo() }
+    }
+
+    /// Returns the schema of the profile with the given name, if there is one.
+    pub fn profile(name: &str) -> Option<Self> {
+        match name {
+            "aleo" => Some(Self::aleo()),
+            "strict" => Some(Self::strict()),
+            _ => None,
+        }
+    }
+
+    /// Returns every way in which `record` does not follow the schema, in the order in which its members are declared.
+    pub fn check(&self, record: &Struct) -> Vec<RecordSchemaViolation> {
+        let mut violations = Vec::new();
+
+        for (position, (name, type_)) in self.required.iter().enumerate() {
+            match record.members.iter().position(|member| member.identifier.name == *name) {
+                None => violations.push(RecordSchemaViolation::Missing { name: *name, type_: type_.clone() }),
+                Some(index) => {
+                    let member = &record.members[index];
+                    if !type_.eq_flat(&member.type_) {
+                        violations
+                            .push(RecordSchemaViolation::WrongType { member: member.identifier, type_: type_.clone() });
+                    }
+                    if self.ordered && index != position {
+                        violations.push(RecordSchemaViolation::Misplaced { member: member.identifier, position });
+                    }
+                }
+            }
+        }
+
+        for Member { identifier, .. } in record.members.iter() {
+            let name = identifier.name.to_string();
+            if let Some(prefix) = self.reserved_prefixes.iter().find(|prefix| name.starts_with(prefix.as_str())) {
+                violations.push(RecordSchemaViolation::Reserved { member: *identifier, prefix: prefix.clone() });
+            }
+        }
+
+        violations
+    }
+}
+
+/// A way in which a record does not follow a [`RecordSchema`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RecordSchemaViolation {
+    /// A required member is not declared.
+    Missing { name: Symbol, type_: Type },
+    /// A required member is declared with the wrong type.
+    WrongType { member: Identifier, type_: Type },
+    /// A required member is not declared at its position.
+    Misplaced { member: Identifier, position: usize },
+    /// A member name starts with a reserved prefix.
+    Reserved { member: Identifier, prefix: String },
+}
+
+impl fmt::Display for RecordSchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { name, type_ } => write!(f, "the member `{name}: {type_}` is missing"),
+            Self::WrongType { member, type_ } => write!(f, "the member `{member}` must have type `{type_}`"),
+            Self::Misplaced { member, position } => {
+                write!(f, "the member `{member}` must be declared at position {}", position + 1)
+            }
+            Self::Reserved { member, prefix } => {
+                write!(f, "the name of the member `{member}` starts with the reserved prefix `{prefix}`")
+            }
+        }
+    }
+}
@@ -0,0 +1,248 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+#![doc = include_str!("../README.md")]
+
+use leo_compiler::{program_interface, Sandbox};
+use leo_compiler_api::{Error, Options};
+use leo_errors::emitter::{DiagnosticRecord, Severity};
+
+use indexmap::IndexMap;
+use pyo3::{
+    create_exception,
+    exceptions::{PyException, PyValueError},
+    prelude::*,
+};
+use std::{collections::HashMap, time::Duration};
+
+create_exception!(leo, CompileError, PyException, "Raised with a message and the diagnostics of a failed compilation.");
+create_exception!(leo, ExecutionError, PyException, "Raised when a compiled program cannot be executed.");
+
+/// An error or a warning reported by the compiler.
+#[pyclass(module = "leo", get_all, frozen)]
+#[derive(Clone)]
+struct Diagnostic {
+    /// `error` or `warning`.
+    severity: String,
+    /// The code of the diagnostic, e.g. `ETYC0372003`, if it has one.
+    code: Option<String>,
+    /// The text of the diagnostic.
+    message: String,
+    /// Where the diagnostic points, as `file:line:column`, if it points anywhere.
+    location: Option<String>,
+}
+
+#[pymethods]
+impl Diagnostic {
+    fn __repr__(&self) -> String {
+        let code = self.code.as_deref().map(|code| format!(" [{code}]")).unwrap_or_default();
+        let location = self.location.as_deref().map(|location| format!(" at {location}")).unwrap_or_default();
+        format!("<{}{code}: {}{location}>", self.severity, self.message)
+    }
+}
+
+impl From<DiagnosticRecord> for Diagnostic {
+    fn from(record: DiagnosticRecord) -> Self {
+        let severity = match record.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        Self { severity: severity.to_string(), code: record.code, message: record.message, location: record.location }
+    }
+}
+
+/// Returns the diagnostics of a call, or raises `CompileError` with all of them if any of them is an error.
+fn diagnostics(records: Vec<DiagnosticRecord>) -> PyResult<Vec<Diagnostic>> {
+    let failed = records.iter().any(|record| record.severity == Severity::Error);
+    let diagnostics = records.into_iter().map(Diagnostic::from).collect::<Vec<_>>();
+    match failed {
+        true => {
+            let errors = diagnostics.iter().filter(|diagnostic| diagnostic.severity == "error").count();
+            let message = format!("compilation failed with {errors} error{}", if errors == 1 { "" } else { "s" });
+            Err(CompileError::new_err((message, diagnostics)))
+        }
+        false => Ok(diagnostics),
+    }
+}
+
+/// The Leo compiler. It reads no files: imported programs are given by their sources, keyed by program name.
+/// Each call is limited in time and size, and reports the errors of the program by raising `CompileError`.
+#[pyclass(module = "leo")]
+struct Compiler {
+    imports: IndexMap<String, String>,
+    dce: bool,
+    time_limit: Duration,
+    max_nodes: usize,
+    max_constraints: u64,
+}
+
+impl Compiler {
+    /// Returns the sandbox in which the compiler runs.
+    fn sandbox(&self) -> Sandbox {
+        let mut sandbox = Sandbox { imports: self.imports.clone(), ..Default::default() };
+        sandbox.build.dce_enabled = self.dce;
+        sandbox.time_limit = self.time_limit;
+        sandbox.max_nodes = self.max_nodes;
+        sandbox.max_constraints = self.max_constraints;
+        sandbox
+    }
+}
+
+#[pymethods]
+impl Compiler {
+    #[new]
+    #[pyo3(signature = (imports = None, dce = true, time_limit = 10.0, max_nodes = 1_000_000, max_constraints = 4_000_000))]
+    fn new(
+        imports: Option<HashMap<String, String>>,
+        dce: bool,
+        time_limit: f64,
+        max_nodes: usize,
+        max_constraints: u64,
+    ) -> PyResult<Self> {
+        let imports = imports.unwrap_or_default().into_iter().collect();
+        // A negative time limit is treated as zero, but an infinite or NaN one is rejected.
+        let time_limit = Duration::try_from_secs_f64(if time_limit < 0.0 { 0.0 } else { time_limit })
+            .map_err(|err| PyValueError::new_err(format!("invalid time limit {time_limit}: {err}")))?;
+        Ok(Self { imports, dce, time_limit, max_nodes, max_constraints })
+    }
+
+    /// Makes the program `name` importable as `import {name}.leo;`, from its `source`.
+    fn add_import(&mut self, name: String, source: String) {
+        self.imports.insert(name, source);
+    }
+
+    /// Parses the program `program_name` from its `source`, and returns its AST as a dictionary.
+    fn parse(&self, py: Python, program_name: &str, source: &str) -> PyResult<PyObject> {
+        let output = self.sandbox().parse(program_name, source);
+        diagnostics(output.diagnostics)?;
+        let ast = output.ast.unwrap_or_default();
+        Ok(py.import("json")?.call_method1("loads", (ast,))?.into())
+    }
+
+    /// Type checks the program `program_name` from its `source`, like `leo check`, and returns its warnings.
+    fn check(&self, program_name: &str, source: &str) -> PyResult<Vec<Diagnostic>> {
+        diagnostics(self.sandbox().check(program_name, source).diagnostics)
+    }
+
+    /// Compiles the program `program_name` from its `source`, and returns its Aleo bytecode.
+    fn compile(&self, program_name: &str, source: &str) -> PyResult<String> {
+        let output = self.sandbox().compile(program_name, source);
+        diagnostics(output.diagnostics)?;
+        Ok(output.bytecode.unwrap_or_default())
+    }
+}
+
+/// A function of the interface of a compiled program.
+#[pyclass(module = "leo", get_all, frozen)]
+struct Function {
+    /// The name of the function.
+    name: String,
+    /// The types and visibilities of its inputs, e.g. `u64.private`.
+    inputs: Vec<String>,
+    /// The types and visibilities of its outputs.
+    outputs: Vec<String>,
+}
+
+/// The layout of a struct, record, or mapping of the interface of a compiled program.
+#[pyclass(module = "leo", get_all, frozen)]
+struct Layout {
+    /// `struct`, `record`, or `mapping`.
+    kind: String,
+    /// The name of the struct, record, or mapping.
+    name: String,
+    /// The members of the struct or record, or the key and value of the mapping, e.g. `amount as u64.private`.
+    members: Vec<String>,
+}
+
+/// The interface of a compiled program, i.e. the parts of it that callers and on-chain state depend on.
+#[pyclass(module = "leo", get_all, frozen)]
+struct Interface {
+    /// The name of the program, e.g. `token.aleo`.
+    program: String,
+    /// The functions, in the order of the program.
+    functions: Vec<Py<Function>>,
+    /// The layouts of the structs, records, and mappings, in the order of the program.
+    layouts: Vec<Py<Layout>>,
+}
+
+/// Returns the interface of the compiled program `bytecode`.
+#[pyfunction]
+fn interface(py: Python, bytecode: &str) -> PyResult<Interface> {
+    let interface = program_interface(bytecode);
+    Ok(Interface {
+        program: interface.program,
+        functions: interface
+            .functions
+            .into_iter()
+            .map(|function| {
+                Py::new(py, Function { name: function.name, inputs: function.inputs, outputs: function.outputs })
+            })
+            .collect::<PyResult<_>>()?,
+        layouts: interface
+            .layouts
+            .into_iter()
+            .map(|layout| {
+                Py::new(py, Layout { kind: layout.kind.to_string(), name: layout.name, members: layout.members })
+            })
+            .collect::<PyResult<_>>()?,
+    })
+}
+
+/// Executes `function` of the compiled program `bytecode` on `inputs`, and returns its outputs.
+/// Values are written in the syntax of Aleo values, e.g. `1u32`. The compiled programs it imports are given in
+/// `imports`, each after the programs it imports in turn. The witness is generated with `private_key`, or a new one,
+/// but no proof is produced.
+#[pyfunction]
+#[pyo3(signature = (bytecode, function, inputs, imports = None, private_key = None))]
+fn execute(
+    py: Python,
+    bytecode: String,
+    function: String,
+    inputs: Vec<String>,
+    imports: Option<Vec<String>>,
+    private_key: Option<String>,
+) -> PyResult<Vec<String>> {
+    py.allow_threads(move || {
+        let options = match private_key {
+            Some(private_key) => Options::default().with_private_key(private_key),
+            None => Options::default(),
+        };
+        let inputs = inputs.iter().map(String::as_str).collect::<Vec<_>>();
+        let imports = imports.unwrap_or_default();
+        let imports = imports.iter().map(String::as_str).collect::<Vec<_>>();
+        leo_compiler_api::execute(&bytecode, &function, &inputs, &imports, &options)
+    })
+    .map_err(|error| match error {
+        Error::Execution(message) => ExecutionError::new_err(message),
+        error => ExecutionError::new_err(error.to_string()),
+    })
+}
+
+/// The Leo compiler.
+#[pymodule]
+fn leo(py: Python, module: &PyModule) -> PyResult<()> {
+    module.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    module.add("CompileError", py.get_type::<CompileError>())?;
+    module.add("ExecutionError", py.get_type::<ExecutionError>())?;
+    module.add_class::<Compiler>()?;
+    module.add_class::<Diagnostic>()?;
+    module.add_class::<Interface>()?;
+    module.add_class::<Function>()?;
+    module.add_class::<Layout>()?;
+    module.add_function(wrap_pyfunction!(interface, module)?)?;
+    module.add_function(wrap_pyfunction!(execute, module)?)?;
+    Ok(())
+}
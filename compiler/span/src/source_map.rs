@@ -55,14 +55,42 @@ impl SourceMap {
 
     /// Registers `source` under the given file `name`, returning a `SourceFile` back.
     pub fn new_source(&self, source: &str, name: FileName) -> Rc<SourceFile> {
+        self.register_source(source, name, None)
+    }
+
+    /// Registers the `source` of a file imported by the import statement at `import`.
+    pub fn new_imported_source(&self, source: &str, name: FileName, import: Span) -> Rc<SourceFile> {
+        self.register_source(source, name, Some(import))
+    }
+
+    /// Registers `source` under the given file `name`, along with the import statement it was imported by, if any.
+    fn register_source(&self, source: &str, name: FileName, imported_at: Option<Span>) -> Rc<SourceFile> {
         let len = u32::try_from(source.len()).unwrap();
         let mut inner = self.inner.borrow_mut();
         let start_pos = inner.try_allocate_address_space(len).unwrap();
-        let source_file = Rc::new(SourceFile::new(name, source.to_owned(), start_pos));
+        let source_file = Rc::new(SourceFile::new(name, source.to_owned(), start_pos, imported_at));
         inner.source_files.push(source_file.clone());
         source_file
     }
 
+    /// Returns the import statements that led to the file containing `span`, starting with the one importing it.
+    /// The list is empty if the file was not imported.
+    pub fn import_chain(&self, span: Span) -> Vec<Span> {
+        let mut chain = Vec::new();
+        let mut file = self.find_source_file(span.lo);
+        // Note that a file is always registered after the file importing it, so the chain ends.
+        while let Some(import) = file.and_then(|file| file.imported_at) {
+            chain.push(import);
+            file = self.find_source_file(import.lo);
+        }
+        chain
+    }
+
+    /// Finds the most recently registered source file with the given `name`.
+    pub fn find_source_file_by_name(&self, name: &FileName) -> Option<Rc<SourceFile>> {
+        self.inner.borrow().source_files.iter().rev().find(|file| file.name == *name).cloned()
+    }
+
     /// Find the index for the source file containing `pos`.
     fn find_source_file_index(&self, pos: BytePos) -> Option<usize> {
         self.inner
@@ -158,7 +186,7 @@ impl SourceMapInner {
 ///
 /// For now it's simply a wrapper around `PathBuf`,
 /// but may become more complicated in the future.
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq)]
 pub enum FileName {
     /// A real file.
     Real(PathBuf),
@@ -195,6 +223,8 @@ pub struct SourceFile {
     lines: Vec<BytePos>,
     /// Locations of multi-byte characters in the source code.
     multibyte_chars: Vec<MultiByteChar>,
+    /// The span of the import statement that the source was imported by, if it was imported.
+    pub imported_at: Option<Span>,
 }
 
 impl SourceFile {
@@ -202,11 +232,11 @@ impl SourceFile {
     /// source contents, and the `start_pos`ition.
     ///
     /// This position is used for analysis purposes.
-    fn new(name: FileName, mut src: String, start_pos: BytePos) -> Self {
+    fn new(name: FileName, mut src: String, start_pos: BytePos, imported_at: Option<Span>) -> Self {
         normalize_src(&mut src);
         let end_pos = start_pos + BytePos::from_usize(src.len());
         let (lines, multibyte_chars) = analyze_source_file(&src, start_pos);
-        Self { name, src, start_pos, end_pos, lines, multibyte_chars }
+        Self { name, src, start_pos, end_pos, lines, multibyte_chars, imported_at }
     }
 
     /// Converts an absolute `BytePos` to a `CharPos` relative to the `SourceFile`.
@@ -290,6 +320,7 @@ impl SpanLocation {
                 end_pos: span.hi,
                 lines: Vec::new(),
                 multibyte_chars: Vec::new(),
+                imported_at: None,
             }),
             line_start: 0,
             line_stop: 0,
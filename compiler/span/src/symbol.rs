@@ -154,6 +154,9 @@ symbols! {
     commit_to_field,
     commit_to_group,
     contains,
+    err,
+    from_bits_le,
+    from_field,
     get,
     get_or_use,
     hash_to_address,
@@ -170,10 +173,19 @@ symbols! {
     hash_to_u64,
     hash_to_u128,
     hash_to_scalar,
+    is_err,
+    is_none,
+    is_ok,
+    is_some,
     Keccak256,
     Keccak384,
     Keccak512,
     Mapping,
+    MerkleTree,
+    none,
+    ok,
+    Option,
+    payload,
     Pedersen64,
     Pedersen128,
     Poseidon2,
@@ -195,13 +207,19 @@ symbols! {
     rand_u64,
     rand_u128,
     remove,
+    Result,
     set,
     SHA3_256,
     SHA3_384,
     SHA3_512,
+    some,
+    to_bits_le,
     to_x_coordinate,
     to_y_coordinate,
+    unwrap_or,
+    value,
     verify,
+    verify_bhp256,
 
     // types
     address,
@@ -229,6 +247,7 @@ symbols! {
     True: "true",
 
     // general keywords
+    abort,
     As: "as",
     assert,
     assert_eq,
@@ -239,17 +258,22 @@ symbols! {
     constant,
     decrement,
     Else: "else",
+    error,
+    fail,
     finalize,
     For: "for",
     function,
     If: "if",
     In: "in",
     import,
+    include_constants,
     increment,
     inline,
     input,
+    log,
     Let: "let",
     leo,
+    Macro: "macro",
     main,
     mapping,
     Mut: "mut",
@@ -258,6 +282,7 @@ symbols! {
     SelfUpper: "Self",
     signer,
     Star: "*",
+    std,
     then,
     transition,
     Type: "type",
@@ -267,9 +292,19 @@ symbols! {
     private,
     owner,
     _nonce,
+    nonce,
     program,
     block,
     height,
+    network,
+    id,
+    version,
+
+    // annotations
+    deprecated,
+    max_constraints,
+    should_fail,
+    test,
 }
 
 /// An interned string.
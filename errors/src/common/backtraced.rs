@@ -14,17 +14,81 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::message_code;
+
 use std::fmt;
 
 use backtrace::Backtrace;
 use color_backtrace::{BacktracePrinter, Verbosity};
 use colored::Colorize;
 use derivative::Derivative;
+use is_terminal::IsTerminal;
 use leo_span::source_map::is_not_test_framework;
 
 /// The indent for an error message.
 pub(crate) const INDENT: &str = "    ";
 
+/// The column at which help text is wrapped outside of the test framework.
+pub(crate) const WRAP_WIDTH: usize = 100;
+
+/// Returns whether messages should be rendered with colors.
+/// Colors are never used under the test framework. Otherwise `NO_COLOR` and `CLICOLOR=0` disable them,
+/// `CLICOLOR_FORCE` forces them, and by default they are only used when stderr is a terminal.
+pub(crate) fn use_colors() -> bool {
+    let var = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+
+    if !is_not_test_framework() || var("NO_COLOR").is_some() || var("CLICOLOR").as_deref() == Some("0") {
+        false
+    } else if var("CLICOLOR_FORCE").map_or(false, |value| value != "0") {
+        true
+    } else {
+        std::io::stderr().is_terminal()
+    }
+}
+
+/// Splits `text` into lines of at most `width` characters, breaking only between words.
+/// Words longer than `width` are kept on a line of their own.
+pub(crate) fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split(' ') {
+            if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+                lines.push(std::mem::take(&mut line));
+            } else if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Writes the help text of a message, wrapping it at [`WRAP_WIDTH`] if `wrap` is set.
+pub(crate) fn write_help(f: &mut impl fmt::Write, help: &str, colors: bool, wrap_text: bool) -> fmt::Result {
+    let gutter = |text: &str| if colors { text.bold().blue().to_string() } else { text.to_owned() };
+
+    write!(f, "\n{INDENT     }{bar}\n{INDENT     }{equals} ", bar = gutter(" |"), equals = gutter(" ="))?;
+
+    if !wrap_text {
+        return write!(f, "{help}");
+    }
+
+    // Continuation lines are aligned with the text following `= `.
+    let width = WRAP_WIDTH - INDENT.len() - 3;
+    for (i, line) in wrap(help, width).iter().enumerate() {
+        match i {
+            0 => write!(f, "{line}")?,
+            _ => write!(f, "\n{INDENT     }   {line}")?,
+        }
+    }
+
+    Ok(())
+}
+
 /// Backtraced compiler ouput type
 ///     undefined value `x`
 ///     --> file.leo: 2:8
@@ -84,22 +148,12 @@ impl Backtraced {
 
     /// Gets a unique error identifier.
     pub fn error_code(&self) -> String {
-        format!(
-            "E{error_type}{code_identifier:0>3}{exit_code:0>4}",
-            error_type = self.type_,
-            code_identifier = self.code_identifier,
-            exit_code = self.code,
-        )
+        message_code(true, &self.type_, self.code_identifier, self.code)
     }
 
     /// Gets a unique warning identifier.
     pub fn warning_code(&self) -> String {
-        format!(
-            "W{error_type}{code_identifier:0>3}{exit_code:0>4}",
-            error_type = self.type_,
-            code_identifier = self.code_identifier,
-            exit_code = self.code,
-        )
+        message_code(false, &self.type_, self.code_identifier, self.code)
     }
 }
 
@@ -109,7 +163,8 @@ impl fmt::Display for Backtraced {
         let message = format!("{kind} [{code}]: {message}", message = self.message,);
 
         // To avoid the color enabling characters for comparison with test expectations.
-        if is_not_test_framework() {
+        let colors = use_colors();
+        if colors {
             if self.error {
                 write!(f, "{}", message.bold().red())?;
             } else {
@@ -120,11 +175,7 @@ impl fmt::Display for Backtraced {
         };
 
         if let Some(help) = &self.help {
-            write!(
-                f,
-                "\n{INDENT     } |\n\
-            {INDENT     } = {help}",
-            )?;
+            write_help(f, help, colors, is_not_test_framework())?;
         }
 
         let leo_backtrace = std::env::var("LEO_BACKTRACE").unwrap_or_default().trim().to_owned();
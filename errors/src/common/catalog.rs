@@ -0,0 +1,214 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Display},
+    sync::RwLock,
+};
+
+/// The catalogs registered through [`register_catalog`].
+static CATALOGS: RwLock<Vec<MessageCatalog>> = RwLock::new(Vec::new());
+
+/// The locale selected through [`set_locale`].
+static LOCALE: RwLock<Option<String>> = RwLock::new(None);
+
+/// A translated message template and, optionally, its help text.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct CatalogEntry {
+    /// The message template.
+    pub message: String,
+    /// The help template, if the help text is translated as well.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+}
+
+/// A set of message templates for one locale, keyed by message code such as `EPAR0370005`.
+///
+/// Templates refer to the arguments of a message by the names they have in its definition,
+/// e.g. `{token}` for `invalid_address_lit { args: (token: impl Display), .. }`.
+/// Messages without an entry keep their built-in English text.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct MessageCatalog {
+    /// The locale of the catalog, e.g. `pt-BR`.
+    pub locale: String,
+    /// The templates, keyed by message code.
+    #[serde(default)]
+    pub entries: BTreeMap<String, CatalogEntry>,
+}
+
+impl MessageCatalog {
+    /// Creates an empty catalog for the given locale.
+    pub fn new(locale: impl ToString) -> Self {
+        Self { locale: locale.to_string(), entries: BTreeMap::new() }
+    }
+
+    /// Adds a template for the message with the given code.
+    pub fn with_entry(mut self, code: impl ToString, message: impl ToString, help: Option<String>) -> Self {
+        self.entries.insert(code.to_string(), CatalogEntry { message: message.to_string(), help });
+        self
+    }
+}
+
+/// Registers a catalog, replacing any catalog previously registered for the same locale.
+pub fn register_catalog(catalog: MessageCatalog) {
+    let mut catalogs = CATALOGS.write().unwrap_or_else(|e| e.into_inner());
+    catalogs.retain(|existing| existing.locale != catalog.locale);
+    catalogs.push(catalog);
+}
+
+/// Selects the locale messages are created in, or the built-in English messages for `None`.
+pub fn set_locale(locale: Option<&str>) {
+    *LOCALE.write().unwrap_or_else(|e| e.into_inner()) = locale.map(str::to_owned);
+}
+
+/// Returns the selected locale, if any.
+pub fn locale() -> Option<String> {
+    LOCALE.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Formats the code identifying a message, e.g. `EPAR0370005` or `WTYC0372000`.
+pub fn message_code(error: bool, type_: &str, code_identifier: i8, code: i32) -> String {
+    format!("{kind}{type_}{code_identifier:0>3}{code:0>4}", kind = if error { 'E' } else { 'W' })
+}
+
+/// Looks up the message with the given code in the catalog of the selected locale.
+/// A catalog for the locale's language, e.g. `pt` for `pt-BR`, is used if there is no exact match.
+/// Returns the filled in message and help templates, or `None` if the message is not translated.
+#[doc(hidden)]
+pub fn localize(code: &str, args: impl FnOnce() -> Vec<(&'static str, String)>) -> Option<(String, Option<String>)> {
+    let locale = locale()?;
+    let catalogs = CATALOGS.read().unwrap_or_else(|e| e.into_inner());
+    let language = locale.split(['-', '_']).next().unwrap_or_default();
+    let entry = catalogs
+        .iter()
+        .find(|catalog| catalog.locale == locale)
+        .or_else(|| catalogs.iter().find(|catalog| catalog.locale == language))?
+        .entries
+        .get(code)?;
+
+    let args = args();
+    Some((fill(&entry.message, &args), entry.help.as_deref().map(|help| fill(help, &args))))
+}
+
+/// Replaces each `{name}` in `template` with the value of the argument called `name`.
+/// Placeholders that do not name an argument are left as they are.
+fn fill(template: &str, args: &[(&str, String)]) -> String {
+    let mut filled = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(open) = rest.find('{') {
+        filled.push_str(&rest[..open]);
+        rest = &rest[open..];
+        let value = rest.find('}').and_then(|close| {
+            let name = &rest[1..close];
+            args.iter().find(|(arg, _)| *arg == name).map(|(_, value)| (close, value))
+        });
+        match value {
+            Some((close, value)) => {
+                filled.push_str(value);
+                rest = &rest[close + 1..];
+            }
+            None => {
+                filled.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    filled.push_str(rest);
+
+    filled
+}
+
+/// Renders a message argument for a catalog template through its `Display` implementation.
+/// Paired with [`CatalogDebugArg`], this picks `Display` over `Debug` when an argument has both.
+/// Lists of arguments are rendered through [`CatalogListArg`] instead.
+#[doc(hidden)]
+pub trait CatalogDisplayArg {
+    /// Renders the argument.
+    fn catalog_arg(&self) -> String;
+}
+
+impl<T: Display + ?Sized> CatalogDisplayArg for T {
+    fn catalog_arg(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Renders a message argument for a catalog template through its `Debug` implementation.
+#[doc(hidden)]
+pub trait CatalogDebugArg {
+    /// Renders the argument.
+    fn catalog_arg(&self) -> String;
+}
+
+impl<T: Debug + ?Sized> CatalogDebugArg for &T {
+    fn catalog_arg(&self) -> String {
+        format!("{:?}", *self)
+    }
+}
+
+/// Renders a list of message arguments for a catalog template, separated by commas.
+#[doc(hidden)]
+pub trait CatalogListArg {
+    /// Renders the argument.
+    fn catalog_arg(&self) -> String;
+}
+
+impl<T: Display> CatalogListArg for [T] {
+    fn catalog_arg(&self) -> String {
+        self.iter().map(|item| item.to_string()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl<T: Display> CatalogListArg for Vec<T> {
+    fn catalog_arg(&self) -> String {
+        self.as_slice().catalog_arg()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LeoMessageCode, ParserError};
+    use leo_span::{symbol::create_session_if_not_set_then, Span};
+
+    #[test]
+    fn catalogs_translate_messages_of_the_selected_locale() {
+        // Only messages no other test creates are translated, as the locale is global.
+        let code = ParserError::invalid_address_lit("", Span::default()).error_code();
+        register_catalog(
+            MessageCatalog::new("pt")
+                .with_entry(&code, "endereço inválido: '{token}'", Some("verifique o endereço".into()))
+                .with_entry("EPAR0370000", "{message} e {missing}", None),
+        );
+
+        let (translated, filled, english) = create_session_if_not_set_then(|_| {
+            set_locale(Some("pt-BR"));
+            let translated = ParserError::invalid_address_lit("aleo1", Span::default()).to_string();
+            let filled = ParserError::unexpected_token("algo", Span::default()).to_string();
+            set_locale(None);
+            let english = ParserError::invalid_address_lit("aleo1", Span::default()).to_string();
+            (translated, filled, english)
+        });
+
+        assert!(translated.contains(&format!("Error [{code}]: endereço inválido: 'aleo1'")));
+        assert!(translated.contains("= verifique o endereço"));
+        assert!(filled.contains("algo e {missing}"));
+        assert!(english.contains("invalid address literal: 'aleo1'"));
+    }
+}
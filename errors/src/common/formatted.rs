@@ -14,14 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Backtraced, INDENT};
+use crate::{use_colors, write_help, Backtraced, INDENT};
 
-use leo_span::{source_map::SpanLocation, symbol::with_session_globals, Span};
+use leo_span::{
+    source_map::{is_not_test_framework, SpanLocation},
+    symbol::with_session_globals,
+    Span,
+};
 
 use backtrace::Backtrace;
 use color_backtrace::{BacktracePrinter, Verbosity};
 use colored::Colorize;
-use std::fmt;
+use std::{fmt, rc::Rc};
 
 /// Formatted compiler error type
 ///     undefined value `x`
@@ -30,12 +34,17 @@ use std::fmt;
 ///    2 | let a = x;
 ///      |         ^
 ///      |
+///    1 | let b = 1u8;
+///      |     - secondary label
+///      |
 ///      = help: Initialize a variable `x` first.
 /// Makes use of the same fields as a BacktracedError.
 #[derive(Clone, Debug, Default, Hash, PartialEq)]
 pub struct Formatted {
     /// The formatted error span information.
     pub span: Span,
+    /// Secondary spans pointing at related code, each with a label explaining it.
+    pub labels: Vec<(Span, String)>,
     /// The backtrace to track where the Leo error originated.
     pub backtrace: Backtraced,
 }
@@ -58,6 +67,7 @@ impl Formatted {
     {
         Self {
             span,
+            labels: Vec::new(),
             backtrace: Backtraced::new_from_backtrace(
                 message.to_string(),
                 help,
@@ -70,6 +80,12 @@ impl Formatted {
         }
     }
 
+    /// Adds a secondary span with a label, rendered below the primary span.
+    pub fn with_label(mut self, span: Span, label: impl ToString) -> Self {
+        self.labels.push((span, label.to_string()));
+        self
+    }
+
     /// Calls the backtraces error exit code.
     pub fn exit_code(&self) -> i32 {
         self.backtrace.exit_code()
@@ -84,84 +100,130 @@ impl Formatted {
     pub fn warning_code(&self) -> String {
         self.backtrace.warning_code()
     }
-}
 
-impl fmt::Display for Formatted {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let underline = |mut start: usize, mut end: usize| -> String {
-            if start > end {
-                std::mem::swap(&mut start, &mut end)
-            }
-
-            let mut underline = String::new();
-
-            for _ in 0..start {
-                underline.push(' ');
-                end -= 1;
+    /// Renders the message with a code frame for its primary span and each of its labels.
+    /// Uses colors if `colors` is set, and wraps the help text if `wrap` is set.
+    fn render(&self, f: &mut impl fmt::Write, colors: bool, wrap: bool) -> fmt::Result {
+        let paint = |text: &str, color: colored::Color| {
+            if colors {
+                text.bold().color(color).to_string()
+            } else {
+                text.to_owned()
             }
+        };
+        let gutter = |text: &str| paint(text, colored::Color::Blue);
+        let (kind, code, color) = if self.backtrace.error {
+            ("Error", self.error_code(), colored::Color::Red)
+        } else {
+            ("Warning", self.warning_code(), colored::Color::Yellow)
+        };
 
-            for _ in 0..end {
-                underline.push('^');
+        let underline = |mut start: usize, mut end: usize, marker: char| -> String {
+            if start > end {
+                std::mem::swap(&mut start, &mut end)
             }
-
-            underline
+            format!("{}{}", " ".repeat(start), marker.to_string().repeat(end - start))
+        };
+        let locate = |span: Span| {
+            with_session_globals(|s| {
+                (
+                    s.source_map.span_to_location(span).unwrap_or_else(SpanLocation::dummy),
+                    s.source_map.line_contents_of_span(span).unwrap_or_else(|| "<contents unavailable>".to_owned()),
+                )
+            })
         };
 
-        let (loc, contents) = with_session_globals(|s| {
-            (
-                s.source_map.span_to_location(self.span).unwrap_or_else(SpanLocation::dummy),
-                s.source_map.line_contents_of_span(self.span).unwrap_or_else(|| "<contents unavailable>".to_owned()),
-            )
-        });
-
-        let underlined = underline(loc.col_start, loc.col_stop);
-
-        let (kind, code) =
-            if self.backtrace.error { ("Error", self.error_code()) } else { ("Warning", self.warning_code()) };
+        let (loc, contents) = locate(self.span);
 
-        let message = format!("{kind} [{code}]: {message}", message = self.backtrace.message,);
-
-        // To avoid the color enabling characters for comparison with test expectations.
-        if std::env::var("LEO_TESTFRAMEWORK").unwrap_or_default().trim().to_owned().is_empty() {
-            if self.backtrace.error {
-                write!(f, "{}", message.bold().red())?;
-            } else {
-                write!(f, "{}", message.bold().yellow())?;
-            }
-        } else {
-            write!(f, "{message}")?;
-        };
+        let message = format!("{kind} [{code}]: {message}", message = self.backtrace.message);
+        write!(f, "{}", paint(&message, color))?;
 
         write!(
             f,
-            "\n{indent     }--> {path}:{line_start}:{start}\n\
-            {indent     } |\n",
+            "\n{indent     }{arrow} {path}:{line_start}:{start}\n\
+            {indent     }{bar}\n",
             indent = INDENT,
+            arrow = gutter("-->"),
+            bar = gutter(" |"),
             path = &loc.source_file.name,
             line_start = loc.line_start,
             start = loc.col_start,
         )?;
 
         for (line_no, line) in contents.lines().enumerate() {
-            writeln!(
+            let line_no = format!("{:width$} |", loc.line_start + line_no, width = INDENT.len());
+            writeln!(f, "{} {line}", gutter(&line_no))?;
+        }
+
+        write!(f, "{INDENT     }{}{}", gutter(" |"), paint(&underline(loc.col_start, loc.col_stop, '^'), color))?;
+
+        // Labels only show the first line of their span, to keep the frame short.
+        for (span, label) in self.labels.iter().filter(|(span, _)| !span.is_dummy()) {
+            let (label_loc, label_contents) = locate(*span);
+            let line = label_contents.lines().next().unwrap_or_default();
+            let col_stop = match label_loc.line_stop > label_loc.line_start {
+                true => line.chars().count() + 1,
+                false => label_loc.col_stop,
+            };
+
+            write!(f, "\n{INDENT     }{}", gutter(" |"))?;
+            if !Rc::ptr_eq(&label_loc.source_file, &loc.source_file) {
+                write!(
+                    f,
+                    "\n{INDENT     }{} {}:{}:{}\n{INDENT     }{}",
+                    gutter(":::"),
+                    label_loc.source_file.name,
+                    label_loc.line_start,
+                    label_loc.col_start,
+                    gutter(" |"),
+                )?;
+            }
+            let line_no = format!("{:width$} |", label_loc.line_start, width = INDENT.len());
+            write!(
                 f,
-                "{line_no:width$} | {text}",
-                width = INDENT.len(),
-                line_no = loc.line_start + line_no,
-                text = line,
+                "\n{} {line}\n{INDENT     }{}{}",
+                gutter(&line_no),
+                gutter(" |"),
+                paint(
+                    &format!("{} {label}", underline(label_loc.col_start, col_stop.max(label_loc.col_start + 1), '-')),
+                    colored::Color::Blue
+                ),
             )?;
         }
 
-        write!(f, "{INDENT     } |{underlined}",)?;
-
         if let Some(help) = &self.backtrace.help {
+            write_help(f, help, colors, wrap)?;
+        }
+
+        // Diagnostics in imported files are followed by the chain of imports that led to them.
+        let imports = match self.span.is_dummy() {
+            true => Vec::new(),
+            false => with_session_globals(|s| s.source_map.import_chain(self.span)),
+        };
+        if !imports.is_empty() && self.backtrace.help.is_none() {
+            write!(f, "\n{INDENT     }{}", gutter(" |"))?;
+        }
+        for import in imports {
+            let (import_loc, _) = locate(import);
             write!(
                 f,
-                "\n{INDENT     } |\n\
-            {INDENT     } = {help}",
+                "\n{INDENT     }{} note: imported from {}:{}:{}",
+                gutter(" ="),
+                import_loc.source_file.name,
+                import_loc.line_start,
+                import_loc.col_start
             )?;
         }
 
+        Ok(())
+    }
+}
+
+impl fmt::Display for Formatted {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Colors and wrapping are left out for comparison with test expectations.
+        self.render(f, use_colors(), is_not_test_framework())?;
+
         let leo_backtrace = std::env::var("LEO_BACKTRACE").unwrap_or_default().trim().to_owned();
         match leo_backtrace.as_ref() {
             "1" => {
@@ -190,3 +252,73 @@ impl std::error::Error for Formatted {
         &self.backtrace.message
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{wrap, AstError};
+    use leo_span::{
+        source_map::{FileName, SourceFile},
+        span::BytePos,
+        symbol::{create_session_if_not_set_then, with_session_globals},
+        Span,
+    };
+
+    #[test]
+    fn labels_are_rendered_below_the_primary_span() {
+        create_session_if_not_set_then(|_| {
+            let source = "let a: u8 = 1u8;\nlet a: u8 = 2u8;\n";
+            let file = with_session_globals(|s| s.source_map.new_source(source, FileName::Custom("test".into())));
+            let at = |lo: u32, hi: u32| Span::new(file.start_pos + BytePos(lo), file.start_pos + BytePos(hi));
+
+            let AstError::Formatted(error) =
+                AstError::shadowed_variable("a", at(21, 22)).with_label(at(4, 5), "previous definition of `a` here")
+            else {
+                panic!("expected a formatted error");
+            };
+
+            let mut rendered = String::new();
+            error.render(&mut rendered, false, true).unwrap();
+            assert_eq!(
+                rendered,
+                "Error [EAST0372009]: variable `a` shadowed by\n    --> test:2:5\n     |\n   2 | let a: u8 = 2u8;\n     |     ^\n     |\n   1 | let a: u8 = 1u8;\n     |     - previous definition of `a` here"
+            );
+        });
+    }
+
+    #[test]
+    fn imported_files_are_followed_by_their_imports() {
+        create_session_if_not_set_then(|_| {
+            let main = "import b.leo;\nprogram a.aleo {}\n";
+            let b = "import c.leo;\nprogram b.aleo {}\n";
+            let c = "program c.aleo {\n    const x: u8 = 1u8;\n}\n";
+            let at = |file: &SourceFile, lo: u32, hi: u32| {
+                Span::new(file.start_pos + BytePos(lo), file.start_pos + BytePos(hi))
+            };
+            let main = with_session_globals(|s| s.source_map.new_source(main, FileName::Custom("a.leo".into())));
+            let b = with_session_globals(|s| {
+                s.source_map.new_imported_source(b, FileName::Custom("b.leo".into()), at(&main, 0, 13))
+            });
+            let c = with_session_globals(|s| {
+                s.source_map.new_imported_source(c, FileName::Custom("c.leo".into()), at(&b, 0, 13))
+            });
+
+            let AstError::Formatted(error) = AstError::shadowed_variable("x", at(&c, 27, 28)) else {
+                panic!("expected a formatted error");
+            };
+
+            let mut rendered = String::new();
+            error.render(&mut rendered, false, true).unwrap();
+            assert_eq!(
+                rendered,
+                "Error [EAST0372009]: variable `x` shadowed by\n    --> c.leo:2:11\n     |\n   2 |     const x: u8 = 1u8;\n     |           ^\n     |\n     = note: imported from b.leo:1:1\n     = note: imported from a.leo:1:1"
+            );
+        });
+    }
+
+    #[test]
+    fn wrap_breaks_between_words() {
+        assert_eq!(wrap("one two three", 7), ["one two", "three"]);
+        assert_eq!(wrap("a verylongword b", 4), ["a", "verylongword", "b"]);
+        assert_eq!(wrap("first\nsecond", 80), ["first", "second"]);
+    }
+}
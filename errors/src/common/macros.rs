@@ -90,6 +90,39 @@ macro_rules! create_messages {
         impl $type_ {
             create_messages!(@step 0i32, $(($(#[$docs])* $formatted_or_backtraced_list, $names($($arg_names: $arg_types,)*), $messages, $helps),)*);
         }
+
+        impl $type_ {
+            /// Looks up the translation of the message with the given code in the selected locale's catalog.
+            fn localize(code: i32, args: impl FnOnce() -> Vec<(&'static str, String)>) -> Option<(String, Option<String>)> {
+                let code = $crate::message_code(Self::is_error(), &Self::message_type(), Self::code_identifier(), code + Self::code_mask());
+                $crate::localize(&code, args)
+            }
+
+            /// Returns the span the message points at, if it has one.
+            pub fn span(&self) -> Option<leo_span::Span> {
+                match self {
+                    Self::Formatted(formatted) => Some(formatted.span),
+                    Self::Backtraced(_) => None,
+                }
+            }
+
+            /// Returns the text of the message, without its code, code frame, or help.
+            pub fn message(&self) -> &str {
+                match self {
+                    Self::Formatted(formatted) => &formatted.backtrace.message,
+                    Self::Backtraced(backtraced) => &backtraced.message,
+                }
+            }
+
+            /// Adds a secondary span with a label to the message.
+            /// Backtraced messages have no code frame, so the label is dropped.
+            pub fn with_label(self, span: leo_span::Span, label: impl ToString) -> Self {
+                match self {
+                    Self::Formatted(formatted) => Self::Formatted(formatted.with_label(span, label)),
+                    Self::Backtraced(backtraced) => Self::Backtraced(backtraced),
+                }
+            }
+        }
     };
     // Matches the function if it is a formatted message.
     (@step $code:expr, ($(#[$error_func_docs:meta])* formatted, $name:ident($($arg_names:ident: $arg_types:ty,)*), $message:expr, $help:expr), $(($(#[$docs:meta])* $formatted_or_backtraced_tail:ident, $names:ident($($tail_arg_names:ident: $tail_arg_types:ty,)*), $messages:expr, $helps:expr),)*) => {
@@ -97,10 +130,17 @@ macro_rules! create_messages {
         $(#[$error_func_docs])*
         // Expands additional arguments for the error defining function.
         pub fn $name($($arg_names: $arg_types,)* span: leo_span::Span) -> Self {
+            let (message, help) = Self::localize($code, || {
+                #[allow(unused_imports)]
+                use $crate::{CatalogDebugArg, CatalogDisplayArg, CatalogListArg};
+                vec![$((stringify!($arg_names), (&$arg_names).catalog_arg()),)*]
+            })
+            .map_or_else(|| ($message.to_string(), $help), |(message, help)| (message, help.or($help)));
+
             Self::Formatted(
                 Formatted::new_from_span(
-                    $message,
-                    $help,
+                    message,
+                    help,
                     $code + Self::code_mask(),
                     Self::code_identifier(),
                     Self::message_type(),
@@ -120,10 +160,17 @@ macro_rules! create_messages {
         $(#[$error_func_docs])*
         // Expands additional arguments for the error defining function.
         pub fn $name($($arg_names: $arg_types,)*) -> Self {
+            let (message, help) = Self::localize($code, || {
+                #[allow(unused_imports)]
+                use $crate::{CatalogDebugArg, CatalogDisplayArg, CatalogListArg};
+                vec![$((stringify!($arg_names), (&$arg_names).catalog_arg()),)*]
+            })
+            .map_or_else(|| ($message.to_string(), $help), |(message, help)| (message, help.or($help)));
+
             Self::Backtraced(
                 Backtraced::new_from_backtrace(
-                    $message,
-                    $help,
+                    message,
+                    help,
                     $code + Self::code_mask(),
                     Self::code_identifier(),
                     Self::message_type(),
@@ -18,6 +18,10 @@
 pub mod backtraced;
 pub use self::backtraced::*;
 
+/// This module contains the message catalogs used to translate messages.
+pub mod catalog;
+pub use self::catalog::*;
+
 /// This module contains a formatted error and its methods.
 pub mod formatted;
 pub use self::formatted::*;
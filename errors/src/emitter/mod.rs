@@ -18,10 +18,18 @@ use crate::LeoWarning;
 
 use super::LeoError;
 use core::{default::Default, fmt};
-use std::{cell::RefCell, rc::Rc};
+use leo_span::{source_map::SpanLocation, symbol::with_session_globals, Span};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashSet,
+    io::{self, Write},
+    mem,
+    sync::{Arc, Mutex, MutexGuard},
+};
 
 /// Types that are sinks for compiler errors.
-pub trait Emitter {
+/// Emitters are `Send`, so that a `Handler` can be shared by passes running on several threads.
+pub trait Emitter: Send {
     /// Emit the error `err`.
     fn emit_err(&mut self, err: LeoError);
 
@@ -30,6 +38,9 @@ pub trait Emitter {
 
     /// Emit the warning.
     fn emit_warning(&mut self, warning: LeoWarning);
+
+    /// Writes out any diagnostics held back by the emitter.
+    fn flush(&mut self) {}
 }
 
 /// A trivial `Emitter` using the standard error.
@@ -100,7 +111,7 @@ pub type WarningBuffer = Buffer<LeoWarning>;
 
 /// An `Emitter` that collects into a list.
 #[derive(Default, Clone)]
-pub struct BufferEmitter(Rc<RefCell<ErrBuffer>>, Rc<RefCell<WarningBuffer>>);
+pub struct BufferEmitter(Arc<Mutex<ErrBuffer>>, Arc<Mutex<WarningBuffer>>);
 
 impl BufferEmitter {
     /// Returns a new buffered emitter.
@@ -110,27 +121,227 @@ impl BufferEmitter {
 
     /// Extracts all the errors collected in this emitter.
     pub fn extract_errs(&self) -> ErrBuffer {
-        self.0.take()
+        mem::take(&mut *lock(&self.0))
     }
 
     /// Extracts all the errors collected in this emitter.
     pub fn extract_warnings(&self) -> WarningBuffer {
-        self.1.take()
+        mem::take(&mut *lock(&self.1))
     }
 }
 
 impl Emitter for BufferEmitter {
     fn emit_err(&mut self, err: LeoError) {
-        self.0.borrow_mut().push(err);
+        lock(&self.0).push(err);
     }
 
     fn last_emitted_err_code(&self) -> Option<i32> {
-        let temp = &*self.0.borrow();
-        temp.last_entry().map(|entry| entry.exit_code())
+        lock(&self.0).last_entry().map(|entry| entry.exit_code())
     }
 
     fn emit_warning(&mut self, warning: LeoWarning) {
-        self.1.borrow_mut().push(warning);
+        lock(&self.1).push(warning);
+    }
+}
+
+/// A diagnostic held back by a `DiagnosticSink`.
+enum Diagnostic {
+    /// An emitted error.
+    Error(LeoError),
+    /// An emitted warning.
+    Warning(LeoWarning),
+}
+
+impl Diagnostic {
+    /// Returns the span the diagnostic points at, if it has one.
+    fn span(&self) -> Option<Span> {
+        match self {
+            Diagnostic::Error(err) => err.span(),
+            Diagnostic::Warning(warning) => warning.span(),
+        }
+    }
+}
+
+/// The severity of a recorded diagnostic.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// The diagnostic is an error.
+    Error,
+    /// The diagnostic is a warning.
+    Warning,
+}
+
+/// A summary of a diagnostic, as recorded for build reports.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct DiagnosticRecord {
+    /// Whether the diagnostic is an error or a warning.
+    pub severity: Severity,
+    /// The code of the diagnostic, e.g. `EPAR0370005`, if it has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// The text of the diagnostic, without its code frame or help.
+    pub message: String,
+    /// Where the diagnostic points, as `file:line:column`, if it points anywhere.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+impl DiagnosticRecord {
+    /// Summarizes an error. Returns `None` for `LastErrorCode`, which only refers to an error that was already emitted.
+    pub fn from_error(err: &LeoError) -> Option<Self> {
+        let code = match err {
+            LeoError::LastErrorCode(_) => return None,
+            LeoError::Anyhow(_) => None,
+            _ => Some(err.error_code()),
+        };
+        Some(Self { severity: Severity::Error, code, message: err.message(), location: Self::location(err.span()) })
+    }
+
+    /// Summarizes a warning.
+    pub fn from_warning(warning: &LeoWarning) -> Self {
+        Self {
+            severity: Severity::Warning,
+            code: Some(warning.error_code()),
+            message: warning.message().to_string(),
+            location: Self::location(warning.span()),
+        }
+    }
+
+    /// Formats the start of `span` as `file:line:column`.
+    fn location(span: Option<Span>) -> Option<String> {
+        let span = span.filter(|span| !span.is_dummy())?;
+        let loc = with_session_globals(|s| s.source_map.span_to_location(span).unwrap_or_else(SpanLocation::dummy));
+        Some(format!("{}:{}:{}", loc.source_file.name, loc.line_start, loc.col_start))
+    }
+}
+
+/// A list of diagnostic records shared between a `DiagnosticSink` and its creator.
+#[derive(Clone, Debug, Default)]
+pub struct DiagnosticRecords(Arc<Mutex<Vec<DiagnosticRecord>>>);
+
+impl DiagnosticRecords {
+    /// Adds a record to the list.
+    pub fn push(&self, record: DiagnosticRecord) {
+        lock(&self.0).push(record);
+    }
+
+    /// Extracts the records collected thus far.
+    pub fn take(&self) -> Vec<DiagnosticRecord> {
+        mem::take(&mut *lock(&self.0))
+    }
+}
+
+/// An `Emitter` for badly broken inputs, which holds diagnostics back until it is flushed.
+/// Identical diagnostics, i.e. those with the same code and span, are reported once.
+/// When flushed, diagnostics are sorted by their position in the source, and at most `limit` errors are written
+/// over all flushes, followed by a summary of how many were omitted. Diagnostics without a span are written last.
+/// Duplicates are still counted by the `Handler`, whose error count only decides whether compilation failed.
+pub struct DiagnosticSink {
+    /// The maximum number of errors to write, if any.
+    limit: Option<usize>,
+    /// The number of errors written thus far.
+    written: usize,
+    /// The diagnostics emitted since the last flush.
+    diagnostics: Vec<Diagnostic>,
+    /// The codes and spans of the diagnostics emitted thus far.
+    seen: HashSet<(String, Span)>,
+    /// Exit code of the last emitted error.
+    last_error_code: Option<i32>,
+    /// Where diagnostics are written.
+    output: Box<dyn Write + Send>,
+    /// Where summaries of the diagnostics are recorded, if anywhere.
+    records: Option<DiagnosticRecords>,
+}
+
+impl DiagnosticSink {
+    /// Returns a sink that writes to the standard error.
+    pub fn new(limit: Option<usize>) -> Self {
+        Self::with_output(limit, Box::new(io::stderr()))
+    }
+
+    /// Returns a sink that writes to `output`.
+    pub fn with_output(limit: Option<usize>, output: Box<dyn Write + Send>) -> Self {
+        Self {
+            limit,
+            written: 0,
+            diagnostics: Vec::new(),
+            seen: HashSet::new(),
+            last_error_code: None,
+            output,
+            records: None,
+        }
+    }
+
+    /// Records a summary of every diagnostic in `records`, including the errors beyond the limit.
+    pub fn with_records(mut self, records: DiagnosticRecords) -> Self {
+        self.records = Some(records);
+        self
+    }
+
+    /// Returns whether a diagnostic with the same code and span was already emitted.
+    /// Diagnostics without a real span are never considered duplicates.
+    fn is_duplicate(&mut self, span: Option<Span>, code: impl FnOnce() -> String) -> bool {
+        match span {
+            Some(span) if span != Span::default() => !self.seen.insert((code(), span)),
+            _ => false,
+        }
+    }
+}
+
+impl Emitter for DiagnosticSink {
+    fn emit_err(&mut self, err: LeoError) {
+        self.last_error_code = Some(err.exit_code());
+        // `LastErrorCode` only carries the exit code of an error that was already emitted.
+        if matches!(err, LeoError::LastErrorCode(_)) || self.is_duplicate(err.span(), || err.error_code()) {
+            return;
+        }
+        if let (Some(records), Some(record)) = (&self.records, DiagnosticRecord::from_error(&err)) {
+            records.push(record);
+        }
+        self.diagnostics.push(Diagnostic::Error(err));
+    }
+
+    fn last_emitted_err_code(&self) -> Option<i32> {
+        self.last_error_code
+    }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        if !self.is_duplicate(warning.span(), || warning.error_code()) {
+            if let Some(records) = &self.records {
+                records.push(DiagnosticRecord::from_warning(&warning));
+            }
+            self.diagnostics.push(Diagnostic::Warning(warning));
+        }
+    }
+
+    fn flush(&mut self) {
+        let mut diagnostics = std::mem::take(&mut self.diagnostics);
+        diagnostics.sort_by_key(|diagnostic| diagnostic.span().map_or(u32::MAX, |span| span.lo.0));
+
+        let mut omitted = 0;
+        for diagnostic in diagnostics {
+            match diagnostic {
+                Diagnostic::Error(_) if matches!(self.limit, Some(limit) if self.written >= limit) => omitted += 1,
+                Diagnostic::Error(err) => {
+                    self.written += 1;
+                    let _ = writeln!(self.output, "{err}");
+                }
+                Diagnostic::Warning(warning) => {
+                    let _ = writeln!(self.output, "{warning}");
+                }
+            }
+        }
+        if omitted > 0 {
+            let _ = writeln!(self.output, "{omitted} more error{} omitted.", if omitted == 1 { "" } else { "s" });
+        }
+        let _ = self.output.flush();
+    }
+}
+
+impl Drop for DiagnosticSink {
+    fn drop(&mut self) {
+        self.flush();
     }
 }
 
@@ -162,13 +373,19 @@ impl HandlerInner {
         self.warn_count = self.warn_count.saturating_add(1);
         self.emitter.emit_warning(warning);
     }
+
+    /// Writes out any diagnostics held back by the emitter.
+    fn flush(&mut self) {
+        self.emitter.flush();
+    }
 }
 
 /// A handler deals with errors and other compiler output.
+/// It is `Send` and `Sync`, so that passes running on several threads can report to the same handler.
 pub struct Handler {
     /// The inner handler.
-    /// `RefCell` is used here to avoid `&mut` all over the compiler.
-    inner: RefCell<HandlerInner>,
+    /// `Mutex` is used here to avoid `&mut` all over the compiler.
+    inner: Mutex<HandlerInner>,
 }
 
 impl Default for Handler {
@@ -180,7 +397,7 @@ impl Default for Handler {
 impl Handler {
     /// Construct a `Handler` using the given `emitter`.
     pub fn new(emitter: Box<dyn Emitter>) -> Self {
-        let inner = RefCell::new(HandlerInner { err_count: 0, warn_count: 0, emitter });
+        let inner = Mutex::new(HandlerInner { err_count: 0, warn_count: 0, emitter });
         Self { inner }
     }
 
@@ -198,14 +415,24 @@ impl Handler {
         handler.extend_if_error(logic(&handler)).map_err(|_| buf.extract_errs())
     }
 
+    /// Locks the inner handler.
+    fn inner(&self) -> MutexGuard<'_, HandlerInner> {
+        lock(&self.inner)
+    }
+
     /// Emit the error `err`.
     pub fn emit_err<E: Into<LeoError>>(&self, err: E) {
-        self.inner.borrow_mut().emit_err(err.into());
+        self.inner().emit_err(err.into());
     }
 
     /// Emit the error `err`.
     pub fn emit_warning(&self, warning: LeoWarning) {
-        self.inner.borrow_mut().emit_warning(warning);
+        self.inner().emit_warning(warning);
+    }
+
+    /// Writes out any diagnostics held back by the emitter.
+    pub fn flush(&self) {
+        self.inner().flush();
     }
 
     /// Emits the error `err`.
@@ -213,17 +440,18 @@ impl Handler {
     pub fn fatal_err(&self, err: LeoError) -> ! {
         let code = err.exit_code();
         self.emit_err(err);
+        self.flush();
         std::process::exit(code);
     }
 
     /// The number of errors thus far.
     pub fn err_count(&self) -> usize {
-        self.inner.borrow().err_count
+        self.inner().err_count
     }
 
     /// The number of warnings thus far.
     pub fn warning_count(&self) -> usize {
-        self.inner.borrow().warn_count
+        self.inner().warn_count
     }
 
     /// Did we have any errors thus far?
@@ -234,7 +462,7 @@ impl Handler {
     /// Gets the last emitted error's exit code if it exists.
     /// Then exits the program with it if it did exist.
     pub fn last_err(&self) -> Result<(), Box<LeoError>> {
-        if let Some(code) = self.inner.borrow().last_emitted_err_code() {
+        if let Some(code) = self.inner().last_emitted_err_code() {
             Err(Box::new(LeoError::LastErrorCode(code)))
         } else {
             Ok(())
@@ -255,11 +483,36 @@ impl Handler {
     }
 }
 
+/// Locks `mutex`, even if a thread panicked while holding it, as diagnostics remain usable.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::ParserError;
-    use leo_span::{symbol::create_session_if_not_set_then, Span};
+    use leo_span::{
+        source_map::FileName,
+        span::BytePos,
+        symbol::{create_session_if_not_set_then, with_session_globals},
+        Span,
+    };
+
+    /// A writer whose contents can be inspected after it is handed to an emitter.
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            lock(&self.0).extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
 
     #[test]
     fn fresh_no_errors() {
@@ -268,6 +521,18 @@ mod tests {
         assert!(!handler.had_errors());
     }
 
+    #[test]
+    fn handler_is_shared_between_threads() {
+        let (handler, buf) = Handler::new_with_buf();
+        std::thread::scope(|scope| {
+            for _ in 0..2 {
+                scope.spawn(|| handler.emit_err(ParserError::unexpected_eof(Span::default())));
+            }
+        });
+        assert_eq!(handler.err_count(), 2);
+        assert_eq!(buf.extract_errs().into_inner().len(), 2);
+    }
+
     #[test]
     fn buffer_works() {
         create_session_if_not_set_then(|_| {
@@ -296,4 +561,51 @@ mod tests {
             Handler::with(|_| Ok(())).unwrap();
         })
     }
+
+    #[test]
+    fn diagnostic_sink_works() {
+        create_session_if_not_set_then(|_| {
+            let source =
+                with_session_globals(|s| s.source_map.new_source("aaa\nbbb\nccc\n", FileName::Custom("test".into())));
+            let line = |n: u32| Span::new(source.start_pos + BytePos(4 * n), source.start_pos + BytePos(4 * n + 3));
+
+            let output = SharedBuffer::default();
+            let records = DiagnosticRecords::default();
+            let handler = Handler::new(Box::new(
+                DiagnosticSink::with_output(Some(2), Box::new(output.clone())).with_records(records.clone()),
+            ));
+            handler.emit_err(ParserError::unexpected_eof(line(2)));
+            handler.emit_err(ParserError::invalid_import_list(line(1)));
+            handler.emit_err(ParserError::invalid_import_list(line(1)));
+            handler.emit_err(ParserError::unexpected_eof(line(1)));
+            handler.emit_err(ParserError::unexpected_eof(line(0)));
+            // The duplicate is not written, but it is still counted.
+            assert_eq!(handler.err_count(), 5);
+            assert!(lock(&output.0).is_empty());
+
+            handler.flush();
+            let written = String::from_utf8(mem::take(&mut *lock(&output.0))).unwrap();
+            // The duplicate is dropped, the rest are sorted by position, and only the first two are written.
+            assert_eq!(written.matches("Error [").count(), 2);
+            let eof = written.find("unexpected EOF").unwrap();
+            let import = written.find("Cannot import empty list").unwrap();
+            assert!(eof < import);
+            assert!(written.contains("test:2:1"));
+            assert!(!written.contains("test:3:1"));
+            assert!(written.ends_with("2 more errors omitted.\n"));
+
+            // The limit holds over all flushes.
+            handler.emit_err(ParserError::invalid_import_list(line(2)));
+            handler.flush();
+            let written = String::from_utf8(mem::take(&mut *lock(&output.0))).unwrap();
+            assert_eq!(written, "1 more error omitted.\n");
+
+            // Records are kept for every error but the duplicate, in the order they were emitted.
+            let records = records.take();
+            assert_eq!(records.len(), 5);
+            assert_eq!(records[0].severity, Severity::Error);
+            assert_eq!(records[0].message, "unexpected EOF");
+            assert_eq!(records[0].location.as_deref(), Some("test:3:1"));
+        })
+    }
 }
@@ -178,4 +178,32 @@ create_messages!(
         msg: format!("Failed to write file.\nIO Error: {error}"),
         help: None,
     }
+
+    @backtraced
+    rename_target_not_found {
+        args: (name: impl Display),
+        msg: format!("There is no struct, record, function, mapping, or program constant named `{name}`."),
+        help: None,
+    }
+
+    @backtraced
+    constraint_budget_exceeded {
+        args: (function: impl Display, actual: impl Display, budget: impl Display, contributors: impl Display),
+        msg: format!("The circuit of `{function}` has {actual} constraints, exceeding its budget of {budget} constraints."),
+        help: Some(format!("The most frequent instructions in `{function}` are {contributors}. Reduce the work done by the transition, or raise its `@max_constraints` budget.")),
+    }
+
+    @backtraced
+    failed_to_load_private_key {
+        args: (path: impl Display),
+        msg: format!("Failed to load `PRIVATE_KEY` from `{path}`."),
+        help: Some("Add a line `PRIVATE_KEY=<private key>` to the `.env` file of the package.".to_string()),
+    }
+
+    @backtraced
+    not_in_a_workspace {
+        args: (path: impl Display),
+        msg: format!("`{path}` is not in a workspace."),
+        help: Some("`leo build --all` builds the members listed in the `workspace.json` of a directory or one of its parents.".to_string()),
+    }
 );
@@ -70,4 +70,212 @@ create_messages!(
         msg: format!("The program scope name `{program_scope_name}` must match `{file_name}`."),
         help: None,
     }
+
+    /// For when the compiler can't write the timing report to the provided path.
+    @backtraced
+    failed_to_write_timings {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("Failed to write the timing report to '{path:?}': {error}"),
+        help: None,
+    }
+
+    /// For when the compiler can't write the program metadata to the provided path.
+    @backtraced
+    failed_to_write_metadata {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("Failed to write the program metadata to '{path:?}': {error}"),
+        help: None,
+    }
+
+    /// For when there is no name to rename at the given location.
+    @formatted
+    rename_target_not_found {
+        args: (),
+        msg: "There is no name that can be renamed at this location.",
+        help: None,
+    }
+
+    /// For when a struct member is renamed.
+    @formatted
+    cannot_rename_member {
+        args: (member: impl Display),
+        msg: format!("Cannot rename the struct member `{member}`."),
+        help: Some("Member accesses are resolved during type checking, so their uses cannot be found safely.".to_string()),
+    }
+
+    /// For when the new name of a rename is not a valid identifier.
+    @formatted
+    invalid_rename {
+        args: (name: impl Display),
+        msg: format!("Cannot rename to `{name}`, since it is not a valid identifier."),
+        help: None,
+    }
+
+    /// For when the new name of a rename is already defined.
+    @formatted
+    rename_conflict {
+        args: (name: impl Display),
+        msg: format!("Cannot rename to `{name}`, since it would conflict with this definition."),
+        help: None,
+    }
+
+    /// For when the compiler can't write the call graph to the provided path.
+    @backtraced
+    failed_to_write_call_graph {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("Failed to write the call graph to '{path:?}': {error}"),
+        help: None,
+    }
+
+    /// For when the compiler can't write the information flow report to the provided path.
+    @backtraced
+    failed_to_write_information_flow_report {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("Failed to write the information flow report to '{path:?}': {error}"),
+        help: None,
+    }
+
+    /// For when a program imports itself, directly or indirectly.
+    @formatted
+    cyclic_import {
+        args: (cycle: impl Display),
+        msg: format!("Cyclic import: {cycle}"),
+        help: None,
+    }
+
+    /// For when an imported file exists but cannot be read.
+    @formatted
+    import_read_error {
+        args: (path: impl Display, error: impl ErrorArg),
+        msg: format!("Cannot read the imported file `{path}`: {error}"),
+        help: None,
+    }
+
+    /// For when an import only matches a file whose name differs in case.
+    @formatted
+    import_case_mismatch {
+        args: (expected: impl Display, actual: impl Display),
+        msg: format!("Attempted to import `{expected}`, but the file is named `{actual}`."),
+        help: Some("Import names are case-sensitive on every platform.".to_string()),
+    }
+
+    /// For when a directory in the path of a nested import does not exist.
+    @formatted
+    import_directory_not_found {
+        args: (directory: impl Display),
+        msg: format!("Attempted to import from a directory that does not exist `{directory}`."),
+        help: None,
+    }
+
+    /// For when the compiler can't write the program as Leo source to the provided path.
+    @backtraced
+    failed_to_write_expanded_source {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("Failed to write the expanded program to '{path:?}': {error}"),
+        help: None,
+    }
+
+    /// For when two custom passes have the same name.
+    @backtraced
+    duplicate_pass_name {
+        args: (pass: impl Display),
+        msg: format!("More than one custom pass is named `{pass}`."),
+        help: Some("Passes are identified by their names, so each pass must have a different name.".to_string()),
+    }
+
+    /// For when a custom pass must run after a pass that is not registered.
+    @backtraced
+    unknown_pass_dependency {
+        args: (pass: impl Display, dependency: impl Display),
+        msg: format!("The custom pass `{pass}` must run after `{dependency}`, which is not registered."),
+        help: None,
+    }
+
+    /// For when a custom pass must run after a pass that runs after a later stage.
+    @backtraced
+    pass_dependency_runs_later {
+        args: (pass: impl Display, dependency: impl Display),
+        msg: format!("The custom pass `{pass}` must run after `{dependency}`, which runs after a later stage."),
+        help: Some("A pass can only run after passes of the same stage or an earlier one.".to_string()),
+    }
+
+    /// For when the ordering constraints of custom passes are cyclic.
+    @backtraced
+    cyclic_pass_dependencies {
+        args: (passes: impl Display),
+        msg: format!("The custom passes {passes} must each run after another."),
+        help: Some("Remove one of the ordering constraints between the passes.".to_string()),
+    }
+
+    /// For when a custom pass reports an error.
+    @formatted
+    custom_pass_error {
+        args: (pass: impl Display, message: impl Display),
+        msg: format!("{message}"),
+        help: Some(format!("Reported by the custom pass `{pass}`.")),
+    }
+
+    /// For when a query names a source file that has not been parsed.
+    @backtraced
+    source_file_not_found {
+        args: (file: impl Display),
+        msg: format!("The source file `{file}` has not been parsed by the compiler."),
+        help: None,
+    }
+
+    /// For when a sandboxed compilation imports a program that was not given to the sandbox.
+    @formatted
+    import_not_in_sandbox {
+        args: (name: impl Display),
+        msg: format!("Cannot import `{name}.leo`, which is not one of the programs given to the sandbox."),
+        help: Some("Sandboxed compilations do not read files, so imported programs must be passed along with the program.".to_string()),
+    }
+
+    /// For when compilation takes longer than its time limit.
+    @backtraced
+    time_limit_exceeded {
+        args: (stage: impl Display),
+        msg: format!("Compilation exceeded its time limit during {stage}."),
+        help: None,
+    }
+
+    /// For when compilation creates more AST nodes than its limit.
+    @backtraced
+    node_limit_exceeded {
+        args: (nodes: impl Display, max_nodes: impl Display, stage: impl Display),
+        msg: format!("Compilation created {nodes} AST nodes by the end of {stage}, more than its limit of {max_nodes}."),
+        help: None,
+    }
+
+    /// For when the estimated number of constraints of a transition exceeds the limit.
+    @formatted
+    constraint_limit_exceeded {
+        args: (transition: impl Display, constraints: impl Display, max_constraints: impl Display),
+        msg: format!("The transition `{transition}` is estimated to have {constraints} constraints, more than the limit of {max_constraints}."),
+        help: None,
+    }
+
+    /// For when a source is larger than the limit of a sandboxed compilation.
+    @backtraced
+    source_too_large {
+        args: (name: impl Display, size: impl Display, max_size: impl Display),
+        msg: format!("The source of `{name}` is {size} bytes long, more than the limit of {max_size} bytes."),
+        help: None,
+    }
+
+    /// For when the compiler panics during a sandboxed compilation.
+    @backtraced
+    compiler_panicked {
+        args: (message: impl Display),
+        msg: format!("The compiler panicked: {message}"),
+        help: Some("This is a bug in the compiler. Please report it along with the program.".to_string()),
+    }
+
+    /// For when a program is formatted, but formatting would remove its comments.
+    @backtraced
+    cannot_format_comments {
+        args: (name: impl Display),
+        msg: format!("The program `{name}` cannot be formatted, since formatting does not preserve its comments."),
+        help: None,
+    }
 );
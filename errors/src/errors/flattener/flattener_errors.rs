@@ -53,4 +53,14 @@ create_messages!(
         ),
         help: None,
     }
+
+    /// For when a literal cannot be evaluated to a value.
+    @formatted
+    literal_cannot_be_evaluated {
+        args: (literal: impl Display, reason: impl Display),
+        msg: format!(
+            "The literal `{literal}` cannot be evaluated: {reason}.",
+        ),
+        help: None,
+    }
 );
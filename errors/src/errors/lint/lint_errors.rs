@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+
+use std::fmt::Display;
+
+create_messages!(
+    /// LintError enum that represents all the errors for the `leo-lints` crate.
+    LintError,
+    code_mask: 10000i32,
+    code_prefix: "LNT",
+
+    /// For when a lint whose level is `deny` is violated.
+    @formatted
+    denied_lint {
+        args: (lint: impl Display, message: impl Display),
+        msg: format!("{message}"),
+        help: Some(format!("This is reported by the `{lint}` lint, whose level is `deny`.")),
+    }
+
+    /// For when a level is given for a lint that does not exist.
+    @backtraced
+    unknown_lint {
+        args: (lint: impl Display),
+        msg: format!("There is no lint named `{lint}`."),
+        help: None,
+    }
+
+    /// For when a lint level is not one of `allow`, `warn`, or `deny`.
+    @backtraced
+    invalid_lint_level {
+        args: (level: impl Display),
+        msg: format!("`{level}` is not a lint level, expected `<lint>=allow`, `<lint>=warn`, or `<lint>=deny`."),
+        help: None,
+    }
+);
@@ -0,0 +1,34 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+
+use std::fmt::Display;
+
+create_messages!(
+    /// LintWarning enum that represents all the warnings for the `leo-lints` crate.
+    LintWarning,
+    code_mask: 10000i32,
+    code_prefix: "LNT",
+
+    /// For when a lint whose level is `warn` is violated.
+    @formatted
+    lint {
+        args: (lint: impl Display, message: impl Display),
+        msg: format!("{message}"),
+        help: Some(format!("This is reported by the `{lint}` lint, whose level can be set with `--lint {lint}=<level>`.")),
+    }
+);
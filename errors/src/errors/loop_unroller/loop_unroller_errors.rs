@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::create_messages;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
 
 create_messages!(
     /// LoopUnrollerError enum that represents all the errors for the loop unrolling errors in the `leo-loop_unroller` crate.
@@ -36,4 +36,18 @@ create_messages!(
         msg: format!("The array index must be constant."),
         help: None,
     }
+
+    @formatted
+    unroll_limit_exceeded {
+        args: (function: impl Display, limit: impl Display),
+        msg: format!("Unrolling this loop causes `{function}` to exceed the limit of {limit} unrolled statements."),
+        help: Some("Reduce the number of iterations, or raise the limit with `--max-unrolled-statements`.".to_string()),
+    }
+
+    @formatted
+    iteration_limit_exceeded {
+        args: (function: impl Display, limit: impl Display),
+        msg: format!("Unrolling this loop causes `{function}` to exceed the limit of {limit} unrolled iterations."),
+        help: Some("Reduce the number of iterations, or raise the limit with `--max-unrolled-iterations`.".to_string()),
+    }
 );
@@ -16,6 +16,7 @@
 
 /// Contains the ASG error definitions.
 use crate::LeoMessageCode;
+use leo_span::Span;
 
 /// Contains the AST error definitions.
 pub mod ast;
@@ -37,9 +38,17 @@ pub use self::flattener::*;
 pub mod input;
 pub use self::input::*;
 
+/// Contains the Lint error and warning definitions.
+pub mod lint;
+pub use self::lint::*;
+
 pub mod loop_unroller;
 pub use self::loop_unroller::*;
 
+/// Contains the Move Checker error definitions.
+pub mod move_checker;
+pub use self::move_checker::*;
+
 /// Contains the Package error definitions.
 pub mod package;
 pub use self::package::*;
@@ -48,6 +57,10 @@ pub use self::package::*;
 pub mod parser;
 pub use self::parser::*;
 
+/// Contains the Static Analyzer warning definitions.
+pub mod static_analyzer;
+pub use self::static_analyzer::*;
+
 /// Contains the Type Checker error definitions.
 pub mod type_checker;
 
@@ -81,9 +94,15 @@ pub enum LeoError {
     /// Represents a Loop Unroller Error in a Leo Error.
     #[error(transparent)]
     LoopUnrollerError(#[from] LoopUnrollerError),
+    /// Represents a Move Checker Error in a Leo Error.
+    #[error(transparent)]
+    MoveCheckerError(#[from] MoveCheckerError),
     /// Represents a Flatten Error in a Leo Error.
     #[error(transparent)]
     FlattenError(#[from] FlattenError),
+    /// Represents a Lint Error in a Leo Error.
+    #[error(transparent)]
+    LintError(#[from] LintError),
     /// Purely for just exiting with the correct status code and
     /// not re-displaying an error.
     #[error("")]
@@ -107,12 +126,55 @@ impl LeoError {
             PackageError(error) => error.error_code(),
             TypeCheckerError(error) => error.error_code(),
             LoopUnrollerError(error) => error.error_code(),
+            MoveCheckerError(error) => error.error_code(),
             FlattenError(error) => error.error_code(),
+            LintError(error) => error.error_code(),
             LastErrorCode(_) => unreachable!(),
             Anyhow(_) => unimplemented!(), // todo: implement error codes for snarkvm errors.
         }
     }
 
+    /// Returns the span the error points at, if it has one.
+    pub fn span(&self) -> Option<Span> {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => error.span(),
+            CompilerError(error) => error.span(),
+            CliError(error) => error.span(),
+            InputError(error) => error.span(),
+            ParserError(error) => error.span(),
+            PackageError(error) => error.span(),
+            TypeCheckerError(error) => error.span(),
+            LoopUnrollerError(error) => error.span(),
+            MoveCheckerError(error) => error.span(),
+            FlattenError(error) => error.span(),
+            LintError(error) => error.span(),
+            LastErrorCode(_) | Anyhow(_) => None,
+        }
+    }
+
+    /// Returns the text of the error, without its code, code frame, or help.
+    pub fn message(&self) -> String {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => error.message().to_string(),
+            CompilerError(error) => error.message().to_string(),
+            CliError(error) => error.message().to_string(),
+            InputError(error) => error.message().to_string(),
+            ParserError(error) => error.message().to_string(),
+            PackageError(error) => error.message().to_string(),
+            TypeCheckerError(error) => error.message().to_string(),
+            LoopUnrollerError(error) => error.message().to_string(),
+            MoveCheckerError(error) => error.message().to_string(),
+            FlattenError(error) => error.message().to_string(),
+            LintError(error) => error.message().to_string(),
+            LastErrorCode(_) => String::new(),
+            Anyhow(error) => error.to_string(),
+        }
+    }
+
     /// Implement exit code for each type of Error.
     pub fn exit_code(&self) -> i32 {
         use LeoError::*;
@@ -126,7 +188,9 @@ impl LeoError {
             PackageError(error) => error.exit_code(),
             TypeCheckerError(error) => error.exit_code(),
             LoopUnrollerError(error) => error.exit_code(),
+            MoveCheckerError(error) => error.exit_code(),
             FlattenError(error) => error.exit_code(),
+            LintError(error) => error.exit_code(),
             LastErrorCode(code) => *code,
             Anyhow(_) => unimplemented!(), // todo: implement exit codes for snarkvm errors.
         }
@@ -137,9 +201,21 @@ impl LeoError {
 /// This allows a unified error type throughout the Leo crates.
 #[derive(Debug, Error)]
 pub enum LeoWarning {
+    /// Represents a Compiler Warning in a Leo Warning.
+    #[error(transparent)]
+    CompilerWarning(#[from] CompilerWarning),
+    /// Represents a Lint Warning in a Leo Warning.
+    #[error(transparent)]
+    LintWarning(#[from] LintWarning),
     /// Represents an Parser Error in a Leo Error.
     #[error(transparent)]
     ParserWarning(#[from] ParserWarning),
+    /// Represents a Static Analyzer Warning in a Leo Warning.
+    #[error(transparent)]
+    StaticAnalyzerWarning(#[from] StaticAnalyzerWarning),
+    /// Represents a Type Checker Warning in a Leo Warning.
+    #[error(transparent)]
+    TypeCheckerWarning(#[from] TypeCheckerWarning),
 }
 
 impl LeoWarning {
@@ -148,7 +224,37 @@ impl LeoWarning {
         use LeoWarning::*;
 
         match self {
+            CompilerWarning(warning) => warning.warning_code(),
+            LintWarning(warning) => warning.warning_code(),
             ParserWarning(warning) => warning.warning_code(),
+            StaticAnalyzerWarning(warning) => warning.warning_code(),
+            TypeCheckerWarning(warning) => warning.warning_code(),
+        }
+    }
+
+    /// Returns the span the warning points at, if it has one.
+    pub fn span(&self) -> Option<Span> {
+        use LeoWarning::*;
+
+        match self {
+            CompilerWarning(warning) => warning.span(),
+            LintWarning(warning) => warning.span(),
+            ParserWarning(warning) => warning.span(),
+            StaticAnalyzerWarning(warning) => warning.span(),
+            TypeCheckerWarning(warning) => warning.span(),
+        }
+    }
+
+    /// Returns the text of the warning, without its code, code frame, or help.
+    pub fn message(&self) -> &str {
+        use LeoWarning::*;
+
+        match self {
+            CompilerWarning(warning) => warning.message(),
+            LintWarning(warning) => warning.message(),
+            ParserWarning(warning) => warning.message(),
+            StaticAnalyzerWarning(warning) => warning.message(),
+            TypeCheckerWarning(warning) => warning.message(),
         }
     }
 }
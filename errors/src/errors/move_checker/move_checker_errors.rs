@@ -0,0 +1,34 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+
+use std::fmt::Display;
+
+create_messages!(
+    /// MoveCheckerError enum that represents all the errors for move checking in the `leo-passes` crate.
+    MoveCheckerError,
+    code_mask: 8000i32,
+    code_prefix: "MOV",
+
+    /// For when a record is used after it has been moved.
+    @formatted
+    record_used_after_move {
+        args: (name: impl Display, line: impl Display),
+        msg: format!("The record `{name}` is used here, after it was moved on line {line}."),
+        help: Some("A record is moved when it is passed to a function, returned, or assigned to another variable, after which it can no longer be used.".to_string()),
+    }
+);
@@ -313,4 +313,148 @@ create_messages!(
         msg: format!("IO error env file from the provided file path - {error}"),
         help: None,
     }
+
+    /// For when the artifacts file of a build cannot be read.
+    @backtraced
+    failed_to_read_artifacts_file {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("Failed to read the build artifacts file {path:?}: {error}"),
+        help: Some("Run `leo build` to regenerate it.".to_string()),
+    }
+
+    /// For when the artifacts file of a build cannot be written.
+    @backtraced
+    failed_to_write_artifacts_file {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("Failed to write the build artifacts file {path:?}: {error}"),
+        help: None,
+    }
+
+    /// For when a build artifact cannot be removed.
+    @backtraced
+    failed_to_remove_artifact {
+        args: (path: impl Display, error: impl ErrorArg),
+        msg: format!("Failed to remove the build artifact {path}: {error}"),
+        help: None,
+    }
+
+    /// For when the name of an imported file is not valid UTF-8.
+    @backtraced
+    non_utf8_import_file_name {
+        args: (path: impl Display),
+        msg: format!("The name of the imported file `{path}` is not valid UTF-8."),
+        help: Some("Rename the file so that its name is a valid program name.".to_string()),
+    }
+
+    /// For when the name of an imported file is not a valid program name.
+    @backtraced
+    invalid_import_file_name {
+        args: (name: impl Display),
+        msg: format!("The imported file `{name}` does not have a valid program name."),
+        help: Some("Program names consist of ASCII letters, digits, and underscores, and begin with a letter.".to_string()),
+    }
+
+    /// For when two imported files would compile to the same program on a case-insensitive file system.
+    @backtraced
+    conflicting_import_file_names {
+        args: (first: impl Display, second: impl Display),
+        msg: format!("The imported files `{first}` and `{second}` have names that differ only in case, or not at all."),
+        help: Some("Rename one of the files, since they would overwrite each other on case-insensitive file systems.".to_string()),
+    }
+
+    /// For when the build report could not be written.
+    @backtraced
+    failed_to_write_build_report {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("Failed to write the build report {path:?}: {error}"),
+        help: None,
+    }
+
+    /// For when the workspace manifest cannot be read.
+    @backtraced
+    failed_to_read_workspace_manifest {
+        args: (path: impl Display, error: impl ErrorArg),
+        msg: format!("Failed to read the workspace manifest `{path}`: {error}"),
+        help: None,
+    }
+
+    /// For when the workspace manifest is not valid.
+    @backtraced
+    invalid_workspace_manifest {
+        args: (path: impl Display, error: impl ErrorArg),
+        msg: format!("The workspace manifest `{path}` is not valid: {error}"),
+        help: Some("A workspace manifest lists the directories of its member packages, e.g. `{ \"members\": [\"token\", \"bank\"] }`.".to_string()),
+    }
+
+    /// For when a member of a workspace is not a Leo package.
+    @backtraced
+    workspace_member_not_found {
+        args: (member: impl Display),
+        msg: format!("The workspace member `{member}` is not a Leo package."),
+        help: Some("Each member must be a directory, relative to the workspace manifest, containing a `program.json`.".to_string()),
+    }
+
+    /// For when two members of a workspace define the same program.
+    @backtraced
+    duplicate_workspace_member {
+        args: (program: impl Display, first: impl Display, second: impl Display),
+        msg: format!("The workspace members `{first}` and `{second}` both define the program `{program}`."),
+        help: None,
+    }
+
+    /// For when the constraint system of a transition could not be written.
+    @backtraced
+    failed_to_write_constraint_system {
+        args: (path: impl Display, error: impl ErrorArg),
+        msg: format!("Failed to write the constraint system `{path}`: {error}"),
+        help: None,
+    }
+
+    /// For when a witness could not be written.
+    @backtraced
+    failed_to_write_witness {
+        args: (path: impl Display, error: impl ErrorArg),
+        msg: format!("Failed to write the witness `{path}`: {error}"),
+        help: None,
+    }
+
+    /// For when a witness could not be read.
+    @backtraced
+    failed_to_read_witness {
+        args: (path: impl Display, error: impl ErrorArg),
+        msg: format!("Failed to read the witness `{path}`: {error}"),
+        help: None,
+    }
+
+    /// For when a witness does not fit the constraint system of its transition.
+    @backtraced
+    invalid_witness {
+        args: (function: impl Display, reason: impl Display),
+        msg: format!("The witness of `{function}` is not valid: {reason}."),
+        help: None,
+    }
+
+    /// For when a witness does not satisfy a constraint of its transition.
+    @backtraced
+    unsatisfied_witness {
+        args: (function: impl Display, constraint: impl Display, variables: impl Display),
+        msg: format!("The witness of `{function}` does not satisfy constraint {constraint}, on {variables}."),
+        help: None,
+    }
+
+    /// For when the artifacts file of a build lists a path outside the build and outputs directories.
+    @backtraced
+    invalid_artifact_path {
+        args: (path: impl Display),
+        msg: format!("The build artifact {path} is not in the `build` or `outputs` directory of the package."),
+        help: None,
+    }
+
+    /// For when a constraint system has fewer wires than the constant one and its public inputs.
+    @backtraced
+    invalid_constraint_system {
+        args: (num_wires: impl Display, num_public_inputs: impl Display),
+        msg: format!("The constraint system has {num_wires} wires, fewer than the constant one and {num_public_inputs} public inputs."),
+        help: None,
+    }
 );
@@ -168,6 +168,8 @@ create_messages!(
     }
 
     /// When the user tries to pass an implicit value.
+    /// No longer emitted: the types of unsuffixed literals are inferred. Kept so that the codes of the following errors are stable.
+    #[deprecated = "unsuffixed literals are accepted and their types are inferred"]
     @formatted
     implicit_values_not_allowed {
         args: (input: impl Display),
@@ -176,6 +178,8 @@ create_messages!(
     }
 
     /// When a hex number is provided.
+    /// No longer emitted: hexadecimal integer literals are accepted. Kept so that the codes of the following errors are stable.
+    #[deprecated = "hexadecimal integer literals are accepted"]
     @backtraced
     lexer_hex_number_provided {
         args: (input: impl Display),
@@ -280,7 +284,7 @@ create_messages!(
     @formatted
     console_statements_are_not_yet_supported {
         args: (),
-        msg: format!("`console` statements are not yet supported."),
+        msg: format!("`console` assertions are not yet supported."),
         help: Some("Consider using `assert`, `assert_eq`, or `assert_neq` instead.".to_string()),
     }
 
@@ -298,4 +302,172 @@ create_messages!(
         msg: format!("An array {kind} must have at least one element."),
         help: None,
     }
+
+    /// For when the parser encountered an address literal with the wrong number of characters.
+    @formatted
+    invalid_address_lit_length {
+        args: (token: impl Display, length: impl Display, expected: impl Display),
+        msg: format!("invalid address literal: '{token}' has {length} characters, but an address must have {expected}"),
+        help: None,
+    }
+
+    /// For when a hexadecimal, octal, or binary number has no digits after its prefix.
+    @backtraced
+    lexer_radix_prefix_without_digits {
+        args: (input: impl Display),
+        msg: format!("The number `{input}` has no digits after its radix prefix."),
+        help: None,
+    }
+
+    /// For when a hexadecimal, octal, or binary number is used as a field, group, or scalar.
+    @formatted
+    radix_literal_must_be_integer {
+        args: (literal: impl Display, type_: impl Display),
+        msg: format!("The number `{literal}` cannot be used as a `{type_}`."),
+        help: Some("Hexadecimal, octal, and binary numbers must be integers. Use a decimal number instead.".to_string()),
+    }
+
+    /// For when `self` is used as a parameter outside the first position of a struct method.
+    @formatted
+    invalid_method_receiver {
+        args: (),
+        msg: "`self` can only be the first parameter of a struct method.",
+        help: None,
+    }
+
+    /// For when a struct method does not take `self` or `mut self` as its first parameter.
+    @formatted
+    missing_method_receiver {
+        args: (),
+        msg: "Struct methods must take `self` or `mut self` as their first parameter.",
+        help: None,
+    }
+
+    /// For when a struct method is named after a built-in operation, which would make it impossible to call.
+    @formatted
+    method_name_is_reserved {
+        args: (name: impl Display),
+        msg: format!("`{name}` cannot be used as a method name, since it is reserved for a built-in operation."),
+        help: None,
+    }
+
+    /// For when a macro is declared more than once.
+    @formatted
+    macro_already_declared {
+        args: (name: impl Display),
+        msg: format!("The macro `{name}` is already declared."),
+        help: None,
+    }
+
+    /// For when a macro has two parameters with the same name.
+    @formatted
+    duplicate_macro_parameter {
+        args: (param: impl Display, name: impl Display),
+        msg: format!("The parameter `{param}` of the macro `{name}` is declared more than once."),
+        help: None,
+    }
+
+    /// For when a macro is invoked that has not been declared.
+    @formatted
+    undeclared_macro {
+        args: (name: impl Display),
+        msg: format!("The macro `{name}` is not declared."),
+        help: Some("Macros must be declared in the program scope before they are invoked.".to_string()),
+    }
+
+    /// For when a macro is invoked with the wrong number of arguments.
+    @formatted
+    macro_argument_count {
+        args: (name: impl Display, expected: impl Display, found: impl Display),
+        msg: format!("The macro `{name}` takes {expected} argument(s), but {found} were given."),
+        help: None,
+    }
+
+    /// For when macro expansions are nested too deeply, e.g. because a macro invokes itself.
+    @formatted
+    macro_recursion_limit {
+        args: (name: impl Display, limit: impl Display),
+        msg: format!("The expansion of the macro `{name}` is nested more than {limit} levels deep."),
+        help: Some("Check whether the macro invokes itself.".to_string()),
+    }
+
+    /// For when the file included by `include_constants!` cannot be read.
+    @formatted
+    constants_file_read_error {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to read the constants file `{path}`: {error}"),
+        help: None,
+    }
+
+    /// For when the file included by `include_constants!` is not valid JSON.
+    @formatted
+    invalid_constants_file {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("The constants file `{path}` is not valid JSON: {error}"),
+        help: None,
+    }
+
+    /// For when the file included by `include_constants!` contains a value that is not a constant.
+    @formatted
+    invalid_constant {
+        args: (path: impl Display, value: impl Display),
+        msg: format!("The constants file `{path}` contains `{value}`, which is not a literal."),
+        help: Some("Constants are numbers, booleans, strings holding a literal such as \"1field\", or arrays of them. Numbers that do not fit in 64 bits must be written as strings.".to_string()),
+    }
+
+    /// For when expressions, statements, or types are nested in one another more deeply than allowed.
+    @formatted
+    too_deeply_nested {
+        args: (max_depth: impl Display),
+        msg: format!("Expressions, statements, and types cannot be nested more than {max_depth} deep."),
+        help: Some("Split the nested code by assigning parts of it to variables or moving them into functions, or raise the limit with `--max-expression-depth`.".to_string()),
+    }
+
+    /// For when a file contains more distinct identifiers than allowed.
+    @formatted
+    too_many_identifiers {
+        args: (max_identifiers: impl Display),
+        msg: format!("A file cannot contain more than {max_identifiers} distinct identifiers."),
+        help: Some("Split the program into several programs, or raise the limit with `--max-identifiers`.".to_string()),
+    }
+
+    /// For when `include_constants!` is used in a sandboxed compilation, which reads no files.
+    @formatted
+    constants_file_in_sandbox {
+        args: (path: impl Display),
+        msg: format!("Cannot include the constants file `{path}`, since sandboxed compilations do not read files."),
+        help: Some("Write the constants in the program instead.".to_string()),
+    }
+
+    /// For when a hexadecimal, octal, or binary number contains a digit of a greater radix.
+    @backtraced
+    lexer_invalid_digit_for_radix {
+        args: (input: impl Display, digit: impl Display),
+        msg: format!("The number `{input}` cannot contain the digit `{digit}`."),
+        help: None,
+    }
+
+    /// For when an import ending in `.*` is not of a module of the standard library.
+    @formatted
+    glob_import_outside_std {
+        args: (path: impl Display),
+        msg: format!("Cannot import `{path}.*`, since only modules of the standard library can be imported with `.*`."),
+        help: Some("Import a module of the standard library with `import std.<module>.*;`, or a program with `import <program>.leo;`.".to_string()),
+    }
+
+    /// For when an import names a module that is not in the standard library.
+    @formatted
+    unknown_std_module {
+        args: (module: impl Display, modules: impl Display),
+        msg: format!("The standard library has no module `{module}`."),
+        help: Some(format!("The modules of the standard library are {modules}.")),
+    }
+
+    /// For when a function is named with `__`, which is reserved for the functions that struct methods are lowered to.
+    @formatted
+    function_name_contains_double_underscore {
+        args: (name: impl Display),
+        msg: format!("`{name}` cannot be used as a function name, since names containing `__` are reserved for struct methods."),
+        help: None,
+    }
 );
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+
+use std::fmt::Display;
+
+create_messages!(
+    /// StaticAnalyzerWarning enum that represents all the warnings for static analysis in the `leo-passes` crate.
+    StaticAnalyzerWarning,
+    code_mask: 4000i32,
+    code_prefix: "SAZ",
+
+    /// For when a value is assigned to a variable and overwritten before it is read.
+    @formatted
+    dead_store {
+        args: (name: impl Display),
+        msg: format!("The value assigned to `{name}` is overwritten before it is read."),
+        help: Some(format!("Remove this assignment, or read `{name}` before it is assigned again.")),
+    }
+
+    /// For when a variable is assigned to itself.
+    @formatted
+    self_assignment {
+        args: (place: impl Display),
+        msg: format!("`{place}` is assigned to itself."),
+        help: Some("Remove this assignment, it has no effect.".to_string()),
+    }
+
+    /// For when the condition of a conditional statement always evaluates to the same value.
+    @formatted
+    constant_condition {
+        args: (value: impl Display, reason: impl Display),
+        msg: format!("This condition is always `{value}`, since {reason}."),
+        help: Some("Remove the branch that is never taken. Both branches of a conditional are compiled into the circuit, so the dead branch still adds constraints.".to_string()),
+    }
+
+    /// For when a transition produces records with larger balances than the records it consumes.
+    @formatted
+    non_conserving_balance {
+        args: (record: impl Display, member: impl Display, excess: impl Display),
+        msg: format!("The `{member}` of the `{record}` records produced by this transition can exceed the `{member}` of the `{record}` records it consumes, by `{excess}`."),
+        help: Some("Check the arithmetic on this balance. Only public inputs are treated as amounts that may be added to it.".to_string()),
+    }
+);
@@ -710,6 +710,313 @@ create_messages!(
     array_element_cannot_be_record {
         args: (),
         msg: format!("An array cannot have a record as an element type"),
+        help: Some("Arrays can only contain plaintext values. Use separate variables or a tuple of records instead.".to_string()),
+    }
+
+    @formatted
+    record_nonce_cannot_be_initialized {
+        args: (record: impl Display),
+        msg: format!("The `_nonce` of record `{record}` cannot be initialized in a program"),
+        help: Some("The nonce of a record is assigned by the VM when the record is created. Remove the `_nonce` member from the initializer.".to_string()),
+    }
+
+    @formatted
+    record_nonce_not_accessible {
+        args: (record: impl Display),
+        msg: format!("The `_nonce` of record `{record}` cannot be accessed in a program"),
+        help: Some("A record nonce can only be provided as part of a record input, e.g. in an input file.".to_string()),
+    }
+
+    /// For when an invalid field of network is accessed.
+    @formatted
+    invalid_network_access {
+        args: (),
+        msg: format!("The allowed accesses to `network` are `network.id`."),
+        help: None,
+    }
+
+    @formatted
+    constant_operation_overflows {
+        args: (operation: impl Display, type_: impl Display),
+        msg: format!("The constant operation `{operation}` overflows the `{type_}` type."),
+        help: Some("Operations on literals are evaluated at compile time, and this one would always fail when executed.".to_string()),
+    }
+
+    @formatted
+    constant_division_by_zero {
+        args: (operation: impl Display),
+        msg: format!("The constant operation `{operation}` divides by zero."),
+        help: None,
+    }
+
+    @formatted
+    could_not_infer_literal_type {
+        args: (literal: impl Display),
+        msg: format!("Could not infer the type of the literal `{literal}`."),
+        help: Some(format!("Add a type suffix, e.g. `{literal}u32`, or use the literal where an integer, field, group, or scalar type is expected.")),
+    }
+
+    @formatted
+    incorrect_num_log_arguments {
+        args: (placeholders: impl Display, arguments: impl Display),
+        msg: format!("The format string has {placeholders} `{{}}` placeholders, but {arguments} arguments were given."),
+        help: None,
+    }
+
+    @formatted
+    invalid_log_argument_type {
+        args: (argument: impl Display),
+        msg: format!("`{argument}` cannot be logged."),
+        help: Some("Only plaintext values, i.e. values that are not records or `()`, can be logged.".to_string()),
+    }
+
+    @formatted
+    unknown_method {
+        args: (type_: impl Display, method: impl Display),
+        msg: format!("Type `{type_}` has no method `{method}`."),
+        help: None,
+    }
+
+    @formatted
+    cannot_assign_to_immutable_self {
+        args: (),
+        msg: "Cannot assign to `self`, since the method does not take `mut self`.",
+        help: Some("Declare the receiver as `mut self` to update it.".to_string()),
+    }
+
+    @formatted
+    private_input_returned_as_public_output {
+        args: (name: impl Display),
+        msg: format!("The private input `{name}` cannot be returned as a public output."),
+        help: Some("Declare the output as `private`, or declare the input as `public` to reveal it.".to_string()),
+    }
+
+    @formatted
+    regular_function_outputs_cannot_have_modes {
+        args: (),
+        msg: format!("Standard functions cannot have modes associated with their outputs."),
+        help: Some("Consider removing the mode or using the keyword `transition` instead of `function`.".to_string()),
+    }
+
+    @formatted
+    program_has_no_transitions {
+        args: (program: impl Display),
+        msg: format!("The program `{program}` does not define any transitions."),
+        help: Some("Every program needs at least one `transition` to serve as an entry point.".to_string()),
+    }
+
+    @formatted
+    bit_decomposition_too_wide {
+        args: (type_: impl Display, max: impl Display),
+        msg: format!("Cannot convert `{type_}` to or from bits, since arrays have at most {max} elements."),
+        help: None,
+    }
+
+    @formatted
+    ambiguous_signed_field_conversion {
+        args: (type_: impl Display),
+        msg: format!("Cannot convert a `field` into `{type_}`, since negative integers have no canonical field representation."),
+        help: Some("Convert the field element into an unsigned integer of the same width, and cast the result instead.".to_string()),
+    }
+
+    @formatted
+    implicit_field_integer_conversion {
+        args: (type_: impl Display),
+        msg: format!("Cannot mix `field` and `{type_}` in an operation, since they are not implicitly converted into each other."),
+        help: Some(format!("Convert explicitly, with `x as field` or `{type_}::from_field(f)`.")),
+    }
+
+    @formatted
+    field_used_as_scalar {
+        args: (),
+        msg: "Cannot multiply a group element by a `field`, since scalar multiplication takes a `scalar`.",
+        help: Some("Use a `scalar` literal, e.g. `2scalar`, or cast the field element with `as scalar`.".to_string()),
+    }
+
+    @formatted
+    duplicate_metadata {
+        args: (key: impl Display),
+        msg: format!("The metadata `@{key}` is declared more than once."),
+        help: None,
+    }
+
+    @formatted
+    program_metadata_mismatch {
+        args: (value: impl Display, program: impl Display),
+        msg: format!("The metadata `@program(\"{value}\")` does not match the program `{program}`."),
+        help: None,
+    }
+
+    @formatted
+    invalid_version_metadata {
+        args: (value: impl Display),
+        msg: format!("The version `{value}` is not of the form `MAJOR.MINOR.PATCH`."),
+        help: Some("Use a version like `@version(\"1.2.0\")`.".to_string()),
+    }
+
+    @formatted
+    annotation_takes_no_value {
+        args: (annotation: impl Display),
+        msg: format!("The annotation `@{annotation}` does not take a value."),
+        help: None,
+    }
+
+    @formatted
+    duplicate_annotation {
+        args: (annotation: impl Display),
+        msg: format!("The annotation `@{annotation}` is declared more than once."),
         help: None,
     }
+
+    @formatted
+    should_fail_without_test {
+        args: (),
+        msg: "Only functions annotated with `@test` can be annotated with `@should_fail`.",
+        help: None,
+    }
+
+    @formatted
+    inline_transition {
+        args: (),
+        msg: "A transition cannot be annotated with `@inline`.",
+        help: Some("Remove the annotation, or declare the function with `function` instead of `transition`.".to_string()),
+    }
+
+    @formatted
+    invalid_struct_annotation {
+        args: (annotation: impl Display),
+        msg: format!("The annotation `{annotation}` cannot be applied to a struct or record."),
+        help: Some("Structs and records may only be annotated with `@deprecated`.".to_string()),
+    }
+
+    @formatted
+    constraint_budget_on_non_transition {
+        args: (),
+        msg: "Only a transition can be annotated with `@max_constraints`.",
+        help: Some("Other functions are compiled into the circuits of the transitions that call them, so their constraints count towards the budgets of those transitions.".to_string()),
+    }
+
+    @formatted
+    invalid_constraint_budget {
+        args: (),
+        msg: "The budget of `@max_constraints` must be a positive integer.",
+        help: Some("For example, `@max_constraints(50000)`.".to_string()),
+    }
+
+    @formatted
+    self_type_outside_method {
+        args: (),
+        msg: "`Self` can only be used within the methods of a struct.",
+        help: Some("Use the name of the struct or record instead.".to_string()),
+    }
+
+    @formatted
+    invalid_option_element {
+        args: (type_: impl Display),
+        msg: format!("An option cannot hold a value of type `{type_}`."),
+        help: Some("An option can hold any type with a default value, which excludes records, signatures, tuples, and options, as well as structs containing them.".to_string()),
+    }
+
+    @formatted
+    cannot_infer_option_type {
+        args: (),
+        msg: "The type of the value held by `Option::none()` cannot be inferred.",
+        help: Some("Add a type annotation, e.g. `let x: Option<u8> = Option::none();`.".to_string()),
+    }
+
+    @formatted
+    invalid_result_element {
+        args: (type_: impl Display),
+        msg: format!("A result cannot hold a value or error of type `{type_}`."),
+        help: Some("A result can hold any type with a default value, which excludes records, signatures, tuples, options, and results, as well as structs containing them.".to_string()),
+    }
+
+    @formatted
+    cannot_infer_result_type {
+        args: (function: impl Display),
+        msg: format!("The type of the result constructed by `Result::{function}` cannot be inferred."),
+        help: Some("Add a type annotation, e.g. `let x: Result<u8, u16> = Result::ok(1u8);`.".to_string()),
+    }
+
+    @formatted
+    try_outside_result_function {
+        args: (output_type: impl Display),
+        msg: format!("The `?` operator can only be used in a function that returns a `Result`, but this function returns `{output_type}`."),
+        help: Some("The `?` operator returns the error of a failed result from the enclosing function.".to_string()),
+    }
+
+    @formatted
+    try_in_function_with_finalize {
+        args: (),
+        msg: "The `?` operator cannot be used in a function with a finalize block.".to_string(),
+        help: Some("Returning the error early would not provide the arguments of the finalize block. Check the result with `is_ok()` instead.".to_string()),
+    }
+
+    @formatted
+    record_schema_violations {
+        args: (record: impl Display, profile: impl Display, violations: impl Display),
+        msg: format!("The record `{record}` does not follow the `{profile}` record schema."),
+        help: Some(format!("Fix the following:\n{violations}")),
+    }
+
+    @formatted
+    expression_too_deeply_nested {
+        args: (max_depth: impl Display),
+        msg: format!("Expressions cannot be nested more than {max_depth} deep."),
+        help: Some("Split the expression by assigning its parts to variables, or raise the limit with `--max-expression-depth`.".to_string()),
+    }
+
+    @formatted
+    array_size_limit_exceeded {
+        args: (type_: impl Display, size: impl Display, max_size: impl Display),
+        msg: format!("The array type `{type_}` has {size} elements, but arrays cannot have more than {max_size} elements."),
+        help: Some("Use a smaller array, or raise the limit with `--max-array-size`.".to_string()),
+    }
+
+    @formatted
+    console_log_not_supported {
+        args: (),
+        msg: "`console.log` cannot be compiled, as the Aleo VM has no instruction to print values.".to_string(),
+        help: Some("Check the values with `console.assert` instead, which fails the execution if it does not hold.".to_string()),
+    }
+
+    /// For when a method takes `mut self` but does not return `Self`, so changes to the receiver are lost.
+    @formatted
+    mutable_receiver_not_returned {
+        args: (method: impl Display),
+        msg: format!("The method `{method}` takes `mut self` but does not return `Self`, so changes to `self` are lost."),
+        help: Some("Methods work on a copy of their receiver. Return `self` to use the changed value, or take `self` instead.".to_string()),
+    }
+
+    /// For when a transition takes an input of a type that has no representation as a transition input.
+    @formatted
+    transition_input_not_serializable {
+        args: (type_: impl Display),
+        msg: format!("Transitions cannot take inputs of type `{type_}`, since its values cannot be given as inputs."),
+        help: None,
+    }
+
+    /// For when a program item or member is named after a keyword or opcode of Aleo instructions.
+    @formatted
+    name_is_reserved {
+        args: (name: impl Display),
+        msg: format!("`{name}` cannot be used as a name, since it is a keyword or opcode of Aleo instructions."),
+        help: None,
+    }
+
+    /// For when an item other than a function is named `main`.
+    @formatted
+    main_is_reserved_for_functions {
+        args: (),
+        msg: "`main` can only be used as the name of a function, since input files provide the inputs of `main`.",
+        help: None,
+    }
+
+    /// For when a variable is used after the end of the scope that declares it.
+    @formatted
+    variable_out_of_scope {
+        args: (name: impl Display),
+        msg: format!("The variable `{name}` is not in scope here."),
+        help: Some("A variable can only be used in the block that declares it, including the blocks nested in it.".to_string()),
+    }
 );
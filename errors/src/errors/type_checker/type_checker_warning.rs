@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+
+use std::fmt::Display;
+
+create_messages!(
+    /// TypeCheckerWarning enum that represents all the warnings for the type checker in the `leo-passes` crate.
+    TypeCheckerWarning,
+    code_mask: 2000i32,
+    code_prefix: "TYC",
+
+    /// For when a function annotated with `@deprecated` is called.
+    @formatted
+    deprecated_function {
+        args: (name: impl Display, message: impl Display),
+        msg: format!("The function `{name}` is deprecated."),
+        help: Some(message.to_string()).filter(|message| !message.is_empty()),
+    }
+
+    /// For when a struct or record annotated with `@deprecated` is used.
+    @formatted
+    deprecated_struct {
+        args: (name: impl Display, message: impl Display),
+        msg: format!("The struct `{name}` is deprecated."),
+        help: Some(message.to_string()).filter(|message| !message.is_empty()),
+    }
+
+    /// For when an imported struct or function annotated with `@deprecated` is used.
+    @formatted
+    deprecated_imported_symbol {
+        args: (name: impl Display, origin: impl Display, message: impl Display),
+        msg: format!("`{name}`, imported from `{origin}`, is deprecated."),
+        help: Some(message.to_string()).filter(|message| !message.is_empty()),
+    }
+);
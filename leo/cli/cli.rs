@@ -61,11 +61,21 @@ enum Commands {
         #[clap(flatten)]
         command: Build,
     },
+    #[clap(about = "Check the current package for errors without synthesizing its circuits")]
+    Check {
+        #[clap(flatten)]
+        command: Check,
+    },
     #[clap(about = "Clean the output directory")]
     Clean {
         #[clap(flatten)]
         command: Clean,
     },
+    #[clap(about = "Rename a struct, record, function, mapping, or program constant")]
+    Rename {
+        #[clap(flatten)]
+        command: Rename,
+    },
     #[clap(about = "Run a program with input variables")]
     Run {
         #[clap(flatten)]
@@ -126,8 +136,10 @@ pub fn run_with_args(cli: CLI) -> Result<()> {
 
             command.try_execute(context)
         }
+        Commands::Check { command } => command.try_execute(context),
         Commands::Clean { command } => command.try_execute(context),
         Commands::Example { command } => command.try_execute(context),
+        Commands::Rename { command } => command.try_execute(context),
         Commands::Run { command } => command.try_execute(context),
         Commands::Execute { command } => command.try_execute(context),
         Commands::Update { command } => command.try_execute(context),
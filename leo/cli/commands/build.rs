@@ -16,32 +16,66 @@
 
 use super::*;
 
-use leo_ast::{NodeBuilder, Struct};
+use leo_ast::{NodeBuilder, Program, Struct};
 use leo_compiler::{Compiler, CompilerOptions, InputAst, OutputOptions};
+use leo_errors::{
+    emitter::{DiagnosticRecord, DiagnosticRecords, DiagnosticSink},
+    LeoError,
+};
+use leo_lints::{LintLevels, Linter};
 use leo_package::{
     build::BuildDirectory,
-    imports::ImportsDirectory,
+    imports::{ImportsDirectory, IMPORTS_DIRECTORY_NAME},
     inputs::InputFile,
-    outputs::OutputsDirectory,
+    outputs::{
+        ArtifactsFile,
+        BuildReport,
+        Constraint,
+        ConstraintSystem,
+        ConstraintSystemFormat,
+        DiagnosticsReport,
+        FunctionConstraints,
+        OutputsDirectory,
+        ProgramReport,
+        StageTiming,
+        Term,
+        Witness,
+    },
     source::SourceDirectory,
+    workspace::Workspace,
 };
+use leo_parser::ImportPaths;
+use leo_passes::RecordSchema;
 use leo_span::{symbol::with_session_globals, Symbol};
 
 use snarkvm::{
+    circuit::AleoV0,
     package::Package,
-    prelude::{ProgramID, Testnet3},
+    prelude::{Identifier, PrimeField, ProgramID, Testnet3, ToBytes},
 };
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use std::{
     io::Write,
     path::{Path, PathBuf},
+    str::FromStr,
 };
 
 impl From<BuildOptions> for CompilerOptions {
     fn from(options: BuildOptions) -> Self {
         let mut out_options = Self {
-            build: leo_compiler::BuildOptions { dce_enabled: options.enable_dce },
+            build: leo_compiler::BuildOptions {
+                dce_enabled: options.enable_dce,
+                max_unrolled_statements: options.max_unrolled_statements,
+                max_unrolled_iterations: options.max_unrolled_iterations,
+                max_expression_depth: options.max_expression_depth,
+                max_array_size: options.max_array_size,
+                max_identifiers: options.max_identifiers,
+                record_schema: match options.record_schema {
+                    Some(RecordSchemaProfile::Strict) => RecordSchema::strict(),
+                    Some(RecordSchemaProfile::Aleo) | None => RecordSchema::aleo(),
+                },
+            },
             output: OutputOptions {
                 symbol_table_spans_enabled: options.enable_symbol_table_spans,
                 initial_symbol_table: options.enable_initial_symbol_table_snapshot,
@@ -56,6 +90,10 @@ impl From<BuildOptions> for CompilerOptions {
                 destructured_ast: options.enable_destructured_ast_snapshot,
                 inlined_ast: options.enable_inlined_ast_snapshot,
                 dce_ast: options.enable_dce_ast_snapshot,
+                timings: options.timings,
+                call_graph: options.enable_call_graph_export,
+                information_flow: options.enable_information_flow_report,
+                expanded: options.emit.contains(&Emit::Expanded),
             },
         };
         if options.enable_all_ast_snapshots {
@@ -78,6 +116,8 @@ impl From<BuildOptions> for CompilerOptions {
 pub struct Build {
     #[clap(flatten)]
     pub(crate) options: BuildOptions,
+    #[clap(long, help = "Builds every member of the workspace, in the order in which they are listed.")]
+    pub(crate) all: bool,
 }
 
 impl Command for Build {
@@ -93,27 +133,101 @@ impl Command for Build {
     }
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        match self.all {
+            true => self.build_workspace(context),
+            false => self.build_package(context),
+        }
+    }
+}
+
+impl Build {
+    /// Builds every member of the workspace containing the directory of the context.
+    /// Members import one another from their source files, so they can be built in any order.
+    fn build_workspace(&self, context: Context) -> Result<<Self as Command>::Output> {
+        let path = context.dir()?;
+        let workspace = Workspace::find(&path)?.ok_or_else(|| CliError::not_in_a_workspace(path.display()))?;
+
+        // Imports are resolved against the current directory, so each member is built from its own directory.
+        let current_dir = std::env::current_dir().map_err(CliError::cli_io_error)?;
+        let mut structs = IndexMap::new();
+        let result = workspace.members.iter().try_for_each(|member| {
+            tracing::info!("Building workspace member '{}'", member.program);
+            std::env::set_current_dir(&member.path)
+                .map_err(|err| PackageError::failed_to_set_cwd(member.path.display(), err))?;
+            let (_, member_structs) = self.build_package(Context::new(Some(member.path.clone()))?)?;
+            structs.extend(member_structs);
+            Ok::<_, LeoError>(())
+        });
+        std::env::set_current_dir(&current_dir)
+            .map_err(|err| PackageError::failed_to_set_cwd(current_dir.display(), err))?;
+        result?;
+
+        Ok((None, structs))
+    }
+
+    /// Builds the package in the directory of the context, writing the build report if it is requested.
+    fn build_package(&self, context: Context) -> Result<<Self as Command>::Output> {
         // Get the package path.
         let package_path = context.dir()?;
 
+        // Initialize error handler.
+        // Diagnostics are deduplicated, sorted, and written once the build is done.
+        let records = DiagnosticRecords::default();
+        let handler =
+            Handler::new(Box::new(DiagnosticSink::new(self.options.max_errors).with_records(records.clone())));
+
+        // The programs compiled by the build, summarized for the build report.
+        let mut programs = Vec::new();
+
+        let result = self.build(context, &package_path, &handler, &mut programs);
+
+        // Write the build report, whether or not the build succeeded.
+        let report = match self.options.build_report {
+            true => {
+                let mut messages = records.take();
+                // An error that ends the build is returned rather than emitted, unless it was emitted already.
+                messages.extend(result.as_ref().err().and_then(DiagnosticRecord::from_error));
+                ArtifactsFile::collect(&package_path).and_then(|artifacts| {
+                    BuildReport {
+                        succeeded: result.is_ok(),
+                        diagnostics: DiagnosticsReport::new(messages),
+                        programs,
+                        artifacts: artifacts.artifacts,
+                    }
+                    .write_to(&package_path)
+                })
+            }
+            false => Ok(()),
+        };
+
+        let output = result?;
+        report?;
+        Ok(output)
+    }
+
+    /// Compiles the package at `package_path`, summarizing each compiled program in `programs`.
+    fn build(
+        &self,
+        context: Context,
+        package_path: &Path,
+        handler: &Handler,
+        programs: &mut Vec<ProgramReport>,
+    ) -> Result<<Self as Command>::Output> {
         // Get the program id.
         let manifest = context.open_manifest()?;
         let program_id = manifest.program_id();
 
         // Create the outputs directory.
-        let outputs_directory = OutputsDirectory::create(&package_path)?;
+        let outputs_directory = OutputsDirectory::create(package_path)?;
 
         // Open the build directory.
-        let build_directory = BuildDirectory::open(&package_path)?;
-
-        // Initialize error handler
-        let handler = Handler::default();
+        let build_directory = BuildDirectory::open(package_path)?;
 
         // Initialize a node counter.
         let node_builder = NodeBuilder::default();
 
         // Fetch paths to all .leo files in the source directory.
-        let source_files = SourceDirectory::files(&package_path)?;
+        let source_files = SourceDirectory::files(package_path)?;
 
         // Check the source files.
         SourceDirectory::check_files(&source_files)?;
@@ -121,44 +235,108 @@ impl Command for Build {
         // Store all struct declarations made in the source files.
         let mut structs = IndexMap::new();
 
+        // Store the constraint budgets of the transitions, which are checked once the package is built.
+        let mut budgets = Vec::new();
+
+        // If the package is a member of a workspace, it imports the other members from their source files,
+        // and can import the programs in the shared `imports/` directory of the workspace.
+        let workspace = Workspace::of_member(package_path)?;
+        let import_paths = match &workspace {
+            Some(workspace) => ImportPaths {
+                programs: workspace.programs(package_path),
+                directories: vec![workspace.imports_directory()],
+                ..Default::default()
+            },
+            None => ImportPaths::default(),
+        };
+
+        // The names of the compiled programs, and of the programs they import.
+        let mut compiled = IndexSet::from([program_id.name().to_string()]);
+        let mut imported = Vec::new();
+
         // Compile all .leo files into .aleo files.
         for file_path in source_files.into_iter() {
-            structs.extend(compile_leo_file(
+            let (program_structs, program_budgets, program_report, program_imports) = compile_leo_file(
                 file_path,
-                &package_path,
+                program_id.name().to_string(),
                 program_id,
                 &outputs_directory,
                 &build_directory,
-                &handler,
+                handler,
                 self.options.clone(),
+                &import_paths,
                 false,
-            )?);
+            )?;
+            structs.extend(program_structs);
+            budgets.extend(program_budgets);
+            programs.push(program_report);
+            imported.extend(program_imports);
         }
 
-        if !ImportsDirectory::is_empty(&package_path)? {
+        if !ImportsDirectory::is_empty(package_path)? {
             // Create Aleo build/imports/ directory.
             let build_imports_directory = ImportsDirectory::create(&build_directory)?;
 
             // Fetch paths to all .leo files in the imports directory.
-            let import_files = ImportsDirectory::files(&package_path)?;
+            let import_files = ImportsDirectory::files(package_path)?;
+            let imports_directory = package_path.join(IMPORTS_DIRECTORY_NAME);
 
             // Compile all .leo files into .aleo files.
             for file_path in import_files.into_iter() {
-                structs.extend(compile_leo_file(
+                let program_name = ImportsDirectory::program_name(&imports_directory, &file_path)?.to_string();
+                // Members of the workspace are imported from their source files instead.
+                if import_paths.programs.contains_key(&program_name) {
+                    continue;
+                }
+                compiled.insert(program_name.clone());
+                let (program_structs, program_budgets, program_report, program_imports) = compile_leo_file(
                     file_path,
-                    &package_path,
+                    program_name,
                     program_id,
                     &outputs_directory,
                     &build_imports_directory,
-                    &handler,
+                    handler,
+                    self.options.clone(),
+                    &import_paths,
+                    true,
+                )?;
+                structs.extend(program_structs);
+                budgets.extend(program_budgets);
+                programs.push(program_report);
+                imported.extend(program_imports);
+            }
+        }
+
+        // Compile the programs imported from the workspace, along with the programs they import in turn.
+        if let Some(workspace) = &workspace {
+            let shared_imports = workspace.shared_imports()?;
+            while let Some(program_name) = imported.pop() {
+                // Programs in the `imports/` directory of the package, and programs imported twice, are compiled already.
+                let file_path =
+                    match import_paths.programs.get(&program_name).or_else(|| shared_imports.get(&program_name)) {
+                        Some(file_path) if compiled.insert(program_name.clone()) => file_path.clone(),
+                        _ => continue,
+                    };
+                let (program_structs, program_budgets, program_report, program_imports) = compile_leo_file(
+                    file_path,
+                    program_name,
+                    program_id,
+                    &outputs_directory,
+                    &ImportsDirectory::create(&build_directory)?,
+                    handler,
                     self.options.clone(),
+                    &import_paths,
                     true,
-                )?);
+                )?;
+                structs.extend(program_structs);
+                budgets.extend(program_budgets);
+                programs.push(program_report);
+                imported.extend(program_imports);
             }
         }
 
         // Load the input file at `package_name.in`
-        let input_file_path = InputFile::new(&manifest.program_id().name().to_string()).setup_file_path(&package_path);
+        let input_file_path = InputFile::new(&manifest.program_id().name().to_string()).setup_file_path(package_path);
 
         // Parse the input file.
         let input_ast = if input_file_path.exists() {
@@ -167,7 +345,7 @@ impl Command for Build {
                 .map_err(|e| CompilerError::file_read_error(&input_file_path, e))?;
 
             // TODO: This is a hack to notify the user that something is wrong with the input file. Redesign.
-            leo_parser::parse_input(&handler, &node_builder, &input_sf.src, input_sf.start_pos)
+            leo_parser::parse_input(handler, &node_builder, &input_sf.src, input_sf.start_pos)
                 .map_err(|_e| println!("Warning: Failed to parse input file"))
                 .ok()
         } else {
@@ -175,7 +353,29 @@ impl Command for Build {
         };
 
         // `Package::open` checks that the build directory and that `main.aleo` and all imported files are well-formed.
-        Package::<CurrentNetwork>::open(&build_directory).map_err(CliError::failed_to_execute_build)?;
+        let package = Package::<CurrentNetwork>::open(&build_directory).map_err(CliError::failed_to_execute_build)?;
+
+        // Check that the synthesized circuits are within their constraint budgets, and count the constraints of
+        // every transition if a build report is requested.
+        synthesize_circuits(&package, &budgets, self.options.build_report.then_some(programs.as_mut_slice()))?;
+
+        // Export the constraint systems of the package's transitions, and check the given witnesses against them.
+        let formats: Vec<_> = self
+            .options
+            .emit
+            .iter()
+            .filter_map(|emit| match emit {
+                Emit::R1cs => Some(ConstraintSystemFormat::R1cs),
+                Emit::R1csJson => Some(ConstraintSystemFormat::Json),
+                Emit::Expanded => None,
+            })
+            .collect();
+        let witnesses =
+            self.options.check_witness.iter().map(|path| Witness::read_from(path)).collect::<Result<Vec<_>>>()?;
+        export_constraint_systems(&package, package_path, program_id, &formats, &witnesses)?;
+
+        // Record the files produced by the build, so that tooling and `leo clean` know where to find them.
+        ArtifactsFile::collect(package_path)?.write_to(package_path)?;
 
         // // Unset the Leo panic hook.
         // let _ = std::panic::take_hook();
@@ -199,29 +399,32 @@ impl Command for Build {
     }
 }
 
-/// Compiles a Leo file in the `src/` directory.
+/// The constraint budget of a transition, declared with `@max_constraints`.
+struct ConstraintBudget {
+    program_id: ProgramID<CurrentNetwork>,
+    function_name: String,
+    budget: u64,
+}
+
+/// Compiles the Leo file of the program `program_name`, which is imported by the package if `is_import` is set.
+/// Returns the structs declared in the file, the constraint budgets of its transitions, a summary for the build report,
+/// and the names of the programs it imports, directly or indirectly.
 #[allow(clippy::too_many_arguments)]
 fn compile_leo_file(
     file_path: PathBuf,
-    _package_path: &Path,
+    program_name: String,
     program_id: &ProgramID<Testnet3>,
     outputs: &Path,
     build: &Path,
     handler: &Handler,
     options: BuildOptions,
+    import_paths: &ImportPaths,
     is_import: bool,
-) -> Result<IndexMap<Symbol, Struct>> {
+) -> Result<(IndexMap<Symbol, Struct>, Vec<ConstraintBudget>, ProgramReport, Vec<String>)> {
     // Construct the Leo file name with extension `foo.leo`.
     let file_name =
         file_path.file_name().and_then(|name| name.to_str()).ok_or_else(PackageError::failed_to_get_file_name)?;
 
-    // If the program is an import, construct program name from file_path
-    // Otherwise, use the program_id found in `package.json`.
-    let program_name = match is_import {
-        false => program_id.name().to_string(),
-        true => file_name.strip_suffix(".leo").ok_or_else(PackageError::failed_to_get_file_name)?.to_string(),
-    };
-
     // Create the path to the Aleo file.
     let mut aleo_file_path = build.to_path_buf();
     aleo_file_path.push(match is_import {
@@ -229,6 +432,12 @@ fn compile_leo_file(
         false => format!("main.{}", program_id.network()),
     });
 
+    // Whether to report the time spent in each compiler pass.
+    let report_timings = options.timings;
+
+    // The levels of the lints, which are only checked on the package's own program.
+    let lint_levels = LintLevels::parse(&options.lint)?;
+
     // Create a new instance of the Leo compiler.
     let mut compiler = Compiler::new(
         program_name,
@@ -237,7 +446,11 @@ fn compile_leo_file(
         file_path.clone(),
         outputs.to_path_buf(),
         Some(options.into()),
-    );
+    )
+    .with_import_paths(import_paths.clone());
+    if !is_import {
+        compiler = compiler.with_pass(Linter::new(lint_levels));
+    }
 
     // Compile the Leo program into Aleo instructions.
     let (symbol_table, instructions) = compiler.compile()?;
@@ -249,5 +462,250 @@ fn compile_leo_file(
         .map_err(CliError::failed_to_load_instructions)?;
 
     tracing::info!("✅ Compiled '{}' into Aleo instructions", file_name);
-    Ok(symbol_table.structs)
+
+    // Report the time spent in each compiler pass.
+    if report_timings {
+        tracing::info!("⏱️  Compiler timings for '{}':\n{}", file_name, compiler.timings());
+    }
+
+    // Collect the constraint budgets of the program's transitions.
+    let compiled_program_id = match is_import {
+        true => ProgramID::from_str(&format!("{}.{}", compiler.program_name, program_id.network()))
+            .map_err(CliError::failed_to_execute_build)?,
+        false => *program_id,
+    };
+    let budgets = compiler
+        .ast
+        .ast
+        .program_scopes
+        .values()
+        .flat_map(|scope| scope.functions.iter())
+        .filter_map(|(name, function)| {
+            function.max_constraints().map(|budget| ConstraintBudget {
+                program_id: compiled_program_id,
+                function_name: name.to_string(),
+                budget,
+            })
+        })
+        .collect();
+
+    // Summarize the compilation for the build report.
+    let report = ProgramReport {
+        program: compiled_program_id.to_string(),
+        timings: compiler
+            .timings()
+            .stages()
+            .iter()
+            .map(|(stage, elapsed)| StageTiming {
+                stage: stage.to_string(),
+                milliseconds: elapsed.as_secs_f64() * 1000.0,
+            })
+            .collect(),
+        constraints: Vec::new(),
+    };
+
+    Ok((symbol_table.structs, budgets, report, imported_programs(&compiler.ast.ast)))
+}
+
+/// Returns the names of the programs imported by `program`, directly or indirectly.
+pub(crate) fn imported_programs(program: &Program) -> Vec<String> {
+    fn collect_imports(program: &Program, imports: &mut Vec<String>) {
+        for (name, (import, _)) in program.imports.iter() {
+            imports.push(name.to_string());
+            collect_imports(import, imports);
+        }
+    }
+    let mut imports = Vec::new();
+    collect_imports(program, &mut imports);
+    imports
+}
+
+/// Synthesizes the circuit of each transition with a constraint budget, and checks that it is within the budget.
+/// If `reports` are given, the circuit of every transition of the reported programs is synthesized and counted.
+fn synthesize_circuits(
+    package: &Package<CurrentNetwork>,
+    budgets: &[ConstraintBudget],
+    reports: Option<&mut [ProgramReport]>,
+) -> Result<()> {
+    // Synthesizing circuits is expensive, so only load the process if there is a circuit to synthesize.
+    if budgets.is_empty() && reports.is_none() {
+        return Ok(());
+    }
+    let process = package.get_process().map_err(CliError::failed_to_execute_build)?;
+    let rng = &mut rand::thread_rng();
+
+    // Synthesizes the circuit of a transition and counts its constraints.
+    let mut count_constraints = |program_id: &ProgramID<CurrentNetwork>, function_name: &Identifier<CurrentNetwork>| {
+        process
+            .synthesize_key::<AleoV0, _>(program_id, function_name, rng)
+            .map_err(CliError::failed_to_execute_build)?;
+        let verifying_key =
+            process.get_verifying_key(*program_id, *function_name).map_err(CliError::failed_to_execute_build)?;
+        Ok::<_, LeoError>(verifying_key.circuit_info.num_constraints as u64)
+    };
+
+    for ConstraintBudget { program_id, function_name, budget } in budgets.iter() {
+        let function_name =
+            Identifier::<CurrentNetwork>::from_str(function_name).map_err(CliError::failed_to_execute_build)?;
+
+        // Synthesize the circuit and count its constraints.
+        let num_constraints = count_constraints(program_id, &function_name)?;
+
+        if num_constraints > *budget {
+            // Report the instructions that occur most often in the function, which are the likeliest contributors.
+            let function = process
+                .get_program(*program_id)
+                .and_then(|program| program.get_function(&function_name))
+                .map_err(CliError::failed_to_execute_build)?;
+            let mut opcodes: IndexMap<String, usize> = IndexMap::new();
+            for instruction in function.instructions() {
+                *opcodes.entry(instruction.opcode().to_string()).or_default() += 1;
+            }
+            opcodes.sort_by(|_, a, _, b| b.cmp(a));
+            let contributors = opcodes
+                .iter()
+                .take(5)
+                .map(|(opcode, count)| format!("`{opcode}` ({count}x)"))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            return Err(CliError::constraint_budget_exceeded(
+                format!("{program_id}/{function_name}"),
+                num_constraints,
+                budget,
+                contributors,
+            )
+            .into());
+        }
+
+        tracing::info!("✅ '{program_id}/{function_name}' uses {num_constraints} of its {budget} constraints");
+    }
+
+    for report in reports.into_iter().flatten() {
+        let program_id =
+            ProgramID::<CurrentNetwork>::from_str(&report.program).map_err(CliError::failed_to_execute_build)?;
+        let function_names: Vec<_> = process
+            .get_program(program_id)
+            .map_err(CliError::failed_to_execute_build)?
+            .functions()
+            .keys()
+            .copied()
+            .collect();
+        for function_name in function_names.iter() {
+            let constraints = count_constraints(&program_id, function_name)?;
+            report.constraints.push(FunctionConstraints { function: function_name.to_string(), constraints });
+        }
+    }
+
+    Ok(())
+}
+
+/// Synthesizes the circuit of every transition of the program, and writes its constraint system to the outputs
+/// directory in each of the given formats, as `{program}.{transition}.{extension}`.
+/// Each of the witnesses is checked against the constraint system of its transition.
+fn export_constraint_systems(
+    package: &Package<CurrentNetwork>,
+    package_path: &Path,
+    program_id: &ProgramID<CurrentNetwork>,
+    formats: &[ConstraintSystemFormat],
+    witnesses: &[Witness],
+) -> Result<()> {
+    // Synthesizing circuits is expensive, so only load the process if a constraint system is needed.
+    if formats.is_empty() && witnesses.is_empty() {
+        return Ok(());
+    }
+
+    let process = package.get_process().map_err(CliError::failed_to_execute_build)?;
+    let rng = &mut rand::thread_rng();
+
+    let function_names: Vec<_> = process
+        .get_program(*program_id)
+        .map_err(CliError::failed_to_execute_build)?
+        .functions()
+        .keys()
+        .copied()
+        .collect();
+
+    // Every witness must be for a transition of the program.
+    if let Some(witness) = witnesses.iter().find(|witness| {
+        witness.program != program_id.to_string()
+            || !function_names.iter().any(|function_name| witness.function == function_name.to_string())
+    }) {
+        return Err(PackageError::invalid_witness(
+            &witness.function,
+            format!("`{program_id}` has no transition `{}/{}`", witness.program, witness.function),
+        )
+        .into());
+    }
+
+    for function_name in function_names.iter() {
+        let function_witnesses: Vec<_> =
+            witnesses.iter().filter(|witness| witness.function == function_name.to_string()).collect();
+        if formats.is_empty() && function_witnesses.is_empty() {
+            continue;
+        }
+
+        process
+            .synthesize_key::<AleoV0, _>(program_id, function_name, rng)
+            .map_err(CliError::failed_to_execute_build)?;
+        let proving_key =
+            process.get_proving_key(*program_id, *function_name).map_err(CliError::failed_to_execute_build)?;
+        let circuit = &proving_key.circuit;
+        let constraint_system =
+            to_constraint_system(circuit.index_info.num_public_inputs, &circuit.a, &circuit.b, &circuit.c)?;
+
+        for format in formats.iter() {
+            let name = format!("{}.{function_name}", program_id.name());
+            let path = constraint_system.write_to(package_path, &name, *format)?;
+            tracing::info!("✅ Wrote the constraint system of '{program_id}/{function_name}' to '{}'", path.display());
+        }
+
+        for witness in function_witnesses {
+            constraint_system.check_witness(witness)?;
+            tracing::info!("✅ The witness satisfies the constraint system of '{program_id}/{function_name}'");
+        }
+    }
+
+    Ok(())
+}
+
+/// Converts the matrices of an indexed circuit, whose rows list the coefficients and columns of the wires of each
+/// constraint, into a constraint system. The first `num_public_inputs` columns, starting with the constant one,
+/// are public. The indexer pads the synthesized circuit, so the constraint system may have more constraints and wires
+/// than the circuit reports, but it is satisfied by exactly the same assignments.
+fn to_constraint_system<F: PrimeField>(
+    num_public_inputs: usize,
+    a: &[Vec<(F, usize)>],
+    b: &[Vec<(F, usize)>],
+    c: &[Vec<(F, usize)>],
+) -> Result<ConstraintSystem> {
+    let to_terms = |row: &Vec<(F, usize)>| {
+        row.iter()
+            .map(|(coefficient, column)| {
+                let coefficient = coefficient.to_bytes_le().map_err(CliError::failed_to_execute_build)?;
+                Ok(Term { wire: *column as u32, coefficient })
+            })
+            .collect::<Result<Vec<_>>>()
+    };
+    let constraints = a
+        .iter()
+        .zip(b.iter())
+        .zip(c.iter())
+        .map(|((a, b), c)| Ok(Constraint { a: to_terms(a)?, b: to_terms(b)?, c: to_terms(c)? }))
+        .collect::<Result<Vec<_>>>()?;
+
+    let num_wires = [a, b, c]
+        .iter()
+        .flat_map(|matrix| matrix.iter().flatten())
+        .map(|(_, column)| column + 1)
+        .chain([num_public_inputs])
+        .max()
+        .unwrap_or_default();
+
+    Ok(ConstraintSystem {
+        prime: F::modulus().to_bytes_le().map_err(CliError::failed_to_execute_build)?,
+        num_wires: num_wires as u32,
+        num_public_inputs: num_public_inputs.saturating_sub(1) as u32,
+        constraints,
+    })
 }
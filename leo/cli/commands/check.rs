@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{build::imported_programs, *};
+
+use leo_compiler::Compiler;
+use leo_errors::emitter::DiagnosticSink;
+use leo_lints::{LintLevels, Linter};
+use leo_package::{
+    imports::{ImportsDirectory, IMPORTS_DIRECTORY_NAME},
+    source::SourceDirectory,
+    workspace::Workspace,
+};
+use leo_parser::ImportPaths;
+
+use indexmap::IndexSet;
+use std::path::{Path, PathBuf};
+
+/// Check the current package for errors, without compiling it or synthesizing its circuits.
+#[derive(Parser, Debug)]
+pub struct Check {
+    #[clap(long, help = "Reports at most this many errors, followed by the number of errors omitted.")]
+    pub(crate) max_errors: Option<usize>,
+    #[clap(
+        long,
+        value_name = "LINT=LEVEL",
+        help = "Sets the level of a lint to `allow`, `warn`, or `deny`, e.g. `--lint magic-numbers=warn`."
+    )]
+    pub(crate) lint: Vec<String>,
+    #[clap(long, help = "Reports the time spent in each compiler pass.")]
+    pub(crate) timings: bool,
+    #[clap(long, value_enum, help = "Checks records against the schema of the given profile instead of `aleo`.")]
+    pub(crate) record_schema: Option<RecordSchemaProfile>,
+}
+
+impl Command for Check {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Get the package path and the program id.
+        let package_path = context.dir()?;
+        let manifest = context.open_manifest()?;
+        let program_id = manifest.program_id();
+        let network = program_id.network().to_string();
+
+        // Diagnostics are deduplicated, sorted, and written once the check is done.
+        let handler = Handler::new(Box::new(DiagnosticSink::new(self.max_errors)));
+
+        // The timing report is the only output of a check.
+        let outputs_directory = OutputsDirectory::create(&package_path)?;
+
+        // Fetch paths to all .leo files in the source directory.
+        let source_files = SourceDirectory::files(&package_path)?;
+        SourceDirectory::check_files(&source_files)?;
+
+        // Programs are imported as they are by `leo build`.
+        let workspace = Workspace::of_member(&package_path)?;
+        let import_paths = match &workspace {
+            Some(workspace) => ImportPaths {
+                programs: workspace.programs(&package_path),
+                directories: vec![workspace.imports_directory()],
+                ..Default::default()
+            },
+            None => ImportPaths::default(),
+        };
+
+        // The names of the checked programs, and of the programs they import.
+        let mut checked = IndexSet::from([program_id.name().to_string()]);
+        let mut imported = Vec::new();
+
+        // The lints are only checked on the package's own program.
+        let lint_levels = LintLevels::parse(&self.lint)?;
+        for file_path in source_files.into_iter() {
+            imported.extend(self.check_leo_file(
+                file_path,
+                program_id.name().to_string(),
+                &network,
+                &outputs_directory,
+                &handler,
+                &import_paths,
+                Some(lint_levels.clone()),
+            )?);
+        }
+
+        if !ImportsDirectory::is_empty(&package_path)? {
+            let imports_directory = package_path.join(IMPORTS_DIRECTORY_NAME);
+            for file_path in ImportsDirectory::files(&package_path)?.into_iter() {
+                let program_name = ImportsDirectory::program_name(&imports_directory, &file_path)?.to_string();
+                // Members of the workspace are imported from their source files instead.
+                if import_paths.programs.contains_key(&program_name) {
+                    continue;
+                }
+                checked.insert(program_name.clone());
+                imported.extend(self.check_leo_file(
+                    file_path,
+                    program_name,
+                    &network,
+                    &outputs_directory,
+                    &handler,
+                    &import_paths,
+                    None,
+                )?);
+            }
+        }
+
+        // Check the programs imported from the workspace, along with the programs they import in turn.
+        if let Some(workspace) = &workspace {
+            let shared_imports = workspace.shared_imports()?;
+            while let Some(program_name) = imported.pop() {
+                let file_path =
+                    match import_paths.programs.get(&program_name).or_else(|| shared_imports.get(&program_name)) {
+                        Some(file_path) if checked.insert(program_name.clone()) => file_path.clone(),
+                        _ => continue,
+                    };
+                imported.extend(self.check_leo_file(
+                    file_path,
+                    program_name,
+                    &network,
+                    &outputs_directory,
+                    &handler,
+                    &import_paths,
+                    None,
+                )?);
+            }
+        }
+
+        tracing::info!("✅ Checked '{}' and {} imported program(s)", program_id, checked.len() - 1);
+
+        Ok(())
+    }
+}
+
+impl Check {
+    /// Checks the Leo file of the program `program_name`, and its lints if `lint_levels` are given.
+    /// Returns the names of the programs it imports, directly or indirectly.
+    #[allow(clippy::too_many_arguments)]
+    fn check_leo_file(
+        &self,
+        file_path: PathBuf,
+        program_name: String,
+        network: &str,
+        outputs: &Path,
+        handler: &Handler,
+        import_paths: &ImportPaths,
+        lint_levels: Option<LintLevels>,
+    ) -> Result<Vec<String>> {
+        let options = BuildOptions { timings: self.timings, record_schema: self.record_schema, ..Default::default() };
+        let mut compiler = Compiler::new(
+            program_name,
+            network.to_string(),
+            handler,
+            file_path.clone(),
+            outputs.to_path_buf(),
+            Some(options.into()),
+        )
+        .with_import_paths(import_paths.clone());
+        if let Some(lint_levels) = lint_levels {
+            compiler = compiler.with_pass(Linter::new(lint_levels));
+        }
+
+        compiler.check()?;
+
+        if self.timings {
+            tracing::info!("⏱️  Compiler timings for '{}':\n{}", file_path.display(), compiler.timings());
+        }
+
+        Ok(imported_programs(&compiler.ast.ast))
+    }
+}
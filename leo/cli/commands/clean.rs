@@ -35,6 +35,20 @@ impl Command for Clean {
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
         let path = context.dir()?;
 
+        // If the last build recorded its artifacts, remove exactly those files.
+        // If they cannot be removed, e.g. because the artifacts file lists a path outside the package,
+        // the build and outputs directories are removed instead.
+        match ArtifactsFile::read_from(&path).and_then(|artifacts| artifacts.map(|a| a.remove(&path)).transpose()) {
+            Ok(Some(removed)) => {
+                let package_path = format!("(in \"{}\")", path.display());
+                tracing::info!("🧹 Removed {removed} build artifacts {}", package_path.dimmed());
+                return Ok(());
+            }
+            Ok(None) => {}
+            Err(error) => tracing::info!("⚠️  {error}\n"),
+        }
+
+        // Otherwise, remove the build and outputs directories entirely.
         // Removes the outputs/ directory.
         let outputs_path = OutputsDirectory::remove(&path)?;
         tracing::info!("🧹 Cleaned the outputs directory {}", outputs_path.dimmed());
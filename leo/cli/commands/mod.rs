@@ -20,6 +20,9 @@ pub use account::Account;
 pub mod build;
 pub use build::Build;
 
+pub mod check;
+pub use check::Check;
+
 pub mod clean;
 pub use clean::Clean;
 
@@ -38,6 +41,9 @@ pub use new::New;
 // pub mod node;
 // pub use node::Node;
 
+pub mod rename;
+pub use rename::Rename;
+
 pub mod run;
 pub use run::Run;
 
@@ -47,10 +53,15 @@ pub use update::Update;
 use super::*;
 use crate::cli::helpers::context::*;
 use leo_errors::{emitter::Handler, CliError, CompilerError, PackageError, Result};
-use leo_package::{build::*, outputs::OutputsDirectory, package::*};
+use leo_package::{
+    build::*,
+    outputs::{ArtifactsFile, OutputsDirectory},
+    package::*,
+};
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use colored::Colorize;
+use std::path::PathBuf;
 use tracing::span::Span;
 
 /// Base trait for the Leo CLI, see methods and their documentation for details.
@@ -151,4 +162,64 @@ pub struct BuildOptions {
     pub enable_inlined_ast_snapshot: bool,
     #[clap(long, help = "Writes AST snapshot of the dead code eliminated (DCE) AST.")]
     pub enable_dce_ast_snapshot: bool,
+    #[clap(long, help = "Reports the time spent in each compiler pass.")]
+    pub timings: bool,
+    #[clap(long, help = "Writes the call graph of the program and its imports as JSON and DOT.")]
+    pub enable_call_graph_export: bool,
+    #[clap(long, help = "Writes a report of the public outputs of each transition that depend on its private inputs.")]
+    pub enable_information_flow_report: bool,
+    #[clap(long, help = "Fails the build if unrolling loops grows any function beyond this many statements.")]
+    pub max_unrolled_statements: Option<usize>,
+    #[clap(long, help = "Fails the build if unrolling loops grows any function beyond this many iterations.")]
+    pub max_unrolled_iterations: Option<usize>,
+    #[clap(long, help = "Fails the build if expressions, statements, or types nest deeper than this. Defaults to 128.")]
+    pub max_expression_depth: Option<usize>,
+    #[clap(long, help = "Fails the build if any array type has more elements than this, counting nested arrays.")]
+    pub max_array_size: Option<usize>,
+    #[clap(long, help = "Fails the build if any file contains more distinct identifiers than this.")]
+    pub max_identifiers: Option<usize>,
+    #[clap(long, help = "Reports at most this many errors, followed by the number of errors omitted.")]
+    pub max_errors: Option<usize>,
+    #[clap(long, help = "Writes a summary of the build to `outputs/build-report.json`.")]
+    pub build_report: bool,
+    #[clap(
+        long,
+        value_enum,
+        help = "Writes an additional output to the outputs directory, e.g. the expanded program."
+    )]
+    pub emit: Vec<Emit>,
+    #[clap(
+        long,
+        value_name = "LINT=LEVEL",
+        help = "Sets the level of a lint to `allow`, `warn`, or `deny`, e.g. `--lint magic-numbers=warn`."
+    )]
+    pub lint: Vec<String>,
+    #[clap(long, value_enum, help = "Checks records against the schema of the given profile instead of `aleo`.")]
+    pub record_schema: Option<RecordSchemaProfile>,
+    #[clap(
+        long,
+        value_name = "WITNESS",
+        help = "Checks that the witness in the given file satisfies the constraint system of its transition."
+    )]
+    pub check_witness: Vec<PathBuf>,
+}
+
+/// The profile of the schema that records are checked against, selected with `--record-schema`.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecordSchemaProfile {
+    /// Records must have an `owner: address` member.
+    Aleo,
+    /// Records must have an `owner: address` member, declared first.
+    Strict,
+}
+
+/// An additional output of the compiler, selected with `--emit`.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Emit {
+    /// The program as Leo source after constant propagation, loop unrolling, and function inlining.
+    Expanded,
+    /// The constraint system of each transition, in the binary `.r1cs` format of circom and snarkjs.
+    R1cs,
+    /// The constraint system of each transition, as JSON with one row per constraint.
+    R1csJson,
 }
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_compiler::{Compiler, RenameEdit};
+use leo_package::source::SourceDirectory;
+use leo_passes::DefinitionKind;
+
+use indexmap::IndexMap;
+
+/// Rename a struct, record, function, mapping, or program constant across the package and its imports.
+#[derive(Parser, Debug)]
+pub struct Rename {
+    #[clap(name = "NAME", help = "The name of the struct, record, function, mapping, or program constant to rename")]
+    pub(crate) name: String,
+    #[clap(name = "NEW_NAME", help = "The new name")]
+    pub(crate) new_name: String,
+}
+
+impl Command for Rename {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Get the package path and the program id.
+        let package_path = context.dir()?;
+        let manifest = context.open_manifest()?;
+        let program_id = manifest.program_id();
+
+        // Fetch the path to the main file.
+        let source_files = SourceDirectory::files(&package_path)?;
+        SourceDirectory::check_files(&source_files)?;
+
+        // Resolve the names in the program and its imports.
+        let handler = Handler::default();
+        let mut compiler = Compiler::new(
+            program_id.name().to_string(),
+            program_id.network().to_string(),
+            &handler,
+            source_files[0].clone(),
+            OutputsDirectory::create(&package_path)?,
+            None,
+        );
+        compiler.parse_program()?;
+        compiler.symbol_table_pass()?;
+        compiler.name_resolution_pass()?;
+
+        // Find the definition with the given name.
+        // Note that the items of the program and its imports are resolved before the bodies of its functions.
+        let definition = compiler
+            .resolution_table()
+            .definitions()
+            .into_iter()
+            .find(|definition| {
+                definition.name.to_string() == self.name
+                    && matches!(
+                        definition.kind,
+                        DefinitionKind::Const
+                            | DefinitionKind::Function
+                            | DefinitionKind::Mapping
+                            | DefinitionKind::Struct
+                    )
+            })
+            .ok_or_else(|| CliError::rename_target_not_found(&self.name))?;
+        let edits = compiler.rename(definition.span, &self.new_name)?;
+
+        // Apply the edits to each file, from the last to the first, so that the offsets remain valid.
+        let mut files: IndexMap<String, Vec<RenameEdit>> = IndexMap::new();
+        for edit in edits.iter() {
+            files.entry(edit.file.clone()).or_default().push(edit.clone());
+        }
+        for (file, edits) in files.iter() {
+            let mut source = std::fs::read_to_string(file).map_err(|e| CompilerError::file_read_error(file, e))?;
+            for edit in edits.iter().rev() {
+                source.replace_range(edit.range.0..edit.range.1, &edit.new_text);
+            }
+            std::fs::write(file, source).map_err(CliError::failed_to_write_file)?;
+        }
+
+        tracing::info!(
+            "✅ Renamed `{}` to `{}` in {} location(s) across {} file(s)",
+            self.name,
+            self.new_name,
+            edits.len(),
+            files.len()
+        );
+
+        Ok(())
+    }
+}
@@ -16,7 +16,19 @@
 
 use super::*;
 
-use snarkvm::cli::Run as SnarkVMRun;
+use leo_package::{imports::ImportsDirectory, source::SourceDirectory};
+
+use snarkvm::{
+    circuit::AleoV0,
+    cli::Run as SnarkVMRun,
+    package::Package,
+    prelude::{Identifier, PrivateKey, Value},
+};
+
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 /// Build, Prove and Run Leo program with inputs
 #[derive(Parser, Debug)]
@@ -40,7 +52,7 @@ impl Command for Run {
     }
 
     fn prelude(&self, context: Context) -> Result<Self::Input> {
-        (Build { options: self.compiler_options.clone() }).execute(context)
+        (Build { options: self.compiler_options.clone(), all: false }).execute(context)
     }
 
     fn apply(self, context: Context, input: Self::Input) -> Result<Self::Output> {
@@ -82,3 +94,155 @@ impl Command for Run {
         Ok(())
     }
 }
+
+/// A call made while executing a function, with the size of its circuit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceStep {
+    /// The program of the called function, e.g. `token.aleo`.
+    pub program_id: String,
+    /// The name of the called function.
+    pub function_name: String,
+    /// The number of instructions in the function.
+    pub num_instructions: usize,
+    /// The number of constraints of the circuit, including those checking the request and the response.
+    pub num_constraints: u64,
+}
+
+/// The result of executing a function of a Leo package.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Execution {
+    /// The outputs of the function, in the syntax of Aleo values.
+    pub outputs: Vec<String>,
+    /// The calls made while executing the function, including the call to the function itself.
+    pub trace: Vec<TraceStep>,
+}
+
+/// Executes `function` of the Leo package at `package_path` on `inputs`, given in the syntax of Aleo values.
+/// The package is built with `options` unless its build is up to date. The witness is generated with the private key
+/// in the `.env` file of the package, but no proof is produced.
+pub fn execute(package_path: &Path, function: &str, inputs: &[String], options: BuildOptions) -> Result<Execution> {
+    let package = open_package(package_path, options)?;
+    let private_key = load_private_key(package_path)?;
+    let function_name = Identifier::<CurrentNetwork>::from_str(function).map_err(CliError::failed_to_execute_run)?;
+    let inputs = parse_inputs(inputs)?;
+
+    // Generate the witness of the function and of each function that it calls.
+    let rng = &mut rand::thread_rng();
+    let (response, metrics) =
+        package.run::<AleoV0, _>(&private_key, function_name, &inputs, rng).map_err(CliError::failed_to_execute_run)?;
+
+    Ok(Execution {
+        outputs: response.outputs().iter().map(|output| output.to_string()).collect(),
+        trace: metrics
+            .iter()
+            .map(|call| TraceStep {
+                program_id: call.program_id.to_string(),
+                function_name: call.function_name.to_string(),
+                num_instructions: call.num_instructions,
+                num_constraints: call.num_request_constraints
+                    + call.num_function_constraints
+                    + call.num_response_constraints,
+            })
+            .collect(),
+    })
+}
+
+/// Returns `true` if the compiled program in the build directory is newer than the manifest and every source file.
+fn is_build_up_to_date(package_path: &Path) -> Result<bool> {
+    let modified = |path: &Path| std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok();
+
+    let Some(built) = modified(&package_path.join(BUILD_DIRECTORY_NAME).join("main.aleo")) else {
+        return Ok(false);
+    };
+
+    let mut sources: Vec<PathBuf> = SourceDirectory::files(package_path)?;
+    if !ImportsDirectory::is_empty(package_path)? {
+        sources.extend(ImportsDirectory::files(package_path)?);
+    }
+    sources.push(package_path.join("program.json"));
+
+    Ok(sources.iter().all(|source| matches!(modified(source), Some(source) if source <= built)))
+}
+
+/// The result of executing a function of a Leo package on many input sets.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatchExecution {
+    /// For each input set, in order, the outputs of the function in the syntax of Aleo values,
+    /// or the error raised while executing it, e.g. a failed assertion.
+    pub outputs: Vec<std::result::Result<Vec<String>, String>>,
+}
+
+/// Executes `function` of the Leo package at `package_path` on each of `input_sets`, as with [`execute`].
+/// The package is built and loaded once, and the circuit of the function is synthesized once, so that its proving key
+/// is shared by every execution. The witnesses are then generated in parallel, on up to `threads` threads.
+/// Note that the keys of functions in other programs are synthesized by the first execution that calls them.
+pub fn execute_batch(
+    package_path: &Path,
+    function: &str,
+    input_sets: &[Vec<String>],
+    options: BuildOptions,
+    threads: usize,
+) -> Result<BatchExecution> {
+    let package = open_package(package_path, options)?;
+    let private_key = load_private_key(package_path)?;
+    let function_name = Identifier::<CurrentNetwork>::from_str(function).map_err(CliError::failed_to_execute_run)?;
+    let input_sets = input_sets.iter().map(|inputs| parse_inputs(inputs)).collect::<Result<Vec<_>>>()?;
+
+    // Load the process and synthesize the proving key of the function.
+    let program_id = *package.program_id();
+    let process = package.get_process().map_err(CliError::failed_to_execute_run)?;
+    process
+        .synthesize_key::<AleoV0, _>(&program_id, &function_name, &mut rand::thread_rng())
+        .map_err(CliError::failed_to_execute_run)?;
+
+    // Executes the function on one input set.
+    let execute_one = |inputs: &Vec<Value<CurrentNetwork>>| {
+        let rng = &mut rand::thread_rng();
+        process
+            .authorize::<AleoV0, _>(&private_key, program_id, function_name, inputs.iter(), rng)
+            .and_then(|authorization| process.execute::<AleoV0, _>(authorization, rng))
+            .map(|(response, _)| response.outputs().iter().map(|output| output.to_string()).collect())
+            .map_err(|error| error.to_string())
+    };
+
+    // Split the input sets into contiguous chunks, one for each thread, so that the outputs stay in order.
+    let chunk_size = input_sets.len().div_ceil(threads.max(1)).max(1);
+    let outputs = std::thread::scope(|scope| {
+        let handles = input_sets
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(|| chunk.iter().map(execute_one).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+        // Note that a panic while generating a witness is propagated to the caller.
+        handles.into_iter().flat_map(|handle| handle.join().unwrap()).collect()
+    });
+
+    Ok(BatchExecution { outputs })
+}
+
+/// Opens the built package at `package_path`, building it with `options` unless the build is newer than its sources.
+fn open_package(package_path: &Path, options: BuildOptions) -> Result<Package<CurrentNetwork>> {
+    if !is_build_up_to_date(package_path)? {
+        Build { options, all: false }.execute(Context::new(Some(package_path.to_path_buf()))?)?;
+    }
+    Ok(Package::<CurrentNetwork>::open(&BuildDirectory::open(package_path)?)
+        .map_err(CliError::failed_to_execute_run)?)
+}
+
+/// Loads the private key from the `.env` file of the package at `package_path`.
+fn load_private_key(package_path: &Path) -> Result<PrivateKey<CurrentNetwork>> {
+    let path = package_path.join(".env");
+    let private_key = dotenvy::from_path_iter(&path)
+        .ok()
+        .and_then(|variables| variables.flatten().find(|(key, _)| key == "PRIVATE_KEY"))
+        .ok_or_else(|| CliError::failed_to_load_private_key(path.display()))?;
+    Ok(PrivateKey::<CurrentNetwork>::from_str(&private_key.1).map_err(CliError::failed_to_execute_run)?)
+}
+
+/// Parses inputs given in the syntax of Aleo values.
+fn parse_inputs(inputs: &[String]) -> Result<Vec<Value<CurrentNetwork>>> {
+    Ok(inputs
+        .iter()
+        .map(|input| Value::<CurrentNetwork>::from_str(input))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(CliError::failed_to_execute_run)?)
+}
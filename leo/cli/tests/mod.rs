@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+mod workspace;
+
 use leo_errors::Result;
 // use std::path::PathBuf;
 
@@ -0,0 +1,301 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Builds workspaces of Leo packages that import one another through their `imports/` directories.
+
+use crate::cli::{helpers::context::Context, Build, BuildOptions, Command};
+use leo_errors::Result;
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+/// Held while a package is built, since imports are resolved against the current directory.
+static CURRENT_DIR: Mutex<()> = Mutex::new(());
+
+const TOKEN: &str = "
+program token.aleo {
+    record Token {
+        owner: address,
+        amount: u64,
+    }
+
+    transition mint(owner: address, amount: u64) -> Token {
+        return Token { owner, amount };
+    }
+
+    inline double(amount: u64) -> u64 {
+        return amount * 2u64;
+    }
+}
+";
+
+const BANK: &str = "
+import token.leo;
+
+program bank.aleo {
+    transition deposit(owner: address, amount: u64) -> token.leo/Token.record {
+        return token.leo/mint(owner, amount);
+    }
+}
+";
+
+// Imports `token.leo` both directly and through `bank.leo`.
+const EXCHANGE: &str = "
+import token.leo;
+import bank.leo;
+
+program exchange.aleo {
+    transition swap(owner: address, amount: u64) -> (token.leo/Token.record, token.leo/Token.record) {
+        let minted: Token = token.leo/mint(owner, amount);
+        let deposited: Token = bank.leo/deposit(owner, amount);
+        return (minted, deposited);
+    }
+}
+";
+
+const FEES: &str = "
+program fees.aleo {
+    transition fee(amount: u64) -> u64 {
+        return amount / 100u64;
+    }
+}
+";
+
+const TELLER: &str = "
+import token.leo;
+import bank.leo;
+import fees.leo;
+
+program teller.aleo {
+    transition pay(owner: address, amount: u64) -> (token.leo/Token.record, u64) {
+        let deposited: Token = bank.leo/deposit(owner, amount);
+        let charged: u64 = fees.leo/fee(amount);
+        return (deposited, charged);
+    }
+}
+";
+
+/// A directory of Leo packages, removed when dropped.
+struct Workspace {
+    root: PathBuf,
+}
+
+impl Workspace {
+    fn new(name: &str) -> Self {
+        let root = std::env::temp_dir().join(format!("leo-workspace-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        Self { root }
+    }
+
+    fn package(&self, name: &str) -> PathBuf {
+        self.root.join(name)
+    }
+
+    /// Creates the package `name` with the given main file.
+    /// The main files of its dependencies, and their own imports, are copied into its `imports/` directory.
+    fn add_package(&self, name: &str, source: &str, dependencies: &[&str]) {
+        let package = self.package(name);
+        fs::create_dir_all(package.join("src")).unwrap();
+        fs::create_dir_all(package.join("imports")).unwrap();
+        fs::write(
+            package.join("program.json"),
+            format!(
+                "{{\n    \"program\": \"{name}.aleo\",\n    \"version\": \"0.0.0\",\n    \"description\": \"\",\n    \"license\": \"MIT\"\n}}\n"
+            ),
+        )
+        .unwrap();
+        fs::write(package.join("src").join("main.leo"), source).unwrap();
+
+        for dependency in dependencies {
+            let dependency_path = self.package(dependency);
+            fs::copy(
+                dependency_path.join("src").join("main.leo"),
+                package.join("imports").join(format!("{dependency}.leo")),
+            )
+            .unwrap();
+            for entry in fs::read_dir(dependency_path.join("imports")).unwrap() {
+                let entry = entry.unwrap();
+                fs::copy(entry.path(), package.join("imports").join(entry.file_name())).unwrap();
+            }
+        }
+    }
+
+    /// Runs `leo build` in the package `name`.
+    fn build(&self, name: &str) -> Result<()> {
+        let package = self.package(name);
+        let _guard = CURRENT_DIR.lock().unwrap_or_else(|e| e.into_inner());
+        let current_dir = std::env::current_dir().unwrap();
+        std::env::set_current_dir(&package).unwrap();
+        let result = (Build { options: BuildOptions::default(), all: false }).execute(Context::new(Some(package))?);
+        std::env::set_current_dir(current_dir).unwrap();
+        result.map(|_| ())
+    }
+
+    /// Runs `leo build --all` in the root of the workspace.
+    fn build_all(&self) -> Result<()> {
+        let _guard = CURRENT_DIR.lock().unwrap_or_else(|e| e.into_inner());
+        (Build { options: BuildOptions::default(), all: true }).execute(Context::new(Some(self.root.clone()))?)?;
+        Ok(())
+    }
+
+    /// Returns the contents of the files in the `build/` directory of the package `name`, keyed by relative path.
+    fn build_outputs(&self, name: &str) -> BTreeMap<PathBuf, Vec<u8>> {
+        fn collect(root: &Path, directory: &Path, files: &mut BTreeMap<PathBuf, Vec<u8>>) {
+            for entry in fs::read_dir(directory).unwrap() {
+                let path = entry.unwrap().path();
+                match path.is_dir() {
+                    true => collect(root, &path, files),
+                    false => {
+                        files.insert(path.strip_prefix(root).unwrap().to_path_buf(), fs::read(&path).unwrap());
+                    }
+                }
+            }
+        }
+        let build_directory = self.package(name).join("build");
+        let mut files = BTreeMap::new();
+        collect(&build_directory, &build_directory, &mut files);
+        files
+    }
+
+    /// Returns the generated Aleo instructions of the package `name`.
+    fn instructions(&self, name: &str) -> String {
+        fs::read_to_string(self.package(name).join("build").join("main.aleo")).unwrap()
+    }
+
+    /// Creates the `token`, `bank`, and `exchange` packages, each of which imports the ones before it.
+    fn with_exchange(name: &str) -> Self {
+        let workspace = Self::new(name);
+        workspace.add_package("token", TOKEN, &[]);
+        workspace.add_package("bank", BANK, &["token"]);
+        workspace.add_package("exchange", EXCHANGE, &["token", "bank"]);
+        workspace
+    }
+}
+
+impl Drop for Workspace {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+#[test]
+fn imported_programs_are_resolved_across_packages() -> Result<()> {
+    let workspace = Workspace::with_exchange("resolution");
+    for package in ["token", "bank", "exchange"] {
+        workspace.build(package)?;
+    }
+
+    let bank = workspace.instructions("bank");
+    assert!(bank.contains("import token.aleo;"));
+    assert!(bank.contains("call token.aleo/mint"));
+    assert!(bank.contains("output r2 as token.aleo/Token.record;"));
+
+    let exchange = workspace.instructions("exchange");
+    assert!(exchange.contains("import token.aleo;\nimport bank.aleo;"));
+    assert!(exchange.contains("call token.aleo/mint"));
+    assert!(exchange.contains("call bank.aleo/deposit"));
+
+    let outputs = workspace.build_outputs("exchange");
+    assert!(outputs.contains_key(Path::new("imports/token.aleo")));
+    assert!(outputs.contains_key(Path::new("imports/bank.aleo")));
+    Ok(())
+}
+
+#[test]
+fn only_transitions_of_imported_programs_are_visible() {
+    let workspace = Workspace::with_exchange("visibility");
+
+    // Inline functions are inlined into their callers, so they cannot be called from another program.
+    let inline = "
+import token.leo;
+
+program doubler.aleo {
+    transition twice(amount: u64) -> u64 {
+        return token.leo/double(amount);
+    }
+}
+";
+    workspace.add_package("doubler", inline, &["token"]);
+    assert!(workspace.build("doubler").is_err());
+
+    // Programs must be imported to be called, even if they are in the `imports/` directory.
+    let unimported = "
+program teller.aleo {
+    transition deposit(owner: address, amount: u64) -> token.leo/Token.record {
+        return token.leo/mint(owner, amount);
+    }
+}
+";
+    workspace.add_package("teller", unimported, &["token"]);
+    assert!(workspace.build("teller").is_err());
+}
+
+#[test]
+fn workspace_builds_are_deterministic() -> Result<()> {
+    let first = Workspace::with_exchange("deterministic-first");
+    let second = Workspace::with_exchange("deterministic-second");
+    first.build("exchange")?;
+    let outputs = first.build_outputs("exchange");
+
+    // Rebuilding the same package, or building a copy of it elsewhere, produces the same files.
+    first.build("exchange")?;
+    second.build("exchange")?;
+    assert_eq!(first.build_outputs("exchange"), outputs);
+    assert_eq!(second.build_outputs("exchange"), outputs);
+    Ok(())
+}
+
+#[test]
+fn workspace_members_import_one_another_from_source() -> Result<()> {
+    let workspace = Workspace::new("members");
+    workspace.add_package("token", TOKEN, &[]);
+    workspace.add_package("bank", BANK, &[]);
+    workspace.add_package("teller", TELLER, &[]);
+    fs::write(workspace.root.join("workspace.json"), r#"{ "members": ["token", "bank", "teller"] }"#).unwrap();
+
+    // Programs imported by several members are shared through the `imports/` directory of the workspace.
+    fs::create_dir_all(workspace.root.join("imports")).unwrap();
+    fs::write(workspace.root.join("imports").join("fees.leo"), FEES).unwrap();
+
+    // A stale copy of a member is ignored, since members are imported from their source files.
+    fs::write(
+        workspace.package("bank").join("imports").join("token.leo"),
+        "program token.aleo { transition burn(amount: u64) -> u64 { return amount; } }",
+    )
+    .unwrap();
+
+    workspace.build_all()?;
+
+    assert!(workspace.instructions("bank").contains("call token.aleo/mint"));
+    assert!(workspace.instructions("teller").contains("import token.aleo;\nimport bank.aleo;\nimport fees.aleo;"));
+    let outputs = workspace.build_outputs("teller");
+    for import in ["imports/token.aleo", "imports/bank.aleo", "imports/fees.aleo"] {
+        assert!(outputs.contains_key(Path::new(import)));
+    }
+    assert!(
+        !String::from_utf8_lossy(&workspace.build_outputs("bank")[Path::new("imports/token.aleo")]).contains("burn")
+    );
+
+    // Members are also built with the rest of the workspace when built on their own.
+    workspace.build("teller")?;
+    assert_eq!(workspace.build_outputs("teller"), outputs);
+    Ok(())
+}
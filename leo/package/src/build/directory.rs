@@ -22,7 +22,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-pub static BUILD_DIRECTORY_NAME: &str = "build/";
+pub static BUILD_DIRECTORY_NAME: &str = "build";
 
 pub struct BuildDirectory;
 
@@ -23,7 +23,11 @@ use std::{
     path::{Path, PathBuf},
 };
 
-pub static IMPORTS_DIRECTORY_NAME: &str = "imports/";
+pub static IMPORTS_DIRECTORY_NAME: &str = "imports";
+
+/// The files that serve as the entry point of a package directory, in order of preference.
+/// A package directory `foo/` with an entry file defines the program `foo`.
+pub static ENTRY_FILE_NAMES: [&str; 2] = ["lib.leo", "main.leo"];
 
 pub struct ImportsDirectory;
 
@@ -83,6 +87,96 @@ impl ImportsDirectory {
 
         parse_file_paths(directory, &mut file_paths)?;
 
+        // Sort the paths so that imports are compiled in the same order on every platform.
+        file_paths.sort();
+
+        // Check that every file is named after a valid program, and that no two names collide on a
+        // case-insensitive file system. Program names are ASCII, so no further normalization is needed.
+        for (index, file_path) in file_paths.iter().enumerate() {
+            let name = Self::program_name(&path, file_path)?;
+            let conflict = file_paths[..index].iter().find(
+                |other| matches!(Self::program_name(&path, other), Ok(other) if other.eq_ignore_ascii_case(name)),
+            );
+            if let Some(other) = conflict {
+                return Err(PackageError::conflicting_import_file_names(other.display(), file_path.display()).into());
+            }
+        }
+
         Ok(file_paths)
     }
+
+    /// Returns the name of the program defined by a file in the imports directory `imports`.
+    /// This is the name of the file without its extension, unless the file is the entry file of a package
+    /// directory, in which case it is the name of the directory.
+    pub fn program_name<'a>(imports: &Path, file_path: &'a Path) -> Result<&'a str> {
+        let stem = match file_path.parent() {
+            Some(directory) if directory != imports && Self::entry_file(directory).as_deref() == Some(file_path) => {
+                directory.file_name()
+            }
+            _ => file_path.file_stem(),
+        };
+        let name = stem
+            .unwrap_or_default()
+            .to_str()
+            .ok_or_else(|| PackageError::non_utf8_import_file_name(file_path.display()))?;
+
+        let mut chars = name.chars();
+        let is_valid = matches!(chars.next(), Some(c) if c.is_ascii_alphabetic())
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+        match is_valid {
+            true => Ok(name),
+            false => Err(PackageError::invalid_import_file_name(file_path.display()).into()),
+        }
+    }
+
+    /// Returns the entry file of the package directory at the provided path, if it has one.
+    pub fn entry_file(directory: &Path) -> Option<PathBuf> {
+        ENTRY_FILE_NAMES.iter().map(|name| directory.join(name)).find(|path| path.is_file())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_program_name() {
+        let imports = Path::new("imports");
+        assert_eq!(ImportsDirectory::program_name(imports, Path::new("imports/foo.leo")).unwrap(), "foo");
+        assert_eq!(ImportsDirectory::program_name(imports, Path::new("imports/foo_bar1.leo")).unwrap(), "foo_bar1");
+
+        assert!(ImportsDirectory::program_name(imports, Path::new("imports/_foo.leo")).is_err());
+        assert!(ImportsDirectory::program_name(imports, Path::new("imports/1foo.leo")).is_err());
+        assert!(ImportsDirectory::program_name(imports, Path::new("imports/foo-bar.leo")).is_err());
+        assert!(ImportsDirectory::program_name(imports, Path::new("imports/café.leo")).is_err());
+        assert!(ImportsDirectory::program_name(imports, Path::new("imports/.leo")).is_err());
+
+        #[cfg(unix)]
+        {
+            use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+            let path = imports.join(OsStr::from_bytes(b"fo\xffo.leo"));
+            assert!(ImportsDirectory::program_name(imports, &path).is_err());
+        }
+    }
+
+    #[test]
+    fn test_entry_files_are_named_after_their_directory() {
+        let package = std::env::temp_dir().join(format!("leo-imports-entry-{}", std::process::id()));
+        let imports = package.join(IMPORTS_DIRECTORY_NAME);
+        let _ = fs::remove_dir_all(&package);
+        for file in ["lib.leo", "token/lib.leo", "token/main.leo", "token/util.leo", "nft/main.leo"] {
+            let path = imports.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+
+        let names = ImportsDirectory::files(&package)
+            .unwrap()
+            .iter()
+            .map(|path| ImportsDirectory::program_name(&imports, path).unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["lib", "nft", "token", "main", "util"]);
+
+        fs::remove_dir_all(package).unwrap();
+    }
 }
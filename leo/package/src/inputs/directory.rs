@@ -23,7 +23,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-pub static INPUTS_DIRECTORY_NAME: &str = "inputs/";
+pub static INPUTS_DIRECTORY_NAME: &str = "inputs";
 
 pub struct InputsDirectory;
 
@@ -48,6 +48,9 @@ impl InputsDirectory {
         let mut file_paths = Vec::new();
         parse_file_paths(directory, &mut file_paths)?;
 
+        // Sort the paths, since `read_dir` does not guarantee an order.
+        file_paths.sort();
+
         Ok(file_paths)
     }
 }
@@ -28,7 +28,7 @@ use std::{
         {self},
     },
     io::Write,
-    path::Path,
+    path::{Path, PathBuf},
 };
 
 pub static INPUT_FILE_EXTENSION: &str = ".in";
@@ -43,8 +43,8 @@ impl InputFile {
         Self { package_name: package_name.to_string() }
     }
 
-    pub fn filename(&self) -> String {
-        format!("{INPUTS_DIRECTORY_NAME}{}{INPUT_FILE_EXTENSION}", self.package_name)
+    pub fn filename(&self) -> PathBuf {
+        Path::new(INPUTS_DIRECTORY_NAME).join(format!("{}{INPUT_FILE_EXTENSION}", self.package_name))
     }
 
     pub fn exists_at(&self, path: &Path) -> bool {
@@ -24,6 +24,7 @@ pub mod outputs;
 pub mod package;
 pub mod root;
 pub mod source;
+pub mod workspace;
 
 use leo_errors::{PackageError, Result};
 
@@ -62,3 +63,61 @@ pub(crate) fn parse_file_paths(directory: ReadDir, file_paths: &mut Vec<PathBuf>
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{build::BuildDirectory, imports::ImportsDirectory, inputs::InputFile, source::*};
+
+    use std::{ffi::OsStr, fs, path::PathBuf};
+
+    /// Creates an empty package directory that is unique to the given test.
+    fn package_directory(test: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("leo-package-{test}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    #[test]
+    fn test_file_names_are_joined_by_component() {
+        assert_eq!(MainFile::filename().iter().collect::<Vec<_>>(), [OsStr::new("src"), OsStr::new("main.leo")]);
+        assert_eq!(InputFile::new("foo").filename().iter().collect::<Vec<_>>(), [
+            OsStr::new("inputs"),
+            OsStr::new("foo.in")
+        ]);
+    }
+
+    #[test]
+    fn test_directories_are_resolved_within_the_package() {
+        let package = package_directory("directories");
+
+        SourceDirectory::create(&package).unwrap();
+        MainFile::new("foo").write_to(&package).unwrap();
+        assert_eq!(SourceDirectory::files(&package).unwrap(), [package.join("src").join("main.leo")]);
+
+        // Directories are not nested when the path already names them.
+        let imports = ImportsDirectory::create(&package).unwrap();
+        assert_eq!(imports, package.join("imports"));
+        assert_eq!(ImportsDirectory::create(&imports).unwrap(), imports);
+        let build = BuildDirectory::create(&package).unwrap();
+        assert_eq!(build, package.join("build"));
+        assert_eq!(BuildDirectory::open(&build).unwrap(), build);
+
+        fs::remove_dir_all(package).unwrap();
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_paths_use_windows_separators() {
+        assert_eq!(MainFile::filename().to_str(), Some(r"src\main.leo"));
+        assert_eq!(InputFile::new("foo").filename().to_str(), Some(r"inputs\foo.in"));
+
+        let package = package_directory("windows");
+        let imports = ImportsDirectory::create(&package).unwrap();
+        assert!(!imports.to_string_lossy().contains('/'));
+        let build = BuildDirectory::create(&package).unwrap();
+        assert!(!build.to_string_lossy().contains('/'));
+
+        fs::remove_dir_all(package).unwrap();
+    }
+}
@@ -0,0 +1,295 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The artifacts file, which records every file produced by `leo build`.
+
+use crate::{
+    build::BUILD_DIRECTORY_NAME,
+    outputs::{BUILD_REPORT_FILE_NAME, OUTPUTS_DIRECTORY_NAME},
+};
+use leo_errors::{PackageError, Result};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    cmp::Reverse,
+    collections::BTreeSet,
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+pub static ARTIFACTS_FILE_NAME: &str = "artifacts.json";
+
+/// The kind of a build artifact.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtifactKind {
+    /// Aleo instructions, e.g. `build/main.aleo`.
+    Bytecode,
+    /// The program manifest mirrored into the build directory, i.e. `build/program.json`.
+    ProgramManifest,
+    /// A checksum of a compiled program.
+    Checksum,
+    /// A JSON snapshot of the AST after a compiler pass.
+    AstSnapshot,
+    /// A JSON snapshot of the symbol table after a compiler pass.
+    SymbolTableSnapshot,
+    /// The report written by `leo build --timings`.
+    Timings,
+    /// A proving or verifying key.
+    Key,
+    /// Any other file in the build or outputs directory.
+    Other,
+}
+
+impl ArtifactKind {
+    /// Infers the kind of an artifact from its file name.
+    fn from_file_name(file_name: &str) -> Self {
+        if file_name.ends_with(".aleo") {
+            Self::Bytecode
+        } else if file_name == "program.json" {
+            Self::ProgramManifest
+        } else if file_name.ends_with(".sum") {
+            Self::Checksum
+        } else if file_name.ends_with("_ast.json") {
+            Self::AstSnapshot
+        } else if file_name.ends_with("_symbol_table.json") {
+            Self::SymbolTableSnapshot
+        } else if file_name.ends_with(".timings.json") {
+            Self::Timings
+        } else if file_name.ends_with(".prover") || file_name.ends_with(".verifier") {
+            Self::Key
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// A file produced by `leo build`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Artifact {
+    /// The path of the artifact, relative to the package root.
+    pub path: PathBuf,
+    /// The kind of the artifact.
+    pub kind: ArtifactKind,
+    /// The SHA256 checksum of the artifact.
+    pub checksum: String,
+}
+
+/// The list of artifacts produced by `leo build`, stored in `outputs/artifacts.json`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArtifactsFile {
+    pub artifacts: Vec<Artifact>,
+}
+
+impl ArtifactsFile {
+    /// Collects the files in the build and outputs directories of the package at `package_path`.
+    pub fn collect(package_path: &Path) -> Result<Self> {
+        let mut artifacts = Vec::new();
+        for directory in [BUILD_DIRECTORY_NAME, OUTPUTS_DIRECTORY_NAME] {
+            let directory = package_path.join(directory);
+            if directory.exists() {
+                collect_artifacts(package_path, &directory, &mut artifacts)?;
+            }
+        }
+        // Sort the artifacts, since the order of directory entries is platform-dependent.
+        artifacts.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(Self { artifacts })
+    }
+
+    /// Reads the artifacts file of the package at `package_path`, if it exists.
+    pub fn read_from(package_path: &Path) -> Result<Option<Self>> {
+        let path = Self::file_path(package_path);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let string = fs::read_to_string(&path).map_err(|e| PackageError::failed_to_read_artifacts_file(&path, e))?;
+        let artifacts =
+            serde_json::from_str(&string).map_err(|e| PackageError::failed_to_read_artifacts_file(&path, e))?;
+        Ok(Some(artifacts))
+    }
+
+    /// Writes the artifacts file of the package at `package_path`.
+    /// The file is first written to a temporary file and then renamed, so that it is never partially written.
+    pub fn write_to(&self, package_path: &Path) -> Result<()> {
+        let path = Self::file_path(package_path);
+        let temporary_path = path.with_extension("json.tmp");
+        // Note that this unwrap is safe since the artifacts only contain strings.
+        let string = serde_json::to_string_pretty(self).unwrap();
+        fs::write(&temporary_path, string).map_err(|e| PackageError::failed_to_write_artifacts_file(&path, e))?;
+        fs::rename(&temporary_path, &path).map_err(|e| PackageError::failed_to_write_artifacts_file(&path, e))?;
+        Ok(())
+    }
+
+    /// Removes the artifacts, the artifacts file, and the build report of the package at `package_path`.
+    /// Directories that are left empty are removed as well. Returns the number of removed artifacts.
+    /// Nothing is removed if any artifact is not in the build or outputs directory of the package.
+    pub fn remove(&self, package_path: &Path) -> Result<usize> {
+        let paths = self
+            .artifacts
+            .iter()
+            .map(|artifact| artifact_path(package_path, &artifact.path))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut removed = 0;
+        for path in paths {
+            if path.exists() {
+                fs::remove_file(&path).map_err(|e| PackageError::failed_to_remove_artifact(path.display(), e))?;
+                removed += 1;
+            }
+        }
+
+        let path = Self::file_path(package_path);
+        fs::remove_file(&path).map_err(|e| PackageError::failed_to_remove_artifact(path.display(), e))?;
+        let report_path = package_path.join(OUTPUTS_DIRECTORY_NAME).join(BUILD_REPORT_FILE_NAME);
+        if report_path.exists() {
+            fs::remove_file(&report_path)
+                .map_err(|e| PackageError::failed_to_remove_artifact(report_path.display(), e))?;
+        }
+
+        // Remove the directories that no longer contain any files, deepest first.
+        let mut directories: Vec<PathBuf> = self
+            .artifacts
+            .iter()
+            .filter_map(|artifact| artifact.path.parent().map(Path::to_path_buf))
+            .chain(std::iter::once(PathBuf::from(OUTPUTS_DIRECTORY_NAME)))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        directories.sort_by_key(|directory| Reverse(directory.components().count()));
+        for directory in directories {
+            let directory = package_path.join(directory);
+            let is_empty = fs::read_dir(&directory).map(|mut entries| entries.next().is_none()).unwrap_or(false);
+            if is_empty {
+                fs::remove_dir(&directory)
+                    .map_err(|e| PackageError::failed_to_remove_directory(directory.display(), e))?;
+            }
+        }
+
+        Ok(removed)
+    }
+
+    fn file_path(package_path: &Path) -> PathBuf {
+        package_path.join(OUTPUTS_DIRECTORY_NAME).join(ARTIFACTS_FILE_NAME)
+    }
+}
+
+/// Returns the path of the artifact `relative_path` of the package at `package_path`.
+/// Fails if the path is absolute, contains `..`, is not in the build or outputs directory, or leads out of the
+/// package through a symbolic link, since the artifacts file may have been edited by hand.
+fn artifact_path(package_path: &Path, relative_path: &Path) -> Result<PathBuf> {
+    let invalid = || PackageError::invalid_artifact_path(relative_path.display());
+    let mut components = relative_path.components();
+    let in_build_directory = match components.next() {
+        Some(Component::Normal(directory)) => {
+            directory.to_str().map_or(false, |name| name == BUILD_DIRECTORY_NAME || name == OUTPUTS_DIRECTORY_NAME)
+        }
+        _ => false,
+    };
+    if !in_build_directory || !components.all(|component| matches!(component, Component::Normal(_))) {
+        return Err(invalid().into());
+    }
+
+    let path = package_path.join(relative_path);
+    if path.exists() {
+        let canonical_package_path =
+            package_path.canonicalize().map_err(|e| PackageError::failed_to_read_file(package_path.display(), e))?;
+        let canonical_path = path.canonicalize().map_err(|e| PackageError::failed_to_read_file(path.display(), e))?;
+        if !canonical_path.starts_with(canonical_package_path) {
+            return Err(invalid().into());
+        }
+    }
+    Ok(path)
+}
+
+/// Recursively collects the files in `directory` as artifacts of the package at `package_path`.
+fn collect_artifacts(package_path: &Path, directory: &Path, artifacts: &mut Vec<Artifact>) -> Result<()> {
+    let entries = fs::read_dir(directory).map_err(|e| PackageError::failed_to_read_file(directory.display(), e))?;
+    for entry in entries {
+        let path = entry.map_err(|e| PackageError::failed_to_read_file(directory.display(), e))?.path();
+        if path.is_dir() {
+            collect_artifacts(package_path, &path, artifacts)?;
+            continue;
+        }
+
+        let file_name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+        // Skip the artifacts file itself and the build report, and any partially written copies of them.
+        if file_name.starts_with(ARTIFACTS_FILE_NAME) || file_name.starts_with(BUILD_REPORT_FILE_NAME) {
+            continue;
+        }
+
+        let bytes = fs::read(&path).map_err(|e| PackageError::failed_to_read_file(path.display(), e))?;
+        artifacts.push(Artifact {
+            path: path.strip_prefix(package_path).unwrap_or(&path).to_path_buf(),
+            kind: ArtifactKind::from_file_name(file_name),
+            checksum: format!("{:x}", Sha256::digest(&bytes)),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn artifact(path: impl Into<PathBuf>) -> Artifact {
+        Artifact { path: path.into(), kind: ArtifactKind::Other, checksum: String::new() }
+    }
+
+    #[test]
+    fn test_remove_only_removes_build_artifacts() {
+        let package = tempfile::tempdir().unwrap();
+        let outside = tempfile::tempdir().unwrap();
+        fs::create_dir_all(package.path().join(BUILD_DIRECTORY_NAME)).unwrap();
+        fs::create_dir_all(package.path().join(OUTPUTS_DIRECTORY_NAME)).unwrap();
+        fs::write(package.path().join(BUILD_DIRECTORY_NAME).join("main.aleo"), "").unwrap();
+        fs::write(package.path().join("program.json"), "").unwrap();
+        fs::write(outside.path().join("secret"), "").unwrap();
+
+        // Nothing is removed if any artifact escapes the build and outputs directories.
+        for path in [
+            PathBuf::from("program.json"),
+            PathBuf::from("build/../program.json"),
+            PathBuf::from("outputs/../../secret"),
+            outside.path().join("secret"),
+        ] {
+            let artifacts = ArtifactsFile { artifacts: vec![artifact("build/main.aleo"), artifact(path)] };
+            artifacts.write_to(package.path()).unwrap();
+            assert!(artifacts.remove(package.path()).is_err());
+            assert!(package.path().join(BUILD_DIRECTORY_NAME).join("main.aleo").exists());
+        }
+        assert!(package.path().join("program.json").exists());
+
+        // A symbolic link in the build directory cannot lead out of the package either.
+        #[cfg(unix)]
+        {
+            let link = package.path().join(BUILD_DIRECTORY_NAME).join("link");
+            std::os::unix::fs::symlink(outside.path(), &link).unwrap();
+            let artifacts = ArtifactsFile { artifacts: vec![artifact("build/link/secret")] };
+            assert!(artifacts.remove(package.path()).is_err());
+            fs::remove_file(link).unwrap();
+        }
+        assert!(outside.path().join("secret").exists());
+
+        // The artifacts are removed, along with the directories they leave empty.
+        let artifacts = ArtifactsFile { artifacts: vec![artifact("build/main.aleo")] };
+        artifacts.write_to(package.path()).unwrap();
+        assert_eq!(artifacts.remove(package.path()).unwrap(), 1);
+        assert!(!package.path().join(BUILD_DIRECTORY_NAME).exists());
+        assert!(!package.path().join(OUTPUTS_DIRECTORY_NAME).exists());
+        assert!(package.path().join("program.json").exists());
+    }
+}
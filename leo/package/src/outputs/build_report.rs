@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The build report, which summarizes a `leo build` for dashboards and CI bots.
+
+use crate::outputs::{Artifact, OUTPUTS_DIRECTORY_NAME};
+use leo_errors::{
+    emitter::{DiagnosticRecord, Severity},
+    PackageError,
+    Result,
+};
+
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub static BUILD_REPORT_FILE_NAME: &str = "build-report.json";
+
+/// The time spent in a stage of compilation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StageTiming {
+    /// The name of the stage.
+    pub stage: String,
+    /// The time spent in the stage.
+    pub milliseconds: f64,
+}
+
+/// The number of constraints in the circuit of a transition.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionConstraints {
+    /// The name of the transition.
+    pub function: String,
+    /// The number of constraints in its circuit.
+    pub constraints: u64,
+}
+
+/// The part of the report about one compiled program.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProgramReport {
+    /// The program id, e.g. `hello.aleo`.
+    pub program: String,
+    /// The time spent in each stage of compiling the program, in the order in which the stages were run.
+    pub timings: Vec<StageTiming>,
+    /// The constraint counts of the program's transitions, if the build got far enough to synthesize them.
+    pub constraints: Vec<FunctionConstraints>,
+}
+
+/// The diagnostics reported during the build.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnosticsReport {
+    /// The number of errors.
+    pub errors: usize,
+    /// The number of warnings.
+    pub warnings: usize,
+    /// The errors and warnings, in the order in which they were reported.
+    pub messages: Vec<DiagnosticRecord>,
+}
+
+impl DiagnosticsReport {
+    /// Summarizes the given diagnostics.
+    pub fn new(messages: Vec<DiagnosticRecord>) -> Self {
+        let errors = messages.iter().filter(|message| message.severity == Severity::Error).count();
+        Self { errors, warnings: messages.len() - errors, messages }
+    }
+}
+
+/// The report written by `leo build --build-report`, stored in `outputs/build-report.json`.
+/// It is only written locally, and never sent anywhere.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BuildReport {
+    /// Whether the build succeeded.
+    pub succeeded: bool,
+    /// The diagnostics reported during the build.
+    pub diagnostics: DiagnosticsReport,
+    /// The programs compiled during the build, the package's own program first.
+    pub programs: Vec<ProgramReport>,
+    /// The files produced by the build and their checksums.
+    pub artifacts: Vec<Artifact>,
+}
+
+impl BuildReport {
+    /// Writes the build report of the package at `package_path`.
+    /// The file is first written to a temporary file and then renamed, so that it is never partially written.
+    pub fn write_to(&self, package_path: &Path) -> Result<()> {
+        let path = Self::file_path(package_path);
+        let temporary_path = path.with_extension("json.tmp");
+        // Note that this unwrap is safe since the report only contains strings and numbers.
+        let string = serde_json::to_string_pretty(self).unwrap();
+        fs::write(&temporary_path, string).map_err(|e| PackageError::failed_to_write_build_report(&path, e))?;
+        fs::rename(&temporary_path, &path).map_err(|e| PackageError::failed_to_write_build_report(&path, e))?;
+        Ok(())
+    }
+
+    /// Returns the path of the build report of the package at `package_path`.
+    pub fn file_path(package_path: &Path) -> PathBuf {
+        package_path.join(OUTPUTS_DIRECTORY_NAME).join(BUILD_REPORT_FILE_NAME)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::outputs::ArtifactsFile;
+
+    #[test]
+    fn test_build_report_is_not_an_artifact() {
+        let package = std::env::temp_dir().join(format!("leo-build-report-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&package);
+        fs::create_dir_all(package.join(OUTPUTS_DIRECTORY_NAME)).unwrap();
+        fs::write(package.join(OUTPUTS_DIRECTORY_NAME).join("main.sum"), "").unwrap();
+
+        let report = BuildReport { succeeded: true, ..Default::default() };
+        report.write_to(&package).unwrap();
+        let written: BuildReport =
+            serde_json::from_str(&fs::read_to_string(BuildReport::file_path(&package)).unwrap()).unwrap();
+        assert_eq!(written, report);
+
+        // The report describes the artifacts, so it is not one of them, but it is cleaned up along with them.
+        let artifacts = ArtifactsFile::collect(&package).unwrap();
+        assert_eq!(artifacts.artifacts.len(), 1);
+        artifacts.write_to(&package).unwrap();
+        artifacts.remove(&package).unwrap();
+        assert!(!BuildReport::file_path(&package).exists());
+
+        let _ = fs::remove_dir_all(package);
+    }
+}
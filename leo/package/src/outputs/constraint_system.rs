@@ -0,0 +1,289 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The constraint systems of transitions, exported by `leo build --emit r1cs` for external analyzers and provers.
+//! They are read back from the indexed circuits of the proving keys that snarkVM synthesizes in memory, so a
+//! constraint system too large for memory cannot be exported either.
+
+use crate::outputs::OUTPUTS_DIRECTORY_NAME;
+use leo_errors::{PackageError, Result};
+
+use serde_json::{json, Value};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A format the constraint system of a transition can be exported in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConstraintSystemFormat {
+    /// The binary `.r1cs` format of circom and snarkjs.
+    R1cs,
+    /// The JSON format of `snarkjs r1cs export json`, with one constraint per line.
+    Json,
+}
+
+impl ConstraintSystemFormat {
+    /// Returns the extension of the files written in this format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::R1cs => "r1cs",
+            Self::Json => "r1cs.json",
+        }
+    }
+}
+
+/// A coefficient multiplying the value of a wire in a linear combination.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Term {
+    /// The index of the wire.
+    pub wire: u32,
+    /// The coefficient, as a field element in little-endian bytes.
+    pub coefficient: Vec<u8>,
+}
+
+/// A rank-1 constraint `a * b = c`, where `a`, `b`, and `c` are linear combinations of wires.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Constraint {
+    pub a: Vec<Term>,
+    pub b: Vec<Term>,
+    pub c: Vec<Term>,
+}
+
+/// The rank-1 constraint system of the circuit of a transition.
+/// Wire 0 is the constant one, followed by the public inputs and then the private wires.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConstraintSystem {
+    /// The modulus of the field, in little-endian bytes. Every coefficient is padded to its length.
+    pub prime: Vec<u8>,
+    /// The number of wires, including the constant one.
+    pub num_wires: u32,
+    /// The number of public inputs, not including the constant one.
+    pub num_public_inputs: u32,
+    /// The constraints.
+    pub constraints: Vec<Constraint>,
+}
+
+impl ConstraintSystem {
+    /// Returns the number of private wires, i.e. the wires other than the constant one and the public inputs.
+    fn num_private_wires(&self) -> Result<u32> {
+        let num_private_wires =
+            self.num_wires.checked_sub(1).and_then(|wires| wires.checked_sub(self.num_public_inputs));
+        num_private_wires
+            .ok_or_else(|| PackageError::invalid_constraint_system(self.num_wires, self.num_public_inputs).into())
+    }
+
+    /// Serializes the constraint system in the binary `.r1cs` format, as version 1 with a header section,
+    /// a constraints section, and a section mapping each wire to itself as its label.
+    pub fn to_r1cs_bytes(&self) -> Result<Vec<u8>> {
+        let field_size = self.prime.len();
+
+        let mut header = Vec::new();
+        header.extend((field_size as u32).to_le_bytes());
+        header.extend(&self.prime);
+        header.extend(self.num_wires.to_le_bytes());
+        // The public inputs are all reported as inputs, as the circuit does not distinguish its outputs.
+        header.extend(0u32.to_le_bytes());
+        header.extend(self.num_public_inputs.to_le_bytes());
+        header.extend(self.num_private_wires()?.to_le_bytes());
+        header.extend(u64::from(self.num_wires).to_le_bytes());
+        header.extend((self.constraints.len() as u32).to_le_bytes());
+
+        let mut constraints = Vec::new();
+        for constraint in self.constraints.iter() {
+            for combination in [&constraint.a, &constraint.b, &constraint.c] {
+                constraints.extend((combination.len() as u32).to_le_bytes());
+                for term in combination.iter() {
+                    constraints.extend(term.wire.to_le_bytes());
+                    constraints.extend(padded(&term.coefficient, field_size));
+                }
+            }
+        }
+
+        let labels: Vec<u8> = (0..u64::from(self.num_wires)).flat_map(u64::to_le_bytes).collect();
+
+        let mut bytes = Vec::new();
+        bytes.extend(b"r1cs");
+        bytes.extend(1u32.to_le_bytes());
+        bytes.extend(3u32.to_le_bytes());
+        for (section_type, section) in [(1u32, header), (2, constraints), (3, labels)] {
+            bytes.extend(section_type.to_le_bytes());
+            bytes.extend((section.len() as u64).to_le_bytes());
+            bytes.extend(section);
+        }
+        Ok(bytes)
+    }
+
+    /// Serializes the constraint system as JSON, in the format of `snarkjs r1cs export json`.
+    /// Each constraint is written on its own line, as a list of the linear combinations `a`, `b`, and `c`,
+    /// which map the indices of their wires to their coefficients in decimal.
+    pub fn to_json_string(&self) -> Result<String> {
+        let header = json!({
+            "n8": self.prime.len(),
+            "prime": to_decimal(&self.prime),
+            "nVars": self.num_wires,
+            "nOutputs": 0,
+            "nPubInputs": self.num_public_inputs,
+            "nPrvInputs": self.num_private_wires()?,
+            "nLabels": self.num_wires,
+            "nConstraints": self.constraints.len(),
+        });
+        let combination = |terms: &[Term]| {
+            Value::Object(
+                terms
+                    .iter()
+                    .map(|term| (term.wire.to_string(), Value::String(to_decimal(&term.coefficient))))
+                    .collect(),
+            )
+        };
+        let rows: Vec<String> = self
+            .constraints
+            .iter()
+            .map(|constraint| {
+                Value::Array(vec![combination(&constraint.a), combination(&constraint.b), combination(&constraint.c)])
+                    .to_string()
+            })
+            .collect();
+
+        // The closing brace of the header is replaced by the constraints and the map of the wires to their labels.
+        let mut json = header.to_string();
+        json.pop();
+        json.push_str(",\"constraints\":[\n");
+        json.push_str(&rows.join(",\n"));
+        json.push_str("\n],\"map\":");
+        json.push_str(&Value::Array((0..self.num_wires).map(Value::from).collect()).to_string());
+        json.push_str("}\n");
+        Ok(json)
+    }
+
+    /// Writes the constraint system in the given format to `outputs/{name}.{extension}` in the package at
+    /// `package_path`, and returns the path of the file.
+    pub fn write_to(&self, package_path: &Path, name: &str, format: ConstraintSystemFormat) -> Result<PathBuf> {
+        let path = Self::file_path(package_path, name, format);
+        let bytes = match format {
+            ConstraintSystemFormat::R1cs => self.to_r1cs_bytes()?,
+            ConstraintSystemFormat::Json => self.to_json_string()?.into_bytes(),
+        };
+        fs::write(&path, bytes).map_err(|e| PackageError::failed_to_write_constraint_system(path.display(), e))?;
+        Ok(path)
+    }
+
+    /// Returns the path of the constraint system called `name` in the given format, in the package at `package_path`.
+    pub fn file_path(package_path: &Path, name: &str, format: ConstraintSystemFormat) -> PathBuf {
+        package_path.join(OUTPUTS_DIRECTORY_NAME).join(format!("{name}.{}", format.extension()))
+    }
+}
+
+/// Pads the little-endian `bytes` with zeros to `size` bytes.
+fn padded(bytes: &[u8], size: usize) -> Vec<u8> {
+    let mut padded = bytes.to_vec();
+    padded.resize(size.max(bytes.len()), 0);
+    padded
+}
+
+/// Formats the little-endian `bytes` as a decimal number.
+fn to_decimal(bytes: &[u8]) -> String {
+    // Repeatedly divide the big-endian digits in base 256 by 10, collecting the remainders.
+    let mut digits: Vec<u8> = bytes.iter().rev().copied().skip_while(|byte| *byte == 0).collect();
+    let mut decimal = Vec::new();
+    while !digits.is_empty() {
+        let mut remainder = 0u32;
+        for digit in digits.iter_mut() {
+            let value = (remainder << 8) | u32::from(*digit);
+            *digit = (value / 10) as u8;
+            remainder = value % 10;
+        }
+        decimal.push(b'0' + remainder as u8);
+        let leading_zeros = digits.iter().take_while(|digit| **digit == 0).count();
+        digits.drain(..leading_zeros);
+    }
+    match decimal.is_empty() {
+        true => "0".to_string(),
+        false => decimal.iter().rev().map(|digit| *digit as char).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The constraint system of `x * x = y` over the field of order 251, with `y` public and `x` private.
+    fn square() -> ConstraintSystem {
+        let one = |wire| Term { wire, coefficient: vec![1] };
+        ConstraintSystem {
+            prime: vec![251],
+            num_wires: 3,
+            num_public_inputs: 1,
+            constraints: vec![Constraint { a: vec![one(2)], b: vec![one(2)], c: vec![one(1)] }],
+        }
+    }
+
+    #[test]
+    fn test_to_decimal() {
+        assert_eq!(to_decimal(&[]), "0");
+        assert_eq!(to_decimal(&[0, 0]), "0");
+        assert_eq!(to_decimal(&[255]), "255");
+        assert_eq!(to_decimal(&[0, 1]), "256");
+        assert_eq!(to_decimal(&u64::MAX.to_le_bytes()), u64::MAX.to_string());
+        assert_eq!(to_decimal(&u128::MAX.to_le_bytes()), u128::MAX.to_string());
+    }
+
+    #[test]
+    fn test_r1cs_bytes() {
+        let bytes = square().to_r1cs_bytes().unwrap();
+
+        let mut expected = b"r1cs".to_vec();
+        expected.extend([1, 0, 0, 0, 3, 0, 0, 0]);
+        // The header: the field size, the prime, the wires, the outputs, the public and private inputs,
+        // the labels, and the constraints.
+        expected.extend([1, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0]);
+        expected.extend([1, 0, 0, 0, 251, 3, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0]);
+        expected.extend([3, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0]);
+        // The constraint, each linear combination having one term.
+        expected.extend([2, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0]);
+        expected.extend([1, 0, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 0, 2, 0, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0, 1]);
+        // The labels.
+        expected.extend([3, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0]);
+        expected.extend([0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn test_json_has_a_row_per_constraint() {
+        let mut system = square();
+        system
+            .constraints
+            .push(Constraint { a: vec![Term { wire: 0, coefficient: vec![2, 0] }], ..Default::default() });
+        let json = system.to_json_string().unwrap();
+
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["prime"], "251");
+        assert_eq!(value["nPubInputs"], 1);
+        assert_eq!(value["nPrvInputs"], 1);
+        assert_eq!(value["constraints"][0], json!([{ "2": "1" }, { "2": "1" }, { "1": "1" }]));
+        assert_eq!(value["constraints"][1], json!([{ "0": "2" }, {}, {}]));
+        assert_eq!(json.lines().filter(|line| line.starts_with("[{")).count(), 2);
+    }
+
+    #[test]
+    fn test_too_few_wires_is_an_error() {
+        let system = ConstraintSystem { num_wires: 1, ..square() };
+        assert!(system.to_r1cs_bytes().is_err());
+        assert!(system.to_json_string().is_err());
+        assert!(ConstraintSystem::default().to_r1cs_bytes().is_err());
+    }
+}
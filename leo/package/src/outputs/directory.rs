@@ -22,7 +22,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-pub static OUTPUTS_DIRECTORY_NAME: &str = "outputs/";
+pub static OUTPUTS_DIRECTORY_NAME: &str = "outputs";
 
 pub struct OutputsDirectory;
 
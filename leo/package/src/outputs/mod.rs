@@ -14,6 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod artifacts;
+pub use self::artifacts::*;
+
+pub mod build_report;
+pub use self::build_report::*;
+
+pub mod constraint_system;
+pub use self::constraint_system::*;
+
+pub mod witness;
+pub use self::witness::*;
+
 pub mod ast_snapshot;
 pub use self::ast_snapshot::*;
 
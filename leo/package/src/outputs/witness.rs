@@ -0,0 +1,272 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Witnesses, i.e. assignments to the wires of the constraint system of a transition, stored as JSON so that they
+//! can be exchanged with other provers and checked against the constraint systems Leo exports.
+
+use crate::outputs::{ConstraintSystem, Term, OUTPUTS_DIRECTORY_NAME};
+use leo_errors::{PackageError, Result};
+
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+pub static WITNESS_FILE_EXTENSION: &str = "witness.json";
+
+/// The value assigned to a wire, with the variable it belongs to, if it is known.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WitnessVariable {
+    /// The index of the wire.
+    pub wire: u32,
+    /// The value of the wire, as a field element in decimal.
+    pub value: String,
+    /// The name of the variable, e.g. `amount` or `r3`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Where the variable is declared, as `file:line:column`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+/// A full assignment to the wires of the constraint system of a transition.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Witness {
+    /// The program id, e.g. `hello.aleo`.
+    pub program: String,
+    /// The name of the transition.
+    pub function: String,
+    /// The values of the wires, including the constant one at wire 0.
+    pub variables: Vec<WitnessVariable>,
+}
+
+impl Witness {
+    /// Creates a witness assigning the given values, in little-endian bytes, to the wires in order.
+    pub fn new(program: impl ToString, function: impl ToString, values: &[Vec<u8>]) -> Self {
+        let variables = values
+            .iter()
+            .enumerate()
+            .map(|(wire, value)| WitnessVariable {
+                wire: wire as u32,
+                value: BigUint::from_bytes_le(value).to_string(),
+                name: None,
+                location: None,
+            })
+            .collect();
+        Self { program: program.to_string(), function: function.to_string(), variables }
+    }
+
+    /// Names the variable of the given wire, and records where it is declared.
+    pub fn with_name(mut self, wire: u32, name: impl ToString, location: Option<String>) -> Self {
+        if let Some(variable) = self.variables.iter_mut().find(|variable| variable.wire == wire) {
+            variable.name = Some(name.to_string());
+            variable.location = location;
+        }
+        self
+    }
+
+    /// Returns the values of the wires, in little-endian bytes, in the order of the wires.
+    /// Every wire must be assigned exactly once.
+    pub fn values(&self) -> Result<Vec<Vec<u8>>> {
+        self.assignment().map(|assignment| assignment.iter().map(BigUint::to_bytes_le).collect())
+    }
+
+    /// Returns the values of the wires in the order of the wires, checking that every wire is assigned exactly once.
+    fn assignment(&self) -> Result<Vec<BigUint>> {
+        let mut assignment = vec![None; self.variables.len()];
+        for variable in self.variables.iter() {
+            let value = BigUint::from_str(&variable.value).map_err(|_| {
+                PackageError::invalid_witness(&self.function, format!("wire {} is not a number", variable.wire))
+            })?;
+            match assignment.get_mut(variable.wire as usize) {
+                Some(slot @ None) => *slot = Some(value),
+                Some(Some(_)) => {
+                    return Err(PackageError::invalid_witness(
+                        &self.function,
+                        format!("wire {} is assigned twice", variable.wire),
+                    )
+                    .into());
+                }
+                None => {
+                    return Err(PackageError::invalid_witness(
+                        &self.function,
+                        format!("wire {} is out of range", variable.wire),
+                    )
+                    .into());
+                }
+            }
+        }
+        // Every wire is assigned, since each of the variables is assigned to a distinct wire in range.
+        Ok(assignment.into_iter().flatten().collect())
+    }
+
+    /// Returns the name of the variable of the given wire, or the index of the wire if it has no name.
+    fn describe(&self, wire: u32) -> String {
+        match self.variables.iter().find(|variable| variable.wire == wire) {
+            Some(WitnessVariable { name: Some(name), location: Some(location), .. }) => {
+                format!("`{name}` (wire {wire}, at {location})")
+            }
+            Some(WitnessVariable { name: Some(name), .. }) => format!("`{name}` (wire {wire})"),
+            _ => format!("wire {wire}"),
+        }
+    }
+
+    /// Serializes the witness as JSON.
+    pub fn to_json_string(&self) -> String {
+        // Note that this unwrap is safe since the witness only contains strings and numbers.
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// Writes the witness to `outputs/{program}.{function}.witness.json` in the package at `package_path`,
+    /// and returns the path of the file.
+    pub fn write_to(&self, package_path: &Path) -> Result<PathBuf> {
+        let name = self.program.strip_suffix(".aleo").unwrap_or(&self.program);
+        let path = package_path
+            .join(OUTPUTS_DIRECTORY_NAME)
+            .join(format!("{name}.{}.{WITNESS_FILE_EXTENSION}", self.function));
+        fs::write(&path, self.to_json_string())
+            .map_err(|e| PackageError::failed_to_write_witness(path.display(), e))?;
+        Ok(path)
+    }
+
+    /// Reads a witness from the given file.
+    pub fn read_from(path: &Path) -> Result<Self> {
+        let string = fs::read_to_string(path).map_err(|e| PackageError::failed_to_read_witness(path.display(), e))?;
+        Ok(serde_json::from_str(&string).map_err(|e| PackageError::failed_to_read_witness(path.display(), e))?)
+    }
+}
+
+impl ConstraintSystem {
+    /// Checks that the witness assigns every wire of the constraint system, starting with the constant one,
+    /// and that it satisfies every constraint.
+    pub fn check_witness(&self, witness: &Witness) -> Result<()> {
+        let assignment = witness.assignment()?;
+        let prime = BigUint::from_bytes_le(&self.prime);
+
+        if assignment.len() != self.num_wires as usize {
+            return Err(PackageError::invalid_witness(
+                &witness.function,
+                format!("it assigns {} wires, but the circuit has {}", assignment.len(), self.num_wires),
+            )
+            .into());
+        }
+        if let Some(wire) = assignment.iter().position(|value| *value >= prime) {
+            return Err(PackageError::invalid_witness(
+                &witness.function,
+                format!("wire {wire} is not a field element"),
+            )
+            .into());
+        }
+        if assignment[0] != BigUint::from(1u32) {
+            return Err(PackageError::invalid_witness(&witness.function, "wire 0 must be the constant one").into());
+        }
+
+        // Evaluates a linear combination, whose wires are known to be in range.
+        let evaluate = |terms: &[Term]| {
+            terms.iter().fold(BigUint::default(), |sum, term| {
+                (sum + BigUint::from_bytes_le(&term.coefficient) * &assignment[term.wire as usize]) % &prime
+            })
+        };
+        for (index, constraint) in self.constraints.iter().enumerate() {
+            let wires = || {
+                let mut wires: Vec<u32> =
+                    constraint.a.iter().chain(&constraint.b).chain(&constraint.c).map(|term| term.wire).collect();
+                wires.sort_unstable();
+                wires.dedup();
+                wires
+            };
+            if let Some(wire) = wires().into_iter().find(|wire| *wire >= self.num_wires) {
+                return Err(PackageError::invalid_witness(
+                    &witness.function,
+                    format!("constraint {index} refers to wire {wire}, which the circuit does not have"),
+                )
+                .into());
+            }
+            if evaluate(&constraint.a) * evaluate(&constraint.b) % &prime != evaluate(&constraint.c) {
+                let variables = wires().into_iter().map(|wire| witness.describe(wire)).collect::<Vec<_>>().join(", ");
+                return Err(PackageError::unsatisfied_witness(&witness.function, index, variables).into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::outputs::Constraint;
+
+    /// The constraint system of `x * x = y` over the field of order 251, with `y` public and `x` private.
+    fn square() -> ConstraintSystem {
+        let one = |wire| Term { wire, coefficient: vec![1] };
+        ConstraintSystem {
+            prime: vec![251],
+            num_wires: 3,
+            num_public_inputs: 1,
+            constraints: vec![Constraint { a: vec![one(2)], b: vec![one(2)], c: vec![one(1)] }],
+        }
+    }
+
+    #[test]
+    fn test_check_witness() {
+        let system = square();
+        // 20 * 20 = 400 = 149 (mod 251).
+        let witness = Witness::new("square.aleo", "main", &[vec![1], vec![149], vec![20]]);
+        system.check_witness(&witness).unwrap();
+
+        let witness = Witness::new("square.aleo", "main", &[vec![1], vec![150], vec![20]]).with_name(
+            1,
+            "y",
+            Some("main.leo:3:5".to_string()),
+        );
+        let error = system.check_witness(&witness).unwrap_err().to_string();
+        assert!(error.contains("constraint 0"), "{error}");
+        assert!(error.contains("`y` (wire 1, at main.leo:3:5), wire 2"), "{error}");
+
+        for values in
+            [vec![vec![1], vec![149]], vec![vec![2], vec![149], vec![20]], vec![vec![1], vec![149], vec![251]]]
+        {
+            assert!(system.check_witness(&Witness::new("square.aleo", "main", &values)).is_err());
+        }
+    }
+
+    #[test]
+    fn test_witness_round_trip() {
+        let package = std::env::temp_dir().join(format!("leo-witness-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&package);
+        fs::create_dir_all(package.join(OUTPUTS_DIRECTORY_NAME)).unwrap();
+
+        let witness = Witness::new("square.aleo", "main", &[vec![1], vec![149], vec![20]]).with_name(2, "x", None);
+        let path = witness.write_to(&package).unwrap();
+        assert!(path.ends_with("outputs/square.main.witness.json"));
+        let read = Witness::read_from(&path).unwrap();
+        assert_eq!(read, witness);
+        assert_eq!(read.values().unwrap(), vec![vec![1], vec![149], vec![20]]);
+
+        // Wires may be listed in any order, but each must be assigned once.
+        let mut shuffled = witness.clone();
+        shuffled.variables.reverse();
+        assert_eq!(shuffled.values().unwrap(), witness.values().unwrap());
+        shuffled.variables[0].wire = 1;
+        assert!(shuffled.values().is_err());
+
+        let _ = fs::remove_dir_all(package);
+    }
+}
@@ -25,7 +25,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-pub static SOURCE_DIRECTORY_NAME: &str = "src/";
+pub static SOURCE_DIRECTORY_NAME: &str = "src";
 
 pub struct SourceDirectory;
 
@@ -53,6 +53,9 @@ impl SourceDirectory {
 
         parse_file_paths(directory, &mut file_paths)?;
 
+        // Sort the paths, since the order of directory entries is platform-dependent.
+        file_paths.sort();
+
         Ok(file_paths)
     }
 
@@ -20,7 +20,12 @@ use crate::source::directory::SOURCE_DIRECTORY_NAME;
 use leo_errors::{PackageError, Result};
 
 use serde::Deserialize;
-use std::{borrow::Cow, fs::File, io::Write, path::Path};
+use std::{
+    borrow::Cow,
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+};
 
 pub static MAIN_FILENAME: &str = "main.leo";
 
@@ -34,8 +39,8 @@ impl MainFile {
         Self { package_name: package_name.to_string() }
     }
 
-    pub fn filename() -> String {
-        format!("{SOURCE_DIRECTORY_NAME}{MAIN_FILENAME}")
+    pub fn filename() -> PathBuf {
+        Path::new(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME)
     }
 
     pub fn exists_at(path: &Path) -> bool {
@@ -0,0 +1,201 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The workspace manifest `workspace.json`, which lists the packages of a repository that are built together.
+//!
+//! The members of a workspace import one another from their local source files, rather than from copies in their
+//! `imports/` directories. Programs imported by several members can be shared through the `imports/` directory
+//! next to the workspace manifest, which is searched after the `imports/` directory of each member.
+
+use crate::{
+    imports::{ImportsDirectory, IMPORTS_DIRECTORY_NAME},
+    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+use leo_errors::{PackageError, Result};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub static WORKSPACE_FILE_NAME: &str = "workspace.json";
+
+/// The contents of a workspace manifest.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkspaceFile {
+    /// The directories of the member packages, relative to the manifest.
+    pub members: Vec<PathBuf>,
+}
+
+/// The part of a package manifest `program.json` that names its program.
+#[derive(Deserialize)]
+struct ProgramManifest {
+    program: String,
+}
+
+/// A member package of a workspace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkspaceMember {
+    /// The name of the program defined by the member, without the network.
+    pub program: String,
+    /// The directory of the member.
+    pub path: PathBuf,
+}
+
+/// A workspace, i.e. a directory with a workspace manifest, and its members.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Workspace {
+    /// The directory containing the workspace manifest.
+    pub root: PathBuf,
+    /// The members, in the order in which they are listed in the manifest.
+    pub members: Vec<WorkspaceMember>,
+}
+
+impl Workspace {
+    /// Opens the workspace whose manifest is in the directory `root`.
+    pub fn open(root: &Path) -> Result<Self> {
+        let path = root.join(WORKSPACE_FILE_NAME);
+        let string = fs::read_to_string(&path)
+            .map_err(|e| PackageError::failed_to_read_workspace_manifest(path.display(), e))?;
+        let manifest: WorkspaceFile =
+            serde_json::from_str(&string).map_err(|e| PackageError::invalid_workspace_manifest(path.display(), e))?;
+
+        let mut members: Vec<WorkspaceMember> = Vec::with_capacity(manifest.members.len());
+        for member in manifest.members.iter() {
+            let program_manifest = root.join(member).join("program.json");
+            let program = fs::read_to_string(&program_manifest)
+                .ok()
+                .and_then(|string| serde_json::from_str::<ProgramManifest>(&string).ok())
+                .ok_or_else(|| PackageError::workspace_member_not_found(member.display()))?
+                .program;
+            let program = program.split('.').next().unwrap_or_default().to_string();
+            let path = root
+                .join(member)
+                .canonicalize()
+                .map_err(|_| PackageError::workspace_member_not_found(member.display()))?;
+
+            if let Some(other) = members.iter().find(|other| other.program == program) {
+                return Err(
+                    PackageError::duplicate_workspace_member(program, other.path.display(), path.display()).into()
+                );
+            }
+            members.push(WorkspaceMember { program, path });
+        }
+
+        Ok(Self { root: root.to_path_buf(), members })
+    }
+
+    /// Returns the workspace whose manifest is in the directory `path` or the nearest of its parents, if any.
+    pub fn find(path: &Path) -> Result<Option<Self>> {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        match path.ancestors().find(|directory| directory.join(WORKSPACE_FILE_NAME).is_file()) {
+            Some(root) => Ok(Some(Self::open(root)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the workspace that the package at `path` is a member of, if any.
+    pub fn of_member(path: &Path) -> Result<Option<Self>> {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        Ok(Self::find(&path)?.filter(|workspace| workspace.member(&path).is_some()))
+    }
+
+    /// Returns the member whose directory is `path`, if any.
+    pub fn member(&self, path: &Path) -> Option<&WorkspaceMember> {
+        self.members.iter().find(|member| member.path == path)
+    }
+
+    /// Returns the `imports/` directory shared by the members of the workspace.
+    pub fn imports_directory(&self) -> PathBuf {
+        self.root.join(IMPORTS_DIRECTORY_NAME)
+    }
+
+    /// Returns the main files of the members that the package at `path` imports from their local path,
+    /// i.e. all members but the package itself, keyed by program name.
+    pub fn programs(&self, path: &Path) -> IndexMap<String, PathBuf> {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        self.members
+            .iter()
+            .filter(|member| member.path != path)
+            .map(|member| (member.program.clone(), member.main_file()))
+            .collect()
+    }
+
+    /// Returns the files in the shared `imports/` directory, keyed by program name.
+    pub fn shared_imports(&self) -> Result<IndexMap<String, PathBuf>> {
+        let mut programs = IndexMap::new();
+        if !ImportsDirectory::is_empty(&self.root)? {
+            let imports = self.imports_directory();
+            for file_path in ImportsDirectory::files(&imports)? {
+                programs.insert(ImportsDirectory::program_name(&imports, &file_path)?.to_string(), file_path);
+            }
+        }
+        Ok(programs)
+    }
+}
+
+impl WorkspaceMember {
+    /// Returns the path of the main file of the member.
+    pub fn main_file(&self) -> PathBuf {
+        self.path.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_workspace_members() {
+        let root = std::env::temp_dir().join(format!("leo-workspace-manifest-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for (member, program) in [("token", "token.aleo"), ("programs/bank", "bank.aleo")] {
+            fs::create_dir_all(root.join(member).join(SOURCE_DIRECTORY_NAME)).unwrap();
+            fs::write(root.join(member).join("program.json"), format!("{{\"program\": \"{program}\"}}")).unwrap();
+        }
+        fs::create_dir_all(root.join(IMPORTS_DIRECTORY_NAME)).unwrap();
+        fs::write(root.join(IMPORTS_DIRECTORY_NAME).join("oracle.leo"), "").unwrap();
+        fs::write(root.join(WORKSPACE_FILE_NAME), r#"{ "members": ["token", "programs/bank"] }"#).unwrap();
+
+        // The workspace is found from any directory inside it, but only its members are members.
+        let bank = root.join("programs").join("bank").canonicalize().unwrap();
+        let workspace = Workspace::of_member(&bank).unwrap().unwrap();
+        assert_eq!(workspace.members.iter().map(|member| member.program.as_str()).collect::<Vec<_>>(), [
+            "token", "bank"
+        ]);
+        assert!(Workspace::find(&root.join("programs")).unwrap().is_some());
+        assert!(Workspace::of_member(&root.join("programs")).unwrap().is_none());
+
+        // A member imports the other members from their local path, but not itself.
+        let programs = workspace.programs(&bank);
+        assert_eq!(programs.keys().collect::<Vec<_>>(), ["token"]);
+        assert_eq!(programs["token"], workspace.members[0].main_file());
+        assert_eq!(workspace.shared_imports().unwrap().keys().collect::<Vec<_>>(), ["oracle"]);
+
+        // Members must be packages that define distinct programs.
+        fs::write(root.join(WORKSPACE_FILE_NAME), r#"{ "members": ["token", "token"] }"#).unwrap();
+        assert!(Workspace::open(&root).is_err());
+        fs::write(root.join(WORKSPACE_FILE_NAME), r#"{ "members": ["missing"] }"#).unwrap();
+        assert!(Workspace::open(&root).is_err());
+        fs::write(root.join(WORKSPACE_FILE_NAME), r#"{ "packages": ["token"] }"#).unwrap();
+        assert!(Workspace::open(&root).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
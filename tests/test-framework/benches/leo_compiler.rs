@@ -88,7 +88,15 @@ fn new_compiler(handler: &Handler) -> Compiler<'_> {
         PathBuf::from(String::new()),
         PathBuf::from(String::new()),
         Some(CompilerOptions {
-            build: BuildOptions { dce_enabled: true },
+            build: BuildOptions {
+                dce_enabled: true,
+                max_unrolled_statements: None,
+                max_unrolled_iterations: None,
+                max_expression_depth: None,
+                max_array_size: None,
+                max_identifiers: None,
+                record_schema: Default::default(),
+            },
             output: OutputOptions {
                 symbol_table_spans_enabled: false,
                 initial_symbol_table: false,
@@ -103,6 +111,10 @@ fn new_compiler(handler: &Handler) -> Compiler<'_> {
                 destructured_ast: false,
                 inlined_ast: false,
                 dce_ast: false,
+                timings: false,
+                call_graph: false,
+                information_flow: false,
+                expanded: false,
             },
         }),
     )
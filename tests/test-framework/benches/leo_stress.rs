@@ -0,0 +1,248 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! This file benchmarks the compiler stages on large generated programs, to measure how they scale:
+//! parsing a large file, type checking thousands of functions, parsing a deep tree of imports,
+//! and unrolling nested loops.
+
+use leo_compiler::{BuildOptions, Compiler, CompilerOptions};
+use leo_errors::emitter::{Emitter, Handler};
+use leo_parser::ImportPaths;
+use leo_span::{source_map::FileName, symbol::SESSION_GLOBALS};
+use leo_test_framework::generator::ProgramShape;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::{
+    fmt::Write,
+    fs,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+/// A dummy buffer emitter since we only bench valid programs.
+struct BufEmitter;
+
+impl Emitter for BufEmitter {
+    fn emit_err(&mut self, _: leo_errors::LeoError) {}
+
+    fn last_emitted_err_code(&self) -> Option<i32> {
+        None
+    }
+
+    fn emit_warning(&mut self, _: leo_errors::LeoWarning) {}
+}
+
+/// A helper function to help create a Leo Compiler struct.
+fn new_compiler(handler: &Handler, import_paths: ImportPaths) -> Compiler<'_> {
+    Compiler::new(
+        String::from("test"),
+        String::from("aleo"),
+        handler,
+        PathBuf::from(String::new()),
+        PathBuf::from(String::new()),
+        Some(CompilerOptions {
+            build: BuildOptions {
+                dce_enabled: true,
+                max_unrolled_statements: None,
+                max_unrolled_iterations: None,
+                max_expression_depth: None,
+                max_array_size: None,
+                max_identifiers: None,
+                record_schema: Default::default(),
+            },
+            output: Default::default(),
+        }),
+    )
+    .with_import_paths(import_paths)
+}
+
+/// Benchmarks `logic(compiler, program)`, which returns the time spent in the stage being measured.
+fn bencher(
+    c: &mut Criterion,
+    stage: &str,
+    size: usize,
+    program: &str,
+    import_paths: &ImportPaths,
+    mut logic: impl FnMut(Compiler, &str) -> Duration,
+) {
+    c.bench_with_input(BenchmarkId::new(stage, size), &program, |b, program| {
+        b.iter_custom(|iters| {
+            (0..iters)
+                .map(|_| {
+                    SESSION_GLOBALS.set(&<_>::default(), || {
+                        let handler = Handler::new(Box::new(BufEmitter));
+                        logic(new_compiler(&handler, import_paths.clone()), black_box(program))
+                    })
+                })
+                .sum()
+        });
+    });
+}
+
+/// Returns a program with `transitions` transitions of `statements` statements each.
+fn large_file(transitions: usize, statements: usize) -> String {
+    let mut program = String::from("program test.aleo {\n");
+    for i in 0..transitions {
+        writeln!(program, "    transition t{i}(a: u32, b: u32) -> u32 {{").unwrap();
+        writeln!(program, "        let x0: u32 = a;").unwrap();
+        for j in 1..statements {
+            writeln!(program, "        let x{j}: u32 = x{} * 3u32 + (b ^ {j}u32) - (a & x{}) / 7u32;", j - 1, j - 1)
+                .unwrap();
+        }
+        writeln!(program, "        return x{};\n    }}", statements - 1).unwrap();
+    }
+    program.push_str("}\n");
+    program
+}
+
+/// Writes `depth` programs to a temporary directory, each importing the next two, and returns a program importing
+/// the first two along with the import paths to find them. The programs form a tree of imports `depth` deep, in which
+/// most programs are imported more than once.
+fn deep_imports(depth: usize) -> (String, ImportPaths) {
+    let directory = std::env::temp_dir().join(format!("leo-bench-imports-{}", std::process::id()));
+    fs::create_dir_all(&directory).expect("failed to create the imports directory");
+
+    let program = |name: &str, imports: &[usize]| {
+        let mut program = String::new();
+        for import in imports {
+            writeln!(program, "import p{import}.leo;").unwrap();
+        }
+        writeln!(program, "program {name}.aleo {{\n    transition f(a: u32) -> u32 {{").unwrap();
+        match imports {
+            [] => program.push_str("        return a;\n"),
+            [first, rest @ ..] => {
+                write!(program, "        return p{first}.aleo/f(a)").unwrap();
+                for import in rest {
+                    write!(program, " + p{import}.aleo/f(a)").unwrap();
+                }
+                program.push_str(";\n");
+            }
+        }
+        program.push_str("    }\n}\n");
+        program
+    };
+
+    let children = |i: usize| (i + 1..depth.min(i + 3)).collect::<Vec<_>>();
+    for i in 0..depth {
+        fs::write(directory.join(format!("p{i}.leo")), program(&format!("p{i}"), &children(i)))
+            .expect("failed to write an imported program");
+    }
+
+    let import_paths = ImportPaths { directories: vec![directory], ..Default::default() };
+    (program("test", &[0, 1]), import_paths)
+}
+
+/// Returns a program with `loops` nested loops of `iterations` iterations each.
+fn heavy_unrolling(loops: usize, iterations: usize) -> String {
+    let mut program =
+        String::from("program test.aleo {\n    transition main(a: u32) -> u32 {\n        let x: u32 = a;\n");
+    for i in 0..loops {
+        writeln!(program, "{}for i{i}: u32 in 0u32..{iterations}u32 {{", "    ".repeat(i + 2)).unwrap();
+    }
+    let indices = (0..loops).map(|i| format!("i{i}")).collect::<Vec<_>>().join(" + ");
+    writeln!(program, "{}x = x + ({indices}) * 3u32;", "    ".repeat(loops + 2)).unwrap();
+    for i in (0..loops).rev() {
+        writeln!(program, "{}}}", "    ".repeat(i + 2)).unwrap();
+    }
+    program.push_str("        return x;\n    }\n}\n");
+    program
+}
+
+fn bench_large_file(c: &mut Criterion) {
+    for transitions in [10, 100] {
+        let program = large_file(transitions, 100);
+        bencher(
+            c,
+            "parse large file",
+            transitions * 100,
+            &program,
+            &ImportPaths::default(),
+            |mut compiler, program| {
+                let start = Instant::now();
+                let out = compiler.parse_program_from_string(program, FileName::Custom(String::new()));
+                let time = start.elapsed();
+                out.expect("Failed to parse program");
+                time
+            },
+        );
+    }
+}
+
+fn bench_many_functions(c: &mut Criterion) {
+    for functions in [1_000, 5_000] {
+        let program = ProgramShape::default().with_functions(functions).generate();
+        bencher(c, "type check functions", functions, &program, &ImportPaths::default(), |mut compiler, program| {
+            compiler
+                .parse_program_from_string(program, FileName::Custom(String::new()))
+                .expect("Failed to parse program");
+            let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
+            compiler.name_resolution_pass().expect("failed to resolve names");
+            let start = Instant::now();
+            let out = compiler.type_checker_pass(symbol_table);
+            let time = start.elapsed();
+            out.expect("failed to run type check pass");
+            time
+        });
+    }
+}
+
+fn bench_deep_imports(c: &mut Criterion) {
+    for depth in [8, 16] {
+        let (program, import_paths) = deep_imports(depth);
+        bencher(c, "parse and type check imports", depth, &program, &import_paths, |mut compiler, program| {
+            let start = Instant::now();
+            compiler
+                .parse_program_from_string(program, FileName::Custom(String::new()))
+                .expect("Failed to parse program");
+            let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
+            compiler.name_resolution_pass().expect("failed to resolve names");
+            compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            start.elapsed()
+        });
+    }
+}
+
+fn bench_heavy_unrolling(c: &mut Criterion) {
+    for (loops, iterations) in [(2, 32), (3, 16)] {
+        let program = heavy_unrolling(loops, iterations);
+        let size = iterations.pow(loops as u32);
+        bencher(c, "loop unrolling", size, &program, &ImportPaths::default(), |mut compiler, program| {
+            compiler
+                .parse_program_from_string(program, FileName::Custom(String::new()))
+                .expect("Failed to parse program");
+            let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
+            compiler.name_resolution_pass().expect("failed to resolve names");
+            let (symbol_table, _struct_graph, _call_graph) =
+                compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            let start = Instant::now();
+            let out = compiler.loop_unrolling_pass(symbol_table);
+            let time = start.elapsed();
+            out.expect("failed to run loop unrolling pass");
+            time
+        });
+    }
+}
+
+criterion_group!(
+    name = benches;
+    config = Criterion::default().sample_size(10).measurement_time(Duration::from_secs(10));
+    targets =
+        bench_large_file,
+        bench_many_functions,
+        bench_deep_imports,
+        bench_heavy_unrolling
+);
+criterion_main!(benches);
@@ -22,7 +22,8 @@ use std::{
 use walkdir::WalkDir;
 
 pub fn find_tests(path: &Path) -> impl Iterator<Item = (PathBuf, String)> {
-    WalkDir::new(path).into_iter().flatten().filter_map(move |f| {
+    // Sort the entries so that tests are run and reported in the same order on every platform.
+    WalkDir::new(path).sort_by_file_name().into_iter().flatten().filter_map(move |f| {
         let path = f.path();
         path.extension()
             .filter(|s| *s == "leo")
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Generation of large Leo programs of a given shape, for benchmarks and for tests that catch passes whose running
+//! time grows faster than the size of the program.
+
+use std::fmt::Write;
+
+/// The shape of a generated program.
+///
+/// The program has `functions` inline functions, which take and return an array of `array_len` elements.
+/// The functions form chains of calls `call_depth` long, and the program's one transition calls the first function
+/// of each chain in turn. Each function has `statements` statements, so the transition has about
+/// `functions * statements` statements once every function is inlined.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProgramShape {
+    /// The number of inline functions.
+    pub functions: usize,
+    /// The length of the chains of calls between the functions.
+    pub call_depth: usize,
+    /// The number of elements of the arrays passed between the functions, from 1 to 32.
+    pub array_len: usize,
+    /// The number of statements of each function.
+    pub statements: usize,
+}
+
+impl Default for ProgramShape {
+    fn default() -> Self {
+        Self { functions: 10, call_depth: 1, array_len: 4, statements: 10 }
+    }
+}
+
+impl ProgramShape {
+    /// Returns the shape with `functions` functions.
+    pub fn with_functions(self, functions: usize) -> Self {
+        Self { functions, ..self }
+    }
+
+    /// Returns the shape with chains of calls `call_depth` long.
+    pub fn with_call_depth(self, call_depth: usize) -> Self {
+        Self { call_depth, ..self }
+    }
+
+    /// Returns the shape with arrays of `array_len` elements.
+    pub fn with_array_len(self, array_len: usize) -> Self {
+        Self { array_len, ..self }
+    }
+
+    /// Returns the shape with `statements` statements in each function.
+    pub fn with_statements(self, statements: usize) -> Self {
+        Self { statements, ..self }
+    }
+
+    /// Generates the program `test.aleo` of this shape.
+    pub fn generate(&self) -> String {
+        let functions = self.functions.max(1);
+        let call_depth = self.call_depth.max(1);
+        let array_len = self.array_len.clamp(1, 32);
+        let statements = self.statements.max(1);
+        let array = format!("[u32; {array_len}]");
+
+        let mut program = String::from("program test.aleo {\n");
+        for i in 0..functions {
+            writeln!(program, "    inline f{i}(a: {array}, x: u32) -> {array} {{").unwrap();
+            writeln!(program, "        let x0: u32 = x + {i}u32;").unwrap();
+            for j in 1..statements {
+                writeln!(program, "        let x{j}: u32 = x{} * 3u32 + a[{}u32];", j - 1, j % array_len).unwrap();
+            }
+            let elements = (0..array_len)
+                .map(|k| match k {
+                    0 => format!("a[0u32] + x{}", statements - 1),
+                    _ => format!("a[{k}u32]"),
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            // Each function calls the next one in its chain, unless it is the last one.
+            match (i + 1) % call_depth != 0 && i + 1 < functions {
+                true => writeln!(program, "        return f{}([{elements}], x);", i + 1).unwrap(),
+                false => writeln!(program, "        return [{elements}];").unwrap(),
+            }
+            program.push_str("    }\n");
+        }
+
+        writeln!(program, "    transition main(a: {array}, x: u32) -> {array} {{").unwrap();
+        writeln!(program, "        let r: {array} = a;").unwrap();
+        for i in (0..functions).step_by(call_depth) {
+            writeln!(program, "        r = f{i}(r, x);").unwrap();
+        }
+        program.push_str("        return r;\n    }\n}\n");
+        program
+    }
+}
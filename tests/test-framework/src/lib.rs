@@ -30,6 +30,8 @@ pub mod error;
 
 pub mod fetch;
 
+pub mod generator;
+
 pub mod output;
 
 pub mod runner;